@@ -50,10 +50,11 @@ fn bench_formatted_chainwork_bits(c: &mut Criterion) {
         initialblockdownload: false,
         mediantime: 1609459200, 
         pruned: false,
+        pruneheight: None,
         size_on_disk: 500000000, 
         time: 1609459200, 
         verificationprogress: 0.9999912438474318,
-        warnings: "".to_string(),
+        warnings: Vec::new(),
     };
 
     c.bench_function("formatted_chainwork_bits", |b| {
@@ -72,10 +73,11 @@ fn bench_formatted_difficulty(c: &mut Criterion) {
         initialblockdownload: false,
         mediantime: 1609459200, 
         pruned: false,
+        pruneheight: None,
         size_on_disk: 500000000, 
         time: 1609459200, 
         verificationprogress: 0.9999912438474318,
-        warnings: "".to_string(),
+        warnings: Vec::new(),
     };
 
     c.bench_function("formatted_difficulty", |b| {
@@ -94,10 +96,11 @@ fn bench_parse_mediantime(c: &mut Criterion) {
         initialblockdownload: false,
         mediantime: 1609459200, 
         pruned: false,
+        pruneheight: None,
         size_on_disk: 500000000, 
         time: 1609459200, 
         verificationprogress: 0.9999912438474318,
-        warnings: "".to_string(),
+        warnings: Vec::new(),
     };
 
     c.bench_function("parse_mediantime", |b| {
@@ -116,10 +119,11 @@ fn bench_parse_time(c: &mut Criterion) {
         initialblockdownload: false,
         mediantime: 1609459200, 
         pruned: false,
+        pruneheight: None,
         size_on_disk: 500000000, 
         time: 1609459200, 
         verificationprogress: 0.9999912438474318,
-        warnings: "".to_string(),
+        warnings: Vec::new(),
     };
 
     c.bench_function("parse_time", |b| {
@@ -138,10 +142,11 @@ fn bench_calculate_time_diff(c: &mut Criterion) {
         initialblockdownload: false,
         mediantime: 1609459200, 
         pruned: false,
+        pruneheight: None,
         size_on_disk: 500000000, 
         time: 1609459200, 
         verificationprogress: 0.9999912438474318,
-        warnings: "".to_string(),
+        warnings: Vec::new(),
     };
 
     c.bench_function("calculate_time_diff", |b| {
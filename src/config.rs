@@ -18,12 +18,23 @@
 //!
 //! This hybrid strategy allows the dashboard to run **non-interactively** (ideal for systemd)
 //! or **interactively** (ideal for first-time local users).
+//!
+//! While the dashboard runs, the config file is watched and re-read on change
+//! (`reload_config`); tasks pick up the new values through `LiveConfig`.
 
 use std::fs;
 use std::env;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant, SystemTime};
+use once_cell::sync::Lazy;
 use std::io::{self, IsTerminal};
 use crate::models::errors::MyError;
+use crate::models::peer_info::PropagationUnit;
+use crate::models::mempool_sampler::MempoolSampling;
+use crate::utils::SizeUnits;
+use crate::models::chaintips_info::DEFAULT_FORK_ALERT_BRANCHLEN;
+use crate::ui::colors::{parse_color, Theme};
 use crate::utils::get_rpc_password_from_keychain;
 
 use serde::{Deserialize, Serialize};
@@ -33,7 +44,31 @@ use serde::{Deserialize, Serialize};
 /// ### Fields
 /// - `username` — RPC user  
 /// - `password` — RPC password (may be loaded from Keychain)  
+/// - `cookie_path` — Bitcoin Core `.cookie` file; overrides `username`/`password`  
+/// - `proxy` — proxy URL for RPC traffic, e.g. `socks5h://127.0.0.1:9050` for Tor  
+/// - `request_timeout_secs` — total RPC request timeout (default `10`, `60` via proxy; `0` disables)  
+/// - `connect_timeout_secs` — RPC connect timeout (default `5`, `30` via proxy; `0` disables)  
 /// - `address` — RPC endpoint such as `http://127.0.0.1:8332`  
+/// - `read_only` — safe mode; blocks state-changing or expensive RPCs (default `false`)  
+/// - `propagation_unit` — `"seconds"` (default) or `"raw_ms"` for block propagation  
+/// - `size_units` — `"binary"` (default, KiB/MiB/GiB) or `"decimal"` (kB/MB/GB) for byte sizes  
+/// - `mempool_sampling` — `"full"` (default) or `"sampled"` (~5% of entries) for the mempool distribution  
+/// - `mempool_fetch_concurrency` — parallel `getmempoolentry` calls for the distribution (default `10`, clamped to `1..=256`)  
+/// - `poll_jitter_pct` — ±% randomization of RPC polling intervals (default `10`, max `50`)  
+/// - `watch_txids` — txids to track from mempool to confirmation (default empty)  
+/// - `watch_bell` — ring the terminal bell when a watched tx confirms (default `false`)  
+/// - `init_timeout_secs` — max wait on the startup screen for first data (default `30`, `0` skips)  
+/// - `tx_stats_window_blocks` — block window for the tx/s throughput line (default `144`)  
+/// - `geoip_db` — MaxMind-format `.mmdb` file for peer country/ASN (default none)  
+/// - `miners_json` — miner payout-address table (default `miners.json` in the data directory, then the current directory)  
+/// - `fork_alert_branchlen` — valid-fork branch length that raises a fork alert (default `2`, min `1`)  
+/// - `reorg_alarm` — ring the terminal bell when a reorg is detected (default `false`)  
+/// - `reorg_alarm_depth` — minimum disconnected blocks for the reorg alarm (default `1`, min `1`)  
+/// - `stale_tip_alert_mins` — minutes without a new block before the time-since-block line flashes (default `30`, `0` disables)  
+/// - `stale_tip_bell` — also ring the terminal bell when that threshold is crossed (default `false`)  
+/// - `intervals` — per-section polling intervals, from the `[intervals]` table (see `PollIntervals`)  
+/// - `theme` — color overrides, from the `[theme]` table (see `ThemeConfig`)  
+/// - `layout` — section heights and visibility, from the `[layout]` table (see `LayoutConfig`)  
 ///
 /// This struct can be loaded from TOML or constructed interactively.
 /// `Serialize` is implemented so that missing config files can be
@@ -41,12 +76,428 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct RpcConfig {
+    #[serde(default)]
     pub username: String,
+    #[serde(default)]
     pub password: String,
     pub address: String,
+    #[serde(default)]
+    pub cookie_path: Option<String>,
+    #[serde(default)]
+    pub proxy: Option<String>,
+    #[serde(default)]
+    pub request_timeout_secs: Option<u64>,
+    #[serde(default)]
+    pub connect_timeout_secs: Option<u64>,
+    #[serde(default)]
+    pub read_only: bool,
+    #[serde(default)]
+    pub propagation_unit: PropagationUnit,
+    #[serde(default)]
+    pub size_units: SizeUnits,
+    #[serde(default)]
+    pub mempool_sampling: MempoolSampling,
+    #[serde(default = "default_mempool_fetch_concurrency")]
+    pub mempool_fetch_concurrency: usize,
+    #[serde(default = "default_poll_jitter_pct")]
+    pub poll_jitter_pct: u8,
+    #[serde(default)]
+    pub watch_txids: Vec<String>,
+    #[serde(default)]
+    pub watch_bell: bool,
+    #[serde(default = "default_init_timeout_secs")]
+    pub init_timeout_secs: u64,
+    #[serde(default)]
+    pub tx_stats_window_blocks: Option<u32>,
+    #[serde(default)]
+    pub geoip_db: Option<String>,
+    #[serde(default)]
+    pub miners_json: Option<String>,
+    #[serde(default = "default_fork_alert_branchlen")]
+    pub fork_alert_branchlen: u64,
+    #[serde(default)]
+    pub reorg_alarm: bool,
+    #[serde(default = "default_reorg_alarm_depth")]
+    pub reorg_alarm_depth: u64,
+    #[serde(default = "default_stale_tip_alert_mins")]
+    pub stale_tip_alert_mins: u64,
+    #[serde(default)]
+    pub stale_tip_bell: bool,
+    #[serde(default)]
+    pub intervals: PollIntervals,
+    #[serde(default)]
+    pub theme: ThemeConfig,
+    #[serde(default)]
+    pub layout: LayoutConfig,
+}
+
+/// Polling interval, in seconds, for each background RPC task.
+///
+/// Read from an optional `[intervals]` table; any section left out keeps
+/// its default. Slower values reduce load on small nodes (e.g. a
+/// Raspberry Pi). Every interval must be at least 1 second.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct PollIntervals {
+    pub blockchain: u64,
+    pub mempool: u64,
+    pub mempool_distribution: u64,
+    pub network: u64,
+    pub peers: u64,
+    pub net_totals: u64,
+    pub chain_tips: u64,
+    pub block_stats: u64,
+    pub mining_info: u64,
+    pub chain_tx_stats: u64,
+    pub deployments: u64,
+    pub fee_estimates: u64,
+    pub uptime: u64,
+}
+
+impl Default for PollIntervals {
+    fn default() -> Self {
+        Self {
+            blockchain: 2,
+            mempool: 3,
+            mempool_distribution: 2,
+            network: 7,
+            peers: 7,
+            net_totals: 7,
+            chain_tips: 10,
+            block_stats: 5,
+            mining_info: 15,
+            chain_tx_stats: 30,
+            deployments: 60,
+            fee_estimates: 30,
+            uptime: 30,
+        }
+    }
+}
+
+impl PollIntervals {
+    /// Every interval as `(section name, seconds)`.
+    pub fn entries(&self) -> [(&'static str, u64); 13] {
+        [
+            ("blockchain", self.blockchain),
+            ("mempool", self.mempool),
+            ("mempool_distribution", self.mempool_distribution),
+            ("network", self.network),
+            ("peers", self.peers),
+            ("net_totals", self.net_totals),
+            ("chain_tips", self.chain_tips),
+            ("block_stats", self.block_stats),
+            ("mining_info", self.mining_info),
+            ("chain_tx_stats", self.chain_tx_stats),
+            ("deployments", self.deployments),
+            ("fee_estimates", self.fee_estimates),
+            ("uptime", self.uptime),
+        ]
+    }
+
+    /// Rejects intervals below 1 second.
+    pub fn validate(&self) -> Result<(), MyError> {
+        match self.entries().iter().find(|(_, secs)| *secs < 1) {
+            Some((name, secs)) => Err(MyError::Config(format!(
+                "intervals.{} must be at least 1 second (got {})",
+                name, secs
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Color overrides per semantic group, from an optional `[theme]` table.
+///
+/// Values are tui color names (`"black"`, `"dark_gray"`, `"light_blue"`, …)
+/// or `#rrggbb` hex. Groups left out keep the built-in palette. Applied
+/// at startup only.
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ThemeConfig {
+    /// Field labels and section titles.
+    pub labels: Option<String>,
+    /// Plain values next to labels.
+    pub values: Option<String>,
+    /// Separators, borders and dimmed text.
+    pub separators: Option<String>,
+    /// Chart bars, gauges and sparklines.
+    pub bars: Option<String>,
+    /// Medium-severity status.
+    pub warning: Option<String>,
+    /// High-severity status.
+    pub critical: Option<String>,
 }
 
+impl ThemeConfig {
+    /// Parses every configured color; unknown names are a config error.
+    pub fn to_theme(&self) -> Result<Theme, MyError> {
+        let parse = |name: &str, value: &Option<String>| -> Result<_, MyError> {
+            value
+                .as_deref()
+                .map(|v| {
+                    parse_color(v).ok_or_else(|| {
+                        MyError::Config(format!(
+                            "theme.{}: '{}' is not a color name or #rrggbb",
+                            name, v
+                        ))
+                    })
+                })
+                .transpose()
+        };
+
+        Ok(Theme {
+            labels: parse("labels", &self.labels)?,
+            values: parse("values", &self.values)?,
+            separators: parse("separators", &self.separators)?,
+            bars: parse("bars", &self.bars)?,
+            warning: parse("warning", &self.warning)?,
+            critical: parse("critical", &self.critical)?,
+        })
+    }
+}
+
+/// A dashboard section whose height and visibility can be configured.
+///
+/// The header and footer are always shown.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DashboardSection {
+    Blockchain,
+    Mempool,
+    Network,
+    Consensus,
+}
+
+impl DashboardSection {
+    /// Key of the section in the `[layout]` table.
+    pub fn name(self) -> &'static str {
+        match self {
+            DashboardSection::Blockchain => "blockchain",
+            DashboardSection::Mempool => "mempool",
+            DashboardSection::Network => "network",
+            DashboardSection::Consensus => "consensus",
+        }
+    }
+}
+
+/// Height (rows, borders included) and visibility of each dashboard section.
+///
+/// Read from an optional `[layout]` table; sections left out keep their
+/// default height. Sections listed in `hidden` are not drawn, which frees
+/// their rows on short terminals (laptops, tmux panes). On a screen
+/// shorter than `total_height`, Consensus and then Network collapse first
+/// (see `ui::layout`). Applied on hot reload.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct LayoutConfig {
+    pub blockchain: u16,
+    pub mempool: u16,
+    pub network: u16,
+    pub consensus: u16,
+    pub hidden: Vec<DashboardSection>,
+}
+
+impl Default for LayoutConfig {
+    fn default() -> Self {
+        Self {
+            blockchain: 17,
+            mempool: 30,
+            network: 16,
+            consensus: 7,
+            hidden: Vec::new(),
+        }
+    }
+}
+
+impl LayoutConfig {
+    /// Smallest section height: both borders plus one row of content.
+    pub const MIN_SECTION_HEIGHT: u16 = 3;
+    /// Largest section height.
+    pub const MAX_SECTION_HEIGHT: u16 = 100;
+    /// Rows outside the sections: outer margin (2), header (3) and footer (1).
+    pub const FIXED_ROWS: u16 = 6;
+
+    /// Every section with its configured height, top to bottom.
+    pub fn sections(&self) -> [(DashboardSection, u16); 4] {
+        [
+            (DashboardSection::Blockchain, self.blockchain),
+            (DashboardSection::Mempool, self.mempool),
+            (DashboardSection::Network, self.network),
+            (DashboardSection::Consensus, self.consensus),
+        ]
+    }
+
+    /// Whether `section` is drawn.
+    pub fn is_visible(&self, section: DashboardSection) -> bool {
+        !self.hidden.contains(&section)
+    }
+
+    /// Terminal rows needed to show every visible section at full height.
+    pub fn total_height(&self) -> u16 {
+        Self::FIXED_ROWS
+            + self
+                .sections()
+                .iter()
+                .filter(|(section, _)| self.is_visible(*section))
+                .map(|(_, height)| height)
+                .sum::<u16>()
+    }
+
+    /// Rejects heights outside `MIN_SECTION_HEIGHT..=MAX_SECTION_HEIGHT`.
+    ///
+    /// Hidden sections are checked too, so un-hiding one later can't
+    /// produce a broken layout.
+    pub fn validate(&self) -> Result<(), MyError> {
+        let range = Self::MIN_SECTION_HEIGHT..=Self::MAX_SECTION_HEIGHT;
+        match self.sections().iter().find(|(_, height)| !range.contains(height)) {
+            Some((section, height)) => Err(MyError::Config(format!(
+                "layout.{} must be between {} and {} rows (got {})",
+                section.name(),
+                Self::MIN_SECTION_HEIGHT,
+                Self::MAX_SECTION_HEIGHT,
+                height
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// On-disk layout: `[bitcoin_rpc]` plus the optional top-level `[intervals]`,
+/// `[theme]` and `[layout]` tables.
+#[derive(Deserialize)]
+struct ConfigFile {
+    bitcoin_rpc: RpcConfig,
+    #[serde(default)]
+    intervals: Option<PollIntervals>,
+    #[serde(default)]
+    theme: Option<ThemeConfig>,
+    #[serde(default)]
+    layout: Option<LayoutConfig>,
+}
+
+/// Parses a config file.
+///
+/// Accepts the documented `[bitcoin_rpc]` layout (with `[intervals]`,
+/// `[theme]` and `[layout]` as sibling tables) as well as a flat file with
+/// the RPC keys at top level.
+pub fn parse_config_str(config_str: &str) -> Result<RpcConfig, MyError> {
+    let table: toml::Table = toml::from_str(config_str)?;
+
+    if table.contains_key("bitcoin_rpc") {
+        let file: ConfigFile = toml::from_str(config_str)?;
+        let mut config = file.bitcoin_rpc;
+        if let Some(intervals) = file.intervals {
+            config.intervals = intervals;
+        }
+        if let Some(theme) = file.theme {
+            config.theme = theme;
+        }
+        if let Some(layout) = file.layout {
+            config.layout = layout;
+        }
+        Ok(config)
+    } else {
+        Ok(toml::from_str(config_str)?)
+    }
+}
+
+/// Defaults for every optional setting, matching the `#[serde(default...)]`
+/// attributes; `address` and the credentials are left empty.
+impl Default for RpcConfig {
+    fn default() -> Self {
+        Self {
+            username: String::new(),
+            password: String::new(),
+            address: String::new(),
+            cookie_path: None,
+            proxy: None,
+            request_timeout_secs: None,
+            connect_timeout_secs: None,
+            read_only: false,
+            propagation_unit: PropagationUnit::default(),
+            size_units: SizeUnits::default(),
+            mempool_sampling: MempoolSampling::default(),
+            mempool_fetch_concurrency: default_mempool_fetch_concurrency(),
+            poll_jitter_pct: default_poll_jitter_pct(),
+            watch_txids: Vec::new(),
+            watch_bell: false,
+            init_timeout_secs: default_init_timeout_secs(),
+            tx_stats_window_blocks: None,
+            geoip_db: None,
+            miners_json: None,
+            fork_alert_branchlen: default_fork_alert_branchlen(),
+            reorg_alarm: false,
+            reorg_alarm_depth: default_reorg_alarm_depth(),
+            stale_tip_alert_mins: default_stale_tip_alert_mins(),
+            stale_tip_bell: false,
+            intervals: PollIntervals::default(),
+            theme: ThemeConfig::default(),
+            layout: LayoutConfig::default(),
+        }
+    }
+}
+
+/// Default polling jitter: ±10%.
+fn default_poll_jitter_pct() -> u8 {
+    10
+}
+
+/// Default parallel `getmempoolentry` calls: 10.
+fn default_mempool_fetch_concurrency() -> usize {
+    10
+}
+
+/// Default wait for first data on the startup screen: 30 seconds.
+fn default_init_timeout_secs() -> u64 {
+    30
+}
+
+fn default_fork_alert_branchlen() -> u64 {
+    DEFAULT_FORK_ALERT_BRANCHLEN
+}
+
+fn default_reorg_alarm_depth() -> u64 {
+    1
+}
+
+/// Default stale-tip alert threshold: 30 minutes without a block.
+fn default_stale_tip_alert_mins() -> u64 {
+    30
+}
+
+/// Upper bound for `poll_jitter_pct`; larger spreads make pacing erratic.
+const MAX_POLL_JITTER_PCT: u8 = 50;
+
+/// Upper bound for `mempool_fetch_concurrency`.
+pub const MAX_MEMPOOL_FETCH_CONCURRENCY: usize = 256;
+
 impl RpcConfig {
+    /// Username and password for RPC basic auth.
+    ///
+    /// With `cookie_path` set, the cookie file is re-read whenever it
+    /// changes (Core rotates it on every restart), so reconnects after a
+    /// node restart pick up the new secret. The change check runs at most
+    /// once per `COOKIE_RECHECK_INTERVAL`, not on every request. If the file can't be read,
+    /// the last known credentials are used.
+    pub fn credentials(&self) -> (String, String) {
+        if let Some(path) = &self.cookie_path {
+            if let Some(creds) = cached_cookie(Path::new(path)) {
+                return creds;
+            }
+        }
+        (self.username.clone(), self.password.clone())
+    }
+
+    /// Permits for the mempool distribution's `getmempoolentry` fan-out:
+    /// `mempool_fetch_concurrency` clamped to `1..=MAX_MEMPOOL_FETCH_CONCURRENCY`.
+    ///
+    /// More permits warm the distribution up faster on a strong local node;
+    /// fewer keep a Tor link or a small node (e.g. a Raspberry Pi) from
+    /// being flooded with parallel requests.
+    pub fn mempool_fetch_permits(&self) -> usize {
+        self.mempool_fetch_concurrency.clamp(1, MAX_MEMPOOL_FETCH_CONCURRENCY)
+    }
+
     /// Attempts to fetch the RPC password securely from macOS Keychain.
     ///
     /// This allows the user to avoid storing credentials on disk.
@@ -56,6 +507,63 @@ impl RpcConfig {
     }
 }
 
+/// How long a cookie read is trusted before the file's mtime is checked
+/// again. Keeps the mempool fan-out from stat-ing the file per request;
+/// Core takes far longer than this to restart and rotate it.
+const COOKIE_RECHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Last cookie read: path, file mtime, when that mtime was last checked,
+/// and `(user, password)`.
+type CookieCache = Option<(PathBuf, SystemTime, Instant, (String, String))>;
+
+static COOKIE_CACHE: Lazy<Mutex<CookieCache>> = Lazy::new(|| Mutex::new(None));
+
+/// Returns the cookie credentials, re-reading the file only if its
+/// modification time changed since the last read. The mtime itself is
+/// checked at most once per `COOKIE_RECHECK_INTERVAL`.
+fn cached_cookie(path: &Path) -> Option<(String, String)> {
+    let mut cache = COOKIE_CACHE.lock().unwrap();
+
+    if let Some((cached_path, _, checked_at, creds)) = cache.as_ref() {
+        if cached_path == path && checked_at.elapsed() < COOKIE_RECHECK_INTERVAL {
+            return Some(creds.clone());
+        }
+    }
+
+    let modified = fs::metadata(path).and_then(|m| m.modified()).ok()?;
+
+    if let Some((cached_path, cached_mtime, checked_at, creds)) = cache.as_mut() {
+        if cached_path == path && *cached_mtime == modified {
+            *checked_at = Instant::now();
+            return Some(creds.clone());
+        }
+    }
+
+    let creds = read_cookie_file(path).ok()?;
+    *cache = Some((path.to_path_buf(), modified, Instant::now(), creds.clone()));
+    Some(creds)
+}
+
+/// Parses a Bitcoin Core cookie file (`__cookie__:<password>`).
+///
+/// Splits on the first `:`; the password itself may contain colons.
+pub fn read_cookie_file(path: &Path) -> Result<(String, String), MyError> {
+    let contents = fs::read_to_string(path).map_err(|e| {
+        MyError::Config(format!("Cannot read cookie file '{}': {}", path.display(), e))
+    })?;
+
+    let line = contents.lines().next().unwrap_or("").trim();
+    match line.split_once(':') {
+        Some((user, pass)) if !user.is_empty() && !pass.is_empty() => {
+            Ok((user.to_string(), pass.to_string()))
+        }
+        _ => Err(MyError::Config(format!(
+            "Malformed cookie file '{}': expected `user:password`",
+            path.display()
+        ))),
+    }
+}
+
 /// Determine the path to a config file based on:
 /// 1. `--config` CLI argument  
 /// 2. `BLOCKCHAININFO_CONFIG` environment variable  
@@ -63,7 +571,7 @@ impl RpcConfig {
 ///
 /// This resolution order mirrors typical Unix tool behavior and makes
 /// the dashboard easy to embed in automated systems.
-fn get_config_path() -> String {
+pub fn get_config_path() -> String {
     // --- 1. CLI argument: --config <path> ---
     let args: Vec<String> = env::args().collect();
     if let Some(pos) = args.iter().position(|arg| arg == "--config") {
@@ -88,6 +596,9 @@ fn get_config_path() -> String {
 /// If a config file exists at the chosen path:
 /// - Parse TOML → produce `RpcConfig`
 ///
+/// Else, if `RPC_COOKIE_PATH` is set:
+/// - Use cookie-file auth (with `RPC_ADDRESS` or the default local port)
+///
 /// Else:
 /// - Attempt to load values from ENV:
 ///     - `RPC_USER`  
@@ -109,9 +620,18 @@ pub fn load_config() -> Result<RpcConfig, MyError> {
     // --- Path 1: Load existing config file ---
     let config: RpcConfig = if Path::new(&file_path).exists() {
         let config_str = fs::read_to_string(file_path)?;
-        toml::from_str(&config_str)?
+        parse_config_str(&config_str)?
+    } else if let Ok(cookie_path) = env::var("RPC_COOKIE_PATH") {
+        // --- Path 2: Cookie auth from ENV → no credential prompts ---
+        let address = env::var("RPC_ADDRESS").unwrap_or_else(|_| "http://127.0.0.1:8332".to_string());
+
+        RpcConfig {
+            address,
+            cookie_path: Some(cookie_path),
+            ..RpcConfig::default()
+        }
     } else {
-        // --- Path 2: No config found → fallback to ENV or interactive prompts ---
+        // --- Path 3: No config found → fallback to ENV or interactive prompts ---
 
         // RPC username
         let username = env::var("RPC_USER").unwrap_or_else(|_| {
@@ -132,7 +652,12 @@ pub fn load_config() -> Result<RpcConfig, MyError> {
             input.trim().to_string()
         });
 
-        let config = RpcConfig { username, password, address };
+        let config = RpcConfig {
+            username,
+            password,
+            address,
+            ..RpcConfig::default()
+        };
 
         // Auto-save config.toml only when NO env variables were set.
         if env::var("RPC_USER").is_err()
@@ -149,9 +674,148 @@ pub fn load_config() -> Result<RpcConfig, MyError> {
         config
     };
 
+    finalize_config(config)
+}
+
+/// Re-reads the config file at `path` for hot reload.
+///
+/// Unlike `load_config` this never falls back to environment variables or
+/// prompts: the file must exist and parse. The result goes through the
+/// same validation as at startup, plus the credential/address check from
+/// `main`, so a bad edit is rejected and the running config is kept.
+pub fn reload_config(path: &Path) -> Result<RpcConfig, MyError> {
+    let config_str = fs::read_to_string(path)?;
+    let config = finalize_config(parse_config_str(&config_str)?)?;
+
+    let has_credentials = config.cookie_path.is_some()
+        || (!config.username.is_empty() && !config.password.is_empty());
+    if !has_credentials || config.address.is_empty() {
+        return Err(MyError::Config(
+            "address and credentials (or cookie_path) are required".to_string(),
+        ));
+    }
+
     Ok(config)
 }
 
+/// Applies cookie credentials and validates a freshly loaded config.
+fn finalize_config(mut config: RpcConfig) -> Result<RpcConfig, MyError> {
+    // Cookie auth takes precedence over configured user/password. A missing
+    // file (node not started yet) is not fatal: `credentials()` retries
+    // on every request.
+    if let Some(path) = &config.cookie_path {
+        if let Ok((user, pass)) = read_cookie_file(Path::new(path)) {
+            config.username = user;
+            config.password = pass;
+        }
+    }
+
+    // A malformed address would otherwise surface as cryptic request errors.
+    config.address = normalize_rpc_address(&config.address)?;
+
+    // Reject a bad proxy now rather than failing every request later.
+    if let Some(proxy) = &config.proxy {
+        reqwest::Proxy::all(proxy.as_str()).map_err(|e| {
+            MyError::Config(format!("Invalid proxy '{}': {}", proxy, e))
+        })?;
+    }
+
+    if config.poll_jitter_pct > MAX_POLL_JITTER_PCT {
+        return Err(MyError::Config(format!(
+            "poll_jitter_pct must be between 0 and {} (got {})",
+            MAX_POLL_JITTER_PCT, config.poll_jitter_pct
+        )));
+    }
+
+    if config.fork_alert_branchlen == 0 {
+        return Err(MyError::Config(
+            "fork_alert_branchlen must be at least 1".to_string(),
+        ));
+    }
+
+    if config.reorg_alarm_depth == 0 {
+        return Err(MyError::Config(
+            "reorg_alarm_depth must be at least 1".to_string(),
+        ));
+    }
+
+    config.intervals.validate()?;
+    config.theme.to_theme()?;
+    config.layout.validate()?;
+
+    if let Some(bad) = config
+        .watch_txids
+        .iter()
+        .find(|t| t.len() != 64 || !t.chars().all(|c| c.is_ascii_hexdigit()))
+    {
+        return Err(MyError::Config(format!(
+            "watch_txids: '{}' is not a 64-character hex txid",
+            bad
+        )));
+    }
+
+    Ok(config)
+}
+
+/// Checks that `address` is a usable RPC URL and normalizes it.
+///
+/// A bare `host:port` (e.g. `127.0.0.1:8332`) gets an `http://` scheme;
+/// anything else is kept as written, minus surrounding whitespace.
+///
+/// ### Errors
+/// - Not parseable as a URL (bad host, port out of range, …)
+/// - Scheme other than `http` / `https`
+/// - Missing host, or port 0
+pub fn normalize_rpc_address(address: &str) -> Result<String, MyError> {
+    let address = address.trim();
+    let address = if address.contains("://") {
+        address.to_string()
+    } else {
+        format!("http://{}", address)
+    };
+
+    let invalid = |reason: String| {
+        MyError::Config(format!("address '{}' is not a valid RPC URL: {}", address, reason))
+    };
+
+    let url = reqwest::Url::parse(&address).map_err(|e| invalid(e.to_string()))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(invalid(format!("scheme must be http or https, not '{}'", url.scheme())));
+    }
+    if url.host_str().is_none_or(str::is_empty) {
+        return Err(invalid("missing host".to_string()));
+    }
+    if url.port() == Some(0) {
+        return Err(invalid("port 0".to_string()));
+    }
+
+    Ok(address)
+}
+
+/// The active `RpcConfig`, shared with every polling task and swapped on
+/// hot reload.
+///
+/// Tasks call `current()` once per cycle, so a reload takes effect on
+/// each task's next iteration.
+#[derive(Clone)]
+pub struct LiveConfig(Arc<RwLock<Arc<RpcConfig>>>);
+
+impl LiveConfig {
+    pub fn new(config: RpcConfig) -> Self {
+        Self(Arc::new(RwLock::new(Arc::new(config))))
+    }
+
+    /// Snapshot of the active config.
+    pub fn current(&self) -> Arc<RpcConfig> {
+        self.0.read().unwrap().clone()
+    }
+
+    /// Makes `config` the active config.
+    pub fn replace(&self, config: RpcConfig) {
+        *self.0.write().unwrap() = Arc::new(config);
+    }
+}
+
 fn resolve_rpc_password() -> Result<String, MyError> {
     // 1) ENV
     if let Ok(p) = std::env::var("RPC_PASSWORD") {
@@ -49,6 +49,53 @@ pub const DIFFICULTY_PERIOD_DAYS: u64 = 14;
 pub const DIFFICULTY_ADJUSTMENT_INTERVAL: u64 =
     BLOCKS_PER_HOUR * HOURS_PER_DAY * DIFFICULTY_PERIOD_DAYS;
 
+// -------------------------------------------------------------
+// Coinbase Maturity
+//
+// Newly minted coins cannot be spent until their block is buried
+// 100 blocks deep, so a reorg can't invalidate spends of coins
+// that no longer exist. Core: COINBASE_MATURITY = 100.
+// -------------------------------------------------------------
+
+/// Confirmations a coinbase output needs before it can be spent
+/// in the next block.
+pub const COINBASE_MATURITY: u64 = 100;
+
+// -------------------------------------------------------------
+// Block Subsidy
+//
+// New coins enter circulation through the coinbase. The first
+// blocks paid 50 BTC, and the amount halves every 210,000 blocks
+// (about four years at 10-minute blocks). Core: GetBlockSubsidy().
+// -------------------------------------------------------------
+
+/// Satoshis in one bitcoin (Core: COIN).
+pub const SATS_PER_BTC: u64 = 100_000_000;
+
+/// Subsidy of the genesis era, in whole bitcoin.
+pub const INITIAL_SUBSIDY_BTC: u64 = 50;
+
+/// Blocks between subsidy halvings (Core: nSubsidyHalvingInterval).
+pub const HALVING_INTERVAL: u64 = 210_000;
+
+/// Subsidy (satoshis) a block at `height` may claim.
+///
+/// Each halving is a right shift; after 64 of them the shift would be
+/// undefined, and the subsidy is zero anyway.
+pub fn block_subsidy(height: u64) -> u64 {
+    let halvings = height / HALVING_INTERVAL;
+    if halvings >= 64 {
+        return 0;
+    }
+    (INITIAL_SUBSIDY_BTC * SATS_PER_BTC) >> halvings
+}
+
+/// Blocks after `height` until the next halving block, the first to pay
+/// the reduced subsidy.
+pub fn blocks_until_halving(height: u64) -> u64 {
+    HALVING_INTERVAL - height % HALVING_INTERVAL
+}
+
 // -------------------------------------------------------------
 // Notes:
 // - These values are consensus truths, not configurable settings.
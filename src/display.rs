@@ -7,6 +7,7 @@
 //! - `display_mempool_info`           → Mempool metrics + fee distribution
 //! - `display_network_info`           → Network connections, peers, versions, clients
 //! - `display_consensus_security_info`→ Fork awareness and chain tip health
+//! - `display_peer_list`              → Per-peer table and detail (popups)
 //!
 //! The functions here act as thin wrappers around the internal renderers,
 //! keeping the `runapp` module clean while preserving clear boundaries
@@ -33,17 +34,31 @@ pub mod display_mempool_info;
 pub mod display_network_info;
 /// Consensus security / fork monitoring renderer.
 pub mod display_consensus_security_info;
+/// Peer list popup renderer.
+pub mod display_peer_list;
 
 use tui::backend::Backend;
 use tui::Frame;
 use tui::layout::Rect;
 
 use crate::models::block_info::BlockInfo;
+use crate::display::display_network_info::{DistributionView, NetworkView};
+use crate::models::mining_info::MiningInfo;
+use crate::models::block_stats::BlockStats;
+use crate::models::chain_tx_stats::ChainTxStats;
+use crate::models::block_intervals::BlockIntervals;
+use crate::models::disk_growth::DiskGrowth;
+use crate::models::witness_adoption::WitnessAdoption;
+use crate::models::coinbase_reward::CoinbaseReward;
+use crate::utils::SizeUnits;
+use crate::models::deployment_info::DeploymentInfo;
 use crate::models::blockchain_info::BlockchainInfo;
 use crate::models::chaintips_info::ChainTip;
 use crate::models::mempool_info::{MempoolDistribution, MempoolInfo};
 use crate::models::network_info::NetworkInfo;
 use crate::models::network_totals::NetTotals;
+use crate::models::peer_info::{PeerInfo, PropagationUnit};
+use crate::geoip::GeoIp;
 
 use std::collections::VecDeque;
 use std::sync::Arc;
@@ -52,11 +67,21 @@ use std::sync::Arc;
 /// verification progress, latest miner, etc.
 ///
 /// Internally delegates to `display_blockchain_info::display_blockchain_info`.
+#[allow(clippy::too_many_arguments)]
 pub fn display_blockchain_info<B: Backend>(
     blockchain_info: &BlockchainInfo,
     block_info: &BlockInfo,
     block24_info: &BlockInfo,
     last_miner: &Arc<str>,
+    mining_info: &MiningInfo,
+    last_block_stats: &BlockStats,
+    witness_adoption: &WitnessAdoption,
+    coinbase_reward: &CoinbaseReward,
+    chain_tx_stats: &ChainTxStats,
+    block_intervals: &BlockIntervals,
+    disk_growth: &DiskGrowth,
+    size_units: SizeUnits,
+    stale_tip: bool,
     frame: &mut Frame<B>,
     area: Rect,
 ) {
@@ -65,6 +90,15 @@ pub fn display_blockchain_info<B: Backend>(
         block_info,
         block24_info,
         last_miner,
+        mining_info,
+        last_block_stats,
+        witness_adoption,
+        coinbase_reward,
+        chain_tx_stats,
+        block_intervals,
+        disk_growth,
+        size_units,
+        stale_tip,
         frame,
         area,
     );
@@ -76,11 +110,13 @@ pub fn display_blockchain_info<B: Backend>(
 /// from depending on internal module paths.
 pub fn render_hashrate_distribution_chart<B: Backend>(
     distribution: &Vec<(Arc<str>, u64)>,
+    syncing: bool,
     frame: &mut Frame<B>,
     area: Rect,
 ) {
     let _ = display_blockchain_info::render_hashrate_distribution_chart(
         distribution,
+        syncing,
         frame,
         area,
     );
@@ -103,13 +139,18 @@ pub fn draw_last20_miners<B: Backend>(
 }
 
 /// Render the `[Mempool]` section: mempool stats, fee distribution,
-/// dust filtering mode, etc.
+/// dust filtering mode, and the confirmed fee-rate trend sparkline.
 ///
 /// Delegates to `display_mempool_info::display_mempool_info`.
+#[allow(clippy::too_many_arguments)]
 pub fn display_mempool_info<B: Backend>(
     mempool_info: &MempoolInfo,
     distribution: &MempoolDistribution,
     dust_free: bool,
+    size_by_vsize: bool,
+    fee_rate_trend: &[u64],
+    fee_estimates: &[(u16, f64)],
+    size_units: SizeUnits,
     frame: &mut Frame<B>,
     area: Rect,
 ) {
@@ -117,6 +158,10 @@ pub fn display_mempool_info<B: Backend>(
         mempool_info,
         distribution,
         dust_free,
+        size_by_vsize,
+        fee_rate_trend,
+        fee_estimates,
+        size_units,
         frame,
         area,
     );
@@ -124,15 +169,20 @@ pub fn display_mempool_info<B: Backend>(
 
 /// Render the `[Network]` section: node info, version/client distribution charts,
 /// peer count, data in/out, block propagation, and optional client distribution view.
+#[allow(clippy::too_many_arguments)]
 pub fn display_network_info<B: Backend>(
     network_info: &NetworkInfo,
     net_totals: &NetTotals,
     frame: &mut Frame<B>,
-    version_counts: &[(String, usize)],
-    client_counts: &[(String, usize)],
+    view: NetworkView,
     avg_block_propagate_time: &i64,
     propagation_times: &VecDeque<i64>,
-    show_client_distribution: bool,
+    propagation_unit: PropagationUnit,
+    size_units: SizeUnits,
+    node_warnings: &[String],
+    blocks_behind: Option<u64>,
+    clock_skew: Option<i64>,
+    distribution_view: DistributionView,
     show_propagation_avg: bool,
     area: Rect,
 ) {
@@ -140,11 +190,15 @@ pub fn display_network_info<B: Backend>(
         network_info,
         net_totals,
         frame,
-        version_counts,
-        client_counts,
+        view,
         avg_block_propagate_time,
         propagation_times,
-        show_client_distribution,
+        propagation_unit,
+        size_units,
+        node_warnings,
+        blocks_behind,
+        clock_skew,
+        distribution_view,
         show_propagation_avg,
         area,
     );
@@ -156,10 +210,36 @@ pub fn display_network_info<B: Backend>(
 /// Delegates to `display_consensus_security_info`.
 pub fn display_consensus_security_info<B: Backend>(
     chaintips_info: &Vec<ChainTip>,
+    deployment_info: Option<&DeploymentInfo>,
     frame: &mut Frame<B>,
     area: Rect,
 ) {
     let _ = display_consensus_security_info::display_consensus_security_info(
-        chaintips_info, frame, area,
+        chaintips_info, deployment_info, frame, area,
     );
 }
+
+/// Render the Peer List popup: one scrollable row per connected peer.
+///
+/// Delegates to `display_peer_list`; `scroll` is clamped in place.
+pub fn display_peer_list<B: Backend>(
+    peers: &[PeerInfo],
+    geoip: Option<&GeoIp>,
+    scroll: &mut u16,
+    frame: &mut Frame<B>,
+    area: Rect,
+) {
+    let _ = display_peer_list::display_peer_list(peers, geoip, scroll, frame, area);
+}
+
+/// Render the Peer Detail popup: per-message byte counts for one peer.
+///
+/// Delegates to `display_peer_list::display_peer_detail`.
+pub fn display_peer_detail<B: Backend>(
+    peer: Option<&PeerInfo>,
+    size_units: SizeUnits,
+    frame: &mut Frame<B>,
+    area: Rect,
+) {
+    let _ = display_peer_list::display_peer_detail(peer, size_units, frame, area);
+}
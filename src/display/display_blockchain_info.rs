@@ -1,9 +1,15 @@
 
 // This module renders all Blockchain-related metrics in the TUI.
-// It draws Best Block, Miner, Difficulty, Time Since Block,
+// It draws Best Block, Miner, Difficulty, Time Since Block (with a sparkline
+// of recent block intervals),
 // difficulty projections, chainwork, verification progress,
 // disk size, timestamps, and the Hash Rate Distribution chart.
 //
+// During initial block download the tip is far behind the network, so the
+// section shows a SYNCING badge and a verification gauge, and grays out
+// metrics that assume a synced tip (difficulty projections, miner
+// distribution).
+//
 // No RPC logic lives here — this is pure UI rendering.
 //
 
@@ -16,15 +22,22 @@ use tui::{
     Frame,
 };
 use num_format::{Locale, ToFormattedString};
+use chrono::{TimeZone, Utc};
 use crate::{
-    models::{block_info::BlockInfo, blockchain_info::BlockchainInfo},
-    utils::{estimate_difficulty_change, estimate_24h_difficulty_change, format_size},
+    models::{
+        block_info::BlockInfo, blockchain_info::{format_eta, format_work_bits, BlockchainInfo}, mining_info::MiningInfo,
+        block_stats::BlockStats, chain_tx_stats::ChainTxStats, block_intervals::BlockIntervals,
+        disk_growth::DiskGrowth, witness_adoption::WitnessAdoption,
+        coinbase_reward::CoinbaseReward,
+    },
+    utils::{estimate_difficulty_change, estimate_24h_difficulty_change, format_size, format_hashrate, text_sparkline, SizeUnits, BLOCK24_PRUNED},
     ui::colors::*
 };
 use crate::models::errors::MyError;
 use crate::models::flashing_text::{BEST_BLOCK_TEXT, MINER_TEXT};
 use crate::consensus::satoshi_math::*;
 use std::sync::Arc;
+use std::sync::atomic::Ordering;
 use unicode_width::UnicodeWidthStr;
 
 /// Renders the Blockchain section of the dashboard.
@@ -33,20 +46,35 @@ use unicode_width::UnicodeWidthStr;
 /// • Chain name  
 /// • Best block height (flashing)  
 /// • Miner of best block (flashing)  
-/// • Time since block  
+/// • Time since block, with a sparkline of recent block intervals; it
+///   flashes once the tip is older than `stale_tip_alert_mins`  
 /// • Difficulty + two projections (epoch + 24h)  
-/// • Blocks until next retarget  
-/// • Chainwork  
+/// • Blocks until next retarget, with an ETA from recent block intervals  
+/// • Chainwork, and the work added this epoch  
 /// • Verification progress  
-/// • On-disk size  
+/// • On-disk size, with its growth per hour  
 /// • Median and block timestamps  
 ///
+/// While `initialblockdownload` is set, a SYNCING badge follows the chain
+/// name, verification progress is drawn as a gauge, and the difficulty
+/// projections read "syncing".
+///
 /// All styling and layout is handled here.
+#[allow(clippy::too_many_arguments)]
 pub fn display_blockchain_info<B: Backend>(
     blockchain_info: &BlockchainInfo,
     block_info: &BlockInfo,
     block24_info: &BlockInfo,
     last_miner: &Arc<str>,
+    mining_info: &MiningInfo,
+    last_block_stats: &BlockStats,
+    witness_adoption: &WitnessAdoption,
+    coinbase_reward: &CoinbaseReward,
+    chain_tx_stats: &ChainTxStats,
+    block_intervals: &BlockIntervals,
+    disk_growth: &DiskGrowth,
+    size_units: SizeUnits,
+    stale_tip: bool,
     frame: &mut Frame<B>,
     area: Rect,
 ) -> Result<(), MyError> {
@@ -54,11 +82,31 @@ pub fn display_blockchain_info<B: Backend>(
     // Convert blockchain timestamps + sizes into displayable formats.
     let mediantime = blockchain_info.parse_mediantime()?;
     let time = blockchain_info.parse_time()?;
-    let formatted_size_on_disk = format_size(blockchain_info.size_on_disk);
+    let formatted_size_on_disk = format_size(blockchain_info.size_on_disk, size_units);
     let time_since_block = blockchain_info.calculate_time_diff()?;
     let formatted_difficulty = blockchain_info.formatted_difficulty()?;
     let formatted_chainwork_bits = blockchain_info.formatted_chainwork_bits()?;
 
+    // Past `stale_tip_alert_mins` without a block, the time-since-block
+    // line flashes (bold, reversed every other second).
+    let time_since_block_style = if stale_tip {
+        let style = Style::default().fg(C_TIME_SINCE_BLOCK).add_modifier(Modifier::BOLD);
+        if chrono::Utc::now().timestamp() % 2 == 0 {
+            style.add_modifier(Modifier::REVERSED)
+        } else {
+            style
+        }
+    } else {
+        Style::default().fg(C_TIME_SINCE_BLOCK)
+    };
+
+    // Work added since the epoch-start block; N/A until that block is cached
+    // or if either chainwork doesn't parse.
+    let epoch_work = match blockchain_info.chainwork_since(&block_info.chainwork) {
+        Ok(work) => format!("+{}", format_work_bits(work)),
+        Err(_) => "N/A".to_string(),
+    };
+
     // Epoch-based difficulty projection.
     // Uses timestamp of last block in epoch-start window.
     let estimate_difficulty_chng = estimate_difficulty_change(
@@ -67,50 +115,27 @@ pub fn display_blockchain_info<B: Backend>(
         block_info.time,
     );
 
-    // Determine how deep we are into the current difficulty epoch.
-    // (epoch = 2016 blocks)
-    let height = blockchain_info.blocks;
-    let blocks_into_epoch = height % DIFFICULTY_ADJUSTMENT_INTERVAL;
-    
-    // Difficulty estimate shown only after block 5 of the epoch.
-    let difficulty_change_display = if blocks_into_epoch < 5 {
-        Span::styled(" N/A ", Style::default().fg(C_MAIN_LABELS))
-    } else {
-        Span::styled(
-            format!(" {:.2}% ", estimate_difficulty_chng.abs()),
-            Style::default().fg(C_MAIN_LABELS),
-        )
-    };
-
     // 24-hour difficulty projection uses timestamps of latest and 24h-ago block.
     let estimate_24h_difficulty_chng = estimate_24h_difficulty_change(
         blockchain_info.time,
         block24_info.time,
     );
 
-    // Arrow for epoch diff projection.
+    let syncing = blockchain_info.initialblockdownload;
 
-    let show_epoch_estimate = blocks_into_epoch >= 5;
+    // Either projection is `None` early in the epoch or when the block
+    // times coincide; both then render as "N/A" with no arrow.
+    let (difficulty_arrow, difficulty_color) = projection_arrow(estimate_difficulty_chng);
+    let difficulty_change_display = difficulty_pct_span(estimate_difficulty_chng);
 
-    let (difficulty_arrow, difficulty_color) = if !show_epoch_estimate {
-        (" ", C_MAIN_LABELS)
-    } else if estimate_difficulty_chng > 0.0 {
-        ("↑", C_ESTIMATE_POS)
-    } else if estimate_difficulty_chng < 0.0 {
-        ("↓", C_ESTIMATE_NEG)
-    } else {
-        ("→", C_SEPARATORS) 
-    };
-
-    // Arrow for 24-hour diff projection.
-    let (difficulty_arrow_24h, difficulty_color_24h) =
-    if estimate_24h_difficulty_chng > 0.0 {
-        ("↑", C_ESTIMATE_POS)
-    } else if estimate_24h_difficulty_chng < 0.0 {
-        ("↓", C_ESTIMATE_NEG)
-    } else {
-        ("→", C_SEPARATORS) // or DarkGray / neutral
-    };
+    // A pruned node may no longer have the 24h-ago block at all.
+    let (difficulty_arrow_24h, difficulty_color_24h, difficulty_24h_display) =
+        if BLOCK24_PRUNED.load(Ordering::Relaxed) {
+            (" ", *C_MAIN_LABELS, Span::styled(" N/A (pruned) ", Style::default().fg(*C_SECTION_LABELS)))
+        } else {
+            let (arrow, color) = projection_arrow(estimate_24h_difficulty_chng);
+            (arrow, color, difficulty_pct_span(estimate_24h_difficulty_chng))
+        };
 
     // FlashingText system: update Best Block & Miner flashing styles.
     BEST_BLOCK_TEXT.lock().unwrap().update(blockchain_info.blocks);
@@ -119,105 +144,252 @@ pub fn display_blockchain_info<B: Backend>(
     let best_block_style = BEST_BLOCK_TEXT.lock().unwrap().style();
     let last_miner_style = MINER_TEXT.lock().unwrap().style();
 
-    // Build the "Best Block | Miner" line with dynamic flashing styles.
-    let best_block_spans = Spans::from(vec![
-        Span::styled("🏆 Best Block: ", Style::default().fg(C_MAIN_LABELS)),
+    // Build the "Best Block | Miner | Reward" line with dynamic flashing styles.
+    let mut best_block_spans = vec![
+        Span::styled("🏆 Best Block: ", Style::default().fg(*C_MAIN_LABELS)),
         Span::styled(
             blockchain_info.blocks.to_formatted_string(&Locale::en),
             best_block_style,
         ),
-        Span::styled(" | ", Style::default().fg(C_SEPARATORS)),
-        Span::styled("⛏️ Miner: ", Style::default().fg(C_MAIN_LABELS)),
+        Span::styled(" | ", Style::default().fg(*C_SEPARATORS)),
+        Span::styled("⛏️ Miner: ", Style::default().fg(*C_MAIN_LABELS)),
         Span::styled(format!("{}", last_miner), last_miner_style),
-    ]);
+    ];
+    // The reward comes with the miner lookup; hide it until that has caught
+    // up with the tip.
+    if coinbase_reward.height == blockchain_info.blocks {
+        best_block_spans.extend([
+            Span::styled(" | ", Style::default().fg(*C_SEPARATORS)),
+            Span::styled("💰 Reward: ", Style::default().fg(*C_MAIN_LABELS)),
+            Span::styled(
+                format!("{} + {:.3} fees BTC", coinbase_reward.subsidy_btc(), coinbase_reward.fees_btc()),
+                Style::default().fg(*C_MAIN_LABELS),
+            ),
+        ]);
+    }
+
+    // Chain name, plus a SYNCING badge during IBD.
+    let mut chain_spans = vec![
+        Span::styled("🔗 Chain: ", Style::default().fg(*C_MAIN_LABELS)),
+        Span::styled(blockchain_info.chain.clone(), Style::default().fg(C_CHAIN)),
+    ];
+    // Halving countdown; its date would be meaningless from a stale IBD tip.
+    if let Some((blocks_left, eta)) = blockchain_info
+        .halving_estimate(block_intervals.expected_interval())
+        .filter(|_| !syncing)
+    {
+        chain_spans.extend([
+            Span::styled(" | ", Style::default().fg(*C_SEPARATORS)),
+            Span::styled("Next halving in ", Style::default().fg(*C_MAIN_LABELS)),
+            Span::styled(
+                format!("{} blocks", blocks_left.to_formatted_string(&Locale::en)),
+                Style::default().fg(*C_MAIN_LABELS),
+            ),
+            Span::styled(
+                Utc.timestamp_opt(eta as i64, 0)
+                    .single()
+                    .map_or_else(String::new, |t| format!(" (≈ {})", t.format("%Y-%m-%d"))),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]);
+    }
+    if syncing {
+        chain_spans.push(Span::raw("  "));
+        chain_spans.push(Span::styled(
+            " SYNCING ",
+            Style::default()
+                .fg(*C_STATUS_MED)
+                .add_modifier(Modifier::BOLD | Modifier::REVERSED),
+        ));
+    }
+
+    // Projections from a tip that is months or years old mean nothing.
+    let estimated_change_spans = if syncing {
+        vec![
+            Span::styled("  📉 Estimated change: ", Style::default().fg(*C_MAIN_LABELS)),
+            Span::styled("N/A (syncing)", Style::default().fg(*C_SECTION_LABELS)),
+        ]
+    } else {
+        vec![
+            Span::styled("  📉 Estimated change: ", Style::default().fg(*C_MAIN_LABELS)),
+
+            // Epoch arrow
+            Span::styled(
+                difficulty_arrow,
+                Style::default().fg(difficulty_color),
+            ),
+            difficulty_change_display,
+
+            Span::styled("(epoch)", Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC)),
+            Span::styled(" | ", Style::default().fg(*C_SEPARATORS)),
+
+            // 24h arrow
+            Span::styled(
+                difficulty_arrow_24h,
+                Style::default().fg(difficulty_color_24h),
+            ),
+            difficulty_24h_display,
+            Span::styled("(24hrs)", Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC)),
+        ]
+    };
+
+    // Verification progress: a gauge while syncing, a precise figure after.
+    let progress_pct = format!("{:.4}%", blockchain_info.verificationprogress * 100.0);
+    let verification_spans = if syncing {
+        vec![
+            Span::styled("📡 Verification progress: ", Style::default().fg(*C_MAIN_LABELS)),
+            Span::styled(
+                sync_gauge(blockchain_info.verificationprogress, SYNC_GAUGE_WIDTH),
+                Style::default().fg(C_VERIFICATION),
+            ),
+            Span::styled(format!(" {}", progress_pct), Style::default().fg(C_VERIFICATION)),
+        ]
+    } else {
+        vec![
+            Span::styled("📡 Verification progress: ", Style::default().fg(*C_MAIN_LABELS)),
+            Span::styled(progress_pct, Style::default().fg(C_VERIFICATION)),
+        ]
+    };
 
     // Build every display line in a Vec<Spans>.
     let blockchain_info_text = vec![
-        Spans::from(vec![
-            Span::styled("🔗 Chain: ", Style::default().fg(C_MAIN_LABELS)),
-            Span::styled(blockchain_info.chain.clone(), Style::default().fg(C_CHAIN)),
-        ]),
+        Spans::from(chain_spans),
+
+        Spans::from(best_block_spans), // Flashing block + miner line
+
+        Spans::from(
+            [
+                vec![
+                    Span::styled("  ⏳ Time since block: ", Style::default().fg(*C_MAIN_LABELS)),
+                    Span::styled(time_since_block, time_since_block_style),
+                ],
+                if stale_tip {
+                    vec![Span::styled(" ⚠ STALE TIP ", time_since_block_style)]
+                } else {
+                    Vec::new()
+                },
+                block_interval_spans(block_intervals),
+            ]
+            .concat(),
+        ),
 
-        best_block_spans, // Flashing block + miner line
+        // Fees and median fee rate of the tip (getblockstats), then its
+        // SegWit/Taproot usage.
+        Spans::from(
+            [
+                vec![
+                    Span::styled("     Last Block: ", Style::default().fg(*C_MAIN_LABELS)),
+                    if last_block_stats.height > 0 {
+                        Span::styled(
+                            format!(
+                                "{:.3} BTC fees, {} sat/vB median",
+                                last_block_stats.total_fee_btc(),
+                                last_block_stats.median_feerate()
+                            ),
+                            Style::default().fg(*C_MAIN_LABELS),
+                        )
+                    } else {
+                        Span::styled("N/A", Style::default().fg(*C_SECTION_LABELS))
+                    },
+                ],
+                witness_adoption_spans(witness_adoption, blockchain_info.blocks),
+            ]
+            .concat(),
+        ),
 
         Spans::from(vec![
-            Span::styled("  ⏳ Time since block: ", Style::default().fg(C_MAIN_LABELS)),
-            Span::styled(time_since_block, Style::default().fg(C_TIME_SINCE_BLOCK)),
+            Span::styled("🎯 Difficulty: ", Style::default().fg(*C_MAIN_LABELS)),
+            Span::styled(formatted_difficulty, Style::default().fg(C_DIFFICULTY)),
         ]),
 
+        // Core's network hashrate estimate (getmininginfo).
         Spans::from(vec![
-            Span::styled("🎯 Difficulty: ", Style::default().fg(C_MAIN_LABELS)),
-            Span::styled(formatted_difficulty, Style::default().fg(C_DIFFICULTY)),
+            Span::styled("     Network Hashrate: ", Style::default().fg(*C_MAIN_LABELS)),
+            if mining_info.networkhashps > 0.0 {
+                Span::styled(format_hashrate(mining_info.networkhashps), Style::default().fg(C_DIFFICULTY))
+            } else {
+                Span::styled("N/A", Style::default().fg(*C_SECTION_LABELS))
+            },
         ]),
 
         // Remaining blocks in difficulty epoch.
         Spans::from(vec![
-            Span::styled("     Blocks until adjustment: ", Style::default().fg(C_MAIN_LABELS)),
+            Span::styled("     Blocks until adjustment: ", Style::default().fg(*C_MAIN_LABELS)),
             match blockchain_info.display_blocks_until_difficulty_adjustment() {
                 Ok((block_text, block_color)) =>
                     Span::styled(block_text, Style::default().fg(block_color)),
                 Err(e) =>
                     Span::styled(format!("Error: {}", e), Style::default().fg(Color::Red)),
             },
+            match blockchain_info.seconds_until_adjustment(block_intervals.expected_interval()) {
+                Ok(secs) => Span::styled(
+                    format!(" (≈ {} to retarget)", format_eta(secs)),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Err(_) => Span::raw(""),
+            },
         ]),
 
         // Difficulty projections block (epoch + 24hr).
-        Spans::from(vec![
-            Span::styled("  📉 Estimated change: ", Style::default().fg(C_MAIN_LABELS)),
-
-            // Epoch arrow
-            Span::styled(
-                difficulty_arrow,
-                Style::default().fg(difficulty_color),
-            ),
-            difficulty_change_display,
+        Spans::from(estimated_change_spans),
 
-            Span::styled("(epoch)", Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC)),
-            Span::styled(" | ", Style::default().fg(C_SEPARATORS)),
-
-            // 24h arrow
+        // Transaction throughput over the getchaintxstats window.
+        Spans::from(vec![
+            Span::styled("   Throughput: ", Style::default().fg(*C_MAIN_LABELS)),
+            match chain_tx_stats.tx_per_sec() {
+                Some(rate) => Span::styled(
+                    format!("{:.2} tx/s ", rate),
+                    Style::default().fg(*C_MAIN_LABELS),
+                ),
+                None => Span::styled("N/A ", Style::default().fg(*C_SECTION_LABELS)),
+            },
             Span::styled(
-                difficulty_arrow_24h,
-                Style::default().fg(difficulty_color_24h),
+                format!("({} blocks)", chain_tx_stats.window_block_count),
+                Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC),
             ),
+            Span::styled(" | ", Style::default().fg(*C_SEPARATORS)),
+            Span::styled("Total: ", Style::default().fg(*C_MAIN_LABELS)),
             Span::styled(
-                format!(" {:.2}% ", estimate_24h_difficulty_chng.abs()),
-                Style::default().fg(C_MAIN_LABELS),
+                format!("{} txs", chain_tx_stats.txcount.to_formatted_string(&Locale::en)),
+                Style::default().fg(*C_MAIN_LABELS),
             ),
-            Span::styled("(24hrs)", Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC)),
         ]),
 
         // Chainwork line
         Spans::from(vec![
-            Span::styled("   Chainwork: ", Style::default().fg(C_MAIN_LABELS)),
+            Span::styled("   Chainwork: ", Style::default().fg(*C_MAIN_LABELS)),
             Span::styled(formatted_chainwork_bits, Style::default().fg(C_CHAINWORK)),
+            Span::styled(" | ", Style::default().fg(*C_SEPARATORS)),
+            Span::styled("This Epoch: ", Style::default().fg(*C_MAIN_LABELS)),
+            Span::styled(epoch_work, Style::default().fg(C_CHAINWORK)),
         ]),
 
         // Verification progress
-        Spans::from(vec![
-            Span::styled("📡 Verification progress: ", Style::default().fg(C_MAIN_LABELS)),
-            Span::styled(
-                format!("{:.4}%", blockchain_info.verificationprogress * 100.0),
-                Style::default().fg(C_VERIFICATION),
-            ),
-        ]),
+        Spans::from(verification_spans),
 
         // Disk size
         Spans::from(vec![
-            Span::styled("💾 Size on Disk: ", Style::default().fg(C_MAIN_LABELS)),
-            Span::styled(formatted_size_on_disk, Style::default().fg(C_MAIN_LABELS)),
+            Span::styled("💾 Size on Disk: ", Style::default().fg(*C_MAIN_LABELS)),
+            Span::styled(formatted_size_on_disk, Style::default().fg(*C_MAIN_LABELS)),
+            match disk_growth.bytes_per_hour() {
+                Some(rate) => Span::styled(
+                    format!(" (+{}/h)", format_size(rate, size_units)),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                None => Span::raw(""),
+            },
         ]),
 
         // Median time
         Spans::from(vec![
-            Span::styled("   Median Time: ", Style::default().fg(C_MAIN_LABELS)),
-            Span::styled(mediantime, Style::default().fg(C_MAIN_LABELS)),
+            Span::styled("   Median Time: ", Style::default().fg(*C_MAIN_LABELS)),
+            Span::styled(mediantime, Style::default().fg(*C_MAIN_LABELS)),
         ]),
 
         // Block time
         Spans::from(vec![
-            Span::styled("⏰ Block Time : ", Style::default().fg(C_MAIN_LABELS)),
-            Span::styled(time, Style::default().fg(C_MAIN_LABELS)),
+            Span::styled("⏰ Block Time : ", Style::default().fg(*C_MAIN_LABELS)),
+            Span::styled(time, Style::default().fg(*C_MAIN_LABELS)),
         ]),
     ];
 
@@ -248,6 +420,76 @@ pub fn display_blockchain_info<B: Backend>(
     Ok(())
 }
 
+/// " | SegWit: 92% | Taproot outs: 14%" for the tip. Empty until the tip's
+/// block has been counted, or when it has only a coinbase.
+fn witness_adoption_spans(adoption: &WitnessAdoption, tip: u64) -> Vec<Span<'static>> {
+    if adoption.height != tip {
+        return Vec::new();
+    }
+    let (Some(segwit), Some(taproot)) = (adoption.segwit_pct(), adoption.taproot_outputs_pct()) else {
+        return Vec::new();
+    };
+
+    vec![
+        Span::styled(" | ", Style::default().fg(*C_SEPARATORS)),
+        Span::styled("SegWit: ", Style::default().fg(*C_MAIN_LABELS)),
+        Span::styled(format!("{:.0}%", segwit), Style::default().fg(*C_MAIN_LABELS)),
+        Span::styled(" | ", Style::default().fg(*C_SEPARATORS)),
+        Span::styled("Taproot outs: ", Style::default().fg(*C_MAIN_LABELS)),
+        Span::styled(format!("{:.0}%", taproot), Style::default().fg(*C_MAIN_LABELS)),
+    ]
+}
+
+/// " | Intervals: ▂▅▁█ (avg 9m 40s)" — recent block intervals, oldest
+/// first. Empty until two consecutive blocks have been seen.
+fn block_interval_spans(block_intervals: &BlockIntervals) -> Vec<Span<'static>> {
+    let Some(mean) = block_intervals.mean() else {
+        return Vec::new();
+    };
+
+    vec![
+        Span::styled(" | ", Style::default().fg(*C_SEPARATORS)),
+        Span::styled("Intervals: ", Style::default().fg(*C_MAIN_LABELS)),
+        Span::styled(
+            text_sparkline(block_intervals.intervals()),
+            Style::default().fg(*C_SPARKLINE),
+        ),
+        Span::styled(
+            format!(" (avg {}m {:02}s)", mean / 60, mean % 60),
+            Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC),
+        ),
+    ]
+}
+
+/// Direction arrow and color for a difficulty projection.
+fn projection_arrow(change: Option<f64>) -> (&'static str, Color) {
+    match change {
+        None => (" ", *C_MAIN_LABELS),
+        Some(c) if c > 0.0 => ("↑", C_ESTIMATE_POS),
+        Some(c) if c < 0.0 => ("↓", C_ESTIMATE_NEG),
+        Some(_) => ("→", *C_SEPARATORS),
+    }
+}
+
+/// Magnitude of a difficulty projection, or "N/A".
+fn difficulty_pct_span(change: Option<f64>) -> Span<'static> {
+    let text = match change {
+        Some(c) => format!(" {:.2}% ", c.abs()),
+        None => " N/A ".to_string(),
+    };
+    Span::styled(text, Style::default().fg(*C_MAIN_LABELS))
+}
+
+/// Width, in cells, of the IBD verification gauge.
+const SYNC_GAUGE_WIDTH: usize = 20;
+
+/// Text gauge for a `0.0..=1.0` fraction, e.g. `█████░░░░░` at 0.5.
+///
+/// Out-of-range values are clamped; a cell is filled only once reached.
+pub fn sync_gauge(fraction: f64, width: usize) -> String {
+    let filled = ((fraction.clamp(0.0, 1.0) * width as f64).floor() as usize).min(width);
+    format!("{}{}", "█".repeat(filled), "░".repeat(width - filled))
+}
 
 /// Renders the Hash Rate Distribution chart (top 8 miners).
 ///
@@ -256,8 +498,12 @@ pub fn display_blockchain_info<B: Backend>(
 /// • Secondary: ascending by miner name  
 ///
 /// Then converts Arc<str> → &str for the BarChart widget.
+///
+/// With `syncing` (IBD) the blocks are historical, not today's hashrate,
+/// so the chart is grayed out and labelled as such.
 pub fn render_hashrate_distribution_chart<B: Backend>(
     distribution: &Vec<(Arc<str>, u64)>,
+    syncing: bool,
     frame: &mut Frame<B>,
     area: Rect,
 ) -> Result<(), MyError> {
@@ -276,19 +522,9 @@ pub fn render_hashrate_distribution_chart<B: Backend>(
         .constraints([Constraint::Length(1), Constraint::Min(7)].as_ref())
         .split(area);
 
-    // Sort by hashrate descending, name ascending.
-    let mut sorted_distribution = distribution.to_vec();
-    sorted_distribution.sort_by(|a, b| {
-        let hashrate_cmp = b.1.cmp(&a.1);
-        if hashrate_cmp == std::cmp::Ordering::Equal {
-            a.0.cmp(&b.0)
-        } else {
-            hashrate_cmp
-        }
-    });
-
     // Keep only top 8 miners.
-    let top_8_distribution: Vec<(Arc<str>, u64)> = sorted_distribution.into_iter().take(8).collect();
+    // `BlockHistory::get_miner_distribution` already returns count desc, name asc.
+    let top_8_distribution: Vec<(Arc<str>, u64)> = distribution.iter().take(8).cloned().collect();
 
     let total_miners = distribution.len();
     let top8_dist = top_8_distribution.len();
@@ -299,20 +535,26 @@ pub fn render_hashrate_distribution_chart<B: Backend>(
         .map(|(miner, hashrate)| (miner.as_ref(), *hashrate))
         .collect::<Vec<_>>();
 
+    let (title_suffix, bar_color, value_color) = if syncing {
+        (" — syncing, not current", *C_SECTION_LABELS, *C_SECTION_LABELS)
+    } else {
+        ("", *C_HASHRATE_CHART_BARS, C_HASHRATE_CHART_VALUES)
+    };
+
     let barchart = BarChart::default()
         .block(
             Block::default()
                 .title(format!(
-                    "Hash Rate Distribution Top {} of {} 🌐 ({})",
-                    top8_dist, total_miners, window_display
+                    "Hash Rate Distribution Top {} of {} 🌐 ({}){}",
+                    top8_dist, total_miners, window_display, title_suffix
                 ))
                 .borders(Borders::ALL),
         )
         .data(&top_8_distribution_ref)
         .bar_width(7)
         .bar_gap(1)
-        .bar_style(Style::default().fg(C_HASHRATE_CHART_BARS))
-        .value_style(Style::default().fg(C_HASHRATE_CHART_VALUES));
+        .bar_style(Style::default().fg(bar_color))
+        .value_style(Style::default().fg(value_color));
 
     frame.render_widget(barchart, chunks[1]);
 
@@ -419,7 +661,7 @@ pub fn draw_last20_miners<B: Backend>(
             Span::raw(spacer),
             Span::styled(
                 miner_out,
-                Style::default().fg(C_LAST20_MINER_LABEL)
+                Style::default().fg(*C_LAST20_MINER_LABEL)
                 .add_modifier(Modifier::DIM),
             ),
         ])
@@ -4,6 +4,8 @@
 // This section displays active chain tips and fork information,
 // helping users visually monitor whether unexpected chains appear
 // (e.g., stale forks, competing tips, potential re-org signals).
+// Below the tips, any soft-fork deployment that is not yet active is
+// listed with its BIP9 status and signaling progress.
 //
 // All processing here is presentation-only; the underlying chaintips
 // were already retrieved and deserialized inside the RPC subsystem.
@@ -15,7 +17,10 @@ use tui::{
     widgets::{Block, Borders, Paragraph},
     layout::{Constraint, Direction, Layout},
 };
-use crate::{models::chaintips_info::ChainTip, ui::colors::{C_CONSENSUS_STATUS_SECTION, C_MAIN_LABELS}};
+use crate::{
+    models::{chaintips_info::{displayed_tips, ChainTip}, deployment_info::DeploymentInfo},
+    ui::colors::{C_CONSENSUS_STATUS_SECTION, C_MAIN_LABELS},
+};
 use crate::models::errors::MyError;
 
 /// Draws the Consensus Security panel.
@@ -26,9 +31,12 @@ use crate::models::errors::MyError;
 ///   • Their heights and branch lengths  
 ///
 /// Only the active chain + top two forks are displayed to keep the UI compact.
+/// Pending deployments (from `getdeploymentinfo`) follow; `None` — e.g. a
+/// node without the RPC — hides them.
 /// The frame & area are passed by `runapp.rs`.
 pub fn display_consensus_security_info<B: tui::backend::Backend>(
     chaintips_info: &Vec<ChainTip>,
+    deployment_info: Option<&DeploymentInfo>,
     frame: &mut tui::Frame<B>,
     area: tui::layout::Rect,
 ) -> Result<(), MyError> {
@@ -65,30 +73,17 @@ pub fn display_consensus_security_info<B: tui::backend::Backend>(
     lines.push(Spans::from(vec![
         Span::styled(
             "🌲 Fork Monitoring:",
-            Style::default().fg(C_MAIN_LABELS),
+            Style::default().fg(*C_MAIN_LABELS),
         ),
     ]));
 
-    // Filter only the relevant tips:
-    //
-    //   "active"      → the main chain
-    //   "valid-fork"  → recognized but stale forks
+    // Only the active chain and valid (stale) forks, highest first, at most
+    // three — the same tips the fork alert considers. Other statuses
+    // (valid-headers, invalid, etc.) generally clutter the panel and rarely
+    // provide useful real-time signal for operators.
     //
-    // Other statuses (valid-headers, invalid, etc.) generally clutter
-    // the panel and rarely provide useful real-time signal for operators.
-    let mut filtered_tips: Vec<&ChainTip> = chaintips_info
-        .iter()
-        .filter(|tip| tip.status == "active" || tip.status == "valid-fork")
-        .collect();
-
-    // Sort by block height descending so highest tips appear first.
-    filtered_tips.sort_by(|a, b| b.height.cmp(&a.height));
-
-    // Only show 3 entries max: the active chain + two highest forks.
-    let limited_tips = filtered_tips.into_iter().take(3);
-
     // Convert each tip into a formatted TUI line.
-    for tip in limited_tips {
+    for tip in displayed_tips(chaintips_info) {
         // Human-readable labels
         let status = match tip.status.as_str() {
             "active"        => "⚡ Active Chain",
@@ -108,7 +103,7 @@ pub fn display_consensus_security_info<B: tui::backend::Backend>(
         let line = Spans::from(vec![
             Span::styled(
                 format!("🌳 Height: {:>7}", tip.height),
-                Style::default().fg(C_MAIN_LABELS),
+                Style::default().fg(*C_MAIN_LABELS),
             ),
             Span::raw(" | "),
             Span::styled(
@@ -118,13 +113,44 @@ pub fn display_consensus_security_info<B: tui::backend::Backend>(
             Span::raw(" | "),
             Span::styled(
                 format!("📏 Length: {:>2}", tip.branchlen),
-                Style::default().fg(C_MAIN_LABELS),
+                Style::default().fg(*C_MAIN_LABELS),
             ),
         ]);
 
         lines.push(line);
     }
 
+    // Soft-fork deployments that are defined, started or locked in.
+    //
+    // 🧬 name: started | Signaling: 45.2% (needs 90%)
+    if let Some(info) = deployment_info {
+        for (name, deployment) in info.pending() {
+            let mut spans = vec![
+                Span::styled(format!("🧬 {}: ", name), Style::default().fg(*C_MAIN_LABELS)),
+                Span::styled(
+                    deployment.status().to_string(),
+                    Style::default().fg(C_CONSENSUS_STATUS_SECTION),
+                ),
+            ];
+
+            if let Some(pct) = deployment.signaling_pct() {
+                spans.push(Span::raw(" | "));
+                spans.push(Span::styled(
+                    format!("Signaling: {:.1}%", pct),
+                    Style::default().fg(*C_MAIN_LABELS),
+                ));
+                if let Some(needed) = deployment.threshold_pct() {
+                    spans.push(Span::styled(
+                        format!(" (needs {:.0}%)", needed),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+            }
+
+            lines.push(Spans::from(spans));
+        }
+    }
+
     // Render the text block into the lower layout chunk.
     let paragraph = Paragraph::new(lines);
     frame.render_widget(paragraph, chunks[1]);
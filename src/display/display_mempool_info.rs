@@ -4,12 +4,16 @@
 //
 // This module is responsible for drawing the entire "Mempool" section
 // of the BlockchainInfo TUI. It shows:
-// - Loading spinner while initial data is collected
+// - Loading spinner while Core loads the mempool or initial data is collected
+// - "Mempool empty" when the node has genuinely no transactions
 // - Mempool memory usage gauge
 // - Flashing transaction count (global mempool size)
 // - Optional "dust-free" decoration on the transaction line
 // - Size / Age / RBF distributions (with percent + ASCII progress bars)
 // - Fee metrics (average, median, fee rate)
+// - Confirmed fee-rate trend sparkline (median per block, last 20 blocks)
+// - Next-block fee-rate floor (estimated from the sampled mempool)
+// - Fee-rate histogram (sats/vB bands, with percent + ASCII progress bars)
 //
 // This file is *display only* — it does not perform any mempool
 // sampling or filtering logic, it simply renders what models provide.
@@ -19,13 +23,13 @@ use tui::{
     layout::{Constraint, Direction, Layout, Rect, Alignment},
     style::{Color, Style, Modifier},
     text::{Span, Spans},
-    widgets::{Block, Borders, Gauge, Paragraph},
+    widgets::{Block, Borders, Gauge, Paragraph, Sparkline},
     Frame,
 };
 use num_format::{Locale, ToFormattedString};
 use crate::{
-    models::mempool_info::{MempoolDistribution, MempoolInfo},
-    utils::{format_size, normalize_percentages, create_progress_bar},
+    models::mempool_info::{MempoolDistribution, MempoolInfo, FEERATE_BUCKET_LABELS},
+    utils::{format_size, normalize_percentages, create_progress_bar, SizeUnits},
     ui::colors::*,
 };
 use crate::models::errors::MyError;
@@ -43,25 +47,51 @@ const SATS_PER_BTC: f64 = 100_000_000.0;
 /// Displays the mempool information in a `tui` terminal.
 ///
 /// This function:
-/// - Shows a loading spinner while mempool distribution is still initializing
+/// - Shows a loading spinner while Core is still loading its mempool (`loaded: false`)
+///   or the distribution is still initializing
+/// - Shows "Mempool empty" when the loaded mempool has no transactions
 /// - Renders a gauge for mempool memory usage
 /// - Updates and displays a flashing transaction counter
 /// - Optionally decorates transaction line with "dust-free" percentage
-/// - Builds distribution panels for Size / Age / RBF
+/// - Builds distribution panels for Size / Age / RBF; Size counts
+///   transactions, or sums their vsize when `size_by_vsize` is set
 /// - Displays fee metrics (avg / median / fee rate)
+/// - Draws the confirmed fee-rate trend beside the distributions
+/// - Shows the fee rate needed to make the next block under the trend
+///
+/// `fee_rate_trend` holds one median fee rate (sat/vB) per block, oldest
+/// first, with `0` marking blocks whose stats were unavailable.
 ///
 /// `area` is the layout region this section should occupy.
+#[allow(clippy::too_many_arguments)]
 pub fn display_mempool_info<B: Backend>(
     mempool_info: &MempoolInfo,
     distribution: &MempoolDistribution,
     dust_free: bool,
+    size_by_vsize: bool,
+    fee_rate_trend: &[u64],
+    fee_estimates: &[(u16, f64)],
+    size_units: SizeUnits,
     frame: &mut Frame<B>,
     area: Rect,
 ) -> Result<(), MyError> {
 
     // -----------------------------------------------------------------------
-    // 1. LOADING STATE
+    // 1. LOADING / EMPTY STATES
     // -----------------------------------------------------------------------
+    // Core reports `loaded: false` until mempool.dat has been read at startup.
+    // Once loaded, an empty mempool is a real (if rare) state — show it as such
+    // instead of spinning forever waiting for distribution buckets to fill.
+    if !mempool_info.loaded {
+        draw_status_line(frame, area, &format!("{} Mempool loading…", next_spinner_frame()), true);
+        return Ok(());
+    }
+
+    if mempool_info.size == 0 {
+        draw_status_line(frame, area, "Mempool empty", false);
+        return Ok(());
+    }
+
     // If all the key distribution buckets are zero, treat it as "still warming up".
     // This avoids drawing empty charts and instead shows an animated status line.
     let is_loading = distribution.small == 0
@@ -70,16 +100,13 @@ pub fn display_mempool_info<B: Backend>(
         && distribution.rbf_count == 0;
 
     if is_loading {
-        // Rotate through spinner frames using a global atomic index.
-        let spinner =
-            SPINNER_FRAMES[SPINNER_INDEX.load(Ordering::Relaxed) % SPINNER_FRAMES.len()];
-        SPINNER_INDEX.fetch_add(1, Ordering::Relaxed);
-
         // Centered "Searching through the Dust..." message while mempool scanner runs.
-        let loading_text = Paragraph::new(format!("{} Searching through the Dust...", spinner))
-            .style(Style::default().fg(Color::Yellow))
-            .alignment(Alignment::Center);
-        frame.render_widget(loading_text, area);
+        draw_status_line(
+            frame,
+            area,
+            &format!("{} Searching through the Dust...", next_spinner_frame()),
+            true,
+        );
         return Ok(());
     }
 
@@ -87,8 +114,8 @@ pub fn display_mempool_info<B: Backend>(
     // 2. MEMORY USAGE & COLOR CODING
     // -----------------------------------------------------------------------
     // Convert raw byte usage to human-readable string (e.g. "23.4 MiB").
-    let mempool_size_in_memory = format_size(mempool_info.usage);
-    let max_mempool_size_in_memory = format_size(mempool_info.maxmempool);
+    let mempool_size_in_memory = format_size(mempool_info.usage, size_units);
+    let max_mempool_size_in_memory = format_size(mempool_info.maxmempool, size_units);
 
     // Compute mempool fullness percentage for the usage gauge.
     let mempool_usage_percent =
@@ -106,14 +133,21 @@ pub fn display_mempool_info<B: Backend>(
         Style::default().fg(Color::Red)
     };
 
-    // Node's minimum relay fee in vSats/vByte (local policy baseline).
-    let min_relay_fee_vsats = mempool_info.min_relay_tx_fee_vsats();
+    // Fee floors in sat/vB. Once the mempool is full, its minimum fee rises
+    // above the relay minimum; that is highlighted as mempool pressure.
+    let under_fee_pressure = mempool_info.under_fee_pressure();
+    let mempool_min_fee_style = if under_fee_pressure {
+        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::Yellow)
+    };
 
-    // Dust-free percentage:
-    // Ratio of [small + medium + large] bucket counts to total mempool tx count.
-    let total_size = distribution.small + distribution.medium + distribution.large;
-    let dust_free_percentage = (total_size as f64 / mempool_info.size as f64) * 100.0;
-    let formatted_dust_free = format!("{:.1}%", dust_free_percentage);
+    // Dust-free percentage: non-dust share of the entries examined so far
+    // (see `MempoolDistribution::dust_free_pct`); N/A while the cache is empty.
+    let formatted_dust_free = match distribution.dust_free_pct() {
+        Some(pct) => format!("{:.1}%", pct),
+        None => "N/A".to_string(),
+    };
 
     // -----------------------------------------------------------------------
     // 3. NORMALIZED DISTRIBUTIONS (SIZE / AGE / RBF)
@@ -122,12 +156,28 @@ pub fn display_mempool_info<B: Backend>(
     // - Each category row has a 0–100% value
     // - We can reuse the same 10-character ASCII progress bar helper.
 
-    // Size Distribution (Small / Medium / Large).
-    let size_counts = vec![
-        distribution.small as u64,
-        distribution.medium as u64,
-        distribution.large as u64,
-    ];
+    // Size Distribution (Small / Medium / Large), by tx count or by the
+    // block space (vsize) each bucket takes up.
+    let size_counts = if size_by_vsize {
+        vec![
+            distribution.small_vsize,
+            distribution.medium_vsize,
+            distribution.large_vsize,
+        ]
+    } else {
+        vec![
+            distribution.small as u64,
+            distribution.medium as u64,
+            distribution.large as u64,
+        ]
+    };
+    let size_value = |i: usize| {
+        if size_by_vsize {
+            format_vsize(size_counts[i])
+        } else {
+            size_counts[i].to_formatted_string(&Locale::en)
+        }
+    };
 
     let size_pcts = normalize_percentages(&size_counts);
     let small_pct = size_pcts[0];
@@ -182,7 +232,7 @@ pub fn display_mempool_info<B: Backend>(
     // Build the "📊 Transactions: N" line.
     // Optional dust-free decoration is appended if the toggle is ON.
     let mut spans: Vec<Span> = vec![
-        Span::styled("📊 Transactions: ", Style::default().fg(C_MAIN_LABELS)),
+        Span::styled("📊 Transactions: ", Style::default().fg(*C_MAIN_LABELS)),
         Span::styled(
             mempool_info.size.to_formatted_string(&Locale::en),
             transaction_style,
@@ -191,16 +241,16 @@ pub fn display_mempool_info<B: Backend>(
 
     // Only show dust-free metrics if toggle is ON.
     if dust_free {
-        spans.push(Span::styled(" | ", Style::default().fg(C_SEPARATORS)));
+        spans.push(Span::styled(" | ", Style::default().fg(*C_SEPARATORS)));
         spans.push(Span::styled(
             format!("{} ", formatted_dust_free),
-            Style::default().fg(C_DUST_FREE_PCT),
+            Style::default().fg(*C_DUST_FREE_PCT),
         ));
         spans.push(
             Span::styled(
                 "dᵤₛₜ₋fᵣₑₑ",
                 Style::default()
-                    .fg(C_DUST_FREE_LABEL)
+                    .fg(*C_DUST_FREE_LABEL)
                     .add_modifier(Modifier::ITALIC),
             ),
         );
@@ -241,7 +291,7 @@ pub fn display_mempool_info<B: Backend>(
     // Shows mempool usage as a percentage of maxmempool, with a labeled border.
     let mempool_gauge = Gauge::default()
         .block(Block::default().title("Mempool Usage").borders(Borders::ALL))
-        .gauge_style(Style::default().fg(C_MEMPOOL_USAGE_GAUGE_FG).bg(C_MEMPOOL_USAGE_GAUGE_BG))
+        .gauge_style(Style::default().fg(*C_MEMPOOL_USAGE_GAUGE_FG).bg(C_MEMPOOL_USAGE_GAUGE_BG))
         .percent(mempool_usage_percent as u16);
     frame.render_widget(mempool_gauge, chunks[1]);
 
@@ -255,41 +305,81 @@ pub fn display_mempool_info<B: Backend>(
 
         // Memory usage breakdown: current vs max.
         Spans::from(vec![
-            Span::styled("💾 Memory: ", Style::default().fg(C_MAIN_LABELS)),
+            Span::styled("💾 Memory: ", Style::default().fg(*C_MAIN_LABELS)),
             Span::styled(
                 format!("{} ", mempool_size_in_memory),
                 mempool_size_in_memory_color,
             ),
             Span::styled(
                 format!("/ {}", max_mempool_size_in_memory),
-                Style::default().fg(C_MEMPOOL_VALUES),
+                Style::default().fg(*C_MEMPOOL_VALUES),
             ),
         ]),
 
         // Total fees currently sitting in the mempool (BTC).
         Spans::from(vec![
-            Span::styled("💰 Total Fees: ", Style::default().fg(C_MAIN_LABELS)),
+            Span::styled("💰 Total Fees: ", Style::default().fg(*C_MAIN_LABELS)),
             Span::styled(
                 format!("{:.8}", mempool_info.total_fee),
-                Style::default().fg(C_MEMPOOL_VALUES),
+                Style::default().fg(*C_MEMPOOL_VALUES),
             ),
         ]),
 
-        // Local node minimum relay fee (vsats/vByte).
+        // Mempool minimum, relay minimum and incremental relay fee (sat/vB).
         Spans::from(vec![
-            Span::styled("⚖️ Min Transaction Fee: ", Style::default().fg(C_MAIN_LABELS)),
+            Span::styled("⚖️ Min Fee: ", Style::default().fg(*C_MAIN_LABELS)),
+            Span::styled(
+                format!("{}", mempool_info.mempool_min_fee_rate()),
+                mempool_min_fee_style,
+            ),
+            Span::styled(" | relay ", Style::default().fg(*C_SEPARATORS)),
             Span::styled(
-                min_relay_fee_vsats.to_formatted_string(&Locale::en),
+                format!("{}", mempool_info.min_relay_fee_rate()),
                 Style::default().fg(Color::Yellow),
             ),
-            Span::styled(" vSats/vByte", Style::default().fg(C_MAIN_LABELS)),
+            Span::styled(" | incr ", Style::default().fg(*C_SEPARATORS)),
+            Span::styled(
+                format!("{}", mempool_info.incremental_relay_fee_rate()),
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::styled(" sat/vB", Style::default().fg(*C_MAIN_LABELS)),
+            Span::styled(
+                if under_fee_pressure { "  ⚠ FULL" } else { "" },
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+        ]),
+
+        // Relay policy: full-RBF badge and transactions not yet broadcast.
+        Spans::from(vec![
+            Span::styled("📡 Relay: ", Style::default().fg(*C_MAIN_LABELS)),
+            if mempool_info.fullrbf {
+                Span::styled(
+                    "FULL-RBF",
+                    Style::default().fg(Color::Black).bg(Color::Cyan).add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Span::styled("opt-in RBF", Style::default().fg(Color::DarkGray))
+            },
+            Span::styled(" | Unbroadcast: ", Style::default().fg(*C_SEPARATORS)),
+            Span::styled(
+                mempool_info.unbroadcastcount.to_formatted_string(&Locale::en),
+                if mempool_info.unbroadcastcount > 0 {
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(*C_MEMPOOL_VALUES)
+                },
+            ),
         ]),
 
         // -------------------------------------------------------------------
         // SIZE DISTRIBUTION
         // -------------------------------------------------------------------
         Spans::from(vec![
-            Span::styled("📏 Size Distribution ", Style::default().fg(C_MAIN_LABELS)),
+            Span::styled("📏 Size Distribution ", Style::default().fg(*C_MAIN_LABELS)),
+            Span::styled(
+                if size_by_vsize { "(by vsize)" } else { "(by count)" },
+                Style::default().fg(Color::DarkGray),
+            ),
             // Optional "dust-free" tag is commented out here; preserved for future use.
             // Span::styled("dᵤₛₜ₋fᵣₑₑ", Style::default().fg(Color::DarkGray)
             //    .add_modifier(Modifier::ITALIC)),
@@ -300,19 +390,19 @@ pub fn display_mempool_info<B: Backend>(
                 Style::default().fg(C_MEMPOOL_DIST_LABELS),
             ),
             Span::styled(
-                format!("{:>7}", (distribution.small).to_formatted_string(&Locale::en)),
-                Style::default().fg(C_MEMPOOL_VALUES),
+                format!("{:>7}", size_value(0)),
+                Style::default().fg(*C_MEMPOOL_VALUES),
             ),
-            Span::styled(" - ", Style::default().fg(C_SEPARATORS)),
+            Span::styled(" - ", Style::default().fg(*C_SEPARATORS)),
             Span::styled(
                 format!("{:>3}%", small_pct),
-                Style::default().fg(C_MEMPOOL_VALUES),
+                Style::default().fg(*C_MEMPOOL_VALUES),
             ),
             
             Span::styled(
                 format!(" {}", small_prog_bar),
                 Style::default()
-                    .fg(C_HORIZONTAL_ASCII_BAR)
+                    .fg(*C_HORIZONTAL_ASCII_BAR)
                     .add_modifier(Modifier::DIM),
             ),
         ]),
@@ -322,19 +412,19 @@ pub fn display_mempool_info<B: Backend>(
                 Style::default().fg(C_MEMPOOL_DIST_LABELS),
             ),
             Span::styled(
-                format!("{:>7}", (distribution.medium).to_formatted_string(&Locale::en)),
-                Style::default().fg(C_MEMPOOL_VALUES),
+                format!("{:>7}", size_value(1)),
+                Style::default().fg(*C_MEMPOOL_VALUES),
             ),
             Span::styled(" - ", Style::default().fg(Color::DarkGray)),
             Span::styled(
                 format!("{:>3}%", medium_pct),
-                Style::default().fg(C_MEMPOOL_VALUES),
+                Style::default().fg(*C_MEMPOOL_VALUES),
             ),
             
             Span::styled(
                 format!(" {}", medium_prog_bar),
                 Style::default()
-                    .fg(C_HORIZONTAL_ASCII_BAR)
+                    .fg(*C_HORIZONTAL_ASCII_BAR)
                     .add_modifier(Modifier::DIM),
             ),
         ]),
@@ -344,19 +434,19 @@ pub fn display_mempool_info<B: Backend>(
                 Style::default().fg(C_MEMPOOL_DIST_LABELS),
             ),
             Span::styled(
-                format!("{:>7}", (distribution.large).to_formatted_string(&Locale::en)),
-                Style::default().fg(C_MEMPOOL_VALUES),
+                format!("{:>7}", size_value(2)),
+                Style::default().fg(*C_MEMPOOL_VALUES),
             ),
-            Span::styled(" - ", Style::default().fg(C_SEPARATORS)),
+            Span::styled(" - ", Style::default().fg(*C_SEPARATORS)),
             Span::styled(
                 format!("{:>3}%", large_pct),
-                Style::default().fg(C_MEMPOOL_VALUES),
+                Style::default().fg(*C_MEMPOOL_VALUES),
             ),
             
             Span::styled(
                 format!(" {}", large_prog_bar),
                 Style::default()
-                    .fg(C_HORIZONTAL_ASCII_BAR)
+                    .fg(*C_HORIZONTAL_ASCII_BAR)
                     .add_modifier(Modifier::DIM),
             ),
         ]),
@@ -365,7 +455,7 @@ pub fn display_mempool_info<B: Backend>(
         // AGE DISTRIBUTION
         // -------------------------------------------------------------------
         Spans::from(vec![
-            Span::styled("⏳ Age Distribution ", Style::default().fg(C_MAIN_LABELS)),
+            Span::styled("⏳ Age Distribution ", Style::default().fg(*C_MAIN_LABELS)),
             //Span::styled("dᵤₛₜ₋fᵣₑₑ", Style::default().fg(Color::DarkGray)
             //    .add_modifier(Modifier::ITALIC)),
         ]),
@@ -376,18 +466,18 @@ pub fn display_mempool_info<B: Backend>(
             ),
             Span::styled(
                 format!("{:>7}", (distribution.young).to_formatted_string(&Locale::en)),
-                Style::default().fg(C_MEMPOOL_VALUES),
+                Style::default().fg(*C_MEMPOOL_VALUES),
             ),
-            Span::styled(" - ", Style::default().fg(C_SEPARATORS)),
+            Span::styled(" - ", Style::default().fg(*C_SEPARATORS)),
             Span::styled(
                 format!("{:>3}%", young_pct),
-                Style::default().fg(C_MEMPOOL_VALUES),
+                Style::default().fg(*C_MEMPOOL_VALUES),
             ),
             
             Span::styled(
                 format!(" {}", young_prog_bar),
                 Style::default()
-                    .fg(C_HORIZONTAL_ASCII_BAR)
+                    .fg(*C_HORIZONTAL_ASCII_BAR)
                     .add_modifier(Modifier::DIM),
             ),
         ]),
@@ -401,18 +491,18 @@ pub fn display_mempool_info<B: Backend>(
                     "{:>7}",
                     (distribution.moderate).to_formatted_string(&Locale::en)
                 ),
-                Style::default().fg(C_MEMPOOL_VALUES),
+                Style::default().fg(*C_MEMPOOL_VALUES),
             ),
-            Span::styled(" - ", Style::default().fg(C_SEPARATORS)),
+            Span::styled(" - ", Style::default().fg(*C_SEPARATORS)),
             Span::styled(
                 format!("{:>3}%", moderate_pct),
-                Style::default().fg(C_MEMPOOL_VALUES),
+                Style::default().fg(*C_MEMPOOL_VALUES),
             ),
             
             Span::styled(
                 format!(" {}", moderate_prog_bar),
                 Style::default()
-                    .fg(C_HORIZONTAL_ASCII_BAR)
+                    .fg(*C_HORIZONTAL_ASCII_BAR)
                     .add_modifier(Modifier::DIM),
             ),
         ]),
@@ -423,18 +513,18 @@ pub fn display_mempool_info<B: Backend>(
             ),
             Span::styled(
                 format!("{:>7}", (distribution.old).to_formatted_string(&Locale::en)),
-                Style::default().fg(C_MEMPOOL_VALUES),
+                Style::default().fg(*C_MEMPOOL_VALUES),
             ),
-            Span::styled(" - ", Style::default().fg(C_SEPARATORS)),
+            Span::styled(" - ", Style::default().fg(*C_SEPARATORS)),
             Span::styled(
                 format!("{:>3}%", old_pct),
-                Style::default().fg(C_MEMPOOL_VALUES),
+                Style::default().fg(*C_MEMPOOL_VALUES),
             ),
             
             Span::styled(
                 format!(" {}", old_prog_bar),
                 Style::default()
-                    .fg(C_HORIZONTAL_ASCII_BAR)
+                    .fg(*C_HORIZONTAL_ASCII_BAR)
                     .add_modifier(Modifier::DIM),
             ),
         ]),
@@ -443,7 +533,7 @@ pub fn display_mempool_info<B: Backend>(
         // RBF DISTRIBUTION
         // -------------------------------------------------------------------
         Spans::from(vec![
-            Span::styled("♻️ RBF Distribution ", Style::default().fg(C_MAIN_LABELS)),
+            Span::styled("♻️ RBF Distribution ", Style::default().fg(*C_MAIN_LABELS)),
             //Span::styled("dᵤₛₜ₋fᵣₑₑ", Style::default().fg(Color::DarkGray)
             //    .add_modifier(Modifier::ITALIC)),
         ]),
@@ -457,18 +547,18 @@ pub fn display_mempool_info<B: Backend>(
                     "{:>7}",
                     (distribution.rbf_count).to_formatted_string(&Locale::en)
                 ),
-                Style::default().fg(C_MEMPOOL_VALUES),
+                Style::default().fg(*C_MEMPOOL_VALUES),
             ),
-            Span::styled(" - ", Style::default().fg(C_SEPARATORS)),
+            Span::styled(" - ", Style::default().fg(*C_SEPARATORS)),
             Span::styled(
                 format!("{:>3}%", rbf_pct),
-                Style::default().fg(C_MEMPOOL_VALUES),
+                Style::default().fg(*C_MEMPOOL_VALUES),
             ),
             
             Span::styled(
                 format!(" {}", rbf_prog_bar),
                 Style::default()
-                    .fg(C_HORIZONTAL_ASCII_BAR)
+                    .fg(*C_HORIZONTAL_ASCII_BAR)
                     .add_modifier(Modifier::DIM),
             ),
         ]),
@@ -482,23 +572,23 @@ pub fn display_mempool_info<B: Backend>(
                     "{:>7}",
                     (distribution.non_rbf_count).to_formatted_string(&Locale::en)
                 ),
-                Style::default().fg(C_MEMPOOL_VALUES),
+                Style::default().fg(*C_MEMPOOL_VALUES),
             ),
-            Span::styled(" - ", Style::default().fg(C_SEPARATORS)),
+            Span::styled(" - ", Style::default().fg(*C_SEPARATORS)),
             Span::styled(
                 format!("{:>3}%", non_rbf_pct),
-                Style::default().fg(C_MEMPOOL_VALUES),
+                Style::default().fg(*C_MEMPOOL_VALUES),
             ),
             
             Span::styled(
                 format!(" {}", non_rbf_prog_bar),
                 Style::default()
-                    .fg(C_HORIZONTAL_ASCII_BAR)
+                    .fg(*C_HORIZONTAL_ASCII_BAR)
                     .add_modifier(Modifier::DIM),
             ),
             /* Span::styled(
                 format!("{:>3}% {}", non_rbf_pct, non_rbf_prog_bar),
-                Style::default().fg(C_HORIZONTAL_ASCII_BAR)
+                Style::default().fg(*C_HORIZONTAL_ASCII_BAR)
                 .add_modifier(Modifier::DIM),
             ), */
         ]),
@@ -507,7 +597,7 @@ pub fn display_mempool_info<B: Backend>(
         // FEE METRICS
         // -------------------------------------------------------------------
         Spans::from(vec![
-            Span::styled("📉 Fee Metrics ", Style::default().fg(C_MAIN_LABELS)),
+            Span::styled("📉 Fee Metrics ", Style::default().fg(*C_MAIN_LABELS)),
         ]),
         Spans::from(vec![
             Span::styled(
@@ -516,7 +606,7 @@ pub fn display_mempool_info<B: Backend>(
             ),
             Span::styled(
                 format!("{:.8}", distribution.average_fee as f64 / SATS_PER_BTC),
-                Style::default().fg(C_MEMPOOL_VALUES),
+                Style::default().fg(*C_MEMPOOL_VALUES),
             ),
             Span::raw("   "),
             Span::styled(
@@ -525,7 +615,7 @@ pub fn display_mempool_info<B: Backend>(
             ),
             Span::styled(
                 format!("{:.8}", distribution.median_fee as f64 / SATS_PER_BTC),
-                Style::default().fg(C_MEMPOOL_VALUES),
+                Style::default().fg(*C_MEMPOOL_VALUES),
             ),
         ]),
         Spans::from(vec![
@@ -535,7 +625,7 @@ pub fn display_mempool_info<B: Backend>(
         ),
         Span::styled(
             format!("{:.2}", distribution.average_fee_rate),
-            Style::default().fg(C_MEMPOOL_VALUES),
+            Style::default().fg(*C_MEMPOOL_VALUES),
         ),
         Span::styled(
             "   Median: ",
@@ -543,7 +633,7 @@ pub fn display_mempool_info<B: Backend>(
         ),
         Span::styled(
             format!("{:.2}", distribution.median_fee_rate),
-            Style::default().fg(C_MEMPOOL_VALUES),
+            Style::default().fg(*C_MEMPOOL_VALUES),
         ),
     ])
     ];
@@ -552,9 +642,214 @@ pub fn display_mempool_info<B: Backend>(
     let mempool_paragraph = Paragraph::new(mempool_content)
         .block(Block::default().borders(Borders::NONE));
 
-    frame.render_widget(mempool_paragraph, chunks[2]);
+    // -----------------------------------------------------------------------
+    // 9. CONTENT SPLIT: METRICS (LEFT) / FEE TRENDS (RIGHT)
+    // -----------------------------------------------------------------------
+    // Mirrors the Network section: 68% text, 32% charts.
+    let content_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(68), Constraint::Percentage(32)])
+        .split(chunks[2]);
+
+    frame.render_widget(mempool_paragraph, content_chunks[0]);
+
+    let fee_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(8),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(8),
+            Constraint::Min(0),
+        ])
+        .split(content_chunks[1]);
+
+    draw_fee_rate_trend(frame, fee_chunks[0], fee_rate_trend);
+    draw_next_block_floor(frame, fee_chunks[1], distribution, mempool_info);
+    draw_fee_targets(frame, fee_chunks[2], fee_estimates);
+    draw_feerate_histogram(frame, fee_chunks[3], distribution);
 
     Ok(())
 }
 
+/// Advances the global spinner and returns the frame to draw.
+fn next_spinner_frame() -> &'static str {
+    SPINNER_FRAMES[SPINNER_INDEX.fetch_add(1, Ordering::Relaxed) % SPINNER_FRAMES.len()]
+}
+
+/// Draws a single centered status message in place of the mempool body.
+///
+/// `busy` states (loading) are yellow; settled states (empty) are gray.
+fn draw_status_line<B: Backend>(frame: &mut Frame<B>, area: Rect, text: &str, busy: bool) {
+    let color = if busy { Color::Yellow } else { *C_MEMPOOL_VALUES };
+    let status = Paragraph::new(text.to_string())
+        .style(Style::default().fg(color))
+        .alignment(Alignment::Center);
+    frame.render_widget(status, area);
+}
 
+/// Draws the confirmed fee-rate trend sparkline.
+///
+/// One column per block (oldest → newest) showing the median fee rate
+/// confirmed in that block, as reported by `getblockstats`. Zero-valued
+/// columns are gaps: blocks not yet fetched or pruned from disk.
+///
+/// The title carries the most recent non-gap value so the trend has a
+/// numerical anchor, similar to the propagation sparkline.
+fn draw_fee_rate_trend<B: Backend>(
+    frame: &mut Frame<B>,
+    area: Rect,
+    fee_rate_trend: &[u64],
+) {
+    if fee_rate_trend.iter().all(|&v| v == 0) {
+        return;
+    }
+
+    let latest = fee_rate_trend
+        .iter()
+        .rev()
+        .find(|&&v| v > 0)
+        .copied()
+        .unwrap_or(0);
+
+    let sparkline = Sparkline::default()
+        .block(
+            Block::default()
+                .title(format!("Block Fee Rate ({} sat/vB)", latest))
+                .borders(Borders::ALL),
+        )
+        .data(fee_rate_trend)
+        .style(Style::default().fg(*C_FEE_TREND_SPARKLINE));
+
+    frame.render_widget(sparkline, area);
+}
+
+
+
+/// Draws the next-block minimum fee rate.
+///
+/// This is the fee rate of the lowest-feerate transaction still inside the
+/// top ~1M vbytes of the cached mempool. When the cache doesn't fill a
+/// block, any transaction paying the relay minimum should confirm, so the
+/// node's `minrelaytxfee` is shown instead.
+fn draw_next_block_floor<B: Backend>(
+    frame: &mut Frame<B>,
+    area: Rect,
+    distribution: &MempoolDistribution,
+    mempool_info: &MempoolInfo,
+) {
+    let rate = distribution.next_block_min_feerate(mempool_info.min_relay_fee_rate());
+    let value = match distribution.next_block_fee_rate_floor {
+        Some(_) => format!("{} sat/vB", rate),
+        None => format!("{} sat/vB (min relay)", rate),
+    };
+
+    let paragraph = Paragraph::new(Spans::from(vec![Span::styled(
+        value,
+        Style::default()
+            .fg(C_NEXT_BLOCK_FLOOR)
+            .add_modifier(Modifier::BOLD),
+    )]))
+    .alignment(Alignment::Center)
+    .block(
+        Block::default()
+            .title("Next-block min feerate")
+            .borders(Borders::ALL),
+    );
+
+    frame.render_widget(paragraph, area);
+}
+
+/// Draws the fee-rate histogram: one row per sats/vB band with its share
+/// of the sampled mempool as a percent and a 10-character ASCII bar.
+///
+/// Shows where the bulk of waiting transactions sits relative to the
+/// next-block floor above it.
+fn draw_feerate_histogram<B: Backend>(
+    frame: &mut Frame<B>,
+    area: Rect,
+    distribution: &MempoolDistribution,
+) {
+    let counts: Vec<u64> = distribution.feerate_buckets.iter().map(|&c| c as u64).collect();
+    let pcts = normalize_percentages(&counts);
+
+    let lines: Vec<Spans> = FEERATE_BUCKET_LABELS
+        .iter()
+        .zip(pcts.iter())
+        .map(|(label, &pct)| {
+            Spans::from(vec![
+                Span::styled(format!("{:<6}", label), Style::default().fg(C_MEMPOOL_DIST_LABELS)),
+                Span::styled(
+                    create_progress_bar(pct, 10),
+                    Style::default()
+                        .fg(*C_HORIZONTAL_ASCII_BAR)
+                        .add_modifier(Modifier::DIM),
+                ),
+                Span::styled(format!(" {:>3}%", pct), Style::default().fg(*C_MEMPOOL_VALUES)),
+            ])
+        })
+        .collect();
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .title("Fee Rates (sat/vB)")
+            .borders(Borders::ALL),
+    );
+
+    frame.render_widget(paragraph, area);
+}
+
+/// Draws Core's `estimatesmartfee` results as one line: `1b 12 · 3b 8 · …` (sat/vB).
+///
+/// Targets Core can't estimate yet are absent; with none at all (fresh
+/// node, `-blocksonly`) the box says so instead.
+fn draw_fee_targets<B: Backend>(
+    frame: &mut Frame<B>,
+    area: Rect,
+    fee_estimates: &[(u16, f64)],
+) {
+    let line = if fee_estimates.is_empty() {
+        Spans::from(Span::styled(
+            "insufficient data",
+            Style::default().fg(*C_SECTION_LABELS),
+        ))
+    } else {
+        let mut spans = Vec::new();
+        for (i, (target, rate)) in fee_estimates.iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::styled(" · ", Style::default().fg(*C_SEPARATORS)));
+            }
+            spans.push(Span::styled(
+                format!("{}b ", target),
+                Style::default().fg(*C_SECTION_LABELS),
+            ));
+            spans.push(Span::styled(
+                format!("{:.0}", rate),
+                Style::default().fg(C_MEMPOOL_DIST_LABELS),
+            ));
+        }
+        Spans::from(spans)
+    };
+
+    let paragraph = Paragraph::new(line)
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .title("Fee Targets (sat/vB)")
+                .borders(Borders::ALL),
+        );
+
+    frame.render_widget(paragraph, area);
+}
+
+/// Compact vbyte total for the size distribution's vsize view, e.g.
+/// `"850 vB"`, `"12.3 kvB"`, `"4.1 MvB"`.
+fn format_vsize(vbytes: u64) -> String {
+    if vbytes >= 1_000_000 {
+        format!("{:.1} MvB", vbytes as f64 / 1_000_000.0)
+    } else if vbytes >= 1_000 {
+        format!("{:.1} kvB", vbytes as f64 / 1_000.0)
+    } else {
+        format!("{} vB", vbytes)
+    }
+}
@@ -6,7 +6,9 @@
 // It includes:
 //   - Incoming/outgoing connection counts (with flashing IN counter)
 //   - Total bytes received/sent (formatted human-readable)
-//   - Average block propagation time (color-coded severity)
+//   - Median block propagation time (color-coded severity)
+//   - High-bandwidth compact-block peer counts (BIP152)
+//   - Tip freshness warning when the node trails its peers
 //   - Toggle-view section: Version Distribution (BarChart) OR Client Distribution (ASCII)
 //   - Sparkline showing recent block propagation times
 //
@@ -21,50 +23,143 @@ use tui::{
     widgets::{BarChart, Block, Borders, Paragraph, Sparkline},
     Frame,
 };
-use crate::models::{errors::MyError, network_info::NetworkInfo, network_totals::NetTotals};
-use crate::utils::{format_size, normalize_percentages, create_progress_bar};
+use crate::models::{
+    errors::MyError,
+    network_info::NetworkInfo,
+    network_totals::NetTotals,
+    peer_info::{PingSummary, PropagationUnit, SLOW_PING_SECS},
+};
+use crate::utils::{format_size, normalize_percentages, create_progress_bar, SizeUnits};
 use crate::ui::colors::*;
 use std::collections::VecDeque;
 use crate::models::flashing_text::CONNECTIONS_IN_TEXT;
+use crate::models::connection_churn::{
+    inbound_outbound_ratio, ChurnLevel, ConnectionChurn, CHURN_WINDOW_SECS,
+};
+
+/// Which peer distribution the Network section charts; `c` cycles
+/// Version → Client → Protocol → Network.
+/// Peer aggregates drawn in the Network section, computed from
+/// `getpeerinfo` once per frame.
+#[derive(Debug, Clone, Copy)]
+pub struct NetworkView<'a> {
+    /// Peers per software version (`/Satoshi:x.y.z/`).
+    pub version_counts: &'a [(String, usize)],
+    /// Peers per client implementation.
+    pub client_counts: &'a [(String, usize)],
+    /// Peers per P2P protocol version.
+    pub protocol_counts: &'a [(String, usize)],
+    /// Peers per network type.
+    pub network_counts: &'a [(String, usize)],
+    /// High-bandwidth compact-block peers: (we relay to, they relay to us).
+    pub hb_compact_peers: (usize, usize),
+    /// Min / median / max ping, if any peer reported one.
+    pub ping_summary: Option<PingSummary>,
+    /// Recent inbound/outbound connection-count changes.
+    pub connection_churn: &'a ConnectionChurn,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DistributionView {
+    /// Software versions from `/Satoshi:x.y.z/` user agents.
+    #[default]
+    Version,
+    /// Client implementations (Core, Knots, btcd, ...).
+    Client,
+    /// P2P protocol versions (`version`, e.g. 70016).
+    Protocol,
+    /// Network types (ipv4, ipv6, onion, i2p, cjdns).
+    Network,
+}
+
+impl DistributionView {
+    /// The view `c` switches to next.
+    pub fn next(self) -> Self {
+        match self {
+            DistributionView::Version => DistributionView::Client,
+            DistributionView::Client => DistributionView::Protocol,
+            DistributionView::Protocol => DistributionView::Network,
+            DistributionView::Network => DistributionView::Version,
+        }
+    }
+
+    /// Short name for the title hint, e.g. `(c→Client)`.
+    pub fn label(self) -> &'static str {
+        match self {
+            DistributionView::Version => "Version",
+            DistributionView::Client => "Client",
+            DistributionView::Protocol => "Protocol",
+            DistributionView::Network => "Net",
+        }
+    }
+}
 
 /// Renders the Network Information section of the dashboard.
 ///
 /// This function displays:
 ///   - Incoming/outgoing peer counts
 ///   - Total bytes received/sent over the network
-///   - Average block propagation time (with dynamic color coding)
+///   - Median block propagation time (with dynamic color coding)
+///   - High-bandwidth compact-block relay peers (in / out)
+///   - Service flags advertised by the node
+///   - Core's own warnings (`node_warnings`), in bold red, ahead of the rest
+///   - A "behind peers" warning when `blocks_behind` is set
+///   - A clock-skew warning when `clock_skew` (median peer offset, s) is set
+///   - Min / median / max peer ping next to the HB compact-block peers,
+///     yellow when any peer is over `SLOW_PING_SECS`
+///   - The inbound:outbound ratio and recent connection churn, yellow/red
+///     when churn passes `CHURN_ELEVATED` / `CHURN_HIGH`
 ///   - Either: version distribution (BarChart) OR client distribution (ASCII)
 ///   - A sparkline of recent propagation times
 ///
-/// The caller controls whether to show client distribution via `show_client_distribution`.
+/// The caller picks the distribution chart (software version, client,
+/// P2P protocol version, or network type) via `distribution_view`; the
+/// peer aggregates come in `view`.
+#[allow(clippy::too_many_arguments)]
 pub fn display_network_info<B: Backend>(
     network_info: &NetworkInfo,
     net_totals: &NetTotals,
     frame: &mut Frame<B>,
-    version_counts: &[(String, usize)],
-    client_counts: &[(String, usize)],
+    view: NetworkView,
     avg_block_propagate_time: &i64,
     propagation_times: &VecDeque<i64>,
-    show_client_distribution: bool,
+    propagation_unit: PropagationUnit,
+    size_units: SizeUnits,
+    node_warnings: &[String],
+    blocks_behind: Option<u64>,
+    clock_skew: Option<i64>,
+    distribution_view: DistributionView,
     show_propagation_avg: bool,
     area: Rect,
 ) -> Result<(), MyError> {
+    let NetworkView {
+        version_counts,
+        client_counts,
+        protocol_counts,
+        network_counts,
+        hb_compact_peers,
+        ping_summary,
+        connection_churn,
+    } = view;
     
     // -----------------------------------------------------------------------
     // 1. BLOCK PROPAGATION TIME COLORING
     // -----------------------------------------------------------------------
-    // Color thresholds:
-    //   < 3 seconds      → Ideal (Green)
-    //   < 60 seconds     → Caution (Yellow)
-    //   >= 60 seconds    → Critical (Red)
-    let color = if avg_block_propagate_time.abs() < 3 {
+    // Color thresholds (either sign; header times carry miner clock skew):
+    //   < 18 seconds     → Ideal (Green)
+    //   < 360 seconds    → Caution (Yellow)
+    //   >= 360 seconds   → Critical (Red)
+    // Thresholds are in seconds, so raw-ms mode is converted before
+    // comparison.
+    let propagation_secs = propagation_unit.to_seconds(*avg_block_propagate_time);
+    let color = if propagation_secs.abs() < 18 {
         C_STATUS_LOW
-    } else if avg_block_propagate_time.abs() < 60 {
-        C_STATUS_MED
+    } else if propagation_secs.abs() < 360 {
+        *C_STATUS_MED
     } else {
-        C_STATUS_HIGH
+        *C_STATUS_HIGH
     };
-    let abpt_text = "seconds";
+    let abpt_text = propagation_unit.label();
 
     // -----------------------------------------------------------------------
     // 2. FLASHING CONNECTION-IN COUNTER
@@ -79,14 +174,30 @@ pub fn display_network_info<B: Backend>(
     let connections_in_style = CONNECTIONS_IN_TEXT.lock().unwrap().style();
 
     let connections_in_spans = Spans::from(vec![
-        Span::styled("🔌 In: ", Style::default().fg(C_MAIN_LABELS)),
+        Span::styled("🔌 In: ", Style::default().fg(*C_MAIN_LABELS)),
         Span::styled(network_info.connections_in.to_string(), connections_in_style),
         Span::raw("   "),
-        Span::styled("Out: ", Style::default().fg(C_MAIN_LABELS)),
+        Span::styled("Out: ", Style::default().fg(*C_MAIN_LABELS)),
         Span::styled(
             network_info.connections_out.to_string(),
             Style::default().fg(C_CONNECTIONS_OUT),
         ),
+        Span::raw("   "),
+        Span::styled(
+            format!(
+                "Ratio: {}   Churn: {}/{}m",
+                inbound_outbound_ratio(network_info.connections_in, network_info.connections_out)
+                    .map(|r| format!("{:.1}:1", r))
+                    .unwrap_or_else(|| "N/A".to_string()),
+                connection_churn.churn(),
+                CHURN_WINDOW_SECS / 60,
+            ),
+            Style::default().fg(match connection_churn.level() {
+                ChurnLevel::Normal => *C_MAIN_LABELS,
+                ChurnLevel::Elevated => *C_STATUS_MED,
+                ChurnLevel::High => *C_STATUS_HIGH,
+            }),
+        ),
     ]);
 
     // -----------------------------------------------------------------------
@@ -102,53 +213,166 @@ pub fn display_network_info<B: Backend>(
         .constraints(
             [
                 Constraint::Length(1),  // Header line.
-                Constraint::Length(4),  // Network stats block.
+                // Network stats block; one more line for the upload target, if set.
+                Constraint::Length(if net_totals.uploadtarget.is_set() { 6 } else { 5 }),
                 Constraint::Min(8),     // Distribution + Sparkline.
             ]
             .as_ref(),
         )
         .split(area);
 
-    // Header placeholder (keeps consistency across display modules).
-    let header = Block::default()
-        .borders(Borders::NONE)
-        .style(Style::default().fg(Color::Cyan));
-    frame.render_widget(header, chunks[0]);
+    // Header line: empty unless Core reports warnings, the node trails its
+    // peers' median height, or its clock disagrees with theirs.
+    let mut warnings: Vec<Span> = Vec::new();
+    if !node_warnings.is_empty() {
+        warnings.push(Span::styled(
+            format!("⚠ {}", node_warnings.join(" | ")),
+            Style::default()
+                .fg(*C_STATUS_HIGH)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    if let Some(behind) = blocks_behind {
+        if !warnings.is_empty() {
+            warnings.push(Span::raw("   "));
+        }
+        warnings.push(Span::styled(
+            format!("⚠ Node appears behind peers by ~{} blocks", behind),
+            Style::default()
+                .fg(*C_STATUS_HIGH)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    if let Some(offset) = clock_skew {
+        if !warnings.is_empty() {
+            warnings.push(Span::raw("   "));
+        }
+        warnings.push(Span::styled(
+            format!("⚠ Network time offset: {:+}s (check clock)", offset),
+            Style::default().fg(*C_STATUS_MED),
+        ));
+    }
+
+    if !warnings.is_empty() {
+        frame.render_widget(Paragraph::new(Spans::from(warnings)), chunks[0]);
+    } else {
+        // Header placeholder (keeps consistency across display modules).
+        let header = Block::default()
+            .borders(Borders::NONE)
+            .style(Style::default().fg(Color::Cyan));
+        frame.render_widget(header, chunks[0]);
+    }
 
     // -----------------------------------------------------------------------
     // 4. CORE NETWORK STATS
     // -----------------------------------------------------------------------
     // These are presented as vertically stacked Span rows.
     // -----------------------------------------------------------------------
-    let network_content = vec![
+    let mut network_content = vec![
         connections_in_spans,
 
         Spans::from(vec![
-            Span::styled("⬇️ Recv: ", Style::default().fg(C_MAIN_LABELS)),
+            Span::styled("⬇️ Recv: ", Style::default().fg(*C_MAIN_LABELS)),
             Span::styled(
-                format_size(net_totals.totalbytesrecv),
-                Style::default().fg(C_MAIN_LABELS),
+                format_size(net_totals.totalbytesrecv, size_units),
+                Style::default().fg(*C_MAIN_LABELS),
             ),
             Span::raw("   "),
-            Span::styled("⬆️ Sent: ", Style::default().fg(C_MAIN_LABELS)),
+            Span::styled("⬆️ Sent: ", Style::default().fg(*C_MAIN_LABELS)),
             Span::styled(
-                format_size(net_totals.totalbytessent),
-                Style::default().fg(C_MAIN_LABELS),
+                format_size(net_totals.totalbytessent, size_units),
+                Style::default().fg(*C_MAIN_LABELS),
             ),
         ]),
 
         Spans::from(vec![
             Span::styled(
-                "⏱️ Average Block Propagation Time: ",
-                Style::default().fg(C_MAIN_LABELS),
+                "⏱️ Median Block Propagation Time: ",
+                Style::default().fg(*C_MAIN_LABELS),
             ),
             Span::styled(
                 format!("{:.0} {}", avg_block_propagate_time, abpt_text),
                 Style::default().fg(color),
             ),
         ]),
+
+        Spans::from(vec![
+            Span::styled("📦 HB Compact-Block Peers: ", Style::default().fg(*C_MAIN_LABELS)),
+            Span::styled(
+                format!("{} in / {} out", hb_compact_peers.0, hb_compact_peers.1),
+                Style::default().fg(if hb_compact_peers.0 == 0 {
+                    *C_STATUS_MED
+                } else {
+                    *C_MAIN_LABELS
+                }),
+            ),
+            Span::raw("   "),
+            Span::styled("📶 Ping: ", Style::default().fg(*C_MAIN_LABELS)),
+            match ping_summary {
+                Some(ping) => Span::styled(
+                    format!(
+                        "{:.0} / {:.0} / {:.0} ms{}",
+                        ping.min_ms,
+                        ping.median_ms,
+                        ping.max_ms,
+                        if ping.slow > 0 {
+                            format!("  ({} over {:.0} ms)", ping.slow, SLOW_PING_SECS * 1000.0)
+                        } else {
+                            String::new()
+                        }
+                    ),
+                    Style::default().fg(if ping.slow > 0 { *C_STATUS_MED } else { *C_MAIN_LABELS }),
+                ),
+                None => Span::styled("N/A", Style::default().fg(*C_MAIN_LABELS)),
+            },
+        ]),
+
+        Spans::from(vec![
+            Span::styled("🛠️ Services: ", Style::default().fg(*C_MAIN_LABELS)),
+            Span::styled(
+                {
+                    let names = network_info.local_service_names();
+                    if names.is_empty() { "N/A".to_string() } else { names.join(" ") }
+                },
+                Style::default().fg(*C_SECTION_LABELS),
+            ),
+        ]),
     ];
 
+    // Upload target (`-maxuploadtarget`): quota left in the current cycle,
+    // or a warning once it is used up and historical blocks stop being served.
+    let upload = &net_totals.uploadtarget;
+    if upload.is_set() {
+        let resets_in = format!(
+            "{}h {:02}m",
+            upload.time_left_in_cycle / 3600,
+            upload.time_left_in_cycle % 3600 / 60
+        );
+        let (text, color) = if upload.target_reached {
+            (
+                format!("⚠ target reached (resets in {})", resets_in),
+                *C_STATUS_HIGH,
+            )
+        } else {
+            (
+                format!(
+                    "{} left of {} (resets in {})",
+                    format_size(upload.bytes_left_in_cycle, size_units),
+                    format_size(upload.target, size_units),
+                    resets_in
+                ),
+                *C_MAIN_LABELS,
+            )
+        };
+        network_content.insert(
+            2,
+            Spans::from(vec![
+                Span::styled("📤 Upload Target: ", Style::default().fg(*C_MAIN_LABELS)),
+                Span::styled(text, Style::default().fg(color)),
+            ]),
+        );
+    }
+
     // Render the network stats paragraph.
     let network_paragraph = Paragraph::new(network_content)
         .block(Block::default().borders(Borders::NONE));
@@ -165,45 +389,24 @@ pub fn display_network_info<B: Backend>(
         .split(chunks[2]);
 
     // -----------------------------------------------------------------------
-    // 6. LEFT SIDE: CLIENT OR VERSION DISTRIBUTION
+    // 6. LEFT SIDE: VERSION, CLIENT, PROTOCOL OR NETWORK DISTRIBUTION
     // -----------------------------------------------------------------------
-    if show_client_distribution {
+    match distribution_view {
         // ASCII client distribution (new feature)
-        draw_client_distribution(frame, sub_chunks[0], client_counts);
-
-    } else {
+        DistributionView::Client => {
+            draw_ascii_distribution(frame, sub_chunks[0], "Client Distribution", client_counts)
+        }
         // Traditional Version Distribution BarChart (Top 5 entries)
-        if !version_counts.is_empty() {
-            let limited_version_counts = version_counts.iter().take(5);
-
-            // Convert input tuple format → BarChart data array
-            let data: Vec<(&str, u64)> = limited_version_counts
-                .map(|(version, count)| (version.as_str(), *count as u64))
-                .collect();
-
-            let total_versions = version_counts.len();
-            let top5orless = if total_versions < 5 {
-                total_versions
-            } else {
-                5
-            };
-
-            let barchart = BarChart::default()
-                .block(
-                    Block::default()
-                        .title(format!(
-                            "Version Distribution (Top {} of {})",
-                            top5orless, total_versions
-                        ))
-                        .borders(Borders::ALL),
-                )
-                .data(&data)
-                .bar_width(7)
-                .bar_gap(1)
-                .bar_style(Style::default().fg(C_VERSION_CHART_BARS))
-                .value_style(Style::default().fg(C_VERSION_CHART_VALUES));
-
-            frame.render_widget(barchart, sub_chunks[0]);
+        DistributionView::Version => {
+            draw_distribution_barchart(frame, sub_chunks[0], "Version", version_counts)
+        }
+        // Same chart over P2P protocol versions (e.g. 70016)
+        DistributionView::Protocol => {
+            draw_distribution_barchart(frame, sub_chunks[0], "Protocol", protocol_counts)
+        }
+        // Peers per network type (ipv4 / ipv6 / onion / i2p / cjdns)
+        DistributionView::Network => {
+            draw_ascii_distribution(frame, sub_chunks[0], "Network Distribution", network_counts)
         }
     }
 
@@ -249,6 +452,7 @@ pub fn display_network_info<B: Backend>(
             total_len as i64,
             oldest_5_avg,
             newest_5_avg,
+            propagation_unit.suffix(),
         );
 
     } 
@@ -262,11 +466,11 @@ pub fn display_network_info<B: Backend>(
         let sparkline = Sparkline::default()
             .block(
                 Block::default()
-                    .title("Propagation Times")
+                    .title(format!("Propagation Times ({})", propagation_unit.suffix()))
                     .borders(Borders::ALL),
             )
             .data(&propagation_data)
-            .style(Style::default().fg(C_SPARKLINE));
+            .style(Style::default().fg(*C_SPARKLINE));
 
         frame.render_widget(sparkline, sub_chunks[1]);
     }
@@ -274,26 +478,66 @@ pub fn display_network_info<B: Backend>(
     Ok(())
 }
 
-/// Draws the ASCII Client Distribution panel.
+/// Version-style BarChart of the top 5 `counts`, titled
+/// "<kind> Distribution (Top N of M)". Draws nothing without data.
+fn draw_distribution_barchart<B: Backend>(
+    frame: &mut Frame<B>,
+    area: Rect,
+    kind: &str,
+    counts: &[(String, usize)],
+) {
+    if counts.is_empty() {
+        return;
+    }
+
+    // Convert input tuple format → BarChart data array
+    let data: Vec<(&str, u64)> = counts
+        .iter()
+        .take(5)
+        .map(|(label, count)| (label.as_str(), *count as u64))
+        .collect();
+
+    let barchart = BarChart::default()
+        .block(
+            Block::default()
+                .title(format!(
+                    "{} Distribution (Top {} of {})",
+                    kind,
+                    data.len(),
+                    counts.len()
+                ))
+                .borders(Borders::ALL),
+        )
+        .data(&data)
+        .bar_width(7)
+        .bar_gap(1)
+        .bar_style(Style::default().fg(*C_VERSION_CHART_BARS))
+        .value_style(Style::default().fg(C_VERSION_CHART_VALUES));
+
+    frame.render_widget(barchart, area);
+}
+
+/// Draws a peer distribution as ASCII rows under `title`.
 ///
-/// This is used when `[Network] (c→Client)` toggle is active.
-/// Displays up to 6 client names, with count, percent, and ASCII progress bar.
+/// Used for the Client and Network views of the `c` cycle.
+/// Displays up to 6 names, with count, percent, and ASCII progress bar.
 ///
 /// Example row:
 ///   BitcoinKnots     134  -  18% [====      ]
-fn draw_client_distribution<B: Backend>(
+fn draw_ascii_distribution<B: Backend>(
     frame: &mut Frame<B>,
     area: Rect,
-    client_counts: &[(String, usize)],
+    title: &str,
+    counts: &[(String, usize)],
 ) {
-    if client_counts.is_empty() {
+    if counts.is_empty() {
         return;
     }
 
     // -----------------------------------------------------------------------
     // 0. Build a display list: top 5 + "Other" (rest)
     // -----------------------------------------------------------------------
-    let mut rows: Vec<(String, usize)> = client_counts.to_vec();
+    let mut rows: Vec<(String, usize)> = counts.to_vec();
 
     rows.sort_by(|a, b| b.1.cmp(&a.1));
 
@@ -301,7 +545,7 @@ fn draw_client_distribution<B: Backend>(
         rows
     } else {
         let mut top = rows.into_iter().take(5).collect::<Vec<_>>();
-        let other_sum: usize = client_counts.iter().skip(5).map(|(_, c)| *c).sum();
+        let other_sum: usize = counts.iter().skip(5).map(|(_, c)| *c).sum();
         top.push(("Other".to_string(), other_sum));
         top
     };
@@ -326,12 +570,12 @@ fn draw_client_distribution<B: Backend>(
 
         let bar = create_progress_bar(*pct, 10);
 
-        let count_span = Span::styled(format!("{:>5} ", count), Style::default().fg(C_CLIENT_DIST_MINER_COUNT));
+        let count_span = Span::styled(format!("{:>5} ", count), Style::default().fg(*C_CLIENT_DIST_MINER_COUNT));
 
-        let dash_span = Span::styled("- ", Style::default().fg(C_SEPARATORS));
+        let dash_span = Span::styled("- ", Style::default().fg(*C_SEPARATORS));
 
         let pct_span =
-            Span::styled(format!("{:>3}% ", pct), Style::default().fg(C_CLIENT_DIST_MINER_PCT));
+            Span::styled(format!("{:>3}% ", pct), Style::default().fg(*C_CLIENT_DIST_MINER_PCT));
 
         // Construct final row
         lines.push(Spans::from(vec![
@@ -340,7 +584,7 @@ fn draw_client_distribution<B: Backend>(
             count_span,
             dash_span,
             pct_span,
-            Span::styled(bar, Style::default().fg(C_HORIZONTAL_ASCII_BAR)
+            Span::styled(bar, Style::default().fg(*C_HORIZONTAL_ASCII_BAR)
             .add_modifier(Modifier::DIM)),
         ]));
     }
@@ -350,7 +594,7 @@ fn draw_client_distribution<B: Backend>(
 
     // Build the containing block + paragraph
     let block = Block::default()
-        .title("Client Distribution")
+        .title(title)
         .borders(Borders::ALL);
 
     let paragraph = Paragraph::new(lines).block(block);
@@ -365,8 +609,9 @@ fn draw_client_distribution<B: Backend>(
 /// for network synchronization health, complementing the sparkline view
 /// which emphasizes variance and shape rather than direction.
 ///
-/// The value is intentionally rendered as whole seconds to keep the signal
-/// calm, readable, and free of visual noise.
+/// The value is intentionally rendered as whole units (seconds, or
+/// milliseconds in raw mode) to keep the signal calm, readable, and free
+/// of visual noise. `unit` is the suffix appended to each value.
 pub fn draw_propagation_avg<B: Backend>(
     frame: &mut Frame<B>,
     area: Rect,
@@ -374,27 +619,31 @@ pub fn draw_propagation_avg<B: Backend>(
     propagation_len: i64,
     oldest_5_avg: Option<i64>,
     newest_5_avg: Option<i64>,
+    unit: &str,
 ) {
 
     let mut lines = Vec::new();
 
     lines.push(format!(
-        "Avg ({} blks): {}s",
+        "Avg ({} blks): {}{}",
         propagation_len,
-        overall_avg
+        overall_avg,
+        unit
     ));
 
     if let Some(avg) = oldest_5_avg {
         lines.push(format!(
-            "Oldest 5: {}s",
-            avg
+            "Oldest 5: {}{}",
+            avg,
+            unit
         ));
     }
 
     if let Some(avg) = newest_5_avg {
         lines.push(format!(
-            "Latest 5: {}s",
-            avg
+            "Latest 5: {}{}",
+            avg,
+            unit
         ));
     }
 
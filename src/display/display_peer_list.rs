@@ -0,0 +1,316 @@
+// display/display_peer_list.rs
+//
+// Renders the Peer List popup: one row per connected peer from
+// `getpeerinfo`, sorted by peer id. The Network section only shows
+// peers in aggregate (version / client charts); this table lets the
+// user see the individual connections behind those numbers.
+//
+// Inbound and outbound peers are colored differently. Long addresses
+// (Tor v3 onions, I2P) are shortened so the table keeps its columns.
+//
+// With a `geoip_db` configured, each IP peer is annotated with its
+// country and ASN from the offline database; anything unknown shows "—".
+//
+// The top visible row is the selected peer; Enter opens the Peer Detail
+// popup for it, breaking its traffic down by P2P message type.
+//
+
+use std::collections::BTreeMap;
+
+use tui::{
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+use crate::geoip::{GeoIp, PeerGeo};
+use crate::models::{errors::MyError, peer_info::{PeerInfo, SLOW_PING_SECS}};
+use crate::utils::{format_size, SizeUnits};
+use crate::ui::colors::{C_CONNECTIONS_IN, C_CONNECTIONS_OUT, C_MAIN_LABELS, C_SEPARATORS};
+
+/// Display width of the address column.
+const ADDR_WIDTH: usize = 28;
+
+/// Display width of the user-agent column.
+const SUBVER_WIDTH: usize = 22;
+
+/// Display width of the ASN column (`AS<number> <organization>`).
+const ASN_WIDTH: usize = 20;
+
+/// Placeholder for an unknown country / ASN.
+const UNKNOWN: &str = "—";
+
+/// Peers in table order (by peer id).
+fn sorted_peers(peers: &[PeerInfo]) -> Vec<&PeerInfo> {
+    let mut sorted: Vec<&PeerInfo> = peers.iter().collect();
+    sorted.sort_by_key(|p| p.id);
+    sorted
+}
+
+/// The peer shown in row `index` of the table, if any.
+pub fn peer_at(peers: &[PeerInfo], index: u16) -> Option<&PeerInfo> {
+    sorted_peers(peers).get(index as usize).copied()
+}
+
+/// Draws the peer table into `area`, scrolled to `*scroll`.
+///
+/// The top visible row is highlighted as the selection. The offset is
+/// clamped to the last row, so callers can add to it freely on key presses.
+pub fn display_peer_list<B: tui::backend::Backend>(
+    peers: &[PeerInfo],
+    geoip: Option<&GeoIp>,
+    scroll: &mut u16,
+    frame: &mut tui::Frame<B>,
+    area: tui::layout::Rect,
+) -> Result<(), MyError> {
+    frame.render_widget(Clear, area);
+
+    let sorted = sorted_peers(peers);
+
+    let header = Spans::from(Span::styled(
+        format!(
+            "{:>5}  {:<aw$}  {:<7}  {:<2}  {:<nw$}  {:<sw$}  {:>9}  {}",
+            "ID", "Address", "Network", "CC", "ASN", "Client", "Ping", "Dir",
+            aw = ADDR_WIDTH,
+            nw = ASN_WIDTH,
+            sw = SUBVER_WIDTH,
+        ),
+        Style::default().fg(*C_MAIN_LABELS).add_modifier(Modifier::BOLD),
+    ));
+
+    let rows: Vec<Spans> = if sorted.is_empty() {
+        vec![Spans::from("No peers connected.")]
+    } else {
+        sorted
+            .iter()
+            .map(|peer| {
+                let geo = geoip.map(|db| db.lookup(&peer.addr)).unwrap_or_default();
+                peer_row(peer, &geo)
+            })
+            .collect()
+    };
+
+    // Every row can be scrolled to the top, where it is the selection.
+    let max_scroll = (rows.len() as u16).saturating_sub(1);
+    *scroll = (*scroll).min(max_scroll);
+
+    let mut lines = vec![header];
+    for (i, row) in rows.into_iter().enumerate().skip(*scroll as usize) {
+        if i == *scroll as usize && !sorted.is_empty() {
+            lines.push(highlighted(row));
+        } else {
+            lines.push(row);
+        }
+    }
+
+    let inbound = peers.iter().filter(|p| p.inbound).count();
+    let block = Block::default()
+        .title(format!(
+            "Peers — {} in / {} out (↑/↓ PgUp/PgDn scroll, Enter details, Esc to go back)",
+            inbound,
+            peers.len() - inbound
+        ))
+        .borders(Borders::ALL)
+        .style(Style::default().fg(Color::Yellow));
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+
+    Ok(())
+}
+
+/// One table row; the whole row takes the direction color.
+fn peer_row(peer: &PeerInfo, geo: &PeerGeo) -> Spans<'static> {
+    let (dir, color) = if peer.inbound {
+        ("in", C_CONNECTIONS_IN)
+    } else {
+        ("out", C_CONNECTIONS_OUT)
+    };
+
+    // Slow peers (over `SLOW_PING_SECS`) are flagged with a trailing "!".
+    let ping = match peer.pingtime {
+        Some(secs) if secs > SLOW_PING_SECS => format!("{:.0} ms!", secs * 1000.0),
+        Some(secs) => format!("{:.0} ms", secs * 1000.0),
+        None => "-".to_string(),
+    };
+
+    let asn = match (geo.asn, geo.as_org.as_deref()) {
+        (Some(n), Some(org)) => format!("AS{} {}", n, org),
+        (Some(n), None) => format!("AS{}", n),
+        _ => UNKNOWN.to_string(),
+    };
+
+    Spans::from(vec![
+        Span::styled(format!("{:>5}  ", peer.id), Style::default().fg(*C_SEPARATORS)),
+        Span::styled(
+            format!(
+                "{:<aw$}  {:<7}  {:<2}  {:<nw$}  {:<sw$}  {:>9}  {}",
+                shorten_addr(&peer.addr, ADDR_WIDTH),
+                peer.network.as_deref().unwrap_or("?"),
+                geo.country.as_deref().unwrap_or(UNKNOWN),
+                shorten(&asn, ASN_WIDTH),
+                shorten(&peer.subver, SUBVER_WIDTH),
+                ping,
+                dir,
+                aw = ADDR_WIDTH,
+                nw = ASN_WIDTH,
+                sw = SUBVER_WIDTH,
+            ),
+            Style::default().fg(color),
+        ),
+    ])
+}
+
+/// Marks the selected row by reversing its colors.
+fn highlighted(row: Spans<'static>) -> Spans<'static> {
+    Spans::from(
+        row.0
+            .into_iter()
+            .map(|span| Span::styled(span.content, span.style.add_modifier(Modifier::REVERSED)))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Draws the Peer Detail popup: per-message byte counts for one peer.
+///
+/// Message types are merged from `bytessent_per_msg` and
+/// `bytesrecv_per_msg` and sorted by total traffic, largest first.
+/// `None` (the peer disconnected since it was selected) shows a notice.
+pub fn display_peer_detail<B: tui::backend::Backend>(
+    peer: Option<&PeerInfo>,
+    size_units: SizeUnits,
+    frame: &mut tui::Frame<B>,
+    area: tui::layout::Rect,
+) -> Result<(), MyError> {
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title("Peer Detail (Esc to go back)")
+        .borders(Borders::ALL)
+        .style(Style::default().fg(Color::Yellow));
+
+    let Some(peer) = peer else {
+        frame.render_widget(
+            Paragraph::new("Peer is no longer connected.").block(block),
+            area,
+        );
+        return Ok(());
+    };
+
+    let label = Style::default().fg(*C_MAIN_LABELS);
+    let value = Style::default().fg(if peer.inbound { C_CONNECTIONS_IN } else { C_CONNECTIONS_OUT });
+
+    let mut lines = vec![
+        Spans::from(vec![
+            Span::styled(format!("Peer {}  ", peer.id), label),
+            Span::styled(
+                format!("{} ({})", peer.addr, if peer.inbound { "inbound" } else { "outbound" }),
+                value,
+            ),
+        ]),
+        Spans::from(vec![
+            Span::styled("Client: ", label),
+            Span::styled(peer.subver.clone(), value),
+        ]),
+        Spans::from(vec![
+            Span::styled("Total:  ", label),
+            Span::styled(
+                format!("{} sent, {} received", format_size(peer.bytessent, size_units), format_size(peer.bytesrecv, size_units)),
+                value,
+            ),
+        ]),
+        Spans::from(""),
+    ];
+
+    let breakdown = message_breakdown(peer);
+    if breakdown.is_empty() {
+        lines.push(Spans::from(Span::styled(
+            "This node does not report per-message byte counts.",
+            Style::default().fg(*C_SEPARATORS),
+        )));
+    } else {
+        lines.push(Spans::from(Span::styled(
+            format!("{:<14}{:>14}{:>14}", "Message", "Sent", "Received"),
+            label.add_modifier(Modifier::BOLD),
+        )));
+        for (msg, sent, recv) in breakdown {
+            lines.push(Spans::from(Span::styled(
+                format!("{:<14}{:>14}{:>14}", msg, format_size(sent, size_units), format_size(recv, size_units)),
+                value,
+            )));
+        }
+    }
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+
+    Ok(())
+}
+
+/// `(message, sent, received)` per P2P message type, by total bytes
+/// descending.
+///
+/// Empty when the node omits both per-message maps.
+pub fn message_breakdown(peer: &PeerInfo) -> Vec<(String, u64, u64)> {
+    let mut merged: BTreeMap<&str, (u64, u64)> = BTreeMap::new();
+    for (msg, bytes) in peer.bytessent_per_msg.iter().flatten() {
+        merged.entry(msg.as_str()).or_default().0 += bytes;
+    }
+    for (msg, bytes) in peer.bytesrecv_per_msg.iter().flatten() {
+        merged.entry(msg.as_str()).or_default().1 += bytes;
+    }
+
+    // BTreeMap order makes ties fall back to message name.
+    let mut rows: Vec<(String, u64, u64)> = merged
+        .into_iter()
+        .map(|(msg, (sent, recv))| (msg.to_string(), sent, recv))
+        .collect();
+    rows.sort_by_key(|r| std::cmp::Reverse(r.1 + r.2));
+    rows
+}
+
+/// Shortens `host:port` to at most `max` characters.
+///
+/// The port and any `.onion` / `.i2p` suffix are kept; the middle of the
+/// host is replaced with `…`, e.g. `vww6ybal4bd7szmg…ptyd.onion:8333`.
+pub fn shorten_addr(addr: &str, max: usize) -> String {
+    if addr.chars().count() <= max {
+        return addr.to_string();
+    }
+
+    // IPv6 hosts are bracketed, so the last ':' always starts the port.
+    let (host, port) = match addr.rfind(':') {
+        Some(i) if !addr[i + 1..].is_empty() && addr[i + 1..].bytes().all(|b| b.is_ascii_digit()) => {
+            addr.split_at(i)
+        }
+        _ => (addr, ""),
+    };
+    let suffix = [".onion", ".b32.i2p", ".i2p"]
+        .iter()
+        .find(|s| host.ends_with(*s))
+        .copied()
+        .unwrap_or("");
+    let stem = &host[..host.len() - suffix.len()];
+
+    // Budget for the stem: everything not used by "…", suffix and port.
+    let fixed = 1 + suffix.chars().count() + port.chars().count();
+    if fixed + 2 > max {
+        return shorten(addr, max);
+    }
+    let keep = max - fixed;
+    let head: String = stem.chars().take(keep - keep / 3).collect();
+    let tail: String = {
+        let n = keep / 3;
+        let chars: Vec<char> = stem.chars().collect();
+        chars[chars.len().saturating_sub(n)..].iter().collect()
+    };
+
+    format!("{}…{}{}{}", head, tail, suffix, port)
+}
+
+/// Cuts `s` to `max` characters, marking the cut with `…`.
+fn shorten(s: &str, max: usize) -> String {
+    if s.chars().count() <= max {
+        return s.to_string();
+    }
+    let mut out: String = s.chars().take(max.saturating_sub(1)).collect();
+    out.push('…');
+    out
+}
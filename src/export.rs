@@ -0,0 +1,139 @@
+//! Export of dashboard data to files for external analysis.
+//!
+//! - Mempool distribution as CSV (key `e`), so time series can be
+//!   collected without scraping the TUI.
+//! - Full snapshot of the cached dashboard state as JSON (key `j`).
+//! - One-shot JSON report on stdout (`--oneshot` / `--json`), for cron and
+//!   scripts that shouldn't enter the TUI.
+//!
+//! Files are written to the data directory with a timestamped name.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{Local, Utc};
+use serde_json::json;
+
+use crate::config::RpcConfig;
+use crate::models::blockchain_info::BlockchainInfo;
+use crate::models::errors::MyError;
+use crate::models::mempool_info::{MempoolDistribution, MempoolInfo};
+use crate::models::network_info::NetworkInfo;
+use crate::models::network_totals::NetTotals;
+use crate::rpc::{fetch_blockchain_info, fetch_mempool_info, fetch_net_totals, fetch_network_info};
+use crate::utils::{
+    data_dir, BLOCKCHAIN_INFO_CACHE, CHAIN_TIP_CACHE, MEMPOOL_DISTRIBUTION_CACHE,
+    MEMPOOL_INFO_CACHE, NETWORK_INFO_CACHE, NET_TOTALS_CACHE, PEER_INFO_CACHE,
+};
+
+/// CSV header, matching the column order of each data row.
+pub const MEMPOOL_CSV_HEADER: &str = "exported_at,small,medium,large,young,moderate,old,\
+rbf,non_rbf,average_fee,median_fee,average_fee_rate";
+
+/// One CSV data row for `dist`, stamped with `exported_at`.
+pub fn mempool_distribution_csv_row(dist: &MempoolDistribution, exported_at: &str) -> String {
+    format!(
+        "{},{},{},{},{},{},{},{},{},{},{},{}",
+        exported_at,
+        dist.small,
+        dist.medium,
+        dist.large,
+        dist.young,
+        dist.moderate,
+        dist.old,
+        dist.rbf_count,
+        dist.non_rbf_count,
+        dist.average_fee,
+        dist.median_fee,
+        dist.average_fee_rate,
+    )
+}
+
+/// Writes `dist` to `path` as a header plus one row, stamped with the
+/// current UTC time (RFC 3339).
+pub fn export_mempool_distribution_csv(dist: &MempoolDistribution, path: &Path) -> Result<(), MyError> {
+    let row = mempool_distribution_csv_row(dist, &Utc::now().to_rfc3339());
+    fs::write(path, format!("{}\n{}\n", MEMPOOL_CSV_HEADER, row))
+        .map_err(|e| MyError::FileError(format!("{}: {}", path.display(), e)))
+}
+
+/// Timestamped export path in the data directory, e.g.
+/// `mempool_distribution_20250101_120000.csv`.
+pub fn mempool_export_path() -> PathBuf {
+    data_dir().join(format!(
+        "mempool_distribution_{}.csv",
+        Local::now().format("%Y%m%d_%H%M%S")
+    ))
+}
+
+/// Serializes every cached dashboard section into one JSON document.
+///
+/// Each cache is read and serialized in its own statement, so only one
+/// read guard is held at a time and only briefly. Must not be called
+/// while the caller itself holds guards on these caches: a queued writer
+/// would then block the read.
+pub async fn snapshot_state() -> Result<String, MyError> {
+    let blockchain = serde_json::to_value(&*BLOCKCHAIN_INFO_CACHE.read().await)?;
+    let mempool = serde_json::to_value(&*MEMPOOL_INFO_CACHE.read().await)?;
+    let network = serde_json::to_value(&*NETWORK_INFO_CACHE.read().await)?;
+    let net_totals = serde_json::to_value(&*NET_TOTALS_CACHE.read().await)?;
+    let peers = serde_json::to_value(&*PEER_INFO_CACHE.read().await)?;
+    let chain_tips = serde_json::to_value(&CHAIN_TIP_CACHE.read().await.result)?;
+    let distribution = serde_json::to_value(&*MEMPOOL_DISTRIBUTION_CACHE.read().await)?;
+
+    let snapshot = json!({
+        "timestamp": Utc::now().to_rfc3339(),
+        "blockchain": blockchain,
+        "mempool": mempool,
+        "mempool_distribution": distribution,
+        "network": network,
+        "net_totals": net_totals,
+        "peers": peers,
+        "chain_tips": chain_tips,
+    });
+
+    Ok(serde_json::to_string_pretty(&snapshot)?)
+}
+
+/// Timestamped snapshot path in the data directory, e.g.
+/// `snapshot_20250101_120000.json`.
+pub fn snapshot_path() -> PathBuf {
+    data_dir().join(format!("snapshot_{}.json", Local::now().format("%Y%m%d_%H%M%S")))
+}
+
+/// True when `--oneshot` or `--json` is among the CLI arguments.
+pub fn oneshot_requested<I: IntoIterator<Item = String>>(args: I) -> bool {
+    args.into_iter().any(|arg| arg == "--oneshot" || arg == "--json")
+}
+
+/// The one-shot report: the four core sections under a timestamp.
+pub fn oneshot_report(
+    blockchain: &BlockchainInfo,
+    mempool: &MempoolInfo,
+    network: &NetworkInfo,
+    net_totals: &NetTotals,
+) -> Result<String, MyError> {
+    let report = json!({
+        "timestamp": Utc::now().to_rfc3339(),
+        "blockchain": serde_json::to_value(blockchain)?,
+        "mempool": serde_json::to_value(mempool)?,
+        "network": serde_json::to_value(network)?,
+        "net_totals": serde_json::to_value(net_totals)?,
+    });
+
+    Ok(serde_json::to_string_pretty(&report)?)
+}
+
+/// Fetches blockchain, mempool, network and net totals once (concurrently)
+/// and builds the one-shot report. Any failed call fails the whole report,
+/// so scripts can rely on the exit code.
+pub async fn fetch_oneshot_report(config: &RpcConfig) -> Result<String, MyError> {
+    let (blockchain, mempool, network, net_totals) = tokio::try_join!(
+        fetch_blockchain_info(config),
+        fetch_mempool_info(config),
+        fetch_network_info(config),
+        fetch_net_totals(config),
+    )?;
+
+    oneshot_report(&blockchain, &mempool, &network, &net_totals)
+}
@@ -0,0 +1,80 @@
+//! Offline country / ASN lookup for peer addresses.
+//!
+//! Reads a MaxMind-format `.mmdb` file (GeoLite2, DB-IP, IPinfo, …) set with
+//! `geoip_db` in `config.toml`. Lookups never leave the machine, so peer
+//! addresses are not disclosed to a remote service.
+//!
+//! Country and ASN records are read from the same database. A country-only
+//! or ASN-only file simply leaves the other column empty. Overlay networks
+//! (Tor, I2P) have no IP to look up and are skipped.
+
+use std::net::{IpAddr, SocketAddr};
+use std::path::Path;
+
+use maxminddb::{geoip2, Reader};
+
+use crate::models::errors::MyError;
+
+/// An opened `.mmdb` database.
+pub struct GeoIp {
+    reader: Reader<Vec<u8>>,
+}
+
+/// What the database knows about one peer address.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PeerGeo {
+    /// ISO 3166 country code, e.g. `"DE"`.
+    pub country: Option<String>,
+    /// Autonomous system number.
+    pub asn: Option<u32>,
+    /// Autonomous system organization, e.g. `"Hetzner Online GmbH"`.
+    pub as_org: Option<String>,
+}
+
+impl GeoIp {
+    /// Loads the database into memory.
+    pub fn open(path: &Path) -> Result<Self, MyError> {
+        let reader = Reader::open_readfile(path).map_err(|e| {
+            MyError::FileError(format!("GeoIP database {}: {}", path.display(), e))
+        })?;
+        Ok(Self { reader })
+    }
+
+    /// Looks up a `getpeerinfo` address (`host:port`).
+    ///
+    /// Onion / I2P peers, unparsable addresses and IPs missing from the
+    /// database all return an empty `PeerGeo`.
+    pub fn lookup(&self, addr: &str) -> PeerGeo {
+        let Some(ip) = peer_ip(addr) else {
+            return PeerGeo::default();
+        };
+
+        let country = self
+            .reader
+            .lookup::<geoip2::Country>(ip)
+            .ok()
+            .and_then(|c| c.country.or(c.registered_country))
+            .and_then(|c| c.iso_code)
+            .map(str::to_string);
+
+        let (asn, as_org) = match self.reader.lookup::<geoip2::Asn>(ip) {
+            Ok(a) => (
+                a.autonomous_system_number,
+                a.autonomous_system_organization.map(str::to_string),
+            ),
+            Err(_) => (None, None),
+        };
+
+        PeerGeo { country, asn, as_org }
+    }
+}
+
+/// IP address of a peer, or `None` for overlay-network addresses.
+///
+/// Accepts `1.2.3.4:8333`, `[2001:db8::1]:8333` and bare IPs.
+pub fn peer_ip(addr: &str) -> Option<IpAddr> {
+    addr.parse::<SocketAddr>()
+        .map(|s| s.ip())
+        .or_else(|_| addr.parse::<IpAddr>())
+        .ok()
+}
@@ -37,4 +37,13 @@ pub mod display;
 pub mod consensus;
 
 /// tui Color assignments
-pub mod ui;
\ No newline at end of file
+pub mod ui;
+
+/// CSV export of dashboard data (mempool distribution).
+pub mod export;
+
+/// Offline country / ASN lookup for peer addresses (`.mmdb`).
+pub mod geoip;
+
+/// Headless Prometheus `/metrics` endpoint (`--metrics <addr:port>`).
+pub mod metrics;
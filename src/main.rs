@@ -6,6 +6,10 @@
 //! 3. Running the application event loop (`run_app`)
 //! 4. Cleaning up the terminal on exit
 //!
+//! With `--oneshot` (or `--json`) steps 2–4 are skipped: the core sections
+//! are fetched once, printed to stdout as JSON, and the process exits.
+//! With `--metrics <addr:port>` the TUI is replaced by a Prometheus endpoint.
+//!
 //! All heavy logic is delegated to modules under:
 //! - `runapp`    → Core event loop and update cycle
 //! - `rpc`       → All Bitcoin Core RPC calls
@@ -23,10 +27,17 @@ mod display;
 mod runapp;
 mod consensus;
 mod ui;
+mod export;
+mod geoip;
+mod metrics;
 
 use config::load_config;
+use export::{fetch_oneshot_report, oneshot_requested};
+use metrics::{metrics_addr, run_metrics};
 use models::errors::MyError;
 use runapp::{setup_terminal, cleanup_terminal, run_app};
+use ui::color_support::init_color_support;
+use ui::colors::init_theme;
 
 /// Tokio async runtime entrypoint.
 ///
@@ -35,6 +46,10 @@ use runapp::{setup_terminal, cleanup_terminal, run_app};
 ///    Reads from TOML, CLI flags, env vars, or prompts the user.
 ///    Ensures the node address and credentials are valid.
 ///
+///    With `--oneshot` / `--json`, fetch the core sections once, print
+///    them as JSON and exit here. With `--metrics <addr:port>`, serve
+///    Prometheus metrics instead of the TUI until Ctrl-C.
+///
 /// 2. **Initialize TUI terminal state**  
 ///    Switches to raw mode and prepares Crossterm for rendering.
 ///
@@ -55,17 +70,36 @@ use runapp::{setup_terminal, cleanup_terminal, run_app};
 /// - Application loop encounters a fatal error  
 #[tokio::main]
 async fn main() -> Result<(), MyError> {
+    // A bad `--metrics` is a usage error; report it before any config prompts.
+    let metrics = metrics_addr(std::env::args())?;
+
     // Load RPC credentials and node address from config/system.
     let config = load_config()?;
 
     // Validate minimum configuration requirements.
-    if config.username.is_empty()
-        || config.password.is_empty()
-        || config.address.is_empty()
-    {
+    // With cookie auth the credentials may appear once the node starts.
+    let has_credentials = config.cookie_path.is_some()
+        || (!config.username.is_empty() && !config.password.is_empty());
+    if !has_credentials || config.address.is_empty() {
         return Err(MyError::Config("Invalid config data".to_string()));
     }
 
+    // Scripted use: print one JSON report and exit (non-zero on failure),
+    // without touching the terminal.
+    if oneshot_requested(std::env::args()) {
+        println!("{}", fetch_oneshot_report(&config).await?);
+        return Ok(());
+    }
+
+    // Monitoring: headless pollers + `/metrics`; the TUI is not started.
+    if let Some(addr) = metrics {
+        return run_metrics(&config, &addr).await;
+    }
+
+    // Detect terminal color depth and apply `[theme]` before entering TUI mode.
+    init_color_support();
+    init_theme(config.theme.to_theme()?);
+
     // Switch terminal into alternate-screen TUI mode.
     let mut terminal = setup_terminal()?;
 
@@ -0,0 +1,312 @@
+//! Prometheus metrics mode (`--metrics <addr:port>`).
+//!
+//! Replaces the TUI with a headless process: a few pollers keep the global
+//! caches fed, and a minimal HTTP server answers `GET /metrics` in the
+//! Prometheus text exposition format. Every scrape reads the caches, so it
+//! never waits on the node.
+//!
+//! The server speaks just enough HTTP/1.1 for Prometheus and curl: one
+//! request per connection, `/metrics` or 404.
+
+use std::future::Future;
+use std::sync::Arc;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::RwLock;
+use tokio::time::{sleep, Duration, Instant};
+
+use crate::config::{LiveConfig, PollIntervals, RpcConfig};
+use crate::models::errors::MyError;
+use crate::models::peer_info::{PeerInfo, PropagationUnit};
+use crate::rpc::retry::{with_retry, RPC_RETRY_ATTEMPTS, RPC_RETRY_BASE_DELAY};
+use crate::rpc::{
+    fetch_blockchain_info, fetch_mempool_info, fetch_net_totals, fetch_network_info,
+    fetch_peer_info,
+};
+use crate::utils::{
+    jittered, log_error, node_reachable, note_rpc_outcome, BLOCKCHAIN_INFO_CACHE,
+    MEMPOOL_INFO_CACHE, NETWORK_INFO_CACHE, NET_TOTALS_CACHE, PEER_INFO_CACHE,
+};
+
+/// Largest request head read before answering; anything longer is cut off.
+const MAX_REQUEST_BYTES: usize = 8 * 1024;
+
+/// Returns the address given with `--metrics <addr>` or `--metrics=<addr>`;
+/// `Ok(None)` if the flag is absent.
+///
+/// ### Errors
+/// - `Config` (usage error) if the flag has no address, so a headless run
+///   never falls back to the TUI
+pub fn metrics_addr<I: IntoIterator<Item = String>>(args: I) -> Result<Option<String>, MyError> {
+    let mut args = args.into_iter();
+    let addr = loop {
+        match args.next() {
+            None => return Ok(None),
+            Some(arg) if arg == "--metrics" => break args.next(),
+            Some(arg) => {
+                if let Some(addr) = arg.strip_prefix("--metrics=") {
+                    break Some(addr.to_string());
+                }
+            }
+        }
+    };
+
+    match addr {
+        Some(addr) if !addr.is_empty() && !addr.starts_with("--") => Ok(Some(addr)),
+        _ => Err(MyError::Config(
+            "--metrics needs an address, e.g. --metrics 127.0.0.1:9332".to_string(),
+        )),
+    }
+}
+
+/// Values exported on each scrape.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MetricsSnapshot {
+    pub node_reachable: bool,
+    pub block_height: u64,
+    pub mempool_txs: u64,
+    pub mempool_bytes: u64,
+    pub connections_in: u32,
+    pub connections_out: u32,
+    pub bytes_sent: u64,
+    pub bytes_recv: u64,
+    /// Median delay (ms) between the tip's timestamp and peers announcing it.
+    pub block_propagation_ms: i64,
+}
+
+impl MetricsSnapshot {
+    /// Reads the current values from the global caches.
+    pub async fn from_caches() -> Self {
+        let (height, tip_time) = {
+            let chain = BLOCKCHAIN_INFO_CACHE.read().await;
+            (chain.blocks, chain.time)
+        };
+        let (mempool_txs, mempool_bytes) = {
+            let mempool = MEMPOOL_INFO_CACHE.read().await;
+            (mempool.size, mempool.bytes)
+        };
+        let (connections_in, connections_out) = {
+            let network = NETWORK_INFO_CACHE.read().await;
+            (network.connections_in, network.connections_out)
+        };
+        let (bytes_sent, bytes_recv) = {
+            let totals = NET_TOTALS_CACHE.read().await;
+            (totals.totalbytessent, totals.totalbytesrecv)
+        };
+        let block_propagation_ms = PeerInfo::calculate_block_propagation_time(
+            &PEER_INFO_CACHE.read().await,
+            tip_time,
+            height,
+            PropagationUnit::RawMs,
+        );
+
+        Self {
+            node_reachable: node_reachable(),
+            block_height: height,
+            mempool_txs,
+            mempool_bytes,
+            connections_in,
+            connections_out,
+            bytes_sent,
+            bytes_recv,
+            block_propagation_ms,
+        }
+    }
+
+    /// Renders the snapshot in the Prometheus text exposition format.
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        let mut metric = |name: &str, kind: &str, help: &str, samples: &[(&str, String)]| {
+            out.push_str(&format!("# HELP {} {}\n# TYPE {} {}\n", name, help, name, kind));
+            for (labels, value) in samples {
+                out.push_str(&format!("{}{} {}\n", name, labels, value));
+            }
+        };
+
+        metric(
+            "blockchaininfo_node_reachable",
+            "gauge",
+            "1 if the last core RPC reached the node, else 0.",
+            &[("", u8::from(self.node_reachable).to_string())],
+        );
+        metric(
+            "blockchaininfo_block_height",
+            "gauge",
+            "Height of the node's best block.",
+            &[("", self.block_height.to_string())],
+        );
+        metric(
+            "blockchaininfo_mempool_transactions",
+            "gauge",
+            "Transactions in the mempool.",
+            &[("", self.mempool_txs.to_string())],
+        );
+        metric(
+            "blockchaininfo_mempool_bytes",
+            "gauge",
+            "Sum of mempool transaction sizes (vbytes).",
+            &[("", self.mempool_bytes.to_string())],
+        );
+        metric(
+            "blockchaininfo_connections",
+            "gauge",
+            "Peer connections by direction.",
+            &[
+                ("{direction=\"in\"}", self.connections_in.to_string()),
+                ("{direction=\"out\"}", self.connections_out.to_string()),
+            ],
+        );
+        metric(
+            "blockchaininfo_network_bytes_total",
+            "counter",
+            "Bytes sent and received since the node started.",
+            &[
+                ("{direction=\"sent\"}", self.bytes_sent.to_string()),
+                ("{direction=\"recv\"}", self.bytes_recv.to_string()),
+            ],
+        );
+        metric(
+            "blockchaininfo_block_propagation_milliseconds",
+            "gauge",
+            "Median delay between the tip's timestamp and peers announcing it.",
+            &[("", self.block_propagation_ms.to_string())],
+        );
+
+        out
+    }
+}
+
+/// Runs metrics mode: starts the pollers and serves `/metrics` on `addr`
+/// until Ctrl-C.
+///
+/// ### Errors
+/// - `addr` cannot be bound
+pub async fn run_metrics(config: &RpcConfig, addr: &str) -> Result<(), MyError> {
+    let listener = TcpListener::bind(addr)
+        .await
+        .map_err(|e| MyError::Config(format!("--metrics: cannot listen on {}: {}", addr, e)))?;
+
+    spawn_pollers(LiveConfig::new(config.clone()));
+    println!("Serving Prometheus metrics on http://{}/metrics (Ctrl-C to stop)", addr);
+
+    loop {
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    tokio::spawn(async move {
+                        if let Err(e) = serve_connection(stream).await {
+                            let _ = log_error(&format!("Metrics connection failed: {}", e));
+                        }
+                    });
+                }
+                Err(e) => {
+                    let _ = log_error(&format!("Metrics accept failed: {}", e));
+                }
+            },
+        }
+    }
+}
+
+/// Answers one HTTP request: the metrics for `GET /metrics`, else 404.
+async fn serve_connection(mut stream: TcpStream) -> std::io::Result<()> {
+    let mut buf = vec![0u8; MAX_REQUEST_BYTES];
+    let mut len = 0;
+    // Read until the end of the request head (or the buffer is full).
+    while len < buf.len() {
+        let n = stream.read(&mut buf[len..]).await?;
+        if n == 0 {
+            break;
+        }
+        len += n;
+        if buf[..len].windows(4).any(|w| w == b"\r\n\r\n") {
+            break;
+        }
+    }
+
+    let head = String::from_utf8_lossy(&buf[..len]);
+    let mut request_line = head.lines().next().unwrap_or("").split_whitespace();
+    let (method, path) = (request_line.next(), request_line.next());
+    // Prometheus may append query parameters; only the path matters.
+    let path = path.map(|p| p.split('?').next().unwrap_or(p));
+
+    let response = match (method, path) {
+        (Some("GET"), Some("/metrics")) => {
+            let body = MetricsSnapshot::from_caches().await.to_prometheus();
+            format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4; charset=utf-8\r\n\
+                 Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            )
+        }
+        _ => "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string(),
+    };
+
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+/// Spawns the pollers behind the exported values, at the configured intervals.
+fn spawn_pollers(live: LiveConfig) {
+    spawn_poller(live.clone(), "Blockchain Info", |i| i.blockchain, &BLOCKCHAIN_INFO_CACHE, true, |c| async move {
+        fetch_blockchain_info(&c).await
+    });
+    spawn_poller(live.clone(), "Mempool Info", |i| i.mempool, &MEMPOOL_INFO_CACHE, true, |c| async move {
+        fetch_mempool_info(&c).await
+    });
+    spawn_poller(live.clone(), "Network Info", |i| i.network, &NETWORK_INFO_CACHE, true, |c| async move {
+        fetch_network_info(&c).await
+    });
+    spawn_poller(live.clone(), "Net Totals", |i| i.net_totals, &NET_TOTALS_CACHE, false, |c| async move {
+        fetch_net_totals(&c).await
+    });
+    spawn_poller(live, "Peer Info", |i| i.peers, &PEER_INFO_CACHE, false, |c| async move {
+        fetch_peer_info(&c).await
+    });
+}
+
+/// One polling loop: fetch, store into `cache`, sleep for the (jittered)
+/// interval. `core` calls are retried and feed the reachability state, like
+/// the dashboard's blockchain/mempool/network workers.
+fn spawn_poller<T, F, Fut>(
+    live: LiveConfig,
+    name: &'static str,
+    interval: fn(&PollIntervals) -> u64,
+    cache: &'static Arc<RwLock<T>>,
+    core: bool,
+    fetch: F,
+) where
+    T: Send + Sync + 'static,
+    F: Fn(Arc<RpcConfig>) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<T, MyError>> + Send,
+{
+    tokio::spawn(async move {
+        loop {
+            let config = live.current();
+            let start = Instant::now();
+
+            let result = if core {
+                let result =
+                    with_retry(RPC_RETRY_ATTEMPTS, RPC_RETRY_BASE_DELAY, || fetch(config.clone())).await;
+                note_rpc_outcome(&result);
+                result
+            } else {
+                fetch(config.clone()).await
+            };
+            match result {
+                Ok(data) => *cache.write().await = data,
+                Err(e) => {
+                    let _ = log_error(&format!("{} failed: {}", name, e));
+                }
+            }
+
+            let interval = jittered(Duration::from_secs(interval(&config.intervals)), config.poll_jitter_pct);
+            let elapsed = start.elapsed();
+            if elapsed < interval {
+                sleep(interval - elapsed).await;
+            }
+        }
+    });
+}
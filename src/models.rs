@@ -41,6 +41,22 @@ pub mod errors;
 /// Includes miner extraction logic and 24-hour block history structures.
 pub mod block_info;
 
+/// Models for `getblockstats`, plus the rolling per-block fee-rate
+/// history used by the fee trend sparkline.
+pub mod block_stats;
+
+/// Models for `estimatesmartfee`, Core's confirmation-target fee estimates.
+pub mod fee_estimate;
+
+/// Models for `getdeploymentinfo`, used for soft-fork deployment status.
+pub mod deployment_info;
+
+/// Models for `getchaintxstats`, used for transaction throughput.
+pub mod chain_tx_stats;
+
+/// Models for `getmininginfo`, used for the network hashrate estimate.
+pub mod mining_info;
+
 /// Models for `getchaintips`, used to track forks, side-branches,
 /// and chain-status metadata from Core.
 pub mod chaintips_info;
@@ -70,4 +86,50 @@ pub mod flashing_text;
 ///
 /// This module centralizes tag patterns to reduce conditional logic
 /// and keep coinbase-based attribution explicit and maintainable.
-pub mod miner_tags;
\ No newline at end of file
+pub mod miner_tags;
+
+/// User watch list of txids, tracked from mempool entry to confirmation.
+pub mod watch_list;
+
+/// Bounded concurrent map with first-in, first-out eviction (mempool entry cache).
+pub mod fifo_map;
+
+/// Models for `getblockheader` (verbose), used to walk back from a new tip.
+pub mod block_header;
+
+/// Reorg detection: remembers recent best blocks and measures how many a
+/// new tip disconnects.
+pub mod reorg;
+
+/// Core's `warnings` field (string or array) and the combined node warnings.
+pub mod core_warnings;
+
+/// Models for `getaddressinfo`, used by the address mode of the transaction lookup.
+pub mod address_info;
+
+/// Rolling seconds-between-blocks history for the Blockchain section sparkline.
+pub mod block_intervals;
+
+/// Strategies choosing which mempool entries the distribution pipeline fetches.
+pub mod mempool_sampler;
+
+/// Inbound/outbound ratio and connection churn for the Network section.
+pub mod connection_churn;
+
+/// Table-driven peer client classification from user agents.
+pub mod client_tags;
+
+/// Model for `uptime`, shown in the Network section title.
+pub mod uptime;
+
+/// Generic JSON-RPC response envelope and Core's error object.
+pub mod rpc_envelope;
+
+/// `size_on_disk` growth rate for the Blockchain section.
+pub mod disk_growth;
+
+/// SegWit and Taproot shares of the latest block's transactions.
+pub mod witness_adoption;
+
+/// Subsidy and fees paid out by the latest block's coinbase.
+pub mod coinbase_reward;
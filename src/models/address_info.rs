@@ -0,0 +1,147 @@
+//! Data models for Bitcoin Core’s `getaddressinfo` RPC.
+//!
+//! Used by the address mode of the transaction lookup popup. Core keeps no
+//! address index, so `getaddressinfo` only knows what the loaded wallet knows:
+//! ownership, watch-only status, labels and the descriptor it derives from.
+//! It is a wallet RPC — nodes without a loaded wallet answer with an error.
+
+use serde::Deserialize;
+
+use crate::models::rpc_envelope::RpcErrorObject;
+
+/// JSON-RPC code when no wallet is loaded.
+pub const RPC_WALLET_NOT_FOUND: i64 = -18;
+
+/// JSON-RPC code when several wallets are loaded and none was selected.
+pub const RPC_WALLET_NOT_SPECIFIED: i64 = -19;
+
+/// JSON-RPC code for an address that does not parse on this network.
+pub const RPC_INVALID_ADDRESS_OR_KEY: i64 = -5;
+
+/// Wrapper for the `getaddressinfo` RPC response.
+///
+/// `result` is `null` when Core returns an error object instead.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(dead_code)]
+pub struct AddressInfoJsonWrap {
+    pub error: Option<RpcErrorObject>,
+    pub id: Option<String>,
+    pub result: Option<AddressInfo>,
+}
+
+/// Wallet view of one address.
+#[derive(Debug, Deserialize, Default, Clone, PartialEq)]
+#[allow(dead_code)]
+pub struct AddressInfo {
+    /// The address as Core normalized it.
+    pub address: String,
+
+    /// Hex-encoded output script.
+    #[serde(rename = "scriptPubKey", default)]
+    pub script_pub_key: String,
+
+    /// The wallet holds the keys (or scripts) to spend to this address.
+    #[serde(default)]
+    pub ismine: bool,
+
+    /// The wallet tracks the address without being able to spend.
+    #[serde(default)]
+    pub iswatchonly: bool,
+
+    /// The wallet knows how to sign for it, ignoring missing private keys.
+    #[serde(default)]
+    pub solvable: bool,
+
+    /// P2SH or P2WSH address.
+    #[serde(default)]
+    pub isscript: bool,
+
+    /// SegWit (v0 or later) address.
+    #[serde(default)]
+    pub iswitness: bool,
+
+    /// Witness version for SegWit addresses (1 = taproot).
+    #[serde(default)]
+    pub witness_version: Option<u8>,
+
+    /// Output descriptor, present when the address is solvable.
+    #[serde(default)]
+    pub desc: Option<String>,
+
+    /// The descriptor wallet that owns the address.
+    #[serde(default)]
+    pub parent_desc: Option<String>,
+
+    /// Key belongs to the wallet's change chain.
+    #[serde(default)]
+    pub ischange: bool,
+
+    /// HD derivation path of the key, if known.
+    #[serde(default)]
+    pub hdkeypath: Option<String>,
+
+    /// Labels attached to the address.
+    #[serde(default)]
+    pub labels: Vec<String>,
+}
+
+impl AddressInfo {
+    /// Short ownership verdict: `mine`, `watch-only` or `not in wallet`.
+    pub fn ownership(&self) -> &'static str {
+        if self.ismine {
+            "mine"
+        } else if self.iswatchonly {
+            "watch-only"
+        } else {
+            "not in wallet"
+        }
+    }
+
+    /// Address kind from the witness/script flags.
+    pub fn kind(&self) -> String {
+        match (self.iswitness, self.witness_version, self.isscript) {
+            (true, Some(1), _) => "taproot (witness v1)".to_string(),
+            (true, Some(v), _) if v > 1 => format!("witness v{}", v),
+            (true, _, true) => "P2WSH".to_string(),
+            (true, _, false) => "P2WPKH".to_string(),
+            (false, _, true) => "P2SH".to_string(),
+            (false, _, false) => "P2PKH".to_string(),
+        }
+    }
+
+    /// Multi-line summary shown in the lookup popup.
+    pub fn summary(&self) -> String {
+        let labels: Vec<&str> = self
+            .labels
+            .iter()
+            .map(String::as_str)
+            .filter(|l| !l.is_empty())
+            .collect();
+        let labels = if labels.is_empty() { "none".to_string() } else { labels.join(", ") };
+
+        let mut out = format!(
+            "Address:    {}\nType:       {}\nOwnership:  {}\nSolvable:   {}\nLabels:     {}\n",
+            self.address,
+            self.kind(),
+            self.ownership(),
+            if self.solvable { "yes" } else { "no" },
+            labels,
+        );
+        if self.ismine {
+            out.push_str(&format!(
+                "Chain:      {}\n",
+                if self.ischange { "change" } else { "receive" }
+            ));
+        }
+        if let Some(path) = &self.hdkeypath {
+            out.push_str(&format!("HD path:    {}\n", path));
+        }
+        if let Some(desc) = &self.desc {
+            out.push_str(&format!("Descriptor: {}\n", desc));
+        }
+        out.push_str(&format!("Script:     {}\n", self.script_pub_key));
+        out.push_str("\nBalance and history need an address index, which Core does not keep.");
+        out
+    }
+}
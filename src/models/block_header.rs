@@ -0,0 +1,32 @@
+//! Data models for Bitcoin Core’s `getblockheader` RPC (verbose).
+//!
+//! Headers are the cheapest way to walk the chain backwards: reorg
+//! detection follows `previousblockhash` links from a new tip until it
+//! reaches a block it has already seen.
+
+use serde::Deserialize;
+
+/// Wrapper for the `getblockheader` RPC response.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(dead_code)]
+pub struct BlockHeaderJsonWrap {
+    pub error: Option<String>,
+    pub id: Option<String>,
+    pub result: BlockHeader,
+}
+
+/// Subset of a verbose block header consumed by the dashboard.
+#[derive(Debug, Deserialize, Default, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct BlockHeader {
+    /// Block hash.
+    pub hash: String,
+
+    /// Block height.
+    pub height: u64,
+
+    /// Parent block hash; absent only for the genesis block.
+    #[serde(default)]
+    pub previousblockhash: Option<String>,
+}
@@ -11,12 +11,15 @@
 //!
 //! Additionally, this module provides:
 //! - models for miner-tagged metadata,
-//! - support types for 24h block-history tracking,
+//! - support types for 24h block-history tracking (persisted across restarts),
 //! - helpers for extracting addresses from verbose transactions.
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::{VecDeque, HashMap};
+use std::fs;
+use std::path::Path;
 use std::sync::{Mutex, Arc};
+use crate::models::errors::MyError;
 use crate::utils::hex_decode;
 use crate::consensus::satoshi_math::*;
 use crate::models::miner_tags::OCEAN_PATS;
@@ -145,6 +148,11 @@ impl Transaction {
             .collect()
     }
 
+    /// Whether this is the block's coinbase transaction.
+    pub fn is_coinbase(&self) -> bool {
+        self.vin.first().is_some_and(|vin| vin.coinbase.is_some())
+    }
+
     /// Returns coinbase scriptSig bytes (decoded from hex) if this TX is a coinbase TX.
     /// Bitcoin Core provides `vin[0].coinbase` as hex string for coinbase transactions.
     fn extract_coinbase_bytes(&self) -> Option<Vec<u8>> {
@@ -413,9 +421,8 @@ pub struct TxIn {
     pub vout: Option<u32>,
     #[serde(rename = "scriptSig")]
     pub script_sig: Option<ScriptSig>,
+    /// Witness stack items (hex); absent for non-witness spends.
     #[serde(default)]
-    #[serde(skip)]
-    #[allow(dead_code)]
     pub txinwitness: Option<Vec<String>>,
     pub sequence: u32,
 }
@@ -429,8 +436,9 @@ pub struct ScriptSig {
 }
 
 /// Miner → known payout address mapping.
-/// Loaded from `miners.json`.
-#[derive(Deserialize, Clone)]
+/// Loaded from `miners.json`; empty when the file is missing, in which case
+/// miners are named from coinbase tags only.
+#[derive(Deserialize, Clone, Default)]
 pub struct MinersData {
     pub miners: Vec<Miner>,
 }
@@ -448,6 +456,13 @@ pub struct BlockHistoryEntry {
     pub miner: Option<Arc<str>>,
 }
 
+/// On-disk form of a `BlockHistoryEntry` (`block_history.json`).
+#[derive(Serialize, Deserialize)]
+struct PersistedBlockEntry {
+    height: u64,
+    miner: Option<String>,
+}
+
 /// Rolling 24-hour miner distribution tracking.
 /// Stores the last 144 block miners with their heights.
 ///
@@ -491,6 +506,11 @@ impl BlockHistory {
     pub fn add_block(&self, height: u64, miner: Option<String>) {
         let mut blocks = self.blocks.lock().unwrap();
 
+        // Same height again (restored history, or a re-fetch): replace.
+        if blocks.back().is_some_and(|entry| entry.height == height) {
+            blocks.pop_back();
+        }
+
         if blocks.len() == 144 {
             blocks.pop_front(); // Maintain fixed-size window
         }
@@ -502,6 +522,9 @@ impl BlockHistory {
     }
 
     /// Count block frequency by miner across the 144-block window.
+    ///
+    /// Sorted by block count descending, then miner name ascending, so the
+    /// order is stable between calls regardless of `HashMap` iteration.
     pub fn get_miner_distribution(&self) -> Vec<(Arc<str>, u64)> {
         let blocks = self.blocks.lock().unwrap().clone();
         let mut distribution: HashMap<Arc<str>, u64> = HashMap::new();
@@ -512,6 +535,57 @@ impl BlockHistory {
             }
         }
 
-        distribution.into_iter().collect()
+        let mut distribution: Vec<(Arc<str>, u64)> = distribution.into_iter().collect();
+        distribution.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        distribution
+    }
+
+    /// Write the window to `path` as JSON, oldest block first.
+    ///
+    /// Written to a temporary file and renamed, so an interrupted save
+    /// never leaves a truncated history behind.
+    pub fn save(&self, path: &Path) -> Result<(), MyError> {
+        let entries: Vec<PersistedBlockEntry> = self
+            .blocks
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|entry| PersistedBlockEntry {
+                height: entry.height,
+                miner: entry.miner.as_deref().map(str::to_string),
+            })
+            .collect();
+
+        let json = serde_json::to_string(&entries)?;
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, json)?;
+        fs::rename(&tmp, path)?;
+        Ok(())
+    }
+
+    /// Restore a window saved by [`BlockHistory::save`].
+    ///
+    /// Entries 144 or more blocks behind `tip` are discarded (`tip == 0`
+    /// keeps everything). A missing or corrupt file yields an empty history.
+    pub fn load(path: &Path, tip: u64) -> Self {
+        let history = Self::new();
+
+        let Ok(json) = fs::read_to_string(path) else {
+            return history;
+        };
+        let Ok(mut entries) = serde_json::from_str::<Vec<PersistedBlockEntry>>(&json) else {
+            return history;
+        };
+
+        let window = BLOCKS_PER_HOUR * HOURS_PER_DAY;
+        let oldest_kept = if tip == 0 { 0 } else { tip.saturating_sub(window - 1) };
+
+        entries.retain(|e| e.height >= oldest_kept && (tip == 0 || e.height <= tip));
+        entries.sort_by_key(|e| e.height);
+
+        for entry in entries {
+            history.add_block(entry.height, entry.miner);
+        }
+        history
     }
 }
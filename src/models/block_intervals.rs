@@ -0,0 +1,76 @@
+//! Rolling history of block intervals (seconds between consecutive blocks).
+//!
+//! Fed from the UI loop whenever a new best block height shows up, by
+//! diffing its header time against the previous block's. Shown as a small
+//! sparkline in the Blockchain section to make the variance of block
+//! production visible.
+//!
+//! Header times are miner-set and not monotonic, so a block can carry an
+//! earlier timestamp than its parent; such intervals count as zero. When
+//! blocks are skipped (the tip advanced by more than one between polls) or
+//! the chain reorganizes, the interval of the individual block is unknown
+//! and no sample is taken; the new tip becomes the baseline.
+
+use std::collections::VecDeque;
+
+use crate::consensus::satoshi_math::BLOCK_TIME_SECONDS;
+
+/// Number of intervals kept (matches the propagation window).
+pub const BLOCK_INTERVAL_WINDOW: usize = 20;
+
+/// Intervals recorded before the observed mean replaces the 600s target in
+/// time estimates; a handful of blocks is too noisy to extrapolate from.
+pub const MIN_INTERVALS_FOR_ESTIMATE: usize = 6;
+
+/// Bounded block-interval history.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct BlockIntervals {
+    /// Height and header time of the last block seen.
+    last: Option<(u64, u64)>,
+    /// Seconds between consecutive blocks, oldest first.
+    intervals: VecDeque<u64>,
+}
+
+impl BlockIntervals {
+    /// Records a newly seen tip.
+    ///
+    /// The first observation only sets the baseline. Returns the interval
+    /// recorded, if any.
+    pub fn observe(&mut self, height: u64, time: u64) -> Option<u64> {
+        let previous = self.last.replace((height, time));
+        let (prev_height, prev_time) = previous?;
+        if height != prev_height + 1 {
+            return None;
+        }
+
+        let interval = time.saturating_sub(prev_time);
+        if self.intervals.len() == BLOCK_INTERVAL_WINDOW {
+            self.intervals.pop_front();
+        }
+        self.intervals.push_back(interval);
+        Some(interval)
+    }
+
+    /// Recorded intervals in seconds, oldest first.
+    pub fn intervals(&self) -> &VecDeque<u64> {
+        &self.intervals
+    }
+
+    /// Mean interval in seconds, `None` before the first interval.
+    pub fn mean(&self) -> Option<u64> {
+        if self.intervals.is_empty() {
+            return None;
+        }
+        Some(self.intervals.iter().sum::<u64>() / self.intervals.len() as u64)
+    }
+
+    /// Interval (seconds) to extrapolate with: the observed mean once
+    /// `MIN_INTERVALS_FOR_ESTIMATE` intervals are recorded, else the 600s
+    /// target spacing.
+    pub fn expected_interval(&self) -> u64 {
+        match self.mean() {
+            Some(mean) if self.intervals.len() >= MIN_INTERVALS_FOR_ESTIMATE => mean,
+            _ => BLOCK_TIME_SECONDS,
+        }
+    }
+}
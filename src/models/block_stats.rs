@@ -0,0 +1,127 @@
+//! Data models for Bitcoin Core’s `getblockstats` RPC.
+//!
+//! `getblockstats` computes per-block statistics from the block and its
+//! undo data. BlockchainInfo uses it to follow *confirmed* fee pressure:
+//! - `feerate_percentiles` → median confirmed fee rate per block
+//! - `totalfee` / `subsidy` → the "Last Block" line for the current tip
+//!
+//! The RPC requires undo data, so it fails for blocks that a pruned node
+//! has already discarded. Those heights are recorded as gaps rather than
+//! retried forever.
+//!
+//! This module also provides `FeeRateHistory`, a small rolling buffer
+//! keyed by block height that feeds the fee-rate trend sparkline.
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// Wrapper for the `getblockstats` RPC response.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(dead_code)]
+pub struct BlockStatsJsonWrap {
+    pub error: Option<String>,
+    pub id: Option<String>,
+    pub result: BlockStats,
+}
+
+/// Per-block statistics returned by `getblockstats`.
+///
+/// Only the fields the dashboard consumes are mirrored here; Core returns
+/// many more (utxo deltas, segwit totals, etc.) which are ignored.
+#[derive(Debug, Deserialize, Default, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+#[allow(dead_code)]
+pub struct BlockStats {
+    /// Height of the block the stats describe.
+    pub height: u64,
+
+    /// Block hash (hex).
+    #[serde(default)]
+    pub blockhash: String,
+
+    /// Fee rates at the 10th, 25th, 50th, 75th and 90th percentile
+    /// weight unit, in sat/vB.
+    #[serde(default)]
+    pub feerate_percentiles: [u64; 5],
+
+    /// Total fees paid in the block, in satoshis.
+    #[serde(default)]
+    pub totalfee: u64,
+
+    /// Block subsidy, in satoshis.
+    #[serde(default)]
+    pub subsidy: u64,
+
+    /// Average fee rate in sat/vB.
+    #[serde(default)]
+    pub avgfeerate: u64,
+
+    /// Smallest transaction size in the block, in bytes.
+    #[serde(default)]
+    pub mintxsize: u64,
+}
+
+impl BlockStats {
+    /// Median (50th percentile) confirmed fee rate in sat/vB.
+    pub fn median_feerate(&self) -> u64 {
+        self.feerate_percentiles[2]
+    }
+
+    /// Total fees in BTC.
+    pub fn total_fee_btc(&self) -> f64 {
+        self.totalfee as f64 / 100_000_000.0
+    }
+}
+
+/// Rolling window of median confirmed fee rates keyed by block height.
+///
+/// A `None` sample marks a height whose stats could not be retrieved
+/// (typically pruned undo data). Such heights are kept as gaps so the
+/// sparkline stays aligned with the chain instead of collapsing.
+#[derive(Debug, Default)]
+pub struct FeeRateHistory {
+    samples: BTreeMap<u64, Option<u64>>,
+}
+
+impl FeeRateHistory {
+    /// Number of most recent blocks tracked.
+    pub const WINDOW: u64 = 20;
+
+    /// Create an empty history.
+    pub fn new() -> Self {
+        Self { samples: BTreeMap::new() }
+    }
+
+    /// Record the median fee rate (or a gap) for `height`.
+    ///
+    /// Entries older than `WINDOW` blocks behind the highest recorded
+    /// height are dropped.
+    pub fn record(&mut self, height: u64, median_feerate: Option<u64>) {
+        self.samples.insert(height, median_feerate);
+
+        if let Some(&newest) = self.samples.keys().next_back() {
+            let oldest_kept = newest.saturating_sub(Self::WINDOW - 1);
+            self.samples = self.samples.split_off(&oldest_kept);
+        }
+    }
+
+    /// Heights within the window ending at `tip` that have no sample yet.
+    pub fn missing_heights(&self, tip: u64) -> Vec<u64> {
+        let start = tip.saturating_sub(Self::WINDOW - 1);
+        (start..=tip)
+            .filter(|h| !self.samples.contains_key(h))
+            .collect()
+    }
+
+    /// Sparkline series for the window ending at `tip`, oldest first.
+    ///
+    /// Missing or pruned heights render as `0`, which the sparkline
+    /// widget draws as an empty column.
+    pub fn sparkline_data(&self, tip: u64) -> Vec<u64> {
+        let start = tip.saturating_sub(Self::WINDOW - 1);
+        (start..=tip)
+            .map(|h| self.samples.get(&h).copied().flatten().unwrap_or(0))
+            .collect()
+    }
+}
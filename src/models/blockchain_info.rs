@@ -14,9 +14,10 @@
 //! - time-since-last-block calculations,
 //! - blocks-remaining in the current difficulty epoch.
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use chrono::{TimeZone, Utc};
 use crate::models::errors::MyError;
+use crate::models::core_warnings::deserialize_warnings;
 use tui::style::Color;
 use crate::consensus::satoshi_math::*;
 
@@ -36,7 +37,7 @@ pub struct BlockchainInfoJsonWrap {
 ///
 /// Chain-state fields include block height, difficulty, chainwork,
 /// pruned state, verification progress, and timestamps.
-#[derive(Debug, Deserialize, Default, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, Default, PartialEq)]
 #[serde(rename_all = "snake_case")]
 #[allow(dead_code)]
 pub struct BlockchainInfo {
@@ -51,12 +52,52 @@ pub struct BlockchainInfo {
     pub initialblockdownload: bool,
     pub mediantime: u64,
     pub pruned: bool,
+    /// Lowest block with data still stored; only sent by pruned nodes.
+    #[serde(default)]
+    pub pruneheight: Option<u64>,
     pub size_on_disk: u64,
     pub time: u64,
     pub verificationprogress: f64,
-    #[serde(skip)]
-    #[allow(dead_code)]
-    pub warnings: String,
+    /// Node warnings; Core ≤27 sends a string, 28+ an array.
+    #[serde(default, deserialize_with = "deserialize_warnings")]
+    pub warnings: Vec<String>,
+}
+
+/// Height of the block ~24 hours (144 blocks) before the tip at `blocks`,
+/// used for the 24h difficulty projection.
+pub fn height_24h_ago(blocks: u64) -> u64 {
+    blocks.saturating_sub((BLOCKS_PER_HOUR * HOURS_PER_DAY) - 1)
+}
+
+/// Parses a hex chainwork value, as returned by `getblockchaininfo` and
+/// `getblock`.
+pub fn parse_chainwork(hex: &str) -> Result<u128, MyError> {
+    u128::from_str_radix(hex, 16).map_err(|_| MyError::InvalidChainworkHexString(hex.to_string()))
+}
+
+/// An amount of work as `log2` bits, e.g. `"95.42 bits"`. Zero work is
+/// `"0 bits"`.
+pub fn format_work_bits(work: u128) -> String {
+    if work == 0 {
+        return "0 bits".to_string();
+    }
+    format!("{:.2} bits", (work as f64).log2())
+}
+
+/// Formats a time estimate with its two largest units: `"3d 4h"`,
+/// `"4h 12m"`, `"12m"`.
+pub fn format_eta(secs: u64) -> String {
+    let days = secs / 86_400;
+    let hours = secs % 86_400 / 3_600;
+    let minutes = secs % 3_600 / 60;
+
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else {
+        format!("{}m", minutes)
+    }
 }
 
 impl BlockchainInfo {
@@ -69,14 +110,23 @@ impl BlockchainInfo {
     /// Chainwork encodes cumulative PoW; interpreting it as `log2` allows
     /// comparison across nodes or networks.
     pub fn formatted_chainwork_bits(&self) -> Result<String, MyError> {
-        u128::from_str_radix(&self.chainwork, 16)
-            .map_or_else(
-                |_| Err(MyError::InvalidChainworkHexString(self.chainwork.clone())),
-                |decimal| {
-                    let bits = (decimal as f64).log2();
-                    Ok(format!("{:.2} bits", bits))
-                },
-            )
+        parse_chainwork(&self.chainwork).map(format_work_bits)
+    }
+
+    /// Work added since the block with chainwork `earlier` (hex), e.g. the
+    /// epoch-start block: the tip's chainwork minus `earlier`.
+    ///
+    /// Fails if either value isn't valid hex, or if `earlier` exceeds the
+    /// tip's chainwork (a block from another branch or a stale cache).
+    pub fn chainwork_since(&self, earlier: &str) -> Result<u128, MyError> {
+        let tip = parse_chainwork(&self.chainwork)?;
+        let earlier_work = parse_chainwork(earlier)?;
+        tip.checked_sub(earlier_work).ok_or_else(|| {
+            MyError::from_custom_error(format!(
+                "Chainwork {} is ahead of the tip's {}",
+                earlier, self.chainwork
+            ))
+        })
     }
 
     /// Scientific formatting for difficulty (e.g., `112.1×10¹²`).
@@ -89,28 +139,42 @@ impl BlockchainInfo {
         if value == 0.0 {
             return Ok("0.0".to_string());
         }
+        // `inf` would never scale below 1000 and NaN has no exponent.
+        if !value.is_finite() {
+            return Err(MyError::from_custom_error(format!(
+                "Cannot format non-finite value {} in scientific notation",
+                value
+            )));
+        }
 
-        let mut exponent = 0;
+        let mut exponent: i32 = 0;
         let mut scaled_value = value;
 
         while scaled_value.abs() >= 1000.0 {
             scaled_value /= 10.0;
             exponent += 1;
         }
-        while scaled_value.abs() < 100.0 {
+        // Values in 1..1000 stay as they are (×10⁰); only fractions scale up.
+        while scaled_value.abs() < 1.0 {
             scaled_value *= 10.0;
             exponent -= 1;
         }
 
-        let group_exponent = exponent / 3 * 3;
-        scaled_value *= 10_f64.powi(exponent % 3);
+        // Euclidean division rounds negative exponents down (-10 → -12), so
+        // tiny regtest/testnet values keep a mantissa ≥ 1 instead of a
+        // fractional one.
+        let group_exponent = exponent.div_euclid(3) * 3;
+        scaled_value *= 10_f64.powi(exponent.rem_euclid(3));
 
         let superscript_map = ['⁰','¹','²','³','⁴','⁵','⁶','⁷','⁸','⁹','⁻'];
 
         let exp_str = group_exponent.to_string();
         let superscript: String = exp_str
             .chars()
-            .filter_map(|c| c.to_digit(10).map(|d| superscript_map[d as usize]))
+            .filter_map(|c| match c {
+                '-' => Some(superscript_map[10]),
+                _ => c.to_digit(10).map(|d| superscript_map[d as usize]),
+            })
             .collect();
 
         if superscript.is_empty() {
@@ -147,6 +211,22 @@ impl BlockchainInfo {
             )
     }
 
+    /// True if the best block is at least `threshold_secs` old at `now`
+    /// (Unix seconds). Never during initial block download, when an old
+    /// tip is expected, nor with a zero threshold (alert disabled).
+    pub fn tip_is_stale(&self, now: u64, threshold_secs: u64) -> bool {
+        threshold_secs > 0
+            && !self.initialblockdownload
+            && self.time > 0
+            && now.saturating_sub(self.time) >= threshold_secs
+    }
+
+    /// False if pruning has deleted the data of the block at `height`, so
+    /// `getblock` for it would fail.
+    pub fn has_block_data(&self, height: u64) -> bool {
+        !self.pruned || self.pruneheight.is_none_or(|lowest| height >= lowest)
+    }
+
     /// Calculate the age of the best block.
     pub fn calculate_time_diff(&self) -> Result<String, MyError> {
         let now = Utc::now();
@@ -179,6 +259,23 @@ impl BlockchainInfo {
         Ok(DIFFICULTY_ADJUSTMENT_INTERVAL - (self.blocks % DIFFICULTY_ADJUSTMENT_INTERVAL))
     }
 
+    /// Estimated seconds until the next retarget: blocks remaining times
+    /// `interval_secs` (see `BlockIntervals::expected_interval`).
+    pub fn seconds_until_adjustment(&self, interval_secs: u64) -> Result<u64, MyError> {
+        Ok(self.blocks_until_adjustment()?.saturating_mul(interval_secs))
+    }
+
+    /// Blocks until the next halving and its estimated Unix time: the tip's
+    /// time plus `interval_secs` per remaining block. `None` before the
+    /// first block is known.
+    pub fn halving_estimate(&self, interval_secs: u64) -> Option<(u64, u64)> {
+        if self.blocks == 0 {
+            return None;
+        }
+        let blocks_left = blocks_until_halving(self.blocks);
+        Some((blocks_left, self.time.saturating_add(blocks_left.saturating_mul(interval_secs))))
+    }
+
     /// Blocks remaining *with* a color-coded urgency indicator for the UI.
     pub fn display_blocks_until_difficulty_adjustment(&self)
         -> Result<(String, Color), MyError>
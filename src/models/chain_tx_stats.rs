@@ -0,0 +1,57 @@
+//! Data models for Bitcoin Core’s `getchaintxstats` RPC.
+//!
+//! Used for chain transaction throughput: the total transaction count and
+//! the tx/s rate over a recent window of blocks (144 by default, ~24h).
+
+use serde::Deserialize;
+
+/// Wrapper for the `getchaintxstats` RPC response.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(dead_code)]
+pub struct ChainTxStatsJsonWrap {
+    pub error: Option<String>,
+    pub id: Option<String>,
+    pub result: ChainTxStats,
+}
+
+/// Transaction statistics for a window of blocks ending at the tip.
+///
+/// Core omits the `window_*` fields and `txrate` when the window is
+/// empty (e.g. at genesis), so they are optional here.
+#[derive(Debug, Deserialize, Default, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+#[allow(dead_code)]
+pub struct ChainTxStats {
+    /// Total transactions in the chain up to the tip.
+    pub txcount: u64,
+
+    /// Number of blocks in the window.
+    #[serde(default)]
+    pub window_block_count: u64,
+
+    /// Transactions in the window.
+    #[serde(default)]
+    pub window_tx_count: Option<u64>,
+
+    /// Elapsed time of the window, in seconds.
+    #[serde(default)]
+    pub window_interval: Option<u64>,
+
+    /// Core's own rate over the window (tx/s).
+    #[serde(default)]
+    pub txrate: Option<f64>,
+}
+
+impl ChainTxStats {
+    /// Transactions per second over the window.
+    ///
+    /// `None` when the window is empty or spans zero seconds, rather than
+    /// dividing by zero.
+    pub fn tx_per_sec(&self) -> Option<f64> {
+        match (self.window_tx_count, self.window_interval) {
+            (Some(count), Some(interval)) if interval > 0 => Some(count as f64 / interval as f64),
+            _ => None,
+        }
+    }
+}
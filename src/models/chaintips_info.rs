@@ -13,13 +13,13 @@
 //! These structs intentionally mirror Core’s response without modification.
 //! Interpretation and filtering occur in higher-level modules.
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Wrapped RPC response for `getchaintips`.
 ///
 /// Core returns a vector of chain tips — one representing the active chain
 /// and zero or more representing forks or incomplete branches.
-#[derive(Debug, Deserialize, Default, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, Default, PartialEq)]
 #[serde(rename_all = "snake_case")]
 #[allow(dead_code)]
 pub struct ChainTipsJsonWrap {
@@ -35,7 +35,7 @@ pub struct ChainTipsJsonWrap {
 /// - `"valid-fork"`: valid but not the active chain  
 /// - `"valid-headers"`: headers-only, not fully validated  
 /// - `"unknown"`: Core cannot classify the branch  
-#[derive(Debug, Deserialize, Default, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, Default, PartialEq)]
 #[serde(rename_all = "snake_case")]
 #[allow(dead_code)]
 pub struct ChainTip {
@@ -53,3 +53,26 @@ pub struct ChainTip {
     pub status: String,
 }
 
+/// Default `fork_alert_branchlen`: a stale branch two blocks long.
+pub const DEFAULT_FORK_ALERT_BRANCHLEN: u64 = 2;
+
+/// Tips shown in the Consensus Security panel: the active chain and
+/// valid forks, highest first, at most three.
+pub fn displayed_tips(tips: &[ChainTip]) -> Vec<&ChainTip> {
+    let mut shown: Vec<&ChainTip> = tips
+        .iter()
+        .filter(|tip| tip.status == "active" || tip.status == "valid-fork")
+        .collect();
+    shown.sort_by_key(|tip| std::cmp::Reverse(tip.height));
+    shown.truncate(3);
+    shown
+}
+
+/// Displayed `valid-fork` tips whose branch is at least `min_branchlen`
+/// blocks long — a potential reorg worth alerting on.
+pub fn alerting_forks(tips: &[ChainTip], min_branchlen: u64) -> Vec<&ChainTip> {
+    displayed_tips(tips)
+        .into_iter()
+        .filter(|tip| tip.status == "valid-fork" && tip.branchlen >= min_branchlen)
+        .collect()
+}
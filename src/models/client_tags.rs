@@ -0,0 +1,50 @@
+/// Peer client classification from `getpeerinfo` user agents (`subver`).
+///
+/// A table-driven mapping of user-agent segment names to client labels
+/// for the Network section's client distribution, in the same shape as
+/// the coinbase tag table in `miner_tags`.
+///
+/// User agents are `/`-separated `name:version` segments, e.g.
+/// `/Satoshi:27.0.0/Knots:20241122/` or `/btcwire:0.5.0/btcd:0.24.2/`.
+/// Each segment name is lowercased and checked against the patterns by
+/// prefix. Table order defines precedence: derivative clients (Knots,
+/// Ronin) also carry a `Satoshi` segment, so they are listed before Core.
+
+#[derive(Clone, Copy)]
+pub struct ClientMapEntry {
+    // any segment name starting with one of these matches
+    pub pats: &'static [&'static str],
+    // canonical display label
+    pub label: &'static str,
+}
+
+// Known clients, highest precedence first
+pub static CLIENT_TAGS: &[ClientMapEntry] = &[
+    ClientMapEntry { pats: &["knots"], label: "Knots" },
+    ClientMapEntry { pats: &["ronin"], label: "Ronin" },
+    ClientMapEntry { pats: &["satoshi"], label: "Core" },
+    ClientMapEntry { pats: &["bcoin"], label: "bcoin" },
+    ClientMapEntry { pats: &["btcd"], label: "btcd" },
+    ClientMapEntry { pats: &["libbitcoin"], label: "libbitcoin" },
+    ClientMapEntry { pats: &["bread"], label: "bread" },
+    ClientMapEntry { pats: &["gocoin"], label: "Gocoin" },
+    ClientMapEntry { pats: &["bitcoin abc"], label: "Bitcoin ABC" },
+];
+
+/// Match a user agent against the client table.
+///
+/// Returns the label of the first table entry matching any segment of
+/// `subver`, or `None` for unknown clients.
+pub fn match_client(subver: &str) -> Option<&'static str> {
+    let names: Vec<String> = subver
+        .trim_matches('/')
+        .split('/')
+        .map(|seg| seg.split(':').next().unwrap_or("").trim().to_lowercase())
+        .filter(|name| !name.is_empty())
+        .collect();
+
+    CLIENT_TAGS
+        .iter()
+        .find(|e| names.iter().any(|n| e.pats.iter().any(|p| n.starts_with(p))))
+        .map(|e| e.label)
+}
@@ -0,0 +1,51 @@
+//! Coinbase reward of the latest block, split into subsidy and fees.
+//!
+//! Computed from the verbose=2 block already fetched for miner
+//! identification. The subsidy follows from the height alone
+//! (`satoshi_math::block_subsidy`); whatever the coinbase pays beyond it is
+//! the fees the miner collected.
+
+use crate::consensus::satoshi_math::{block_subsidy, SATS_PER_BTC};
+use crate::models::block_info::BlockInfoFull;
+
+/// Coinbase payout of one block, in satoshis.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CoinbaseReward {
+    /// Height of the block; 0 until a block has been read.
+    pub height: u64,
+    /// Sum of the coinbase outputs.
+    pub total: u64,
+    /// Subsidy allowed at this height.
+    pub subsidy: u64,
+}
+
+impl CoinbaseReward {
+    /// Reads the coinbase (`tx[0]`) of a verbose=2 block; `None` if the
+    /// block has no coinbase.
+    pub fn from_block(block: &BlockInfoFull) -> Option<Self> {
+        let coinbase = block.tx.first().filter(|tx| tx.is_coinbase())?;
+        let total = coinbase
+            .vout
+            .iter()
+            .map(|vout| (vout.value * SATS_PER_BTC as f64).round() as u64)
+            .sum();
+
+        Some(Self { height: block.height, total, subsidy: block_subsidy(block.height) })
+    }
+
+    /// Fees claimed by the miner. A miner may claim less than allowed, so
+    /// this never goes below zero.
+    pub fn fees(&self) -> u64 {
+        self.total.saturating_sub(self.subsidy)
+    }
+
+    /// Subsidy in BTC.
+    pub fn subsidy_btc(&self) -> f64 {
+        self.subsidy as f64 / SATS_PER_BTC as f64
+    }
+
+    /// Fees in BTC.
+    pub fn fees_btc(&self) -> f64 {
+        self.fees() as f64 / SATS_PER_BTC as f64
+    }
+}
@@ -0,0 +1,82 @@
+//! Inbound/outbound connection ratio and churn tracking.
+//!
+//! Fed from the UI loop with the connection counts of each
+//! `getnetworkinfo` result. Every change in the inbound or outbound count
+//! is recorded with its timestamp; the sum of those changes over the last
+//! `CHURN_WINDOW_SECS` is the churn. A stable node barely moves, while a
+//! flapping network or an eclipse attempt (peers rapidly replaced) shows up
+//! as a burst of changes.
+//!
+//! Counts only see net changes between polls, so a peer swapped for another
+//! inside one poll interval goes unnoticed; this is a coarse signal.
+
+use std::collections::VecDeque;
+
+/// Window (seconds) over which connection-count changes are summed.
+pub const CHURN_WINDOW_SECS: u64 = 120;
+
+/// Churn at or above this is shown as elevated (yellow).
+pub const CHURN_ELEVATED: u64 = 4;
+
+/// Churn at or above this is shown as high (red).
+pub const CHURN_HIGH: u64 = 10;
+
+/// How unusual the recent churn is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChurnLevel {
+    Normal,
+    Elevated,
+    High,
+}
+
+/// Rolling record of connection-count changes.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ConnectionChurn {
+    /// Inbound/outbound counts at the last observation.
+    last: Option<(u32, u32)>,
+    /// `(timestamp, connections gained or lost)`, oldest first.
+    changes: VecDeque<(u64, u64)>,
+}
+
+impl ConnectionChurn {
+    /// Records the counts seen at `now` (Unix seconds).
+    ///
+    /// The first observation only sets the baseline.
+    pub fn observe(&mut self, now: u64, inbound: u32, outbound: u32) {
+        if let Some((prev_in, prev_out)) = self.last.replace((inbound, outbound)) {
+            let delta = inbound.abs_diff(prev_in) as u64 + outbound.abs_diff(prev_out) as u64;
+            if delta > 0 {
+                self.changes.push_back((now, delta));
+            }
+        }
+        while let Some(&(at, _)) = self.changes.front() {
+            if now.saturating_sub(at) < CHURN_WINDOW_SECS {
+                break;
+            }
+            self.changes.pop_front();
+        }
+    }
+
+    /// Connections gained or lost within the window ending at the last
+    /// observation.
+    pub fn churn(&self) -> u64 {
+        self.changes.iter().map(|&(_, delta)| delta).sum()
+    }
+
+    /// Classification of `churn()` against the thresholds.
+    pub fn level(&self) -> ChurnLevel {
+        match self.churn() {
+            c if c >= CHURN_HIGH => ChurnLevel::High,
+            c if c >= CHURN_ELEVATED => ChurnLevel::Elevated,
+            _ => ChurnLevel::Normal,
+        }
+    }
+}
+
+/// Inbound connections per outbound one, `None` without outbound peers.
+pub fn inbound_outbound_ratio(inbound: u32, outbound: u32) -> Option<f64> {
+    if outbound == 0 {
+        return None;
+    }
+    Some(inbound as f64 / outbound as f64)
+}
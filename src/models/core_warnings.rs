@@ -0,0 +1,47 @@
+//! Core's `warnings` field from `getblockchaininfo` / `getnetworkinfo`.
+//!
+//! Up to Core 27 the field is a single string (`""` when there is nothing
+//! to report). Core 28 switched to an array of strings. Both shapes are
+//! normalized to `Vec<String>`, with empty entries dropped.
+
+use serde::{Deserialize, Deserializer};
+
+use crate::models::{blockchain_info::BlockchainInfo, network_info::NetworkInfo};
+
+/// The two wire formats of `warnings`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Warnings {
+    One(String),
+    Many(Vec<String>),
+}
+
+/// `deserialize_with` helper accepting a string or an array of strings.
+pub fn deserialize_warnings<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let list = match Option::<Warnings>::deserialize(deserializer)? {
+        None => Vec::new(),
+        Some(Warnings::One(s)) => vec![s],
+        Some(Warnings::Many(v)) => v,
+    };
+    Ok(list
+        .into_iter()
+        .map(|w| w.trim().to_string())
+        .filter(|w| !w.is_empty())
+        .collect())
+}
+
+/// Warnings from both RPCs, in order, without duplicates.
+///
+/// Core usually reports the same node-wide warning through both.
+pub fn node_warnings(blockchain_info: &BlockchainInfo, network_info: &NetworkInfo) -> Vec<String> {
+    let mut all: Vec<String> = Vec::new();
+    for w in blockchain_info.warnings.iter().chain(&network_info.warnings) {
+        if !all.contains(w) {
+            all.push(w.clone());
+        }
+    }
+    all
+}
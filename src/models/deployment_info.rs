@@ -0,0 +1,136 @@
+//! Data models for Bitcoin Core’s `getdeploymentinfo` RPC.
+//!
+//! Describes soft-fork deployments (buried and BIP9) at the tip. The
+//! Consensus Security section lists deployments that are not yet active,
+//! with their BIP9 status and miner signaling progress.
+//!
+//! `getdeploymentinfo` was added in Core 23; older nodes answer with
+//! "Method not found" and the list is hidden.
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+use crate::models::rpc_envelope::RpcErrorObject;
+
+/// Wrapper for the `getdeploymentinfo` RPC response.
+///
+/// `result` is `null` when Core returns an error object instead.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(dead_code)]
+pub struct DeploymentInfoJsonWrap {
+    pub error: Option<RpcErrorObject>,
+    pub id: Option<String>,
+    pub result: Option<DeploymentInfo>,
+}
+
+/// Deployment state at a given block.
+#[derive(Debug, Deserialize, Default, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+#[allow(dead_code)]
+pub struct DeploymentInfo {
+    /// Block hash the state was evaluated at.
+    #[serde(default)]
+    pub hash: String,
+
+    /// Block height the state was evaluated at.
+    #[serde(default)]
+    pub height: u64,
+
+    /// Deployments keyed by name (`taproot`, `segwit`, `testdummy`, …).
+    #[serde(default)]
+    pub deployments: BTreeMap<String, Deployment>,
+}
+
+/// One soft-fork deployment.
+#[derive(Debug, Deserialize, Default, Clone, PartialEq)]
+#[allow(dead_code)]
+pub struct Deployment {
+    /// `"buried"` or `"bip9"`.
+    #[serde(rename = "type")]
+    pub kind: String,
+
+    /// True if the rules are enforced for the next block.
+    pub active: bool,
+
+    /// Activation height, when known.
+    #[serde(default)]
+    pub height: Option<u64>,
+
+    /// BIP9 state; absent for buried deployments.
+    #[serde(default)]
+    pub bip9: Option<Bip9Info>,
+}
+
+/// BIP9 state machine details.
+#[derive(Debug, Deserialize, Default, Clone, PartialEq)]
+#[allow(dead_code)]
+pub struct Bip9Info {
+    /// `defined`, `started`, `locked_in`, `active` or `failed`.
+    pub status: String,
+
+    /// Height of the first block with the current status.
+    #[serde(default)]
+    pub since: u64,
+
+    /// Signaling statistics for the current period (only while `started`).
+    #[serde(default)]
+    pub statistics: Option<Bip9Statistics>,
+}
+
+/// Signaling progress in the current retarget period.
+#[derive(Debug, Deserialize, Default, Clone, PartialEq)]
+#[allow(dead_code)]
+pub struct Bip9Statistics {
+    /// Blocks in a signaling period.
+    pub period: u64,
+
+    /// Signaling blocks needed to lock in.
+    #[serde(default)]
+    pub threshold: u64,
+
+    /// Blocks elapsed in the current period.
+    pub elapsed: u64,
+
+    /// Signaling blocks so far in the current period.
+    pub count: u64,
+
+    /// Whether lock-in is still reachable this period.
+    #[serde(default)]
+    pub possible: bool,
+}
+
+impl Deployment {
+    /// BIP9 status, or `"buried"` for height-activated deployments.
+    pub fn status(&self) -> &str {
+        self.bip9.as_ref().map_or(self.kind.as_str(), |b| b.status.as_str())
+    }
+
+    /// Share of elapsed blocks in this period that signal, in percent.
+    pub fn signaling_pct(&self) -> Option<f64> {
+        let stats = self.bip9.as_ref()?.statistics.as_ref()?;
+        if stats.elapsed == 0 {
+            return None;
+        }
+        Some(stats.count as f64 / stats.elapsed as f64 * 100.0)
+    }
+
+    /// Lock-in threshold as a percentage of the period.
+    pub fn threshold_pct(&self) -> Option<f64> {
+        let stats = self.bip9.as_ref()?.statistics.as_ref()?;
+        if stats.period == 0 {
+            return None;
+        }
+        Some(stats.threshold as f64 / stats.period as f64 * 100.0)
+    }
+}
+
+impl DeploymentInfo {
+    /// Deployments worth showing: not active and not permanently failed.
+    pub fn pending(&self) -> Vec<(&String, &Deployment)> {
+        self.deployments
+            .iter()
+            .filter(|(_, d)| !d.active && d.status() != "failed")
+            .collect()
+    }
+}
@@ -0,0 +1,61 @@
+//! Growth rate of the node's block storage (`size_on_disk`).
+//!
+//! Fed from the UI loop with each `getblockchaininfo` result. A sample is
+//! kept whenever `size_on_disk` changes; the rate is the growth from the
+//! oldest sample in the window to the latest value, per hour of elapsed
+//! time. Useful for capacity planning on archival nodes.
+//!
+//! On pruned nodes the size drops whenever old block files are deleted, so
+//! the difference can be negative; such rates are clamped to zero.
+
+use std::collections::VecDeque;
+
+/// Window (seconds) the growth rate is measured over.
+pub const DISK_GROWTH_WINDOW_SECS: u64 = 6 * 3600;
+
+/// Shortest span (seconds) a rate is reported for; a few minutes of
+/// samples say more about block timing than about growth.
+pub const DISK_GROWTH_MIN_SPAN_SECS: u64 = 30 * 60;
+
+/// Rolling record of `size_on_disk` changes.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DiskGrowth {
+    /// `(timestamp, size_on_disk)` at each change, oldest first.
+    samples: VecDeque<(u64, u64)>,
+    /// Time of the last observation.
+    last_seen: u64,
+}
+
+impl DiskGrowth {
+    /// Records `size_on_disk` (bytes) as seen at `now` (Unix seconds).
+    ///
+    /// Zero (nothing fetched yet) is ignored.
+    pub fn observe(&mut self, now: u64, size_on_disk: u64) {
+        if size_on_disk == 0 {
+            return;
+        }
+        if self.samples.back().map(|&(_, size)| size) != Some(size_on_disk) {
+            self.samples.push_back((now, size_on_disk));
+        }
+        self.last_seen = now;
+
+        // Keep one sample at or before the window start as the baseline.
+        while self.samples.len() > 1
+            && now.saturating_sub(self.samples[1].0) >= DISK_GROWTH_WINDOW_SECS
+        {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Growth in bytes per hour, `None` until the samples span at least
+    /// `DISK_GROWTH_MIN_SPAN_SECS`. Shrinking (pruning) counts as zero.
+    pub fn bytes_per_hour(&self) -> Option<u64> {
+        let &(start, first) = self.samples.front()?;
+        let &(_, latest) = self.samples.back()?;
+        let span = self.last_seen.saturating_sub(start);
+        if span < DISK_GROWTH_MIN_SPAN_SECS {
+            return None;
+        }
+        Some((latest.saturating_sub(first) as u128 * 3600 / span as u128) as u64)
+    }
+}
@@ -91,6 +91,12 @@ pub enum MyError {
 
     /// File was not found (special case for clarity).
     FileNotFound(String),
+
+    /// The node does not implement the RPC method (older Core versions).
+    RpcMethodNotFound(String),
+
+    /// The requested block's data was deleted by pruning.
+    BlockPruned(String),
 }
 
 // -----------------------------------------------------------------------------
@@ -131,6 +137,24 @@ impl fmt::Display for MyError {
             MyError::TimeoutError(msg) => write!(f, "Error: {}", msg),
             MyError::FileError(msg) => write!(f, "File Error: {}", msg),
             MyError::FileNotFound(msg) => write!(f, "File not found: {}", msg),
+            MyError::RpcMethodNotFound(method) => write!(f, "RPC method not supported by node: {}", method),
+            MyError::BlockPruned(msg) => write!(f, "Block data pruned: {}", msg),
+        }
+    }
+}
+
+/// Wrapped library errors are exposed through `source()`, so callers
+/// (e.g. `anyhow` or `Box<dyn Error>`) can walk the full chain.
+impl std::error::Error for MyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MyError::Reqwest(err) => Some(err),
+            MyError::SerdeJson(err) => Some(err),
+            MyError::Io(err) => Some(err),
+            MyError::TomlDeserialize(err) => Some(err),
+            MyError::TomlSerialize(err) => Some(err),
+            MyError::Join(err) => Some(err),
+            _ => None,
         }
     }
 }
@@ -169,6 +193,12 @@ impl From<serde_json::Error> for MyError {
     }
 }
 
+impl From<tokio::time::error::Elapsed> for MyError {
+    fn from(err: tokio::time::error::Elapsed) -> MyError {
+        MyError::TimeoutError(format!("Operation timed out: {}", err))
+    }
+}
+
 impl From<io::Error> for MyError {
     fn from(err: io::Error) -> MyError {
         MyError::Io(err)
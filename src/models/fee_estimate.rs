@@ -0,0 +1,56 @@
+//! Data models for Bitcoin Core’s `estimatesmartfee` RPC.
+//!
+//! Core’s fee estimator tracks how long transactions at each fee rate took
+//! to confirm and answers “what rate confirms within N blocks?”. This
+//! complements the dashboard’s own mempool-derived metrics with Core’s
+//! historical view.
+//!
+//! Core reports `feerate` in BTC/kvB. When it has too little data (fresh
+//! node, `-blocksonly`, just restarted) it omits `feerate` and returns an
+//! `errors` array instead.
+
+use serde::Deserialize;
+
+/// Confirmation targets (in blocks) shown in the Fee Targets line.
+pub const FEE_TARGETS: [u16; 4] = [1, 3, 6, 144];
+
+/// `(confirmation target, sat/vB)` pairs, as cached for the dashboard.
+pub type FeeEstimates = Vec<(u16, f64)>;
+
+/// Satoshis per vbyte in 1 BTC/kvB (1e8 sat / 1000 vB).
+const SAT_PER_VB_PER_BTC_PER_KVB: f64 = 100_000.0;
+
+/// Wrapper for the `estimatesmartfee` RPC response.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(dead_code)]
+pub struct SmartFeeEstimateJsonWrap {
+    pub error: Option<String>,
+    pub id: Option<String>,
+    pub result: SmartFeeEstimate,
+}
+
+/// Result of `estimatesmartfee <conf_target>`.
+#[derive(Debug, Deserialize, Default, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct SmartFeeEstimate {
+    /// Estimated fee rate in BTC/kvB. Absent when no estimate is available.
+    #[serde(default)]
+    pub feerate: Option<f64>,
+
+    /// Reasons an estimate could not be produced.
+    #[serde(default)]
+    pub errors: Vec<String>,
+
+    /// Block target the estimate actually applies to (may differ from the
+    /// requested one when Core lacks data for it).
+    #[serde(default)]
+    pub blocks: u16,
+}
+
+impl SmartFeeEstimate {
+    /// The estimate in sat/vB, if Core produced one.
+    pub fn sat_per_vb(&self) -> Option<f64> {
+        self.feerate.map(|rate| rate * SAT_PER_VB_PER_BTC_PER_KVB)
+    }
+}
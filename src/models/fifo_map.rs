@@ -0,0 +1,77 @@
+//! Bounded concurrent map with first-in, first-out eviction.
+//!
+//! Backs the mempool entry cache (`TX_CACHE`). Values live in a `DashMap`
+//! so readers (distribution metrics) iterate without a global lock;
+//! insertion order is kept in a queue behind a `Mutex`.
+//!
+//! The queue always holds exactly the keys in the map: `retain` and
+//! `clear` drop their queue entries too, so eviction never has to skip
+//! keys that are already gone.
+
+use std::collections::VecDeque;
+use std::hash::Hash;
+use std::sync::Mutex;
+
+use dashmap::DashMap;
+
+/// A `DashMap` capped at `capacity` entries, evicting the oldest
+/// inserted key when full.
+pub struct FifoMap<K, V> {
+    map: DashMap<K, V>,
+    order: Mutex<VecDeque<K>>,
+    capacity: usize,
+}
+
+impl<K: Eq + Hash + Clone, V> FifoMap<K, V> {
+    /// Creates an empty map holding at most `capacity` entries (min 1).
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            map: DashMap::new(),
+            order: Mutex::new(VecDeque::new()),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Inserts or replaces `key`.
+    ///
+    /// A new key evicts the oldest entry first if the map is full; a
+    /// replaced key keeps its place in the queue.
+    pub fn insert(&self, key: K, value: V) {
+        let mut order = self.order.lock().unwrap_or_else(|e| e.into_inner());
+
+        if !self.map.contains_key(&key) {
+            while self.map.len() >= self.capacity {
+                match order.pop_front() {
+                    Some(oldest) => {
+                        self.map.remove(&oldest);
+                    }
+                    None => break,
+                }
+            }
+            order.push_back(key.clone());
+        }
+        self.map.insert(key, value);
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.map.contains_key(key)
+    }
+
+    /// Keeps only the entries for which `f` returns `true`.
+    pub fn retain(&self, f: impl FnMut(&K, &mut V) -> bool) {
+        let mut order = self.order.lock().unwrap_or_else(|e| e.into_inner());
+        self.map.retain(f);
+        order.retain(|k| self.map.contains_key(k));
+    }
+
+    pub fn clear(&self) {
+        let mut order = self.order.lock().unwrap_or_else(|e| e.into_inner());
+        self.map.clear();
+        order.clear();
+    }
+
+    /// Read-only view of the cached entries, e.g. for aggregation.
+    pub fn as_map(&self) -> &DashMap<K, V> {
+        &self.map
+    }
+}
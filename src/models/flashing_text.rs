@@ -9,12 +9,16 @@
 //!
 //! The flashing duration is intentionally short (200–400ms) to mimic a natural signal pulse,
 //! not a long animation. The dashboard simply re-renders using the `.style()` method.
+//!
+//! In mono mode (`--mono` / `NO_COLOR`) the flash is shown reversed instead of
+//! as a color change.
 
 use lazy_static::lazy_static;
 use std::sync::Mutex;
 use std::time::{Duration, Instant};
 use tui::style::Style;
 use crate::ui::colors::*;
+use crate::ui::color_support::flash_style;
 
 // Global flash tracker for the Best Block height.
 // Updated whenever a new block is detected.
@@ -75,12 +79,8 @@ impl FlashingText {
     /// - Active flash → **White**
     /// - Idle → **Green**
     pub fn style(&self) -> Style {
-        if let Some(flash_until) = self.flash_until {
-            if Instant::now() < flash_until {
-                return Style::default().fg(C_FLASH); // Highlight style
-            }
-        }
-        Style::default().fg(C_PREFLASH) // Default style
+        let flashing = self.flash_until.is_some_and(|until| Instant::now() < until);
+        flash_style(flashing, C_FLASH, C_PREFLASH)
     }
 }
 
@@ -117,11 +117,7 @@ impl FlashingMiner {
     /// - Active flash → **LightYellow**
     /// - Idle → **Yellow**
     pub fn style(&self) -> Style {
-        if let Some(flash_until) = self.flash_until {
-            if Instant::now() < flash_until {
-                return Style::default().fg(C_MINER_FLASH); // Highlight
-            }
-        }
-        Style::default().fg(C_MINER) // Default
+        let flashing = self.flash_until.is_some_and(|until| Instant::now() < until);
+        flash_style(flashing, C_MINER_FLASH, C_MINER)
     }
 }
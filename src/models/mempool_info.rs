@@ -17,10 +17,11 @@
 //! - RBF vs. non-RBF counts
 //! - average/median fees
 //! - fee-per-vbyte estimates
+//! - a fee-rate histogram (sats/vB bands)
 //!
 //! Core philosophy: keep raw RPC models pure, push "interpretation" upward.
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use dashmap::DashMap;
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -38,14 +39,21 @@ use std::time::{SystemTime, UNIX_EPOCH};
 /// The segmentation rules are intentionally simple and stable:
 /// - vsize buckets: 0–249, 250–1000, 1000+  
 /// - age buckets: <5 min, 5–60 min, >60 min
+/// - fee-rate bands (sats/vB): <2, 2–5, 5–10, 10–20, 20–50, 50+
 ///
 /// This keeps the dashboard interpretable across all node types.
-#[derive(Default)]
+#[derive(Default, Serialize)]
 pub struct MempoolDistribution {
     pub small: usize,
     pub medium: usize,
     pub large: usize,
 
+    /// Total vsize (vbytes) per size bucket, for the block-space view of
+    /// the size distribution.
+    pub small_vsize: u64,
+    pub medium_vsize: u64,
+    pub large_vsize: u64,
+
     pub young: usize,
     pub moderate: usize,
     pub old: usize,
@@ -62,17 +70,134 @@ pub struct MempoolDistribution {
 
     /// Median of per-tx fee rates (fee/vsize) in sats/vB.
     pub median_fee_rate: u64,
+
+    /// Fee rate (sats/vB) of the lowest-feerate transaction that still fits
+    /// in the top `NEXT_BLOCK_VSIZE` vbytes of the fee-rate-sorted cache.
+    /// `None` when the cached mempool does not fill a block.
+    pub next_block_fee_rate_floor: Option<u64>,
+
+    /// Transaction counts per fee-rate band; see `FEERATE_BUCKET_LABELS`.
+    pub feerate_buckets: [usize; 6],
+
+    /// Cached mempool entries examined in dust-free mode and left out as
+    /// dust. Zero when dust-free is off.
+    pub dust_count: usize,
+}
+
+/// Lower edges (sats/vB) of the fee-rate bands after the first.
+///
+/// The first band also holds sub-1 sat/vB transactions, which nodes with a
+/// lowered `minrelaytxfee` accept.
+pub const FEERATE_BUCKET_EDGES: [u64; 5] = [2, 5, 10, 20, 50];
+
+/// Display labels for `MempoolDistribution::feerate_buckets`.
+pub const FEERATE_BUCKET_LABELS: [&str; 6] = ["<2", "2-5", "5-10", "10-20", "20-50", "50+"];
+
+/// Fee-rate band index (0–5) for a transaction paying `fee_sats` over
+/// `vsize` vbytes.
+///
+/// Compared exactly (`fee >= edge * vsize`), so a rate of exactly 5 sat/vB
+/// lands in 5-10, not 2-5.
+pub fn feerate_bucket(fee_sats: u64, vsize: u64) -> usize {
+    FEERATE_BUCKET_EDGES
+        .iter()
+        .take_while(|&&edge| fee_sats as u128 >= edge as u128 * vsize as u128)
+        .count()
+}
+
+/// Block space (vbytes) assumed available to mempool transactions when
+/// estimating the next-block fee-rate floor.
+///
+/// 4M weight units / 4 = 1M vbytes; the coinbase and header overhead are
+/// small enough to ignore for display purposes.
+pub const NEXT_BLOCK_VSIZE: u64 = 1_000_000;
+
+/// Minimum fee rate (sats/vB) needed to land in the next block.
+///
+/// `entries` are `(fee_sats, vsize)` pairs. They are sorted by fee rate
+/// (highest first) and accumulated until `block_vsize` is exhausted; the
+/// fee rate of the last transaction that fits is the floor.
+///
+/// Returns `None` if all entries fit — the mempool doesn't fill a block,
+/// so anything above the node's relay minimum should confirm.
+///
+/// Note: this ranks transactions individually and ignores CPFP packages,
+/// so it is an estimate of what a miner's template would contain.
+pub fn next_block_fee_rate_floor(entries: &mut [(u64, u64)], block_vsize: u64) -> Option<u64> {
+    // Compare fee rates exactly via cross-multiplication (no float rounding).
+    entries.sort_unstable_by(|a, b| {
+        (b.0 as u128 * a.1 as u128).cmp(&(a.0 as u128 * b.1 as u128))
+    });
+
+    let mut used: u64 = 0;
+    let mut floor: Option<u64> = None;
+
+    for &(fee, vsize) in entries.iter() {
+        if used.saturating_add(vsize) > block_vsize {
+            return floor.or(Some(0));
+        }
+        used += vsize;
+        floor = Some(fee.checked_div(vsize).unwrap_or(0));
+    }
+
+    None
 }
 
 impl MempoolDistribution {
+    /// Fee rate (sats/vB) needed to make the next block.
+    ///
+    /// The next-block floor when the cached mempool fills a block, never
+    /// below `min_relay` (sats/vB); otherwise `min_relay` itself, since any
+    /// relayable transaction should confirm.
+    pub fn next_block_min_feerate(&self, min_relay: f64) -> f64 {
+        match self.next_block_fee_rate_floor {
+            Some(floor) => (floor as f64).max(min_relay),
+            None => min_relay,
+        }
+    }
+
+    /// Share (0–100) of examined mempool entries that are not dust.
+    ///
+    /// Both sides come from the distribution cache: kept entries
+    /// (small + medium + large) over kept plus dust. The full mempool
+    /// count is deliberately not the denominator — the cache fills over
+    /// several cycles, so that ratio reads low while warming and can pass
+    /// 100% when the mempool shrinks. `None` before anything is examined.
+    pub fn dust_free_pct(&self) -> Option<f64> {
+        let kept = self.small + self.medium + self.large;
+        let examined = kept + self.dust_count;
+        if examined == 0 {
+            return None;
+        }
+        Some((kept as f64 / examined as f64 * 100.0).min(100.0))
+    }
+
     /// Updates the distribution metrics using all entries in the mempool cache.
     ///
     /// Assumes the caller has already filtered out dust if needed.
     /// This function is intentionally CPU-light; it should run every refresh cycle.
+    #[allow(dead_code)]
     pub fn update_metrics(&mut self, cache: &DashMap<[u8; 32], MempoolEntry>) {
+        self.update_metrics_sampled(cache, 1.0);
+    }
+
+    /// Like [`update_metrics`](Self::update_metrics), for a cache holding only
+    /// `sample_fraction` (0–1] of the mempool (see `MempoolSampler`).
+    ///
+    /// Counts and medians describe the sample as-is; the next-block floor is
+    /// taken over the same share of a block (`NEXT_BLOCK_VSIZE * sample_fraction`)
+    /// so it still estimates the full mempool's floor.
+    pub fn update_metrics_sampled(
+        &mut self,
+        cache: &DashMap<[u8; 32], MempoolEntry>,
+        sample_fraction: f64,
+    ) {
         let mut small = 0;
         let mut medium = 0;
         let mut large = 0;
+        let mut small_vsize: u64 = 0;
+        let mut medium_vsize: u64 = 0;
+        let mut large_vsize: u64 = 0;
 
         let mut young = 0;
         let mut moderate = 0;
@@ -88,6 +213,8 @@ impl MempoolDistribution {
         // let mut fees: Vec<f64> = Vec::new();
         let mut fees: Vec<u64> = Vec::new();
         let mut fee_rates: Vec<u64> = Vec::new(); // sats/vB per tx
+        let mut fee_vsizes: Vec<(u64, u64)> = Vec::new(); // (fee sats, vsize) for block-floor estimate
+        let mut feerate_buckets = [0usize; 6];
 
         let now = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -97,11 +224,21 @@ impl MempoolDistribution {
         for entry in cache.iter() {
             let e = entry.value();
 
-            // vsize segmentation
+            // vsize segmentation (count and block space)
+            let vsize = e.vsize;
             match e.vsize {
-                0..=249 => small += 1,
-                250..=1000 => medium += 1,
-                _ => large += 1,
+                0..=249 => {
+                    small += 1;
+                    small_vsize += vsize;
+                }
+                250..=1000 => {
+                    medium += 1;
+                    medium_vsize += vsize;
+                }
+                _ => {
+                    large += 1;
+                    large_vsize += vsize;
+                }
             }
 
             // age segmentation
@@ -130,6 +267,8 @@ impl MempoolDistribution {
             let v = e.vsize as u64;
             let fr = if v > 0 { fee / v } else { 0 };
             fee_rates.push(fr);
+            fee_vsizes.push((fee, v));
+            feerate_buckets[feerate_bucket(fee, v)] += 1;
             
             count += 1;
         }
@@ -138,6 +277,9 @@ impl MempoolDistribution {
         self.small = small;
         self.medium = medium;
         self.large = large;
+        self.small_vsize = small_vsize;
+        self.medium_vsize = medium_vsize;
+        self.large_vsize = large_vsize;
 
         self.young = young;
         self.moderate = moderate;
@@ -146,6 +288,8 @@ impl MempoolDistribution {
         self.rbf_count = rbf_count;
         self.non_rbf_count = non_rbf_count;
 
+        self.feerate_buckets = feerate_buckets;
+
         self.average_fee = if count > 0 { total_fee / count as u64 } else { 0 };
 
         // Median fee
@@ -181,6 +325,11 @@ impl MempoolDistribution {
         } else {
             0
         };
+
+        // Next-block fee-rate floor (top ~1M vbytes, scaled to the sample)
+        let block_vsize = (NEXT_BLOCK_VSIZE as f64 * sample_fraction.clamp(0.0, 1.0)).round() as u64;
+        self.next_block_fee_rate_floor =
+            next_block_fee_rate_floor(&mut fee_vsizes, block_vsize);
     }
 }
 
@@ -202,7 +351,7 @@ pub struct MempoolInfoJsonWrap {
 /// Mirror of Core's `getmempoolinfo` response.
 ///
 /// These values describe global mempool state (memory usage, min fees, RBF mode).
-#[derive(Debug, Deserialize, Default, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, Default, PartialEq)]
 #[serde(rename_all = "snake_case")]
 #[allow(dead_code)]
 pub struct MempoolInfo {
@@ -219,16 +368,48 @@ pub struct MempoolInfo {
     pub fullrbf: bool,
 }
 
+/// Convert a Core fee rate (BTC/kvB) into sats/vB.
+///
+/// Core expresses fee rates in:
+///     BTC/kB
+///
+/// We convert:
+///     sats/vB = (BTC * 1e8) / 1000
+///
+/// Sub-1 rates (e.g. `0.1`) are kept; the result is rounded to 2 decimals
+/// to hide BTC/kvB float noise.
+pub fn btc_per_kvb_to_sat_vb(rate: f64) -> f64 {
+    (rate * 100_000_000.0 / 1000.0 * 100.0).round() / 100.0
+}
+
 impl MempoolInfo {
-    /// Convert Core’s fee rate (BTC/kB) into sats/vB.
-    ///
-    /// Core expresses fee rates in:
-    ///     BTC/kB
-    ///
-    /// We convert:
-    ///     sats/vB = (BTC * 1e8) / 1000
+    /// `minrelaytxfee` in whole sats/vB.
+    #[allow(dead_code)]
     pub fn min_relay_tx_fee_vsats(&self) -> u64 {
-        (self.minrelaytxfee * 100_000_000.0 / 1000.0) as u64
+        btc_per_kvb_to_sat_vb(self.minrelaytxfee) as u64
+    }
+
+    /// `minrelaytxfee` in sats/vB, keeping sub-1 rates (e.g. `0.1`).
+    pub fn min_relay_fee_rate(&self) -> f64 {
+        btc_per_kvb_to_sat_vb(self.minrelaytxfee)
+    }
+
+    /// `mempoolminfee` in sats/vB: the lowest rate the mempool currently
+    /// accepts. Equal to `minrelaytxfee` until the mempool fills up.
+    pub fn mempool_min_fee_rate(&self) -> f64 {
+        btc_per_kvb_to_sat_vb(self.mempoolminfee)
+    }
+
+    /// `incrementalrelayfee` in sats/vB: the step by which `mempoolminfee`
+    /// rises when the mempool evicts, and the minimum bump for replacements.
+    pub fn incremental_relay_fee_rate(&self) -> f64 {
+        btc_per_kvb_to_sat_vb(self.incrementalrelayfee)
+    }
+
+    /// True when the mempool is full enough that its minimum fee has risen
+    /// above the node's relay minimum (low-fee transactions are being purged).
+    pub fn under_fee_pressure(&self) -> bool {
+        self.mempool_min_fee_rate() > self.min_relay_fee_rate()
     }
 }
 
@@ -262,8 +443,9 @@ pub struct MempoolEntryJsonWrap {
     pub result: MempoolEntry,
 }
 
-/// Full mempool entry data.
-/// Mirrors Bitcoin Core exactly (`getmempoolentry`).
+/// Mempool entry data cached in `TX_CACHE`.
+/// Mirrors Bitcoin Core (`getmempoolentry`), minus the `depends`/`spentby`
+/// txid lists, which only the Tx Lookup popup reads (see `MempoolEntryLookup`).
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[allow(dead_code)]
@@ -285,12 +467,6 @@ pub struct MempoolEntry {
     pub wtxid: [u8; 32],
     
     pub fees: Fees,
-    #[serde(skip)]
-    #[allow(dead_code)]
-    pub depends: Option<Vec<String>>,
-    #[serde(skip)]
-    #[allow(dead_code)]
-    pub spentby: Option<Vec<String>>,
 
     #[serde(rename = "bip125-replaceable")]
     pub bip125_replaceable: bool,
@@ -298,6 +474,34 @@ pub struct MempoolEntry {
     pub unbroadcast: Option<bool>,
 }
 
+/// `getmempoolentry` response for the Tx Lookup popup.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(dead_code)]
+pub struct MempoolEntryLookupJsonWrap {
+    pub error: Option<String>,
+    pub id: Option<String>,
+    pub result: MempoolEntryLookup,
+}
+
+/// A single looked-up mempool entry, with its in-mempool relatives.
+///
+/// Kept apart from `MempoolEntry` so the cache of every mempool entry
+/// doesn't hold two txid lists per transaction.
+#[derive(Debug, Deserialize)]
+pub struct MempoolEntryLookup {
+    #[serde(flatten)]
+    pub entry: MempoolEntry,
+
+    /// Unconfirmed parents (txids) this transaction spends from.
+    #[serde(default)]
+    pub depends: Option<Vec<String>>,
+
+    /// Unconfirmed children (txids) spending this transaction's outputs.
+    #[serde(default)]
+    pub spentby: Option<Vec<String>>,
+}
+
 /// Fee structure mirrored directly from Core.
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -0,0 +1,78 @@
+//! Strategies for choosing which mempool entries the distribution pipeline
+//! fetches with `getmempoolentry`.
+//!
+//! `Full` fetches every txid (the default). `Sampled5Percent` fetches a
+//! fixed ~5% of them, trading precision for far fewer RPCs on large mempools
+//! or slow links (Tor). Selection is derived from the txid bytes alone, so
+//! the same transactions stay in the sample across refresh cycles and the
+//! cache can warm up as usual.
+
+use serde::{Deserialize, Serialize};
+
+/// Decides which mempool txids are fetched and cached.
+pub trait MempoolSampler: Send + Sync {
+    /// Whether `txid` belongs to the sample.
+    fn includes(&self, txid: &[u8; 32]) -> bool;
+
+    /// Expected share (0–1] of the mempool that `includes` selects.
+    ///
+    /// Used to scale block-sized quantities (the next-block floor) down to
+    /// the sample.
+    fn fraction(&self) -> f64;
+}
+
+/// Fetches every mempool entry.
+pub struct Full;
+
+impl MempoolSampler for Full {
+    fn includes(&self, _txid: &[u8; 32]) -> bool {
+        true
+    }
+
+    fn fraction(&self) -> f64 {
+        1.0
+    }
+}
+
+/// Fetches roughly one in twenty mempool entries.
+///
+/// A txid is selected when its first four bytes, read as a little-endian
+/// integer, are divisible by 20. Txids are hash outputs, so this is an
+/// unbiased and stable 5% sample.
+pub struct Sampled5Percent;
+
+impl Sampled5Percent {
+    const MODULUS: u32 = 20;
+}
+
+impl MempoolSampler for Sampled5Percent {
+    fn includes(&self, txid: &[u8; 32]) -> bool {
+        let prefix = u32::from_le_bytes([txid[0], txid[1], txid[2], txid[3]]);
+        prefix.is_multiple_of(Self::MODULUS)
+    }
+
+    fn fraction(&self) -> f64 {
+        1.0 / Self::MODULUS as f64
+    }
+}
+
+/// Config value (`mempool_sampling`) selecting the sampling strategy.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MempoolSampling {
+    /// Every entry (`"full"`).
+    #[default]
+    Full,
+    /// About 5% of entries (`"sampled"`).
+    Sampled,
+}
+
+impl MempoolSampling {
+    /// The strategy implementing this setting.
+    pub fn sampler(self) -> &'static dyn MempoolSampler {
+        match self {
+            MempoolSampling::Full => &Full,
+            MempoolSampling::Sampled => &Sampled5Percent,
+        }
+    }
+}
@@ -1,23 +1,25 @@
-/// Coinbase miner tag classification and normalization.
-///
-/// This module provides a table-driven mapping of known miner and pool
-/// identifiers extracted from coinbase transaction data. Coinbase tags
-/// are treated as a *best-effort signal* and are only used when canonical
-/// wallet-based miner attribution is unavailable or when pools (such as
-/// OCEAN) intentionally expose upstream hashrate sources.
-///
-/// Design principles:
-/// - Prefer wallet address attribution as the primary source of truth.
-/// - Use coinbase tags only as a fallback or refinement signal.
-/// - Normalize inputs by collapsing to lowercase alphanumeric form
-///   to handle inconsistent casing, spacing, and branding noise.
-/// - Centralize known tag patterns to simplify maintenance and future
-///   additions without expanding conditional logic.
-///
-/// This module intentionally avoids over-interpreting arbitrary or
-/// promotional coinbase data. Unrecognized tags are allowed to pass
-/// through higher-level heuristics or be displayed in truncated form,
-/// preserving raw signal while preventing UI disruption.
+//! Coinbase miner tag classification and normalization.
+//!
+//! This module provides a table-driven mapping of known miner and pool
+//! identifiers extracted from coinbase transaction data. Coinbase tags
+//! are treated as a *best-effort signal* and are only used when canonical
+//! wallet-based miner attribution is unavailable or when pools (such as
+//! OCEAN) intentionally expose upstream hashrate sources.
+//!
+//! Design principles:
+//! - Prefer wallet address attribution as the primary source of truth.
+//! - Use coinbase tags only as a fallback or refinement signal.
+//! - Normalize inputs by collapsing to lowercase alphanumeric form
+//!   to handle inconsistent casing, spacing, and branding noise.
+//! - Centralize known tag patterns to simplify maintenance and future
+//!   additions without expanding conditional logic.
+//!
+//! This module intentionally avoids over-interpreting arbitrary or
+//! promotional coinbase data. Unrecognized tags are allowed to pass
+//! through higher-level heuristics or be displayed in truncated form,
+//! preserving raw signal while preventing UI disruption.
+
+use crate::models::block_info::Transaction;
 
 #[derive(Clone, Copy)]
 pub struct TagMapEntry {
@@ -66,3 +68,149 @@ pub static PRIMARY_TAGS: &[TagMapEntry] = &[
 
 // Ocean identifiers (pool detection)
 pub static OCEAN_PATS: &[&str] = &["oceanxyz", "ocean"];
+
+/// Match a normalized coinbase signature against known miner tag patterns.
+///
+/// Returns the canonical miner label for the first matching entry in the
+/// tag table. Table order defines precedence when multiple patterns match.
+///
+/// This function performs no allocation and does not short-circuit on
+/// partial matches beyond substring containment.
+pub fn match_primary_tag(sig: &str) -> Option<&'static str> {
+    PRIMARY_TAGS.iter().find_map(|e| {
+        if e.pats.iter().any(|p| sig.contains(p)) { Some(e.label) } else { None }
+    })
+}
+
+/// Classify a miner name from the coinbase transaction tag.
+///
+/// This inspects the **coinbase scriptSig hex** (txin[0].coinbase) and extracts
+/// printable ASCII “runs” (e.g., `/Foundry USA Pool/`, `Mined by AntPool`,
+/// `< OCEAN.XYZ > NiceHash`, etc.). It then applies lightweight heuristics to
+/// derive a human-readable miner label.
+///
+/// ## Return value
+/// Returns `Some((primary, secondary))` where:
+/// - `primary`: the best miner label to display (often the pool name)
+/// - `secondary`: optional pool / coordinator context when the tag contains both
+///   a pool and an upstream hash provider / sub-miner (e.g., `NiceHash (via OCEAN)`).
+///
+/// Returns `None` if the transaction has no usable coinbase tag.
+///
+/// ## Design notes
+/// - This is a **best-effort fallback**. The primary miner identification signal
+///   remains the coinbase payout address lookup (`miners.json`).
+/// - Coinbase tags are not standardized and may include arbitrary bytes, emojis,
+///   padding, or non-printable delimiters. We intentionally search for printable
+///   ASCII sequences and ignore the rest.
+/// - Some pools embed additional identifiers (e.g., OCEAN sub-miner labels).
+///   For these, we try to extract a short “human-ish” token as `primary` and
+///   return the pool name as `secondary`.
+///
+/// ## Heuristics (high-level)
+/// - Extract printable ASCII runs (min length configurable, typically 4).
+/// - Detect strong signatures for common pools (Foundry, AntPool, etc.).
+/// - Special-case pools that embed upstream/miner identifiers (e.g., OCEAN).
+/// - Filter out junk runs: very short tokens, `mm...` padding, long hex blobs,
+///   and strings without letters.
+/// - Prefer short, readable labels (<= 32 chars) to avoid UI truncation.
+///
+/// ## Caveats
+/// - A coinbase tag can lie. This is informational only.
+/// - Some tags include “Mined by …” prefixes; callers may want to normalize or
+///   prefer wallet-based identification when available.
+/// - This function performs **no** consensus-critical parsing—display use only.
+/// - This is intentionally extensible: add new signature rules conservatively to
+///   avoid false positives.
+pub fn classify_miner_from_coinbase(tx: &Transaction) -> Option<(String, Option<String>)> {
+    let runs = tx.extract_coinbase_ascii_runs(4);
+    if runs.is_empty() {
+        return None;
+    }
+
+    // Pre-scan: is Ocean present anywhere?
+    let ocean_present = runs.iter().any(|r| {
+        let sig = Transaction::squash_alnum_lower(r);
+        Transaction::is_ocean(&sig)
+    });
+
+    let mut pool: Option<String> = None;
+
+    // If Ocean is present, we may want to capture a "known upstream" tag (NiceHash, etc.)
+    // before falling back to the loose "best human-ish token" heuristic.
+    let mut ocean_upstream: Option<String> = None;
+
+    // Pass 1: detect OCEAN and (if not ocean_present) return strong canonical tags.
+    // If ocean_present, don't short-circuit primary; only collect upstream candidates.
+    for r in &runs {
+        let sig = Transaction::squash_alnum_lower(r);
+
+        // OCEAN detection
+        if Transaction::is_ocean(&sig) {
+            pool = Some("OCEAN".to_string());
+            continue;
+        }
+
+        // Normal path: strong tags immediately return
+        if !ocean_present {
+            if let Some(label) = match_primary_tag(&sig) {
+                return Some((label.to_string(), None));
+            }
+            continue;
+        }
+
+        // Ocean present: collect first known upstream tag as candidate (NiceHash, etc.)
+        if ocean_upstream.is_none() {
+            if let Some(label) = match_primary_tag(&sig) {
+                ocean_upstream = Some(label.to_string());
+            }
+        }
+    }
+
+    // Pass 2: Ocean logic (your existing heuristic), but prefer known upstream if found
+    if pool.is_some() {
+        if let Some(upstream) = ocean_upstream {
+            return Some((upstream, pool)); // -> "NiceHash (via OCEAN)" at caller
+        }
+        
+        // OCEAN-only candidates (max_gap=3 is a good starting point)
+        let runs_candidate = tx.extract_coinbase_ocean_candidates(3);
+        
+        // Your existing "best human-ish token" heuristic
+        for r in &runs_candidate {
+            let sig = Transaction::squash_alnum_lower(r);
+
+            if Transaction::is_ocean(&sig) {
+                continue;
+            }
+
+            if sig.starts_with("mm") || sig.len() < 3 {
+                continue;
+            }
+
+            let looks_like_hex =
+                sig.len() >= 32 && sig.chars().all(|c| c.is_ascii_hexdigit());
+            if looks_like_hex {
+                continue;
+            }
+
+            if !r.chars().any(|c| c.is_ascii_alphabetic()) {
+                continue;
+            }
+
+            let trimmed = r.trim();
+            if trimmed.len() > 32 {
+                continue;
+            }
+
+            return Some((trimmed.to_string(), pool));
+        }
+
+        return Some(("OCEAN".to_string(), None));
+    }
+
+    // Final fallback: first alphabetic run
+    runs.into_iter()
+        .find(|r| r.chars().any(|c| c.is_ascii_alphabetic()))
+        .map(|r| (r, None))
+}
@@ -0,0 +1,36 @@
+//! Data models for Bitcoin Core’s `getmininginfo` RPC.
+//!
+//! BlockchainInfo uses it for Core’s own network hashrate estimate
+//! (`networkhashps`, averaged over the last 120 blocks by default), shown
+//! in the Blockchain section next to difficulty.
+
+use serde::Deserialize;
+
+/// Wrapper for the `getmininginfo` RPC response.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(dead_code)]
+pub struct MiningInfoJsonWrap {
+    pub error: Option<String>,
+    pub id: Option<String>,
+    pub result: MiningInfo,
+}
+
+/// Subset of `getmininginfo` consumed by the dashboard.
+#[derive(Debug, Deserialize, Default, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+#[allow(dead_code)]
+pub struct MiningInfo {
+    /// Current block height.
+    pub blocks: u64,
+
+    /// Current proof-of-work difficulty.
+    pub difficulty: f64,
+
+    /// Estimated network hashes per second.
+    pub networkhashps: f64,
+
+    /// Transactions in the mempool.
+    #[serde(default)]
+    pub pooledtx: u64,
+}
@@ -13,7 +13,8 @@
 //!
 //! This module intentionally mirrors Core’s RPC format without modifying values.
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use crate::models::core_warnings::deserialize_warnings;
 
 /// Wrapper for the `getnetworkinfo` RPC response.
 ///
@@ -31,7 +32,7 @@ pub struct NetworkInfoJsonWrap {
 ///
 /// These fields describe the node’s network identity, supported protocol
 /// features, fee relay configuration, and high-level connection counts.
-#[derive(Debug, Deserialize, Default, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, Default, PartialEq)]
 #[serde(rename_all = "snake_case")]
 #[allow(dead_code)]
 pub struct NetworkInfo {
@@ -47,14 +48,15 @@ pub struct NetworkInfo {
     pub protocolversion: u32,
 
     /// Bitfield representing service flags (in hex).
-    #[serde(skip)]
-    #[allow(dead_code)]
+    /// Decoded by [`NetworkInfo::local_service_names`].
+    #[serde(default)]
     pub localservices: String,
 
     /// Human-readable names of services offered by this node.
     /// Example: ["NETWORK", "WITNESS", "COMPACT_FILTERS"]
-    #[serde(skip)]
-    #[allow(dead_code)]
+    ///
+    /// Absent on older Core releases; see [`decode_service_flags`].
+    #[serde(default)]
     pub localservicesnames: Vec<String>,
 
     /// Whether this node relays transactions to peers.
@@ -96,15 +98,65 @@ pub struct NetworkInfo {
     pub localaddresses: Vec<LocalAddress>,
 
     /// Core warnings (issues, alerts, or conditions requiring attention).
-    #[serde(skip)]
-    #[allow(dead_code)]
-    pub warnings: String,
+    /// A string up to Core 27, an array from 28.
+    #[serde(default, deserialize_with = "deserialize_warnings")]
+    pub warnings: Vec<String>,
+}
+
+impl NetworkInfo {
+    /// Names of the services this node advertises.
+    ///
+    /// Uses Core's `localservicesnames` when present, otherwise decodes the
+    /// `localservices` hex bitfield so older nodes still get readable flags.
+    pub fn local_service_names(&self) -> Vec<String> {
+        if !self.localservicesnames.is_empty() {
+            return self.localservicesnames.clone();
+        }
+        decode_service_flags(&self.localservices).unwrap_or_default()
+    }
+}
+
+/// Known P2P service bits (`protocol.h`), in bit order.
+const SERVICE_FLAGS: &[(u32, &str)] = &[
+    (0, "NETWORK"),
+    (1, "GETUTXO"),
+    (2, "BLOOM"),
+    (3, "WITNESS"),
+    (6, "COMPACT_FILTERS"),
+    (10, "NETWORK_LIMITED"),
+    (11, "P2P_V2"),
+];
+
+/// Decodes a `localservices` hex bitfield into service flag names.
+///
+/// Names match Core's `localservicesnames`; unknown set bits are reported
+/// as `UNKNOWN[2^n]`, as Core does. Returns `None` if `hex` is empty or not
+/// valid hex (at most 64 bits).
+pub fn decode_service_flags(hex: &str) -> Option<Vec<String>> {
+    let hex = hex.trim().trim_start_matches("0x");
+    if hex.is_empty() {
+        return None;
+    }
+    let bits = u64::from_str_radix(hex, 16).ok()?;
+
+    let names = (0..64)
+        .filter(|bit| bits & (1u64 << bit) != 0)
+        .map(|bit| {
+            SERVICE_FLAGS
+                .iter()
+                .find(|(b, _)| *b == bit)
+                .map(|(_, name)| name.to_string())
+                .unwrap_or_else(|| format!("UNKNOWN[2^{}]", bit))
+        })
+        .collect();
+
+    Some(names)
 }
 
 /// Metadata about a particular address family (IPv4/IPv6/i2p/onion).
 ///
 /// Mirrors Core’s `networks` array from `getnetworkinfo`.
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
 #[allow(dead_code)]
 pub struct Network {
@@ -128,7 +180,7 @@ pub struct Network {
 ///
 /// Core includes these when the node has detected or bound to
 /// public addresses suitable for advertisement.
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
 #[allow(dead_code)]
 pub struct LocalAddress {
@@ -11,7 +11,7 @@
 //!
 //! These structs intentionally mirror Core’s response exactly.
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Wrapper for the `getnettotals` RPC response.
 ///
@@ -29,7 +29,7 @@ pub struct NetTotalsJsonWrap {
 ///
 /// These counters accumulate for the lifetime of the node process.
 /// Restarting the node resets them.
-#[derive(Debug, Deserialize, Default, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, Default, PartialEq)]
 #[serde(rename_all = "snake_case")]
 #[allow(dead_code)]
 pub struct NetTotals {
@@ -45,8 +45,7 @@ pub struct NetTotals {
     pub timemillis: u64,
 
     /// Upload-target state describing bandwidth throttling behavior.
-    #[serde(skip)]
-    #[allow(dead_code)]
+    #[serde(default)]
     pub uploadtarget: UploadTarget,
 }
 
@@ -55,7 +54,7 @@ pub struct NetTotals {
 /// Upload targets are a mechanism to restrict outbound bandwidth usage
 /// over a rolling time window. When enabled (rare in most deployments),
 /// Core enforces a maximum byte count for serving block data.
-#[derive(Debug, Deserialize, Default, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, Default, PartialEq)]
 #[serde(rename_all = "snake_case")]
 #[allow(dead_code)]
 pub struct UploadTarget {
@@ -80,5 +79,10 @@ pub struct UploadTarget {
     pub time_left_in_cycle: u64,
 }
 
-
-
+impl UploadTarget {
+    /// Whether the node runs with `-maxuploadtarget` (Core reports a
+    /// `target` of 0 when it is unset).
+    pub fn is_set(&self) -> bool {
+        self.target > 0
+    }
+}
@@ -5,7 +5,7 @@
 //!
 //! - address, network type, capabilities
 //! - service bits, user-agent ("subver"), and protocol version
-//! - ping times & time offset
+//! - ping times & time offset (summarized by `PeerInfo::ping_summary`)
 //! - header/block sync progress
 //! - per-message traffic stats
 //! - whether the peer is inbound/outbound/manual/feeler/etc.
@@ -19,10 +19,12 @@
 //! These models mirror Core exactly. Higher-level interpretation happens
 //! inside the dashboard logic.
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use crate::models::client_tags::match_client;
+
 //
 // ────────────────────────────────────────────────────────────────────────────────
 //   RPC WRAPPER
@@ -39,6 +41,136 @@ pub struct PeerInfoJsonWrap {
     pub result: Vec<PeerInfo>,
 }
 
+/// Largest peer delta (seconds, either sign) accepted as a propagation sample.
+///
+/// Peer `last_block` timestamps use our clock while block header times are
+/// set by miners, so deltas carry miner clock skew. Anything beyond ten
+/// minutes is a bad clock rather than propagation and is discarded; the
+/// final estimate is clamped to the same range.
+pub const PROPAGATION_MAX_DELTA_SECS: i64 = 600;
+
+/// Samples further than this many (scaled) median absolute deviations from
+/// the median are dropped as outliers before the final median is taken.
+pub const PROPAGATION_MAD_CUTOFF: f64 = 3.0;
+
+/// Scales the MAD to estimate the standard deviation of normal data, so the
+/// cutoff reads like "3 sigma".
+const MAD_TO_SIGMA: f64 = 1.4826;
+
+/// How the block propagation estimate is expressed.
+///
+/// Set via `propagation_unit` in `config.toml`:
+/// - `"seconds"` (default; `"normalized"` is accepted for older configs)
+/// - `"raw_ms"` — the same estimate in milliseconds
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PropagationUnit {
+    #[default]
+    #[serde(alias = "normalized")]
+    Seconds,
+    RawMs,
+}
+
+impl PropagationUnit {
+    /// Convert a delta in milliseconds into this unit (seconds are rounded).
+    pub fn scale_ms(self, ms: i64) -> i64 {
+        match self {
+            PropagationUnit::Seconds => (ms as f64 / 1000.0).round() as i64,
+            PropagationUnit::RawMs => ms,
+        }
+    }
+
+    /// Convert a value in this unit to whole seconds.
+    ///
+    /// Used so severity thresholds stay the same in either mode.
+    pub fn to_seconds(self, value: i64) -> i64 {
+        match self {
+            PropagationUnit::Seconds => value,
+            PropagationUnit::RawMs => (value as f64 / 1000.0).round() as i64,
+        }
+    }
+
+    /// Long label for the stats line.
+    pub fn label(self) -> &'static str {
+        match self {
+            PropagationUnit::Seconds => "seconds",
+            PropagationUnit::RawMs => "ms",
+        }
+    }
+
+    /// Short suffix for compact panels.
+    pub fn suffix(self) -> &'static str {
+        match self {
+            PropagationUnit::Seconds => "s",
+            PropagationUnit::RawMs => "ms",
+        }
+    }
+}
+
+/// Median of `sorted` (non-empty, ascending); even lengths average the
+/// middle pair.
+fn sorted_median(sorted: &[i64]) -> i64 {
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2
+    } else {
+        sorted[mid]
+    }
+}
+
+/// Median of `samples` after dropping outliers by median absolute deviation.
+///
+/// Samples more than `PROPAGATION_MAD_CUTOFF` scaled MADs from the median
+/// are discarded, then the median of the rest is returned. A single peer
+/// with a skewed clock therefore cannot move the result, unlike a mean.
+/// Returns `None` for an empty input.
+pub fn median_without_outliers(samples: &[i64]) -> Option<i64> {
+    if samples.is_empty() {
+        return None;
+    }
+    let mut sorted = samples.to_vec();
+    sorted.sort_unstable();
+    let median = sorted_median(&sorted);
+
+    let mut deviations: Vec<i64> = sorted.iter().map(|x| (x - median).abs()).collect();
+    deviations.sort_unstable();
+    let limit = (PROPAGATION_MAD_CUTOFF * MAD_TO_SIGMA * sorted_median(&deviations) as f64).ceil() as i64;
+
+    // At least half the samples lie within one MAD, so this is never empty.
+    let kept: Vec<i64> = sorted.into_iter().filter(|x| (x - median).abs() <= limit).collect();
+    Some(sorted_median(&kept))
+}
+
+/// Ping (seconds) above which a peer counts as slow in the latency summary
+/// and is highlighted in the peer list.
+pub const SLOW_PING_SECS: f64 = 1.0;
+
+/// Ping statistics across peers, in milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PingSummary {
+    pub min_ms: f64,
+    pub median_ms: f64,
+    pub max_ms: f64,
+    /// Peers whose last ping exceeds `SLOW_PING_SECS`.
+    pub slow: usize,
+}
+
+/// Blocks the node may trail the peer median before it's flagged as behind.
+///
+/// A fresh block reaches peers at slightly different times, so a lag of a
+/// block or two is normal and not worth a warning.
+pub const TIP_LAG_TOLERANCE: u64 = 2;
+
+/// Median peer clock offset (seconds) at which the node's clock is flagged.
+///
+/// Peers' clocks are themselves imperfect, so small medians are noise.
+/// Beyond this the node's own clock is the likely culprit, which skews
+/// mempool acceptance and median-time-past checks.
+pub const CLOCK_SKEW_WARN_SECS: i64 = 30;
+
+/// Minimum peers reporting an offset before a median is trusted.
+pub const MIN_TIME_OFFSET_SAMPLES: usize = 5;
+
 /// Use in propagation storage logic (runapp.rs)
 pub struct NetworkState {
     pub last_propagation_index: Option<usize>,
@@ -59,7 +191,7 @@ pub struct NetworkState {
 /// Core’s output — no modifications or reinterpretation occur here.
 ///
 /// Interpretation happens at the dashboard and analytics layer.
-#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
 #[serde(rename_all = "snake_case")]
 #[allow(dead_code)]
 pub struct PeerInfo {
@@ -108,14 +240,10 @@ pub struct PeerInfo {
     /// Peer’s clock offset.
     pub timeoffset: i64,
 
-    /// Last measured ping time.
-    #[serde(skip)]
-    #[allow(dead_code)]
+    /// Last measured ping time (seconds); absent until the first pong.
     pub pingtime: Option<f64>,
 
-    /// Minimum observed ping time.
-    #[serde(skip)]
-    #[allow(dead_code)]
+    /// Minimum observed ping time (seconds); absent until the first pong.
     pub minping: Option<f64>,
 
     /// P2P protocol version in use.
@@ -161,9 +289,8 @@ pub struct PeerInfo {
     #[allow(dead_code)]
     pub minfeefilter: f64,
 
-    /// Per-message send/receive volume.
-    #[serde(skip)]
-    #[allow(dead_code)]
+    /// Per-message send/receive volume (bytes by P2P message type).
+    /// Optional: not every Core version reports them.
     pub bytessent_per_msg: Option<HashMap<String, u64>>,
     pub bytesrecv_per_msg: Option<HashMap<String, u64>>,
 
@@ -227,6 +354,23 @@ impl PeerInfo {
         list
     }
 
+    /// Aggregates peers by P2P protocol version (`version`, e.g. 70016),
+    /// sorted by peer count (descending), then version (descending).
+    ///
+    /// Peers that have not completed the version handshake report 0 and
+    /// are skipped.
+    pub fn aggregate_and_sort_protocol_versions(peer_info: &[PeerInfo]) -> Vec<(String, usize)> {
+        let mut counts: HashMap<i32, usize> = HashMap::new();
+
+        for peer in peer_info.iter().filter(|p| p.version > 0) {
+            *counts.entry(peer.version).or_insert(0) += 1;
+        }
+
+        let mut list: Vec<(i32, usize)> = counts.into_iter().collect();
+        list.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| b.0.cmp(&a.0)));
+        list.into_iter().map(|(v, n)| (v.to_string(), n)).collect()
+    }
+
     /// Numeric version comparator.
     /// `27.0.1` > `27.0.0`, etc.
     fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
@@ -240,7 +384,7 @@ impl PeerInfo {
 
     //
     // ────────────────────────────────────────────────────────────────────────────────
-    //   CLIENT DISTRIBUTION (Core / Knots / Ronin / Other)
+    //   CLIENT DISTRIBUTION (see `client_tags::CLIENT_TAGS`)
     // ────────────────────────────────────────────────────────────────────────────────
     //
 
@@ -248,49 +392,24 @@ impl PeerInfo {
     ///
     /// `/Satoshi:27.0.0/Knots:20241122/` → `"Knots"`
     /// `/Ronin:23.0.1/` → `"Ronin"`
+    /// `/btcwire:0.5.0/btcd:0.24.2/` → `"btcd"`
     ///
-    /// Normalized mapping:
-    /// - Satoshi → Core  
-    /// - Knots   → Knots  
-    /// - Ronin   → Ronin  
-    /// - Anything else → Other  
-   pub fn extract_client(subver: &str, version: i32) -> Option<String> {
-        fn normalize(name: &str) -> String {
-            match name.to_lowercase().as_str() {
-                "satoshi" => "Core".to_string(),
-                "knots"   => "Knots".to_string(),
-                "ronin"   => "Ronin".to_string(),
-                _ => "Other".to_string(),
-            }
-        }
-
-        // Bitcoin P2P protocol only (semantic sync with Version Distribution)
-        if !subver.contains("Satoshi") || version < 70016 {
+    /// Known clients come from `CLIENT_TAGS`; feature flags such as
+    /// `UASF-BIP110:0.1` cannot hijack the result since only table entries
+    /// count. Satoshi-based agents below protocol 70016 are skipped (in sync
+    /// with the Version Distribution), as are unknown non-Satoshi agents;
+    /// unknown Satoshi-based agents are `"Other"`.
+    pub fn extract_client(subver: &str, version: i32) -> Option<String> {
+        let satoshi_based = subver.contains("Satoshi");
+        if satoshi_based && version < 70016 {
             return None;
         }
 
-        // Remove outer slashes
-        let trimmed = subver.trim_matches('/');
-
-        // Split segments: e.g. ["70016", "Satoshi:29.2.0", "Knots:20251110", "UASF-BIP110:0.1"]
-        let segments: Vec<&str> = trimmed.split('/').collect();
-
-        // Prefer known primary clients anywhere in UA.
-        // This prevents feature flags (e.g. UASF-BIP110:0.1) from hijacking classification.
-        for preferred in ["knots", "ronin", "satoshi"] {
-            if segments.iter().any(|seg| seg.to_lowercase().starts_with(preferred)) {
-                return Some(normalize(preferred));
-            }
-        }
-
-        // Fallback: find any "name:version" segment (scan right → left)
-        for seg in segments.iter().rev() {
-            if let Some((raw, _ver)) = seg.split_once(':') {
-                return Some(normalize(raw.trim()));
-            }
+        match match_client(subver) {
+            Some(label) => Some(label.to_string()),
+            None if satoshi_based => Some("Other".to_string()),
+            None => None,
         }
-
-        Some("Other".to_string())
     }
 
     /// Aggregates client counts and sorts by:
@@ -310,63 +429,209 @@ impl PeerInfo {
         list
     }
 
+    //
+    // ────────────────────────────────────────────────────────────────────────────────
+    //   NETWORK TYPE DISTRIBUTION
+    // ────────────────────────────────────────────────────────────────────────────────
+    //
+
+    /// Counts peers per network type (`ipv4`, `ipv6`, `onion`, `i2p`,
+    /// `cjdns`, ...), sorted by count descending, then name.
+    ///
+    /// A missing or empty `network` field counts as `"unknown"`.
+    pub fn aggregate_by_network(peer_info: &[PeerInfo]) -> Vec<(String, usize)> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+
+        for p in peer_info {
+            let network = p
+                .network
+                .as_deref()
+                .filter(|n| !n.is_empty())
+                .unwrap_or("unknown");
+            *counts.entry(network.to_string()).or_insert(0) += 1;
+        }
+
+        let mut list: Vec<(String, usize)> = counts.into_iter().collect();
+        list.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        list
+    }
+
     //
     // ────────────────────────────────────────────────────────────────────────────────
     //   BLOCK PROPAGATION ANALYTICS
     // ────────────────────────────────────────────────────────────────────────────────
     //
 
-    /// Estimates block propagation time (seconds) across peers.
+    /// Estimates block propagation time across peers.
+    ///
+    /// Each sample is `peer.last_block - best_block_time`: when we got the
+    /// tip from that peer, relative to the block's header time. Positive
+    /// means the block arrived after its timestamp; negative means the
+    /// miner's clock ran ahead of ours.
     ///
     /// Filters:
     /// - must be Satoshi-based clients
     /// - peer must have seen the best block
-    /// - timestamps must be sane (±10 minutes)
+    /// - deltas within `PROPAGATION_MAX_DELTA_SECS`
+    ///
+    /// The estimate is the median of the remaining samples after MAD
+    /// outlier rejection (see `median_without_outliers`), clamped to
+    /// `±PROPAGATION_MAX_DELTA_SECS` and reported in `unit`.
     ///
     /// Returns 0 if no valid sample exists.
     pub fn calculate_block_propagation_time(
         peer_info: &[PeerInfo],
         best_block_time: u64,
         best_block_height: u64,
+        unit: PropagationUnit,
     ) -> i64 {
         let now = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap()
             .as_secs();
 
-        let mut samples: Vec<i64> = Vec::new();
         let h = best_block_height as i64;
+        let samples: Vec<i64> = peer_info
+            .iter()
+            .filter(|p| {
+                p.subver.contains("Satoshi")
+                    && p.last_block > 0
+                    && p.last_block <= now
+                    && p.synced_blocks == h
+            })
+            .map(|peer| (peer.last_block as i64 - best_block_time as i64) * 1000)
+            // discard bad clock peers
+            .filter(|delta_ms| delta_ms.abs() <= PROPAGATION_MAX_DELTA_SECS * 1000)
+            .collect();
 
-        for peer in peer_info.iter().filter(|p| {
-            p.subver.contains("Satoshi")
-                && p.last_block > 0
-                && p.last_block <= now
-                && p.synced_blocks == h
-        }) {
-            let delta_ms = (peer.last_block as i64 - best_block_time as i64) * 1000;
+        let median_ms = match median_without_outliers(&samples) {
+            Some(ms) => ms,
+            None => return 0,
+        };
+        let limit_ms = PROPAGATION_MAX_DELTA_SECS * 1000;
+        unit.scale_ms(median_ms.clamp(-limit_ms, limit_ms))
+    }
 
-            // discard bad clock peers
-            if delta_ms.abs() <= 600_000 {
-                samples.push(delta_ms);
-            }
+    //
+    // ────────────────────────────────────────────────────────────────────────────────
+    //   TIP FRESHNESS
+    // ────────────────────────────────────────────────────────────────────────────────
+    //
+
+    /// Median chain height reported by peers.
+    ///
+    /// Per peer, the best of `synced_headers` (live) and `startingheight`
+    /// (height at connect time) is used; peers reporting nothing useful
+    /// (`-1` / `0`) are skipped. Returns `None` without any samples.
+    pub fn median_peer_height(peer_info: &[PeerInfo]) -> Option<u64> {
+        let mut heights: Vec<i64> = peer_info
+            .iter()
+            .map(|p| p.synced_headers.max(p.startingheight))
+            .filter(|&h| h > 0)
+            .collect();
+
+        if heights.is_empty() {
+            return None;
         }
 
-        if samples.is_empty() {
-            return 0;
+        heights.sort_unstable();
+        Some(heights[heights.len() / 2] as u64)
+    }
+
+    /// How far the node's tip trails the peer median, if meaningfully.
+    ///
+    /// Returns `Some(n)` only when peers are more than `TIP_LAG_TOLERANCE`
+    /// blocks ahead, so a block still propagating doesn't raise a warning.
+    pub fn blocks_behind_peers(peer_info: &[PeerInfo], tip: u64) -> Option<u64> {
+        let median = Self::median_peer_height(peer_info)?;
+        let behind = median.saturating_sub(tip);
+        (behind > TIP_LAG_TOLERANCE).then_some(behind)
+    }
+
+    //
+    // ────────────────────────────────────────────────────────────────────────────────
+    //   CLOCK SKEW
+    // ────────────────────────────────────────────────────────────────────────────────
+    //
+
+    /// Median of the peers' `timeoffset` values (peer clock − our clock, seconds).
+    ///
+    /// Returns `None` with fewer than `MIN_TIME_OFFSET_SAMPLES` peers, where
+    /// one badly-set peer could dominate the result.
+    pub fn median_time_offset(peer_info: &[PeerInfo]) -> Option<i64> {
+        if peer_info.len() < MIN_TIME_OFFSET_SAMPLES {
+            return None;
         }
 
-        let avg_ms: i64 = samples.iter().sum::<i64>() / samples.len() as i64;
-
-        // Peer timestamps are UNIX seconds and may differ significantly due to clock skew.
-        // Dividing by 1000 yields raw seconds, which often appear misleadingly large
-        // (minutes) despite normal network behavior.
-        //
-        // We normalize average skew into 6-second units to:
-        // - reduce clock jitter
-        // - avoid exaggerating harmless peer drift
-        // - better reflect perceived network health
-        //
-        // This keeps values human-scale while preserving direction and magnitude.
-        avg_ms / 6000
+        let mut offsets: Vec<i64> = peer_info.iter().map(|p| p.timeoffset).collect();
+        offsets.sort_unstable();
+        Some(offsets[offsets.len() / 2])
+    }
+
+    /// The median peer time offset, only when it is large enough to warn.
+    ///
+    /// Positive means peers are ahead, i.e. the local clock is running slow.
+    pub fn clock_skew(peer_info: &[PeerInfo]) -> Option<i64> {
+        Self::median_time_offset(peer_info).filter(|o| o.abs() >= CLOCK_SKEW_WARN_SECS)
+    }
+
+    //
+    // ────────────────────────────────────────────────────────────────────────────────
+    //   COMPACT-BLOCK RELAY (BIP152)
+    // ────────────────────────────────────────────────────────────────────────────────
+    //
+
+    /// Counts high-bandwidth compact-block peers as `(in, out)`.
+    ///
+    /// - `in`  — peers *we* selected to push new blocks to us (`bip152_hb_to`)
+    /// - `out` — peers that selected *us* to push blocks to them (`bip152_hb_from`)
+    ///
+    /// Core picks up to 3 HB peers for inbound relay; zero here means new
+    /// blocks arrive via the slower announce → request round trip.
+    pub fn count_hb_compact_peers(peer_info: &[PeerInfo]) -> (usize, usize) {
+        peer_info.iter().fold((0, 0), |(hb_in, hb_out), p| {
+            (
+                hb_in + p.bip152_hb_to as usize,
+                hb_out + p.bip152_hb_from as usize,
+            )
+        })
+    }
+
+    //
+    // ────────────────────────────────────────────────────────────────────────────────
+    //   PEER LATENCY
+    // ────────────────────────────────────────────────────────────────────────────────
+    //
+
+    /// Min / median / max of peers' last `pingtime`, plus how many exceed
+    /// `SLOW_PING_SECS`.
+    ///
+    /// `min_ms` uses each peer's `minping` when reported, so it shows the
+    /// best round trip seen rather than the best current one. Peers that have
+    /// not answered a ping yet are skipped; `None` if none have.
+    pub fn ping_summary(peer_info: &[PeerInfo]) -> Option<PingSummary> {
+        let mut pings: Vec<f64> = peer_info.iter().filter_map(|p| p.pingtime).collect();
+        if pings.is_empty() {
+            return None;
+        }
+        pings.sort_by(|a, b| a.total_cmp(b));
+
+        let mid = pings.len() / 2;
+        let median = if pings.len().is_multiple_of(2) {
+            (pings[mid - 1] + pings[mid]) / 2.0
+        } else {
+            pings[mid]
+        };
+        let min = peer_info
+            .iter()
+            .filter_map(|p| p.minping)
+            .fold(pings[0], f64::min);
+
+        Some(PingSummary {
+            min_ms: min * 1000.0,
+            median_ms: median * 1000.0,
+            max_ms: pings[pings.len() - 1] * 1000.0,
+            slow: pings.iter().filter(|&&p| p > SLOW_PING_SECS).count(),
+        })
     }
 }
@@ -0,0 +1,84 @@
+//! Chain reorganization detection from successive best blocks.
+//!
+//! The tracker remembers the hashes of recent best blocks by height. When
+//! the node reports a new tip, the caller walks back through
+//! `previousblockhash` links (one `getblockheader` per step) while
+//! [`ReorgTracker::needs_parent`] says the chain doesn't connect yet, then
+//! hands the new branch to [`ReorgTracker::connect`]. Any remembered block
+//! the new branch replaces was disconnected — that count is the reorg depth.
+//!
+//! A tip that simply extends the last one costs no extra RPC.
+
+use std::collections::BTreeMap;
+
+/// How many recent best blocks are remembered (~24h).
+///
+/// Reorgs deeper than this are reported with the depth capped at the
+/// remembered window.
+pub const REORG_TRACK_BLOCKS: usize = 144;
+
+/// Recent best-chain block hashes, keyed by height.
+#[derive(Debug, Clone, Default)]
+pub struct ReorgTracker {
+    chain: BTreeMap<u64, String>,
+}
+
+impl ReorgTracker {
+    /// The last best block seen, as `(height, hash)`.
+    pub fn best(&self) -> Option<(u64, &str)> {
+        self.chain.iter().next_back().map(|(h, hash)| (*h, hash.as_str()))
+    }
+
+    /// True if block `height` with parent `prev` does not yet connect to
+    /// the remembered chain, so its parent header must be fetched.
+    ///
+    /// False once the parent is a remembered block, or when the walk has
+    /// gone below the remembered window (or nothing is remembered yet).
+    pub fn needs_parent(&self, height: u64, prev: Option<&str>) -> bool {
+        let Some((&lowest, _)) = self.chain.iter().next() else {
+            return false;
+        };
+        let Some(prev) = prev else {
+            return false; // Genesis.
+        };
+        if height == 0 || height - 1 < lowest {
+            return false;
+        }
+        self.chain.get(&(height - 1)).map(String::as_str) != Some(prev)
+    }
+
+    /// Records a new branch of `(height, hash)` pairs, lowest first, ending
+    /// at the new tip.
+    ///
+    /// Returns how many remembered blocks were disconnected: blocks at the
+    /// branch's heights with a different hash, plus any above the new tip.
+    /// `0` for a normal extension.
+    pub fn connect(&mut self, branch: &[(u64, String)]) -> u64 {
+        let Some((first, _)) = branch.first() else {
+            return 0;
+        };
+
+        let replaced: Vec<u64> = self
+            .chain
+            .range(first..)
+            .filter(|(h, hash)| {
+                branch
+                    .iter()
+                    .find(|(bh, _)| bh == *h)
+                    .is_none_or(|(_, bhash)| bhash != *hash)
+            })
+            .map(|(h, _)| *h)
+            .collect();
+
+        // Everything from the fork point up is replaced by the branch.
+        self.chain.split_off(first);
+        for (height, hash) in branch {
+            self.chain.insert(*height, hash.clone());
+        }
+        while self.chain.len() > REORG_TRACK_BLOCKS {
+            self.chain.pop_first();
+        }
+
+        replaced.len() as u64
+    }
+}
@@ -0,0 +1,58 @@
+//! Generic JSON-RPC response envelope.
+//!
+//! Every Core RPC answers `{"result": ..., "error": ..., "id": ...}`. On
+//! failure `result` is `null` and `error` carries Core's code and message
+//! (e.g. `-8`, "Block height out of range"), usually with a non-200 HTTP
+//! status. Reading the envelope first lets callers report that message
+//! instead of a generic parse failure of the method's result type.
+
+use serde::Deserialize;
+
+use crate::models::errors::MyError;
+
+/// JSON-RPC error object (`{"code": -32601, "message": "..."}`).
+#[derive(Debug, Deserialize, Clone)]
+pub struct RpcErrorObject {
+    pub code: i64,
+    pub message: String,
+}
+
+/// JSON-RPC code for an unknown method.
+pub const RPC_METHOD_NOT_FOUND: i64 = -32601;
+
+/// Part of Core's message for a block whose data was pruned
+/// ("Block not available (pruned data)"). It comes with the generic `-1`
+/// code, so the message is the only way to tell.
+pub const PRUNED_DATA_MESSAGE: &str = "pruned data";
+
+impl RpcErrorObject {
+    /// Core's error for `method` as a `MyError`.
+    ///
+    /// An unknown method becomes `RpcMethodNotFound`, so pollers can stop
+    /// asking, and a pruned block `BlockPruned`; anything else keeps Core's
+    /// message and code.
+    pub fn into_error(self, method: &str) -> MyError {
+        if self.code == RPC_METHOD_NOT_FOUND {
+            MyError::RpcMethodNotFound(method.to_string())
+        } else if self.message.contains(PRUNED_DATA_MESSAGE) {
+            MyError::BlockPruned(format!("{} failed: {}", method, self.message))
+        } else {
+            MyError::CustomError(format!("{} failed: {} ({})", method, self.message, self.code))
+        }
+    }
+}
+
+/// A JSON-RPC response with `result` of type `T`.
+///
+/// `RpcEnvelope<IgnoredAny>` reads just the `error` of any response.
+#[derive(Debug, Deserialize)]
+pub struct RpcEnvelope<T> {
+    #[serde(default = "Option::default")]
+    #[allow(dead_code)]
+    pub result: Option<T>,
+    #[serde(default)]
+    pub error: Option<RpcErrorObject>,
+    #[serde(default)]
+    #[allow(dead_code)]
+    pub id: Option<serde_json::Value>,
+}
@@ -14,6 +14,8 @@
 use serde::Deserialize;
 use std::str;
 
+use crate::consensus::satoshi_math::COINBASE_MATURITY;
+
 //
 // ────────────────────────────────────────────────────────────────────────────────
 //   RPC WRAPPER & MAIN TX STRUCT
@@ -76,6 +78,34 @@ impl GetRawTransactionResponse {
         self.blockhash.is_some()
     }
 
+    /// True for a coinbase transaction: a single input with a `coinbase`
+    /// field instead of a previous outpoint.
+    pub fn is_coinbase(&self) -> bool {
+        self.vin.len() == 1 && self.vin[0].coinbase.is_some()
+    }
+
+    /// Blocks left until a coinbase transaction's outputs are spendable.
+    ///
+    /// Returns `None` for non-coinbase transactions. Unconfirmed coinbase
+    /// (never expected) counts as zero confirmations.
+    pub fn coinbase_blocks_to_maturity(&self) -> Option<u64> {
+        if !self.is_coinbase() {
+            return None;
+        }
+        let confirmations = self.confirmations.unwrap_or(0) as u64;
+        Some(COINBASE_MATURITY.saturating_sub(confirmations))
+    }
+
+    /// Human-readable coinbase status for the lookup pop-up, e.g.
+    /// `"Coinbase (matures in 42 blocks)"` or `"Coinbase (mature)"`.
+    pub fn coinbase_status(&self) -> Option<String> {
+        self.coinbase_blocks_to_maturity().map(|n| match n {
+            0 => "Coinbase (mature)".to_string(),
+            1 => "Coinbase (matures in 1 block)".to_string(),
+            n => format!("Coinbase (matures in {} blocks)", n),
+        })
+    }
+
     /// Sum of all output values (in BTC).
     pub fn total_output_value(&self) -> f64 {
         self.vout.iter().map(|v| v.value).sum()
@@ -96,8 +126,17 @@ impl GetRawTransactionResponse {
     }
 
     /// Returns all OP_RETURN messages decoded as UTF-8 strings.
+    /// Binary payloads are skipped; see `op_return_payloads`.
      #[allow(dead_code)]
     pub fn get_op_return_msg(&self) -> Vec<String> {
+        self.op_return_payloads()
+            .iter()
+            .filter_map(|payload| payload.as_text().map(str::to_string))
+            .collect()
+    }
+
+    /// Returns every OP_RETURN payload, as text or hex.
+    pub fn op_return_payloads(&self) -> Vec<OpReturnPayload> {
         self.vout
             .iter()
             .filter_map(|out| out.decipher_op_return())
@@ -116,12 +155,19 @@ impl GetRawTransactionResponse {
 #[serde(rename_all = "snake_case")]
 #[allow(dead_code)]
 pub struct TxIn {
-    /// The txid of the spent output.
+    /// The txid of the spent output (empty for coinbase inputs).
+    #[serde(default)]
     pub txid: String,
 
-    /// Output index being spent.
+    /// Output index being spent (absent for coinbase inputs).
+    #[serde(default)]
     pub vout: u32,
 
+    /// Coinbase scriptSig (hex). Present only on a coinbase input, which
+    /// has no previous output.
+    #[serde(default)]
+    pub coinbase: Option<String>,
+
     /// ScriptSig for legacy inputs.
     #[serde(rename = "scriptSig")]
     #[serde(skip)]
@@ -129,6 +175,7 @@ pub struct TxIn {
     pub script_sig: Option<ScriptSig>,
 
     /// Sequence number.
+    #[serde(default)]
     pub sequence: u32,
 
     /// Optional witness stack for segwit inputs.
@@ -146,6 +193,18 @@ pub struct ScriptSig {
     pub hex: String,
 }
 
+impl TxIn {
+    /// One-line description for the lookup breakdown: the spent outpoint
+    /// as `txid:vout`, or `coinbase` for a coinbase input.
+    pub fn describe(&self) -> String {
+        if self.coinbase.is_some() {
+            "coinbase".to_string()
+        } else {
+            format!("{}:{}", self.txid, self.vout)
+        }
+    }
+}
+
 //
 // ────────────────────────────────────────────────────────────────────────────────
 //   OUTPUT STRUCTURES
@@ -178,6 +237,9 @@ pub struct ScriptPubKey {
     #[serde(rename = "reqSigs")]
     pub req_sigs: Option<u32>,
     pub r#type: Option<String>,
+    /// Destination address (Core 22+).
+    pub address: Option<String>,
+    /// Destination addresses (Core < 22; removed in favor of `address`).
     pub addresses: Option<Vec<String>>,
 }
 
@@ -188,10 +250,39 @@ pub struct ScriptPubKey {
 //
 
 impl TxOut {
+    /// Destination address, from `address` (Core 22+) or the first of the
+    /// legacy `addresses` list. `None` for OP_RETURN, bare multisig, etc.
+    pub fn address(&self) -> Option<&str> {
+        let spk = self.script_pub_key.as_ref()?;
+        spk.address
+            .as_deref()
+            .or_else(|| spk.addresses.as_ref()?.first().map(String::as_str))
+    }
+
+    /// Script type as reported by Core (`witness_v1_taproot`, `nulldata`, …).
+    pub fn script_type(&self) -> &str {
+        self.script_pub_key
+            .as_ref()
+            .and_then(|spk| spk.r#type.as_deref())
+            .unwrap_or("unknown")
+    }
+
+    /// One-line description for the lookup breakdown, e.g.
+    /// `#1  0.00150000 BTC  bc1q… (witness_v0_keyhash)`.
+    pub fn describe(&self) -> String {
+        match self.address() {
+            Some(addr) => format!("#{}  {:.8} BTC  {} ({})", self.n, self.value, addr, self.script_type()),
+            None => format!("#{}  {:.8} BTC  {}", self.n, self.value, self.script_type()),
+        }
+    }
+
     /// Whether this output pays to a given Bitcoin address.
      #[allow(dead_code)]
     pub fn is_spendable_by(&self, address: &str) -> bool {
         if let Some(script) = &self.script_pub_key {
+            if script.address.as_deref() == Some(address) {
+                return true;
+            }
             if let Some(addrs) = &script.addresses {
                 return addrs.contains(&address.to_string());
             }
@@ -208,32 +299,74 @@ impl TxOut {
             .unwrap_or(false)
     }
 
-    /// Attempt to decode the OP_RETURN payload as UTF-8.
+    /// Decode the OP_RETURN payload.
+    ///
+    /// All data pushes after `OP_RETURN` are joined; other opcodes (protocol
+    /// markers such as `OP_13`) are skipped.
     ///
     /// Returns:
-    /// - `Some(String)` on success
-    /// - `None` if the script is not OP_RETURN or cannot be decoded
-     #[allow(dead_code)]
-    pub fn decipher_op_return(&self) -> Option<String> {
+    /// - `Some(OpReturnPayload::Text)` when the payload is valid UTF-8
+    /// - `Some(OpReturnPayload::Hex)` for binary payloads
+    /// - `None` if the script is not OP_RETURN or carries no data
+    pub fn decipher_op_return(&self) -> Option<OpReturnPayload> {
         let script = self.script_pub_key.as_ref()?;
         let asm = script.asm.as_ref()?;
 
-        if !asm.starts_with("OP_RETURN") {
+        // OP_RETURN <hex> [<hex> ...]
+        let mut parts = asm.split_whitespace();
+        if parts.next() != Some("OP_RETURN") {
             return None;
         }
 
-        // OP_RETURN <hex>
-        let parts: Vec<&str> = asm.split_whitespace().collect();
-        if parts.len() < 2 {
+        // Decode hex → bytes, skipping anything that isn't a data push
+        let bytes: Vec<u8> = parts
+            .filter_map(|push| hex::decode(push).ok())
+            .flatten()
+            .collect();
+        if bytes.is_empty() {
             return None;
         }
 
-        let hex_data = parts[1];
+        match str::from_utf8(&bytes) {
+            Ok(text) => Some(OpReturnPayload::Text(text.to_string())),
+            Err(_) => Some(OpReturnPayload::Hex(hex::encode(&bytes))),
+        }
+    }
+}
+
+/// Hex digits shown for a binary OP_RETURN payload before it is cut off.
+const OP_RETURN_HEX_SHOWN: usize = 80;
 
-        // Decode hex → bytes → utf8
-        let bytes = hex::decode(hex_data).ok()?;
-        let msg = str::from_utf8(&bytes).ok()?.to_string();
+/// Decoded OP_RETURN payload.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OpReturnPayload {
+    /// Payload is valid UTF-8 (memos, tags).
+    Text(String),
+    /// Binary payload (Ordinals, protocol markers), hex-encoded.
+    Hex(String),
+}
+
+impl OpReturnPayload {
+    /// The decoded text, if the payload was UTF-8.
+    #[allow(dead_code)]
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            OpReturnPayload::Text(text) => Some(text),
+            OpReturnPayload::Hex(_) => None,
+        }
+    }
+}
 
-        Some(msg)
+impl std::fmt::Display for OpReturnPayload {
+    /// `OP_RETURN: <text>` or `OP_RETURN (hex): <hex>`, with long hex cut
+    /// to `OP_RETURN_HEX_SHOWN` digits.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OpReturnPayload::Text(text) => write!(f, "OP_RETURN: {}", text),
+            OpReturnPayload::Hex(hex) if hex.len() > OP_RETURN_HEX_SHOWN => {
+                write!(f, "OP_RETURN (hex): {}...", &hex[..OP_RETURN_HEX_SHOWN])
+            }
+            OpReturnPayload::Hex(hex) => write!(f, "OP_RETURN (hex): {}", hex),
+        }
     }
 }
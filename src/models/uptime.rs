@@ -0,0 +1,35 @@
+//! Data model for Bitcoin Core’s `uptime` RPC.
+//!
+//! `uptime` returns the number of seconds the node has been running. It is
+//! polled slowly and shown in the Network section title.
+
+use serde::Deserialize;
+
+use crate::models::rpc_envelope::RpcErrorObject;
+
+/// Wrapper for the `uptime` RPC response.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(dead_code)]
+pub struct UptimeJsonWrap {
+    pub error: Option<RpcErrorObject>,
+    pub id: Option<String>,
+    pub result: Option<u64>,
+}
+
+/// Formats an uptime in seconds as days/hours/minutes.
+///
+/// Leading zero units are dropped: `"3d 4h 12m"`, `"4h 0m"`, `"12m"`.
+pub fn format_uptime(secs: u64) -> String {
+    let days = secs / 86_400;
+    let hours = secs % 86_400 / 3_600;
+    let minutes = secs % 3_600 / 60;
+
+    if days > 0 {
+        format!("{}d {}h {}m", days, hours, minutes)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else {
+        format!("{}m", minutes)
+    }
+}
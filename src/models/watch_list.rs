@@ -0,0 +1,182 @@
+//! Watch list of user-supplied transaction IDs.
+//!
+//! Each watched txid is checked against the mempool TXID cache every UI
+//! cycle and moves through a small state machine:
+//!
+//! ```text
+//! Waiting ──seen──▶ InMempool ──gone + new block──▶ Confirmed
+//!                      │   ▲
+//!           gone, no   │   │ seen again
+//!           new block  ▼   │
+//!                   LeftMempool ──new block──▶ Confirmed
+//! ```
+//!
+//! Confirmation is inferred, not verified: a transaction that leaves the
+//! mempool after a block has been mined since it was first seen is assumed
+//! to be in a block. One that leaves with no new block (eviction, RBF
+//! replacement) is reported as `LeftMempool`.
+
+use std::time::{Duration, Instant};
+
+/// How long a txid must stay absent before it counts as gone.
+///
+/// The mempool cache is rebuilt in place (clear, then insert), so a
+/// single miss can be a read during that rebuild.
+pub const WATCH_ABSENCE_GRACE: Duration = Duration::from_secs(5);
+
+/// Where a watched transaction currently is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchStatus {
+    /// Not seen in the mempool yet.
+    Waiting,
+    /// Currently in the mempool.
+    InMempool,
+    /// Dropped out of the mempool without a new block (evicted or replaced).
+    LeftMempool,
+    /// Left the mempool after a new block; assumed mined at about `height`.
+    Confirmed { height: u64 },
+}
+
+impl WatchStatus {
+    /// Short label for the watch list popup and notifications.
+    pub fn label(&self) -> String {
+        match self {
+            WatchStatus::Waiting => "waiting".to_string(),
+            WatchStatus::InMempool => "in mempool".to_string(),
+            WatchStatus::LeftMempool => "left mempool".to_string(),
+            WatchStatus::Confirmed { height } => format!("confirmed (~block {})", height),
+        }
+    }
+}
+
+/// One watched transaction.
+#[derive(Debug, Clone)]
+pub struct WatchedTx {
+    /// TxID as entered (lowercase hex).
+    pub txid: String,
+    pub status: WatchStatus,
+    bytes: [u8; 32],
+    /// Tip height when the txid first entered the mempool.
+    entered_at_height: u64,
+    /// When the txid was first found missing, if it currently is.
+    missing_since: Option<Instant>,
+}
+
+/// A status change reported by [`WatchList::update`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatchEvent {
+    pub txid: String,
+    pub status: WatchStatus,
+}
+
+/// The set of watched transactions.
+#[derive(Debug, Clone, Default)]
+pub struct WatchList {
+    entries: Vec<WatchedTx>,
+}
+
+impl WatchList {
+    /// Builds a watch list, skipping invalid and duplicate txids.
+    pub fn new(txids: &[String]) -> Self {
+        let mut list = Self::default();
+        for txid in txids {
+            list.add(txid);
+        }
+        list
+    }
+
+    /// Adds a txid. Returns `false` if it is invalid or already watched.
+    pub fn add(&mut self, txid: &str) -> bool {
+        let txid = txid.trim().to_ascii_lowercase();
+        let Some(bytes) = txid_bytes(&txid) else {
+            return false;
+        };
+        if self.entries.iter().any(|e| e.bytes == bytes) {
+            return false;
+        }
+
+        self.entries.push(WatchedTx {
+            txid,
+            status: WatchStatus::Waiting,
+            bytes,
+            entered_at_height: 0,
+            missing_since: None,
+        });
+        true
+    }
+
+    pub fn entries(&self) -> &[WatchedTx] {
+        &self.entries
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Advances every entry against the current mempool and tip height.
+    ///
+    /// `in_mempool` is asked with the raw txid bytes (same encoding as the
+    /// mempool TXID cache). Returns the entries whose status changed.
+    pub fn update(
+        &mut self,
+        in_mempool: impl Fn(&[u8; 32]) -> bool,
+        tip: u64,
+        now: Instant,
+    ) -> Vec<WatchEvent> {
+        let mut events = Vec::new();
+
+        for entry in &mut self.entries {
+            let next = if in_mempool(&entry.bytes) {
+                entry.missing_since = None;
+                match entry.status {
+                    WatchStatus::InMempool => None,
+                    WatchStatus::Confirmed { .. } => None, // Final; ignore stale cache reads.
+                    _ => {
+                        entry.entered_at_height = tip;
+                        Some(WatchStatus::InMempool)
+                    }
+                }
+            } else {
+                match entry.status {
+                    WatchStatus::InMempool => {
+                        let since = *entry.missing_since.get_or_insert(now);
+                        if now.duration_since(since) < WATCH_ABSENCE_GRACE {
+                            None
+                        } else if tip > entry.entered_at_height {
+                            Some(WatchStatus::Confirmed { height: tip })
+                        } else {
+                            Some(WatchStatus::LeftMempool)
+                        }
+                    }
+                    WatchStatus::LeftMempool if tip > entry.entered_at_height => {
+                        Some(WatchStatus::Confirmed { height: tip })
+                    }
+                    _ => None,
+                }
+            };
+
+            if let Some(status) = next {
+                entry.status = status;
+                events.push(WatchEvent {
+                    txid: entry.txid.clone(),
+                    status,
+                });
+            }
+        }
+
+        events
+    }
+}
+
+/// Decodes a 64-character hex txid, as `getrawmempool` returns it.
+fn txid_bytes(txid: &str) -> Option<[u8; 32]> {
+    hex::decode(txid).ok()?.try_into().ok()
+}
+
+/// Shortened txid for one-line notices: `abcd1234…9f8e7d6c`.
+pub fn short_txid(txid: &str) -> String {
+    if txid.len() <= 16 {
+        return txid.to_string();
+    }
+    format!("{}…{}", &txid[..8], &txid[txid.len() - 8..])
+}
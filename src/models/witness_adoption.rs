@@ -0,0 +1,63 @@
+//! SegWit and Taproot adoption in the latest block.
+//!
+//! Computed from the verbose=2 block already fetched for miner
+//! identification, so it costs no extra RPC. The coinbase is left out of
+//! both ratios: it always carries the witness reserved value in a SegWit
+//! block, and its outputs are the miner's payout rather than user activity.
+
+use crate::models::block_info::BlockInfoFull;
+
+/// `scriptPubKey.type` Core reports for Taproot (P2TR) outputs.
+pub const TAPROOT_SCRIPT_TYPE: &str = "witness_v1_taproot";
+
+/// Transaction and output counts of one block.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct WitnessAdoption {
+    /// Height of the counted block; 0 until a block has been counted.
+    pub height: u64,
+    /// Non-coinbase transactions.
+    pub txs: usize,
+    /// Non-coinbase transactions with witness data on any input.
+    pub segwit_txs: usize,
+    /// Outputs of the non-coinbase transactions.
+    pub outputs: usize,
+    /// Of those, outputs paying to Taproot.
+    pub taproot_outputs: usize,
+}
+
+impl WitnessAdoption {
+    /// Counts the transactions of a verbose=2 block.
+    pub fn from_block(block: &BlockInfoFull) -> Self {
+        let mut adoption = Self { height: block.height, ..Self::default() };
+
+        for tx in block.tx.iter().filter(|tx| !tx.is_coinbase()) {
+            adoption.txs += 1;
+            if tx.vin.iter().any(|vin| vin.txinwitness.as_ref().is_some_and(|w| !w.is_empty())) {
+                adoption.segwit_txs += 1;
+            }
+            adoption.outputs += tx.vout.len();
+            adoption.taproot_outputs += tx
+                .vout
+                .iter()
+                .filter(|vout| vout.script_pub_key.r#type == TAPROOT_SCRIPT_TYPE)
+                .count();
+        }
+
+        adoption
+    }
+
+    /// Share of transactions spending with witness data, 0–100; `None`
+    /// for a coinbase-only block.
+    pub fn segwit_pct(&self) -> Option<f64> {
+        percent(self.segwit_txs, self.txs)
+    }
+
+    /// Share of outputs paying to Taproot, 0–100; `None` without outputs.
+    pub fn taproot_outputs_pct(&self) -> Option<f64> {
+        percent(self.taproot_outputs, self.outputs)
+    }
+}
+
+fn percent(part: usize, whole: usize) -> Option<f64> {
+    (whole > 0).then(|| part as f64 * 100.0 / whole as f64)
+}
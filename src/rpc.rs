@@ -45,8 +45,49 @@ mod transaction;
 /// Handles building the client for all RPC modules.
 mod client;
 
+/// Read-only safe-mode policy (blocked state-changing / expensive RPCs).
+pub mod policy;
+
+/// Bounded ring buffer of recent RPC calls (method, duration, outcome).
+pub mod call_log;
+
+/// Exponential-backoff retry for transient RPC failures.
+pub mod retry;
+
 mod getnetworkhashps;
 
+/// Handles RPC calls for `getblockstats`.
+/// Used for the confirmed fee-rate trend.
+mod block_stats;
+
+/// Handles RPC calls for `getmininginfo`.
+/// Used for the network hashrate line in the Blockchain section.
+mod mining;
+
+/// Handles RPC calls for `getchaintxstats`.
+/// Used for the throughput line in the Blockchain section.
+mod chain_tx_stats;
+
+/// Handles RPC calls for `getdeploymentinfo`.
+/// Used for soft-fork deployment status in the Consensus Security section.
+mod deployments;
+
+/// Handles RPC calls for `getblockheader`.
+/// Used for reorg detection.
+mod block_header;
+
+/// Handles RPC calls for `estimatesmartfee`.
+/// Used for the Fee Targets line in the Mempool section.
+mod fee_estimate;
+
+/// Handles RPC calls for `getaddressinfo`.
+/// Used for the address mode of the transaction lookup.
+mod address;
+
+/// Handles RPC calls for `uptime`.
+/// Used for the node uptime in the Network section title.
+mod uptime;
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Imports for returned model types.
 // ─────────────────────────────────────────────────────────────────────────────
@@ -58,8 +99,14 @@ use crate::models::network_info::NetworkInfo;
 use crate::models::chaintips_info::ChainTip;
 use crate::models::network_totals::NetTotals;
 use crate::models::peer_info::PeerInfo;
+use crate::models::block_stats::BlockStats;
+use crate::models::mining_info::MiningInfo;
+use crate::models::chain_tx_stats::ChainTxStats;
+use crate::models::deployment_info::DeploymentInfo;
+use crate::models::block_header::BlockHeader;
 use crate::models::errors::MyError;
 use crate::config::RpcConfig;
+use call_log::{logged, logged_within, RPC_BATCH_DEADLINE};
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Public RPC wrapper functions.
@@ -75,14 +122,14 @@ use crate::config::RpcConfig;
 /// - IBD status
 /// - time / mediantime
 pub async fn fetch_blockchain_info(config: &RpcConfig) -> Result<BlockchainInfo, MyError> {
-    blockchain::fetch_blockchain_info(config).await
+    logged(config, "getblockchaininfo", blockchain::fetch_blockchain_info(config)).await
 }
 
 /// Calls `getmempoolinfo` and returns current mempool statistics.
 ///
 /// Does **not** fetch transaction details — that is handled separately.
 pub async fn fetch_mempool_info(config: &RpcConfig) -> Result<MempoolInfo, MyError> {
-    mempool::fetch_mempool_info(config).await
+    logged(config, "getmempoolinfo", mempool::fetch_mempool_info(config)).await
 }
 
 /// Calls `getnetworkinfo` and returns node-level network metadata.
@@ -93,7 +140,7 @@ pub async fn fetch_mempool_info(config: &RpcConfig) -> Result<MempoolInfo, MyErr
 /// - peer counts
 /// - relay/min-fee values
 pub async fn fetch_network_info(config: &RpcConfig) -> Result<NetworkInfo, MyError> {
-    network::fetch_network_info(config).await
+    logged(config, "getnetworkinfo", network::fetch_network_info(config)).await
 }
 
 /// Fetches block data (verbose=1) by height.
@@ -111,7 +158,7 @@ pub async fn fetch_block_data_by_height(
     blocks: u64,
     mode: u16, // 1 = Epoch Start Block, 2 = 24 Hours Ago Block
 ) -> Result<BlockInfo, MyError> {
-    block::fetch_block_data_by_height(config, blocks, mode).await
+    logged(config, "getblock", block::fetch_block_data_by_height(config, blocks, mode)).await
 }
 
 /// Calls `getchaintips`.
@@ -119,14 +166,14 @@ pub async fn fetch_block_data_by_height(
 /// Returns all known chain tips including valid forks, stale forks,
 /// or unknown headers. Critical for fork detection and monitoring.
 pub async fn fetch_chain_tips(config: &RpcConfig) -> Result<Vec<ChainTip>, MyError> {
-    chain_tips::fetch_chain_tips(config).await
+    logged(config, "getchaintips", chain_tips::fetch_chain_tips(config)).await
 }
 
 /// Calls `getnettotals`.
 ///
 /// Provides total bytes sent/received and upload target information.
 pub async fn fetch_net_totals(config: &RpcConfig) -> Result<NetTotals, MyError> {
-    network_totals::fetch_net_totals(config).await
+    logged(config, "getnettotals", network_totals::fetch_net_totals(config)).await
 }
 
 /// Calls `getpeerinfo`.
@@ -136,7 +183,7 @@ pub async fn fetch_net_totals(config: &RpcConfig) -> Result<NetTotals, MyError>
 /// - client distribution  
 /// - block propagation timing calculations  
 pub async fn fetch_peer_info(config: &RpcConfig) -> Result<Vec<PeerInfo>, MyError> {
-    network_peers::fetch_peer_info(config).await
+    logged(config, "getpeerinfo", network_peers::fetch_peer_info(config)).await
 }
 
 /// Fetches mempool entries and calculates the complete mempool distribution.
@@ -149,7 +196,13 @@ pub async fn fetch_mempool_distribution(
     size_lens: u8,
     last_block: u64,
 ) -> Result<(), MyError> {
-    mempool_distro::fetch_mempool_distribution(config, dust_free, size_lens, last_block).await
+    logged_within(
+        config,
+        "getmempoolentry (batch)",
+        RPC_BATCH_DEADLINE,
+        mempool_distro::fetch_mempool_distribution(config, dust_free, size_lens, last_block),
+    )
+    .await
 }
 
 /// Fetches a transaction either by:
@@ -158,7 +211,15 @@ pub async fn fetch_mempool_distribution(
 ///
 /// Returns a serialized JSON string for display in the Transaction Lookup popup.
 pub async fn fetch_transaction(config: &RpcConfig, txid: &str) -> Result<String, MyError> {
-    transaction::fetch_transaction(config, txid).await
+    logged(config, "getrawtransaction", transaction::fetch_transaction(config, txid)).await
+}
+
+/// Calls `getaddressinfo` for the transaction lookup's address mode.
+///
+/// Returns a formatted summary of the wallet's view of the address. Nodes
+/// without a loaded wallet get a readable explanation instead.
+pub async fn fetch_address_info(config: &RpcConfig, address: &str) -> Result<String, MyError> {
+    logged(config, "getaddressinfo", address::fetch_address_info(config, address)).await
 }
 
 /// Reads miner data and determines the miner for the currently best block.
@@ -171,7 +232,7 @@ pub async fn fetch_miner(
     miners_data: &MinersData,
     current_block: &u64,
 ) -> Result<(), MyError> {
-    block::fetch_miner(config, miners_data, current_block).await
+    logged(config, "getblock (miner)", block::fetch_miner(config, miners_data, current_block)).await
 }
 
 /// Fetch estimated network hashrate (H/s) via `getnetworkhashps`.
@@ -183,5 +244,67 @@ pub async fn getnetworkhashps(
     nblocks: i64,
     height: i64,
 ) -> Result<f64, MyError> {
-    getnetworkhashps::getnetworkhashps(config, nblocks, height).await
-}
\ No newline at end of file
+    logged(config, "getnetworkhashps", getnetworkhashps::getnetworkhashps(config, nblocks, height)).await
+}
+
+/// Calls `getmininginfo`.
+///
+/// Used for Core's network hashrate estimate (`networkhashps`).
+pub async fn fetch_mining_info(config: &RpcConfig) -> Result<MiningInfo, MyError> {
+    logged(config, "getmininginfo", mining::fetch_mining_info(config)).await
+}
+
+/// Calls `getchaintxstats` over the last `window_blocks` blocks
+/// (default 144, ~24h).
+pub async fn fetch_chain_tx_stats(
+    config: &RpcConfig,
+    window_blocks: Option<u32>,
+) -> Result<ChainTxStats, MyError> {
+    logged(config, "getchaintxstats", chain_tx_stats::fetch_chain_tx_stats(config, window_blocks)).await
+}
+
+/// Calls `getdeploymentinfo`.
+///
+/// Returns `MyError::RpcMethodNotFound` on nodes older than Core 23.
+pub async fn fetch_deployment_info(config: &RpcConfig) -> Result<DeploymentInfo, MyError> {
+    logged(config, "getdeploymentinfo", deployments::fetch_deployment_info(config)).await
+}
+
+/// Calls `estimatesmartfee` once per confirmation target.
+///
+/// Returns `(target, sat/vB)` pairs; targets Core cannot estimate yet
+/// are omitted.
+pub async fn fetch_fee_estimates(config: &RpcConfig, targets: &[u16]) -> Result<Vec<(u16, f64)>, MyError> {
+    logged(config, "estimatesmartfee", fee_estimate::fetch_fee_estimates(config, targets)).await
+}
+
+/// True if `txid` (raw bytes, as decoded from `getrawmempool`) is in the
+/// cached mempool TXID set.
+///
+/// Reads the cache filled by `fetch_mempool_info`; no RPC is made.
+pub fn is_in_mempool(txid: &[u8; 32]) -> bool {
+    mempool::MEMPOOL_CACHE.contains(txid)
+}
+
+/// Calls `getblockstats` for the block at `height`.
+///
+/// Used for the per-block median fee-rate sparkline.
+/// Fails for pruned blocks whose undo data is no longer available.
+pub async fn fetch_block_stats(config: &RpcConfig, height: u64) -> Result<BlockStats, MyError> {
+    logged(config, "getblockstats", block_stats::fetch_block_stats(config, height)).await
+}
+
+/// Calls `getblockheader` (verbose) for block `hash`.
+///
+/// Used by reorg detection to follow `previousblockhash` links.
+pub async fn fetch_block_header(config: &RpcConfig, hash: &str) -> Result<BlockHeader, MyError> {
+    logged(config, "getblockheader", block_header::fetch_block_header(config, hash)).await
+}
+
+/// Calls `uptime`: seconds since the node started.
+///
+/// Returns `MyError::RpcMethodNotFound` when the node refuses the method
+/// (e.g. it is outside the user's `-rpcwhitelist`).
+pub async fn fetch_uptime(config: &RpcConfig) -> Result<u64, MyError> {
+    logged(config, "uptime", uptime::fetch_uptime(config)).await
+}
@@ -0,0 +1,99 @@
+//! Handles the `getaddressinfo` RPC call.
+//!
+//! Backs the address mode of the transaction lookup popup. This is a wallet
+//! RPC: a node without a loaded wallet, or built without wallet support,
+//! cannot answer it, and those cases are reported in plain words instead of
+//! raw JSON-RPC errors.
+
+use reqwest::header::CONTENT_TYPE;
+use serde_json::json;
+
+use crate::models::address_info::{
+    AddressInfoJsonWrap, RPC_INVALID_ADDRESS_OR_KEY, RPC_WALLET_NOT_FOUND,
+    RPC_WALLET_NOT_SPECIFIED,
+};
+use crate::models::rpc_envelope::RPC_METHOD_NOT_FOUND;
+use crate::models::errors::MyError;
+use crate::config::RpcConfig;
+use crate::rpc::client::{build_rpc_client, RpcAuth};
+
+/// Fetches the wallet's view of an address via `getaddressinfo`.
+///
+/// ### Returns
+/// A formatted summary (ownership, watch-only status, labels, descriptor).
+///
+/// ### RPC Details
+/// Method: **getaddressinfo**
+/// Params: `[address]`
+///
+/// ### Error Handling
+/// - Timeout during RPC call
+/// - Reqwest network failure
+/// - No wallet loaded, several wallets loaded, or wallet support disabled
+/// - Address invalid for this network
+/// - JSON parse failure
+pub async fn fetch_address_info(config: &RpcConfig, address: &str) -> Result<String, MyError> {
+
+    let json_rpc_request = json!({
+        "jsonrpc": "1.0",
+        "id": "1",
+        "method": "getaddressinfo",
+        "params": [address]
+    });
+
+    let client = build_rpc_client(config)?;
+
+    // Wallet errors come back with a non-200 status and an error object, so
+    // the body is parsed regardless of status.
+    let response = client
+        .post(&config.address)
+        .rpc_auth(config)
+        .header(CONTENT_TYPE, "application/json")
+        .json(&json_rpc_request)
+        .send()
+        .await
+        .map_err(|e| {
+            if e.is_timeout() {
+                MyError::TimeoutError(format!(
+                    "Request to {} timed out for method 'getaddressinfo'",
+                    config.address
+                ))
+            } else {
+                MyError::Reqwest(e)
+            }
+        })?
+        .json::<AddressInfoJsonWrap>()
+        .await
+        .map_err(|_e| {
+            MyError::CustomError("JSON Parsing error for getaddressinfo.".to_string())
+        })?;
+
+    match (response.result, response.error) {
+        (Some(info), _) => Ok(info.summary()),
+        (None, Some(err)) if err.code == RPC_METHOD_NOT_FOUND => Err(MyError::CustomError(
+            "Address lookup unavailable: this node runs without wallet support, \
+             and Core keeps no address index."
+                .to_string(),
+        )),
+        (None, Some(err)) if err.code == RPC_WALLET_NOT_FOUND => Err(MyError::CustomError(
+            "Address lookup needs a loaded wallet: getaddressinfo only knows \
+             addresses the wallet tracks, and Core keeps no address index."
+                .to_string(),
+        )),
+        (None, Some(err)) if err.code == RPC_WALLET_NOT_SPECIFIED => Err(MyError::CustomError(
+            "Address lookup unavailable: several wallets are loaded and the RPC \
+             URL does not select one (/wallet/<name>)."
+                .to_string(),
+        )),
+        (None, Some(err)) if err.code == RPC_INVALID_ADDRESS_OR_KEY => Err(MyError::CustomError(
+            format!("Invalid address for this network: {}", err.message),
+        )),
+        (None, Some(err)) => Err(MyError::CustomError(format!(
+            "getaddressinfo failed: {} ({})",
+            err.message, err.code
+        ))),
+        (None, None) => Err(MyError::CustomError(
+            "JSON Parsing error for getaddressinfo.".to_string(),
+        )),
+    }
+}
@@ -6,6 +6,8 @@
 //! - Fetching full block data with verbose=2 (header + full tx objects)
 //! - Determining the miner via coinbase parsing
 //! - Updating `BLOCK_HISTORY` for the Hash Rate Distribution chart
+//! - Counting SegWit/Taproot adoption of the latest block
+//! - Splitting the latest coinbase into subsidy and fees
 //!
 //! This file represents one of the most critical paths in the dashboard,
 //! powering epoch calculations, 24h difficulty drift, miner extraction,
@@ -15,9 +17,8 @@ use reqwest::header::CONTENT_TYPE;
 use serde_json::json;
 
 use crate::models::errors::MyError;
-use crate::models::block_info::Transaction;
 use crate::config::RpcConfig;
-use crate::rpc::client::build_rpc_client;
+use crate::rpc::client::{build_rpc_client, RpcAuth, RpcJson};
 
 use crate::models::block_info::{
     BlockHash,
@@ -28,8 +29,11 @@ use crate::models::block_info::{
     BlockInfoFullJsonWrap,
 };
 
-use crate::utils::{BLOCK_HISTORY, log_error};
-use crate::models::miner_tags::PRIMARY_TAGS;
+use crate::utils::{BLOCK_HISTORY, COINBASE_REWARD_CACHE, WITNESS_ADOPTION_CACHE, log_error};
+use crate::models::witness_adoption::WitnessAdoption;
+use crate::models::coinbase_reward::CoinbaseReward;
+use crate::models::miner_tags::classify_miner_from_coinbase;
+use crate::models::blockchain_info::height_24h_ago;
 use crate::consensus::satoshi_math::*;
 
 /// Fetch block information at a specific height using `getblock` with verbose=1.
@@ -49,6 +53,7 @@ use crate::consensus::satoshi_math::*;
 /// - Timeout
 /// - Reqwest network error
 /// - JSON parsing error
+/// - `BlockPruned` if the node no longer has the block's data
 /// - Custom error for invalid mode
 pub async fn fetch_block_data_by_height(
     config: &RpcConfig,
@@ -64,7 +69,7 @@ pub async fn fetch_block_data_by_height(
         }
         2 => {
             // Approx. block height 24 hours ago (~144 blocks)
-            blocks.saturating_sub((BLOCKS_PER_HOUR * HOURS_PER_DAY) - 1)
+            height_24h_ago(blocks)
         }
         _ => {
             return Err(MyError::CustomError(
@@ -74,7 +79,7 @@ pub async fn fetch_block_data_by_height(
     };
 
     // RPC client with timeouts tailored for TUI responsiveness
-    let client = build_rpc_client()?;
+    let client = build_rpc_client(config)?;
 
     // ──────────────────────────────
     // Step 1: getblockhash
@@ -88,7 +93,7 @@ pub async fn fetch_block_data_by_height(
 
     let block_hash_response: BlockHash = client
         .post(&config.address)
-        .basic_auth(&config.username, Some(&config.password))
+        .rpc_auth(config)
         .header(CONTENT_TYPE, "application/json")
         .json(&getblockhash_request)
         .send()
@@ -103,11 +108,8 @@ pub async fn fetch_block_data_by_height(
                 MyError::Reqwest(e)
             }
         })?
-        .json::<BlockHash>()
-        .await
-        .map_err(|_e| {
-            MyError::CustomError("JSON Parsing error for getblockhash.".to_string())
-        })?;
+        .rpc_json::<BlockHash>("getblockhash")
+        .await?;
 
     let blockhash = block_hash_response.result;
 
@@ -123,7 +125,7 @@ pub async fn fetch_block_data_by_height(
 
     let block_response: BlockInfoJsonWrap = client
         .post(&config.address)
-        .basic_auth(&config.username, Some(&config.password))
+        .rpc_auth(config)
         .header(CONTENT_TYPE, "application/json")
         .json(&getblock_request)
         .send()
@@ -138,11 +140,8 @@ pub async fn fetch_block_data_by_height(
                 MyError::Reqwest(e)
             }
         })?
-        .json::<BlockInfoJsonWrap>()
-        .await
-        .map_err(|_e| {
-            MyError::CustomError("JSON Parsing error for getblock.".to_string())
-        })?;
+        .rpc_json::<BlockInfoJsonWrap>("getblock")
+        .await?;
 
     Ok(block_response.result)
 }
@@ -161,7 +160,7 @@ async fn fetch_full_block_data_by_height(
     blocks: &u64,
 ) -> Result<BlockInfoFull, MyError> {
 
-    let client = build_rpc_client()?;
+    let client = build_rpc_client(config)?;
 
     // ──────────────────────────────
     // Step 1: getblockhash
@@ -175,7 +174,7 @@ async fn fetch_full_block_data_by_height(
 
     let block_hash_response: BlockHash = client
         .post(&config.address)
-        .basic_auth(&config.username, Some(&config.password))
+        .rpc_auth(config)
         .header(CONTENT_TYPE, "application/json")
         .json(&getblockhash_request)
         .send()
@@ -190,11 +189,8 @@ async fn fetch_full_block_data_by_height(
                 MyError::Reqwest(e)
             }
         })?
-        .json::<BlockHash>()
-        .await
-        .map_err(|_e| {
-            MyError::CustomError("JSON Parsing error for getblockhash.".to_string())
-        })?;
+        .rpc_json::<BlockHash>("getblockhash")
+        .await?;
 
     let blockhash = block_hash_response.result;
 
@@ -210,7 +206,7 @@ async fn fetch_full_block_data_by_height(
 
     let block_response: BlockInfoFullJsonWrap = client
         .post(&config.address)
-        .basic_auth(&config.username, Some(&config.password))
+        .rpc_auth(config)
         .header(CONTENT_TYPE, "application/json")
         .json(&getblock_request)
         .send()
@@ -225,11 +221,8 @@ async fn fetch_full_block_data_by_height(
                 MyError::Reqwest(e)
             }
         })?
-        .json::<BlockInfoFullJsonWrap>()
-        .await
-        .map_err(|_e| {
-            MyError::CustomError("JSON Parsing error for getblock.".to_string())
-        })?;
+        .rpc_json::<BlockInfoFullJsonWrap>("getblock")
+        .await?;
 
     Ok(block_response.result)
 }
@@ -242,6 +235,8 @@ async fn fetch_full_block_data_by_height(
 /// 3. Parse wallet addresses from the coinbase output  
 /// 4. Match the address to known miners from `miners.json`  
 /// 5. Append result to rolling `BlockHistory` (used for hash rate distribution chart)
+/// 6. Count SegWit/Taproot usage into `WITNESS_ADOPTION_CACHE` (same block, no extra RPC)
+/// 7. Split the coinbase payout into subsidy and fees (`COINBASE_REWARD_CACHE`)
 ///
 /// If no miner match is found, `"Unknown"` is used.
 pub async fn fetch_miner(
@@ -265,6 +260,11 @@ pub async fn fetch_miner(
             return Ok(());
         }
     };
+
+    *WITNESS_ADOPTION_CACHE.write().await = WitnessAdoption::from_block(&block);
+    if let Some(reward) = CoinbaseReward::from_block(&block) {
+        *COINBASE_REWARD_CACHE.write().await = reward;
+    }
     
     // Coinbase is always tx[0]
     let coinbase_tx = &block.tx[0];
@@ -333,140 +333,6 @@ async fn find_miner_by_wallet(addresses: Vec<String>, miners_data: &MinersData)
     None
 }
 
-/// Classify a miner name from the coinbase transaction tag.
-///
-/// This inspects the **coinbase scriptSig hex** (txin[0].coinbase) and extracts
-/// printable ASCII “runs” (e.g., `/Foundry USA Pool/`, `Mined by AntPool`,
-/// `< OCEAN.XYZ > NiceHash`, etc.). It then applies lightweight heuristics to
-/// derive a human-readable miner label.
-///
-/// ## Return value
-/// Returns `Some((primary, secondary))` where:
-/// - `primary`: the best miner label to display (often the pool name)
-/// - `secondary`: optional pool / coordinator context when the tag contains both
-///   a pool and an upstream hash provider / sub-miner (e.g., `NiceHash (via OCEAN)`).
-///
-/// Returns `None` if the transaction has no usable coinbase tag.
-///
-/// ## Design notes
-/// - This is a **best-effort fallback**. The primary miner identification signal
-///   remains the coinbase payout address lookup (`miners.json`).
-/// - Coinbase tags are not standardized and may include arbitrary bytes, emojis,
-///   padding, or non-printable delimiters. We intentionally search for printable
-///   ASCII sequences and ignore the rest.
-/// - Some pools embed additional identifiers (e.g., OCEAN sub-miner labels).
-///   For these, we try to extract a short “human-ish” token as `primary` and
-///   return the pool name as `secondary`.
-///
-/// ## Heuristics (high-level)
-/// - Extract printable ASCII runs (min length configurable, typically 4).
-/// - Detect strong signatures for common pools (Foundry, AntPool, etc.).
-/// - Special-case pools that embed upstream/miner identifiers (e.g., OCEAN).
-/// - Filter out junk runs: very short tokens, `mm...` padding, long hex blobs,
-///   and strings without letters.
-/// - Prefer short, readable labels (<= 32 chars) to avoid UI truncation.
-///
-/// ## Caveats
-/// - A coinbase tag can lie. This is informational only.
-/// - Some tags include “Mined by …” prefixes; callers may want to normalize or
-///   prefer wallet-based identification when available.
-/// - This function performs **no** consensus-critical parsing—display use only.
-/// - This is intentionally extensible: add new signature rules conservatively to
-///   avoid false positives.
-
-fn classify_miner_from_coinbase(tx: &Transaction) -> Option<(String, Option<String>)> {
-    let runs = tx.extract_coinbase_ascii_runs(4);
-    if runs.is_empty() {
-        return None;
-    }
-
-    // Pre-scan: is Ocean present anywhere?
-    let ocean_present = runs.iter().any(|r| {
-        let sig = Transaction::squash_alnum_lower(r);
-        Transaction::is_ocean(&sig)
-    });
-
-    let mut pool: Option<String> = None;
-
-    // If Ocean is present, we may want to capture a "known upstream" tag (NiceHash, etc.)
-    // before falling back to the loose "best human-ish token" heuristic.
-    let mut ocean_upstream: Option<String> = None;
-
-    // Pass 1: detect OCEAN and (if not ocean_present) return strong canonical tags.
-    // If ocean_present, don't short-circuit primary; only collect upstream candidates.
-    for r in &runs {
-        let sig = Transaction::squash_alnum_lower(r);
-
-        // OCEAN detection
-        if Transaction::is_ocean(&sig) {
-            pool = Some("OCEAN".to_string());
-            continue;
-        }
-
-        // Normal path: strong tags immediately return
-        if !ocean_present {
-            if let Some(label) = match_table(&sig) {
-                return Some((label.to_string(), None));
-            }
-            continue;
-        }
-
-        // Ocean present: collect first known upstream tag as candidate (NiceHash, etc.)
-        if ocean_upstream.is_none() {
-            if let Some(label) = match_table(&sig) {
-                ocean_upstream = Some(label.to_string());
-            }
-        }
-    }
-
-    // Pass 2: Ocean logic (your existing heuristic), but prefer known upstream if found
-    if pool.is_some() {
-        if let Some(upstream) = ocean_upstream {
-            return Some((upstream, pool)); // -> "NiceHash (via OCEAN)" at caller
-        }
-        
-        // OCEAN-only candidates (max_gap=3 is a good starting point)
-        let runs_candidate = tx.extract_coinbase_ocean_candidates(3);
-        
-        // Your existing "best human-ish token" heuristic
-        for r in &runs_candidate {
-            let sig = Transaction::squash_alnum_lower(r);
-
-            if Transaction::is_ocean(&sig) {
-                continue;
-            }
-
-            if sig.starts_with("mm") || sig.len() < 3 {
-                continue;
-            }
-
-            let looks_like_hex =
-                sig.len() >= 32 && sig.chars().all(|c| c.is_ascii_hexdigit());
-            if looks_like_hex {
-                continue;
-            }
-
-            if !r.chars().any(|c| c.is_ascii_alphabetic()) {
-                continue;
-            }
-
-            let trimmed = r.trim();
-            if trimmed.len() > 32 {
-                continue;
-            }
-
-            return Some((trimmed.to_string(), pool));
-        }
-
-        return Some(("OCEAN".to_string(), None));
-    }
-
-    // Final fallback: first alphabetic run
-    runs.into_iter()
-        .find(|r| r.chars().any(|c| c.is_ascii_alphabetic()))
-        .map(|r| (r, None))
-}
-
 /// Normalize a raw coinbase label into a safe, displayable form.
 ///
 /// This function removes non-ASCII and control characters from miner-provided
@@ -500,22 +366,3 @@ fn clean_secondary(opt: Option<String>) -> Option<String> {
         if s.is_empty() || s == "0" { None } else { Some(s) }
     })
 }
-
-
-/// Match a normalized coinbase signature against known miner tag patterns.
-///
-/// Returns the canonical miner label for the first matching entry in the
-/// tag table. Table order defines precedence when multiple patterns match.
-///
-/// This function performs no allocation and does not short-circuit on
-/// partial matches beyond substring containment.
-fn match_table(sig: &str) -> Option<&'static str> {
-    PRIMARY_TAGS.iter().find_map(|e| {
-        if e.pats.iter().any(|p| sig.contains(p)) { Some(e.label) } else { None }
-    })
-}
-
-
-
-
-
@@ -0,0 +1,59 @@
+//! Handles the `getblockheader` RPC call.
+//!
+//! Used by reorg detection to follow `previousblockhash` links back from a
+//! new tip without fetching full blocks.
+
+use reqwest::header::CONTENT_TYPE;
+use serde_json::json;
+
+use crate::models::block_header::{BlockHeader, BlockHeaderJsonWrap};
+use crate::models::errors::MyError;
+use crate::config::RpcConfig;
+use crate::rpc::client::{build_rpc_client, RpcAuth, RpcJson};
+
+/// Fetches the verbose header of block `hash` via `getblockheader`.
+///
+/// ### Returns
+/// A `BlockHeader` struct (`hash`, `height`, `previousblockhash`).
+///
+/// ### RPC Details
+/// Method: **getblockheader**
+/// Params: `[hash, true]`
+///
+/// ### Error Handling
+/// - Timeout during RPC call
+/// - Reqwest network failure
+/// - JSON parse failure
+pub async fn fetch_block_header(config: &RpcConfig, hash: &str) -> Result<BlockHeader, MyError> {
+
+    let json_rpc_request = json!({
+        "jsonrpc": "1.0",
+        "id": "1",
+        "method": "getblockheader",
+        "params": [hash, true]
+    });
+
+    let client = build_rpc_client(config)?;
+
+    let response = client
+        .post(&config.address)
+        .rpc_auth(config)
+        .header(CONTENT_TYPE, "application/json")
+        .json(&json_rpc_request)
+        .send()
+        .await
+        .map_err(|e| {
+            if e.is_timeout() {
+                MyError::TimeoutError(format!(
+                    "Request to {} timed out for method 'getblockheader'",
+                    config.address
+                ))
+            } else {
+                MyError::Reqwest(e)
+            }
+        })?
+        .rpc_json::<BlockHeaderJsonWrap>("getblockheader")
+        .await?;
+
+    Ok(response.result)
+}
@@ -0,0 +1,68 @@
+//! Handles the `getblockstats` RPC call.
+//!
+//! Block statistics describe what was actually *confirmed* in a block,
+//! complementing the mempool-side view of pending fees:
+//! - Median confirmed fee rate (from `feerate_percentiles`)
+//! - Total fees and subsidy of the current tip
+//!
+//! ### Pruned nodes
+//! Core computes these stats from undo data. Once a block is pruned the
+//! RPC returns a "pruned data" error object, which surfaces here as
+//! `MyError::BlockPruned`. Callers treat that as a permanent gap for the
+//! height.
+
+use reqwest::header::CONTENT_TYPE;
+use serde_json::json;
+
+use crate::models::block_stats::{BlockStats, BlockStatsJsonWrap};
+use crate::models::errors::MyError;
+use crate::config::RpcConfig;
+use crate::rpc::client::{build_rpc_client, RpcAuth, RpcJson};
+
+/// Fetches per-block statistics via `getblockstats`.
+///
+/// ### Returns
+/// A `BlockStats` struct for the block at `height`.
+///
+/// ### RPC Details
+/// Method: **getblockstats**
+/// Params: `[height]`
+///
+/// ### Error Handling
+/// - Timeout during RPC call
+/// - Reqwest network failure
+/// - `BlockPruned` if the block's data or undo data was pruned
+/// - Other Core error objects (`CustomError`) and JSON parse failures
+pub async fn fetch_block_stats(config: &RpcConfig, height: u64) -> Result<BlockStats, MyError> {
+
+    let json_rpc_request = json!({
+        "jsonrpc": "1.0",
+        "id": "1",
+        "method": "getblockstats",
+        "params": [height]
+    });
+
+    let client = build_rpc_client(config)?;
+
+    let response = client
+        .post(&config.address)
+        .rpc_auth(config)
+        .header(CONTENT_TYPE, "application/json")
+        .json(&json_rpc_request)
+        .send()
+        .await
+        .map_err(|e| {
+            if e.is_timeout() {
+                MyError::TimeoutError(format!(
+                    "Request to {} timed out for method 'getblockstats'",
+                    config.address
+                ))
+            } else {
+                MyError::Reqwest(e)
+            }
+        })?
+        .rpc_json::<BlockStatsJsonWrap>("getblockstats")
+        .await?;
+
+    Ok(response.result)
+}
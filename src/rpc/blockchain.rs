@@ -18,7 +18,7 @@ use serde_json::json;
 use crate::models::blockchain_info::{BlockchainInfoJsonWrap, BlockchainInfo};
 use crate::models::errors::MyError;
 use crate::config::RpcConfig;
-use crate::rpc::client::build_rpc_client;
+use crate::rpc::client::{build_rpc_client, RpcAuth, RpcJson};
 
 /// Fetches blockchain-wide metadata via `getblockchaininfo`.
 ///
@@ -54,12 +54,12 @@ pub async fn fetch_blockchain_info(config: &RpcConfig) -> Result<BlockchainInfo,
     });
 
     // Configure lightweight RPC client with tight timeouts for TUI responsiveness
-    let client = build_rpc_client()?;
+    let client = build_rpc_client(config)?;
 
     // Execute request
     let response = client
         .post(&config.address)
-        .basic_auth(&config.username, Some(&config.password))
+        .rpc_auth(config)
         .header(CONTENT_TYPE, "application/json")
         .json(&json_rpc_request)
         .send()
@@ -76,11 +76,8 @@ pub async fn fetch_blockchain_info(config: &RpcConfig) -> Result<BlockchainInfo,
             }
         })?
         // Deserialize into wrapper type containing a `result: BlockchainInfo`
-        .json::<BlockchainInfoJsonWrap>()
-        .await
-        .map_err(|_e| {
-            MyError::CustomError("JSON Parsing error for getblockchaininfo.".to_string())
-        })?;
+        .rpc_json::<BlockchainInfoJsonWrap>("getblockchaininfo")
+        .await?;
 
     Ok(response.result)
 }
@@ -0,0 +1,115 @@
+//! In-memory log of recent RPC calls.
+//!
+//! Every public wrapper in `rpc.rs` runs through `logged`, which records the
+//! method, wall-clock time, duration, and outcome into a bounded ring buffer.
+//! The dashboard's RPC log overlay reads it to show, live, how the app is
+//! talking to the node.
+//!
+//! Notes:
+//! - The buffer keeps the newest `RPC_CALL_LOG_CAPACITY` records only.
+//! - Batched work (e.g. the mempool distribution's many `getmempoolentry`
+//!   calls) is recorded as a single entry for the whole batch.
+//! - Read-only mode (`rpc::policy`) is enforced here: a blocked method is
+//!   refused and recorded without sending anything to the node.
+//! - Each call also runs under an overall deadline (`RPC_CALL_DEADLINE`,
+//!   `RPC_BATCH_DEADLINE` for batches). reqwest's timeouts cover a single
+//!   request and can be disabled in config; the deadline makes sure a hung
+//!   connection can't stall a polling task indefinitely either way.
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Local};
+use once_cell::sync::Lazy;
+use tokio::time::timeout;
+
+use crate::config::RpcConfig;
+use crate::models::errors::MyError;
+use crate::rpc::policy::ensure_method_allowed;
+
+/// Maximum number of calls retained.
+pub const RPC_CALL_LOG_CAPACITY: usize = 200;
+
+/// Overall deadline for one RPC call. Well above the longest default
+/// request timeout (60s via a proxy), so it only fires on a hung call.
+pub const RPC_CALL_DEADLINE: Duration = Duration::from_secs(180);
+
+/// Overall deadline for batched work, which issues many requests.
+pub const RPC_BATCH_DEADLINE: Duration = Duration::from_secs(600);
+
+/// One completed RPC call.
+#[derive(Debug, Clone)]
+pub struct RpcCallRecord {
+    /// When the call finished (local time).
+    pub at: DateTime<Local>,
+    /// RPC method name (or batch label).
+    pub method: &'static str,
+    /// Time from request to parsed response / error.
+    pub duration: Duration,
+    /// `None` on success, otherwise the error message.
+    pub error: Option<String>,
+}
+
+static RPC_CALL_LOG: Lazy<Mutex<VecDeque<RpcCallRecord>>> =
+    Lazy::new(|| Mutex::new(VecDeque::with_capacity(RPC_CALL_LOG_CAPACITY)));
+
+/// Append a record, evicting the oldest when full.
+pub fn record(entry: RpcCallRecord) {
+    let mut log = RPC_CALL_LOG.lock().unwrap();
+    if log.len() == RPC_CALL_LOG_CAPACITY {
+        log.pop_front();
+    }
+    log.push_back(entry);
+}
+
+/// Copy of the log, newest first.
+pub fn recent_calls() -> Vec<RpcCallRecord> {
+    RPC_CALL_LOG.lock().unwrap().iter().rev().cloned().collect()
+}
+
+/// Awaits `fut` and records its timing and outcome under `method`.
+///
+/// `method` is the RPC name, optionally followed by a note such as
+/// `"getblock (miner)"`; the name is checked against the read-only policy.
+///
+/// Fails with `MyError::Config` if read-only mode blocks the method (`fut`
+/// is then never polled), or `MyError::TimeoutError` if `fut` runs past
+/// `RPC_CALL_DEADLINE`.
+pub async fn logged<T, F>(config: &RpcConfig, method: &'static str, fut: F) -> Result<T, MyError>
+where
+    F: Future<Output = Result<T, MyError>>,
+{
+    logged_within(config, method, RPC_CALL_DEADLINE, fut).await
+}
+
+/// `logged` with an explicit `deadline`.
+pub async fn logged_within<T, F>(
+    config: &RpcConfig,
+    method: &'static str,
+    deadline: Duration,
+    fut: F,
+) -> Result<T, MyError>
+where
+    F: Future<Output = Result<T, MyError>>,
+{
+    let start = Instant::now();
+    let rpc_method = method.split_whitespace().next().unwrap_or(method);
+    let result = match ensure_method_allowed(config, rpc_method) {
+        Err(refused) => Err(refused),
+        Ok(()) => match timeout(deadline, fut).await {
+            Ok(result) => result,
+            Err(elapsed) => Err(elapsed.into()),
+        },
+    };
+
+    record(RpcCallRecord {
+        at: Local::now(),
+        method,
+        duration: start.elapsed(),
+        error: result.as_ref().err().map(|e| e.to_string()),
+    });
+
+    result
+}
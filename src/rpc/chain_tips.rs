@@ -22,7 +22,7 @@ use serde_json::json;
 use crate::models::chaintips_info::{ChainTip, ChainTipsJsonWrap};
 use crate::models::errors::MyError;
 use crate::config::RpcConfig;
-use crate::rpc::client::build_rpc_client;
+use crate::rpc::client::{build_rpc_client, RpcAuth, RpcJson};
 
 /// Fetch the list of known chain tips via `getchaintips`.
 ///
@@ -57,12 +57,12 @@ pub async fn fetch_chain_tips(config: &RpcConfig) -> Result<Vec<ChainTip>, MyErr
     });
 
     // Build HTTP client with conservative timeouts for fast refresh cycles
-    let client = build_rpc_client()?;
+    let client = build_rpc_client(config)?;
 
     // Send request
     let response = client
         .post(&config.address)
-        .basic_auth(&config.username, Some(&config.password))
+        .rpc_auth(config)
         .header(CONTENT_TYPE, "application/json")
         .json(&json_rpc_request)
         .send()
@@ -78,11 +78,8 @@ pub async fn fetch_chain_tips(config: &RpcConfig) -> Result<Vec<ChainTip>, MyErr
             }
         })?
         // Deserialize into wrapper struct with `result: Vec<ChainTip>`
-        .json::<ChainTipsJsonWrap>()
-        .await
-        .map_err(|_e| {
-            MyError::CustomError("JSON Parsing error for getchaintips.".to_string())
-        })?;
+        .rpc_json::<ChainTipsJsonWrap>("getchaintips")
+        .await?;
 
     Ok(response.result)
 }
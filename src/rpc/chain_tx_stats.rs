@@ -0,0 +1,67 @@
+//! Handles the `getchaintxstats` RPC call.
+//!
+//! Provides the chain's total transaction count and throughput over a
+//! window of recent blocks.
+
+use reqwest::header::CONTENT_TYPE;
+use serde_json::json;
+
+use crate::models::chain_tx_stats::{ChainTxStats, ChainTxStatsJsonWrap};
+use crate::models::errors::MyError;
+use crate::config::RpcConfig;
+use crate::rpc::client::{build_rpc_client, RpcAuth, RpcJson};
+
+/// Default throughput window: 144 blocks (~24 hours).
+pub const DEFAULT_TX_STATS_WINDOW: u32 = 144;
+
+/// Fetches chain transaction statistics via `getchaintxstats`.
+///
+/// ### Returns
+/// A `ChainTxStats` struct for the window ending at the tip.
+///
+/// ### RPC Details
+/// Method: **getchaintxstats**
+/// Params: `[nblocks]` (`window_blocks`, default 144)
+///
+/// ### Error Handling
+/// - Timeout during RPC call
+/// - Reqwest network failure
+/// - JSON parse failure (includes Core rejecting a window larger than the chain)
+pub async fn fetch_chain_tx_stats(
+    config: &RpcConfig,
+    window_blocks: Option<u32>,
+) -> Result<ChainTxStats, MyError> {
+
+    let nblocks = window_blocks.unwrap_or(DEFAULT_TX_STATS_WINDOW);
+
+    let json_rpc_request = json!({
+        "jsonrpc": "1.0",
+        "id": "1",
+        "method": "getchaintxstats",
+        "params": [nblocks]
+    });
+
+    let client = build_rpc_client(config)?;
+
+    let response = client
+        .post(&config.address)
+        .rpc_auth(config)
+        .header(CONTENT_TYPE, "application/json")
+        .json(&json_rpc_request)
+        .send()
+        .await
+        .map_err(|e| {
+            if e.is_timeout() {
+                MyError::TimeoutError(format!(
+                    "Request to {} timed out for method 'getchaintxstats'",
+                    config.address
+                ))
+            } else {
+                MyError::Reqwest(e)
+            }
+        })?
+        .rpc_json::<ChainTxStatsJsonWrap>("getchaintxstats")
+        .await?;
+
+    Ok(response.result)
+}
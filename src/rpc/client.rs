@@ -1,15 +1,44 @@
 // src/rpc/client.rs/// Builds a preconfigured JSON-RPC HTTP client for Bitcoin RPC calls.
 
-use reqwest::{Client, Proxy};
+use reqwest::{Client, Proxy, RequestBuilder, Response};
+use serde::de::{DeserializeOwned, IgnoredAny};
+use once_cell::sync::Lazy;
+use std::future::Future;
+use std::sync::Mutex;
 use std::time::Duration;
 
+use crate::config::RpcConfig;
+use crate::models::errors::MyError;
+use crate::models::rpc_envelope::RpcEnvelope;
+
+/// Idle keep-alive connections kept per node.
+const RPC_POOL_MAX_IDLE_PER_HOST: usize = 8;
+
+/// Settings a client is built from; the shared client is rebuilt when they change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ClientSettings {
+    proxy: Option<String>,
+    timeout_secs: u64,
+    connect_timeout_secs: u64,
+}
+
+/// Process-wide client, with the settings it was built from.
+///
+/// `reqwest::Client` is a handle to a shared connection pool, so every RPC
+/// module reuses the same keep-alive connections instead of opening (and,
+/// over Tor, handshaking) new ones on each call.
+static SHARED_CLIENT: Lazy<Mutex<Option<(ClientSettings, Client)>>> = Lazy::new(|| Mutex::new(None));
+
+///
+/// Returns the shared JSON-RPC HTTP client for Bitcoin RPC calls, building
+/// it on first use.
 ///
 /// This client adapts its timeout behavior based on whether RPC traffic
 /// is routed through a proxy (e.g., Tor).
 ///
 /// ## Behavior
 ///
-/// - If the `BCI_RPC_PROXY` environment variable is set:
+/// - If a proxy is configured (`proxy` in config, else `BCI_RPC_PROXY`):
 ///   • Requests are routed through the configured proxy  
 ///   • Timeouts are extended to tolerate Tor latency and circuit churn  
 ///
@@ -17,14 +46,21 @@ use std::time::Duration;
 ///   • Direct LAN / localhost connections are assumed  
 ///   • Shorter, responsive timeouts are used for TUI snappiness  
 ///
-/// ## Environment
+/// ## Configuration
+///
+/// - `proxy` in `RpcConfig`  
+///   Optional proxy URL (e.g., `socks5h://127.0.0.1:9050`). Validated at
+///   startup by `load_config`.
 ///
 /// - `BCI_RPC_PROXY`  
-///   Optional. When set, must contain a valid proxy URL
-///   (e.g., `socks5h://127.0.0.1:9050`).
+///   Optional fallback when `proxy` is not set. When set, must contain a
+///   valid proxy URL.
 ///
 /// ## Design Notes
 ///
+/// - `request_timeout_secs` / `connect_timeout_secs` in config override
+///   the defaults above; `0` disables that timeout entirely.
+///
 /// - Timeouts are intentionally asymmetric:
 ///   • `connect_timeout` governs circuit establishment  
 ///   • `timeout` governs total request lifetime  
@@ -32,34 +68,114 @@ use std::time::Duration;
 /// - Proxy configuration is explicit and does **not** rely on
 ///   system proxy auto-detection to avoid ambiguity.
 ///
+/// - One client is shared by all calls. If the proxy or timeouts differ
+///   from the ones it was built with (e.g. after a config reload), a new
+///   client replaces it, so configured timeouts always apply.
+///
+/// - Idle keep-alive connections are pooled, at most
+///   `RPC_POOL_MAX_IDLE_PER_HOST` per node, so a burst of calls doesn't leave
+///   a trail of idle sockets open to it.
+///
 /// - This function performs no I/O; it only constructs the client.
 ///
 /// ## Errors
 ///
-/// Returns an error if the proxy URL is invalid or the client
-/// cannot be constructed.
-pub fn build_rpc_client() -> Result<Client, reqwest::Error> {
-    let is_proxied = std::env::var("BCI_RPC_PROXY").is_ok();
+/// Returns `MyError::Config` if the proxy URL is invalid, or
+/// `MyError::Reqwest` if the client cannot be constructed.
+pub fn build_rpc_client(config: &RpcConfig) -> Result<Client, MyError> {
+    let settings = client_settings(config);
+
+    let mut shared = SHARED_CLIENT.lock().unwrap();
+    if let Some((built_with, client)) = shared.as_ref() {
+        if *built_with == settings {
+            return Ok(client.clone());
+        }
+    }
 
-    let timeout = if is_proxied {
-        Duration::from_secs(60)   // Tor breathing room
+    let client = new_client(&settings)?;
+    *shared = Some((settings, client.clone()));
+    Ok(client)
+}
+
+/// Proxy and timeouts for `config`.
+fn client_settings(config: &RpcConfig) -> ClientSettings {
+    let proxy = config
+        .proxy
+        .clone()
+        .or_else(|| std::env::var("BCI_RPC_PROXY").ok());
+    let is_proxied = proxy.is_some();
+
+    // Configured values win; otherwise pick defaults by transport.
+    let timeout_secs = config.request_timeout_secs.unwrap_or(if is_proxied {
+        60   // Tor breathing room
     } else {
-        Duration::from_secs(10)   // LAN / local
-    };
+        10   // LAN / local
+    });
 
-    let connect_timeout = if is_proxied {
-        Duration::from_secs(30)
+    let connect_timeout_secs = config.connect_timeout_secs.unwrap_or(if is_proxied {
+        30
     } else {
-        Duration::from_secs(5)
-    };
+        5
+    });
+
+    ClientSettings { proxy, timeout_secs, connect_timeout_secs }
+}
 
-    let mut builder = Client::builder()
-        .timeout(timeout)
-        .connect_timeout(connect_timeout);
+/// Builds a client from `settings`.
+fn new_client(settings: &ClientSettings) -> Result<Client, MyError> {
+    // 0 disables the respective timeout.
+    let mut builder = Client::builder().pool_max_idle_per_host(RPC_POOL_MAX_IDLE_PER_HOST);
+    if settings.timeout_secs > 0 {
+        builder = builder.timeout(Duration::from_secs(settings.timeout_secs));
+    }
+    if settings.connect_timeout_secs > 0 {
+        builder = builder.connect_timeout(Duration::from_secs(settings.connect_timeout_secs));
+    }
 
-    if let Ok(proxy) = std::env::var("BCI_RPC_PROXY") {
-        builder = builder.proxy(Proxy::all(&proxy)?);
+    if let Some(proxy) = &settings.proxy {
+        let proxy = Proxy::all(proxy)
+            .map_err(|e| MyError::Config(format!("Invalid RPC proxy '{}': {}", proxy, e)))?;
+        builder = builder.proxy(proxy);
     }
 
-    builder.build()
+    Ok(builder.build()?)
+}
+
+/// Attaches RPC basic auth to a request.
+///
+/// Credentials come from `RpcConfig::credentials`, so cookie-file auth
+/// picks up a rotated cookie without restarting the dashboard.
+pub trait RpcAuth {
+    fn rpc_auth(self, config: &RpcConfig) -> Self;
+}
+
+impl RpcAuth for RequestBuilder {
+    fn rpc_auth(self, config: &RpcConfig) -> Self {
+        let (username, password) = config.credentials();
+        self.basic_auth(username, Some(password))
+    }
+}
+
+/// Reads a JSON-RPC response body as `W` (the method's wrapper type).
+///
+/// The body is read whatever the HTTP status, since Core answers errors
+/// with a 4xx/5xx and an error object. If the envelope carries an `error`,
+/// that is returned (see `RpcErrorObject::into_error`), so the user sees
+/// e.g. "getblockhash failed: Block height out of range (-8)". Otherwise a
+/// body that doesn't fit `W` is a parse failure of `method`.
+pub trait RpcJson {
+    fn rpc_json<W: DeserializeOwned>(self, method: &str) -> impl Future<Output = Result<W, MyError>> + Send;
+}
+
+impl RpcJson for Response {
+    async fn rpc_json<W: DeserializeOwned>(self, method: &str) -> Result<W, MyError> {
+        let body = self.bytes().await.map_err(MyError::Reqwest)?;
+
+        if let Ok(RpcEnvelope::<IgnoredAny> { error: Some(error), .. }) = serde_json::from_slice(&body) {
+            return Err(error.into_error(method));
+        }
+
+        serde_json::from_slice(&body)
+            .map_err(|_e| MyError::CustomError(format!("JSON Parsing error for {}.", method)))
+    }
 }
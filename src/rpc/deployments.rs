@@ -0,0 +1,66 @@
+//! Handles the `getdeploymentinfo` RPC call.
+//!
+//! Provides soft-fork deployment state for the Consensus Security section.
+//! Nodes older than Core 23 don't know the method; that case surfaces as
+//! `MyError::RpcMethodNotFound` so the caller can stop polling and hide the
+//! list instead of logging an error every cycle.
+
+use reqwest::header::CONTENT_TYPE;
+use serde_json::json;
+
+use crate::models::deployment_info::{DeploymentInfo, DeploymentInfoJsonWrap};
+use crate::models::errors::MyError;
+use crate::config::RpcConfig;
+use crate::rpc::client::{build_rpc_client, RpcAuth, RpcJson};
+
+/// Fetches soft-fork deployment state via `getdeploymentinfo`.
+///
+/// ### Returns
+/// A `DeploymentInfo` for the current tip.
+///
+/// ### RPC Details
+/// Method: **getdeploymentinfo**
+/// Params: none (evaluated at the tip)
+///
+/// ### Error Handling
+/// - Timeout during RPC call
+/// - Reqwest network failure
+/// - `RpcMethodNotFound` on nodes without the RPC
+/// - JSON parse failure
+pub async fn fetch_deployment_info(config: &RpcConfig) -> Result<DeploymentInfo, MyError> {
+
+    let json_rpc_request = json!({
+        "jsonrpc": "1.0",
+        "id": "1",
+        "method": "getdeploymentinfo",
+        "params": []
+    });
+
+    let client = build_rpc_client(config)?;
+
+    // Core answers unknown methods with HTTP 404 and an error object;
+    // `rpc_json` turns that into `RpcMethodNotFound`.
+    let response = client
+        .post(&config.address)
+        .rpc_auth(config)
+        .header(CONTENT_TYPE, "application/json")
+        .json(&json_rpc_request)
+        .send()
+        .await
+        .map_err(|e| {
+            if e.is_timeout() {
+                MyError::TimeoutError(format!(
+                    "Request to {} timed out for method 'getdeploymentinfo'",
+                    config.address
+                ))
+            } else {
+                MyError::Reqwest(e)
+            }
+        })?
+        .rpc_json::<DeploymentInfoJsonWrap>("getdeploymentinfo")
+        .await?;
+
+    response.result.ok_or_else(|| {
+        MyError::CustomError("JSON Parsing error for getdeploymentinfo.".to_string())
+    })
+}
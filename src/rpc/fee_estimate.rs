@@ -0,0 +1,72 @@
+//! Handles the `estimatesmartfee` RPC call.
+//!
+//! One request is made per confirmation target. Targets Core cannot
+//! estimate (it returns `errors` and no `feerate`) are left out of the
+//! result rather than failing the whole batch.
+
+use reqwest::header::CONTENT_TYPE;
+use serde_json::json;
+
+use crate::models::fee_estimate::SmartFeeEstimateJsonWrap;
+use crate::models::errors::MyError;
+use crate::config::RpcConfig;
+use crate::rpc::client::{build_rpc_client, RpcAuth, RpcJson};
+
+/// Fetches Core's fee estimates for each confirmation target.
+///
+/// ### Returns
+/// `(target, sat/vB)` pairs, in `targets` order, for targets Core could
+/// estimate. Empty when Core has no estimates at all.
+///
+/// ### RPC Details
+/// Method: **estimatesmartfee**
+/// Params: `[conf_target]`
+///
+/// ### Error Handling
+/// - Timeout during RPC call
+/// - Reqwest network failure
+/// - JSON parse failure
+pub async fn fetch_fee_estimates(
+    config: &RpcConfig,
+    targets: &[u16],
+) -> Result<Vec<(u16, f64)>, MyError> {
+
+    let client = build_rpc_client(config)?;
+    let mut estimates = Vec::with_capacity(targets.len());
+
+    for &target in targets {
+        let json_rpc_request = json!({
+            "jsonrpc": "1.0",
+            "id": "1",
+            "method": "estimatesmartfee",
+            "params": [target]
+        });
+
+        let response = client
+            .post(&config.address)
+            .rpc_auth(config)
+            .header(CONTENT_TYPE, "application/json")
+            .json(&json_rpc_request)
+            .send()
+            .await
+            .map_err(|e| {
+                if e.is_timeout() {
+                    MyError::TimeoutError(format!(
+                        "Request to {} timed out for method 'estimatesmartfee'",
+                        config.address
+                    ))
+                } else {
+                    MyError::Reqwest(e)
+                }
+            })?
+            .rpc_json::<SmartFeeEstimateJsonWrap>("estimatesmartfee")
+            .await?;
+
+        // No `feerate` → insufficient data for this target; skip it.
+        if let Some(rate) = response.result.sat_per_vb() {
+            estimates.push((target, rate));
+        }
+    }
+
+    Ok(estimates)
+}
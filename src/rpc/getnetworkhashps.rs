@@ -39,7 +39,7 @@
 /// - Designed for observational context, not precise accounting.
 /// ----------------------------------------------------------------------------
 use crate::models::errors::MyError;
-use crate::rpc::client::build_rpc_client;
+use crate::rpc::client::{build_rpc_client, RpcAuth, RpcJson};
 use crate::config::RpcConfig;
 use reqwest::header::CONTENT_TYPE;
 use serde_json::json;
@@ -57,11 +57,11 @@ pub async fn getnetworkhashps(
         "params": [nblocks, height]
     });
 
-    let client = build_rpc_client()?;
+    let client = build_rpc_client(config)?;
 
     let response = client
         .post(&config.address)
-        .basic_auth(&config.username, Some(&config.password))
+        .rpc_auth(config)
         .header(CONTENT_TYPE, "application/json")
         .json(&json_rpc_request)
         .send()
@@ -76,11 +76,8 @@ pub async fn getnetworkhashps(
                 MyError::Reqwest(e)
             }
         })?
-        .json::<serde_json::Value>()
-        .await
-        .map_err(|_e| {
-            MyError::CustomError("JSON Parsing error for getnetworkhashps.".to_string())
-        })?;
+        .rpc_json::<serde_json::Value>("getnetworkhashps")
+        .await?;
 
     let hashrate = response["result"]
         .as_f64()
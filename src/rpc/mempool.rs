@@ -18,7 +18,7 @@ use crate::models::mempool_info::{
 };
 use crate::models::errors::MyError;
 use crate::config::RpcConfig;
-use crate::rpc::client::build_rpc_client;
+use crate::rpc::client::{build_rpc_client, RpcAuth, RpcJson};
 
 use std::sync::Arc;
 use dashmap::DashSet;
@@ -85,11 +85,11 @@ pub async fn fetch_mempool_info(
         "params": []
     });
 
-    let client = build_rpc_client()?;
+    let client = build_rpc_client(config)?;
 
     let mempoolinfo_response = client
         .post(&config.address)
-        .basic_auth(&config.username, Some(&config.password))
+        .rpc_auth(config)
         .header(CONTENT_TYPE, "application/json")
         .json(&json_rpc_request)
         .send()
@@ -104,11 +104,8 @@ pub async fn fetch_mempool_info(
                 MyError::Reqwest(e)
             }
         })?
-        .json::<MempoolInfoJsonWrap>()
-        .await
-        .map_err(|_e| {
-            MyError::CustomError("JSON Parsing error for getmempoolinfo.".to_string())
-        })?;
+        .rpc_json::<MempoolInfoJsonWrap>("getmempoolinfo")
+        .await?;
 
     // ─────────────────────────────────────────────────────────────
     // Step 2: Fetch raw mempool TXIDs
@@ -122,7 +119,7 @@ pub async fn fetch_mempool_info(
 
     let raw_mempool_response = client
         .post(&config.address)
-        .basic_auth(&config.username, Some(&config.password))
+        .rpc_auth(config)
         .header(CONTENT_TYPE, "application/json")
         .json(&json_rpc_request)
         .send()
@@ -137,11 +134,8 @@ pub async fn fetch_mempool_info(
                 MyError::Reqwest(e)
             }
         })?
-        .json::<RawMempoolTxsJsonWrap>() 
-        .await
-        .map_err(|_e| {
-            MyError::CustomError("JSON Parsing error for getrawmempool.".to_string())
-        })?;
+        .rpc_json::<RawMempoolTxsJsonWrap>("getrawmempool")
+        .await?;
 
     // ─────────────────────────────────────────────────────────────
     // Step 3: Rebuild the global mempool TXID cache
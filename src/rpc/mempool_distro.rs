@@ -4,8 +4,9 @@
 //! - Fetching individual mempool entries via `getmempoolentry`
 //! - Maintaining a rolling TX cache (TX_CACHE)
 //! - Respecting the "Dust-Free" toggle by filtering low-fee transactions
+//! - Fetching every entry or a stable ~5% sample (`mempool_sampling`)
 //! - Limiting RPC concurrency to avoid node overload
-//! - Evicting cached TXs using deterministic random selection
+//! - Evicting the oldest cached TXs when the cache is full
 //! - Computing aggregated mempool distribution metrics
 //!
 //! This module powers the **Mempool Distribution Chart**, one of the most
@@ -24,18 +25,17 @@ use serde_json::json;
 use crate::models::errors::MyError;
 use crate::config::RpcConfig;
 use crate::models::mempool_info::{MempoolEntryJsonWrap, MempoolEntry};
-use crate::rpc::client::build_rpc_client;
+use crate::models::fifo_map::FifoMap;
+use crate::models::mempool_sampler::MempoolSampling;
+use crate::rpc::client::{build_rpc_client, RpcAuth};
 
-use rand::rngs::StdRng;
-use rand::SeedableRng; 
-use rand::prelude::SliceRandom;
 
 use crate::utils::log_error;
 use crate::rpc::mempool::MEMPOOL_CACHE; 
 use crate::utils::MEMPOOL_DISTRIBUTION_CACHE;
 
 use once_cell::sync::Lazy;
-use dashmap::DashMap;
+use dashmap::DashSet;
 
 use std::sync::Arc;
 use tokio::sync::Semaphore;
@@ -57,14 +57,27 @@ const MAX_TX_CACHE_SIZE: usize = 250_000;
 ///
 /// Stores complete `MempoolEntry` objects keyed by TXID.
 ///
-/// - Backed by `DashMap` for thread-safe concurrent read/write
+/// - Backed by `FifoMap` (a `DashMap` plus insertion order) for thread-safe concurrent read/write
+/// - Evicts the oldest inserted entry once `MAX_TX_CACHE_SIZE` is reached.
+///   Entries are pruned as soon as they leave the mempool, so eviction only
+///   runs when the mempool outgrows the cache, and then it drops the entries
+///   fetched longest ago. That spreads evictions evenly over time, where a
+///   fixed-seed random pick would keep hitting the same positions.
 /// - Initialized lazily
 /// - Used by the "Dust-Free" toggle and distribution metrics
-static TX_CACHE: Lazy<Arc<DashMap<[u8; 32], MempoolEntry>>> =
-    Lazy::new(|| Arc::new(DashMap::with_capacity(MAX_TX_CACHE_SIZE)));
+static TX_CACHE: Lazy<Arc<FifoMap<[u8; 32], MempoolEntry>>> =
+    Lazy::new(|| Arc::new(FifoMap::new(MAX_TX_CACHE_SIZE)));
+
+/// TXIDs examined in dust-free mode and found to be dust.
+///
+/// Counted for the dust-free percentage (kept vs. examined entries) and
+/// skipped when looking for entries to fetch. Cleared and pruned on the
+/// same triggers as `TX_CACHE`.
+static DUST_TXIDS: Lazy<Arc<DashSet<[u8; 32]>>> = Lazy::new(|| Arc::new(DashSet::new()));
 
 struct LastSeen {
     dust_free: bool,
+    sampling: MempoolSampling,
     last_block: u64,
     initialized: bool,
 }
@@ -85,7 +98,8 @@ const MEDIUM_MAX_VB: u32 = 1000;
 ///
 /// ### 2. Fetch missing mempool entries via RPC
 /// - Identifies TXIDs lacking entries in TX_CACHE  
-/// - Spawns a bounded number of concurrent RPC calls (`Semaphore` limited to 10)  
+/// - Spawns a bounded number of concurrent RPC calls (`Semaphore` sized by
+///   `mempool_fetch_concurrency`, default 10)  
 /// - Ensures we do not overwhelm the node with many parallel RPCs  
 ///
 /// ### 3. Update distribution metrics
@@ -94,7 +108,7 @@ const MEDIUM_MAX_VB: u32 = 1000;
 ///
 /// ### RPC Notes
 /// - Uses `getmempoolentry` for each TXID  
-/// - Applies deterministic random eviction when cache reaches MAX_TX_CACHE_SIZE  
+/// - Evicts the oldest inserted entry when cache reaches MAX_TX_CACHE_SIZE  
 ///
 /// ### Error Behavior
 /// Errors for individual transactions do **not** stop the entire distribution process.
@@ -106,12 +120,15 @@ pub async fn fetch_mempool_distribution(
     last_block: u64,
 ) -> Result<(), MyError> {
 
-    // Build lightweight RPC client
-    let client = build_rpc_client()?;
-    
+    // Shared RPC client (pooled connections)
+    let client = build_rpc_client(config)?;
+    let sampling = config.mempool_sampling;
+    let sampler = sampling.sampler();
+
     let mutex = LAST_SEEN.get_or_init(|| {
         Mutex::new(LastSeen {
             dust_free,
+            sampling,
             last_block,
             initialized: false,
         })
@@ -123,13 +140,14 @@ pub async fn fetch_mempool_distribution(
         // ─────────────────────────────────────────────────────────────
         // Handle Dust-Free toggle behavior
         // ─────────────────────────────────────────────────────────────
-        update_tx_cache(dust_free, last_block, &mut *state);
+        update_tx_cache(dust_free, sampling, last_block, &mut *state);
     }
 
 
-    // Identify TXIDs that require fetching
+    // Identify TXIDs that require fetching (within the sample)
     let new_tx_ids: Vec<[u8; 32]> = MEMPOOL_CACHE.iter()
-       .filter(|txid| !TX_CACHE.contains_key(&**txid))
+       .filter(|txid| sampler.includes(txid))
+       .filter(|txid| !TX_CACHE.contains_key(&**txid) && !DUST_TXIDS.contains(&**txid))
         .map(|txid| *txid)
         .collect();
 
@@ -138,7 +156,7 @@ pub async fn fetch_mempool_distribution(
     // Step 1: RPC fetch with concurrency control
     // ─────────────────────────────────────────────────────────────
 
-    let semaphore = Arc::new(Semaphore::new(10)); // Limit: 10 concurrent RPCs
+    let semaphore = Arc::new(Semaphore::new(config.mempool_fetch_permits()));
     let mut tasks = Vec::new();
 
     for tx_id_bytes in new_tx_ids {
@@ -161,7 +179,7 @@ pub async fn fetch_mempool_distribution(
 
             // Execute request and attempt to parse entry
             let result = client.post(&config.address)
-                .basic_auth(&config.username, Some(&config.password))
+                .rpc_auth(&config)
                 .header(CONTENT_TYPE, "application/json")
                 .json(&json_rpc_request)
                 .send()
@@ -184,22 +202,18 @@ pub async fn fetch_mempool_distribution(
             match result {
                 Ok(mempool_entry) => {
                     
-                    // Evict oldest entry if cache is full
-                    if TX_CACHE.len() == MAX_TX_CACHE_SIZE {
-                        let mut keys: Vec<_> = TX_CACHE.iter().map(|entry| entry.key().clone()).collect();
-                        let mut rng = StdRng::seed_from_u64(42); // deterministic shuffle
-                        keys.shuffle(&mut rng);
-
-                        if let Some(random_key) = keys.first() {
-                            TX_CACHE.remove(random_key);
-                        }
-                    }
-
                     let vb = mempool_entry.vsize as u32; 
-                    let keep = (!dust_free || mempool_entry.fees.base >= DUST_THRESHOLD) && size_ok(vb, size_lens);
+                    let is_dust = mempool_entry.fees.base < DUST_THRESHOLD;
+                    let keep = (!dust_free || !is_dust) && size_ok(vb, size_lens);
+
+                    // Remember filtered dust (within the size lens) for the dust-free percentage.
+                    if dust_free && is_dust && size_ok(vb, size_lens) {
+                        DUST_TXIDS.insert(tx_id_bytes);
+                    }
 
+                    // Evicts the oldest inserted entry if the cache is full.
                     if keep {
-                        TX_CACHE.insert(tx_id_bytes.clone(), mempool_entry);
+                        TX_CACHE.insert(tx_id_bytes, mempool_entry);
                     }
 
                    // prune only when any filter is active
@@ -244,7 +258,8 @@ pub async fn fetch_mempool_distribution(
     // Step 2: Recompute and store aggregated mempool distribution metrics
     // ─────────────────────────────────────────────────────────────
     let mut dist = MEMPOOL_DISTRIBUTION_CACHE.write().await;
-    dist.update_metrics(&TX_CACHE);
+    dist.update_metrics_sampled(TX_CACHE.as_map(), sampler.fraction());
+    dist.dust_count = if dust_free { DUST_TXIDS.len() } else { 0 };
 
     Ok(())
 }
@@ -262,7 +277,8 @@ pub async fn fetch_mempool_distribution(
 /// ## Important
 ///
 /// The cache is cleared **only once** when transitioning from
-/// `dust_free = true` → `false`.
+/// `dust_free = true` → `false`, or when `mempool_sampling` changes
+/// (config reload), since the cached set belongs to the old sample.
 ///
 /// This avoids repeatedly clearing the cache on every refresh cycle,
 /// which would otherwise prevent the cache from warming and cause
@@ -282,6 +298,7 @@ pub async fn fetch_mempool_distribution(
 /// ## Parameters
 ///
 /// - `dust_free`: Current dust-free toggle state.
+/// - `sampling`: Current mempool sampling strategy.
 /// - `state`: Mutable mempool distribution state tracking the
 ///   previous dust-free value.
 ///
@@ -292,12 +309,17 @@ pub async fn fetch_mempool_distribution(
 /// invalidation, making this the correct design for all environments.
 fn update_tx_cache(
     dust_free: bool,
+    sampling: MempoolSampling,
     last_block: u64,
     state: &mut LastSeen,
 ) {
     if state.initialized {
-        if state.dust_free != dust_free || state.last_block != last_block {
+        if state.dust_free != dust_free
+            || state.sampling != sampling
+            || state.last_block != last_block
+        {
             TX_CACHE.clear();
+            DUST_TXIDS.clear();
         }
     } else {
         state.initialized = true;
@@ -306,9 +328,11 @@ fn update_tx_cache(
     // Retain only in dust-free mode
     if dust_free {
         TX_CACHE.retain(|tx_id, _| MEMPOOL_CACHE.contains(tx_id));
+        DUST_TXIDS.retain(|tx_id| MEMPOOL_CACHE.contains(tx_id));
     }
 
     state.dust_free = dust_free;
+    state.sampling = sampling;
     state.last_block = last_block;
 }
 
@@ -0,0 +1,59 @@
+//! Handles the `getmininginfo` RPC call.
+//!
+//! Provides Core’s network hashrate estimate (`networkhashps`) for the
+//! Blockchain section, alongside height, difficulty and mempool tx count.
+
+use reqwest::header::CONTENT_TYPE;
+use serde_json::json;
+
+use crate::models::mining_info::{MiningInfo, MiningInfoJsonWrap};
+use crate::models::errors::MyError;
+use crate::config::RpcConfig;
+use crate::rpc::client::{build_rpc_client, RpcAuth, RpcJson};
+
+/// Fetches mining-related state via `getmininginfo`.
+///
+/// ### Returns
+/// A `MiningInfo` struct (`blocks`, `difficulty`, `networkhashps`, `pooledtx`).
+///
+/// ### RPC Details
+/// Method: **getmininginfo**
+/// Params: none
+///
+/// ### Error Handling
+/// - Timeout during RPC call
+/// - Reqwest network failure
+/// - JSON parse failure
+pub async fn fetch_mining_info(config: &RpcConfig) -> Result<MiningInfo, MyError> {
+
+    let json_rpc_request = json!({
+        "jsonrpc": "1.0",
+        "id": "1",
+        "method": "getmininginfo",
+        "params": []
+    });
+
+    let client = build_rpc_client(config)?;
+
+    let response = client
+        .post(&config.address)
+        .rpc_auth(config)
+        .header(CONTENT_TYPE, "application/json")
+        .json(&json_rpc_request)
+        .send()
+        .await
+        .map_err(|e| {
+            if e.is_timeout() {
+                MyError::TimeoutError(format!(
+                    "Request to {} timed out for method 'getmininginfo'",
+                    config.address
+                ))
+            } else {
+                MyError::Reqwest(e)
+            }
+        })?
+        .rpc_json::<MiningInfoJsonWrap>("getmininginfo")
+        .await?;
+
+    Ok(response.result)
+}
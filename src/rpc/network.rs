@@ -19,7 +19,7 @@ use serde_json::json;
 use crate::models::network_info::{NetworkInfoJsonWrap, NetworkInfo};
 use crate::models::errors::MyError;
 use crate::config::RpcConfig;
-use crate::rpc::client::build_rpc_client;
+use crate::rpc::client::{build_rpc_client, RpcAuth, RpcJson};
 
 /// Fetch high-level network metadata using `getnetworkinfo`.
 ///
@@ -56,12 +56,12 @@ pub async fn fetch_network_info(config: &RpcConfig) -> Result<NetworkInfo, MyErr
     });
 
     // Build HTTP client with tight timeouts for TUI responsiveness
-    let client = build_rpc_client()?;
+    let client = build_rpc_client(config)?;
 
     // Execute RPC
     let response = client
         .post(&config.address)
-        .basic_auth(&config.username, Some(&config.password))
+        .rpc_auth(config)
         .header(CONTENT_TYPE, "application/json")
         .json(&json_rpc_request)
         .send()
@@ -77,11 +77,8 @@ pub async fn fetch_network_info(config: &RpcConfig) -> Result<NetworkInfo, MyErr
             }
         })?
         // Parse into wrapper struct containing NetworkInfo
-        .json::<NetworkInfoJsonWrap>()
-        .await
-        .map_err(|_e| {
-            MyError::CustomError("JSON Parsing error for getnetworkinfo.".to_string())
-        })?;
+        .rpc_json::<NetworkInfoJsonWrap>("getnetworkinfo")
+        .await?;
 
     Ok(response.result)
 }
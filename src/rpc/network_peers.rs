@@ -18,7 +18,7 @@
 use crate::models::peer_info::{PeerInfo, PeerInfoJsonWrap};
 use crate::config::RpcConfig;
 use crate::models::errors::MyError;
-use crate::rpc::client::build_rpc_client;
+use crate::rpc::client::{build_rpc_client, RpcAuth, RpcJson};
 
 use reqwest::header::CONTENT_TYPE;
 use serde_json::json;
@@ -59,12 +59,12 @@ pub async fn fetch_peer_info(config: &RpcConfig) -> Result<Vec<PeerInfo>, MyErro
     });
 
     // Lightweight RPC client with conservative timeouts
-    let client = build_rpc_client()?;
+    let client = build_rpc_client(config)?;
 
     // Send request
     let response = client
         .post(&config.address)
-        .basic_auth(&config.username, Some(&config.password))
+        .rpc_auth(config)
         .header(CONTENT_TYPE, "application/json")
         .json(&json_rpc_request)
         .send()
@@ -80,11 +80,8 @@ pub async fn fetch_peer_info(config: &RpcConfig) -> Result<Vec<PeerInfo>, MyErro
             }
         })?
         // Deserialize into wrapper struct containing `result: Vec<PeerInfo>`
-        .json::<PeerInfoJsonWrap>()
-        .await
-        .map_err(|_e| {
-            MyError::CustomError("JSON Parsing error for getpeerinfo.".to_string())
-        })?;
+        .rpc_json::<PeerInfoJsonWrap>("getpeerinfo")
+        .await?;
 
     Ok(response.result)
 }
@@ -20,7 +20,7 @@ use serde_json::json;
 use crate::models::network_totals::{NetTotalsJsonWrap, NetTotals};
 use crate::models::errors::MyError;
 use crate::config::RpcConfig;
-use crate::rpc::client::build_rpc_client;
+use crate::rpc::client::{build_rpc_client, RpcAuth, RpcJson};
 
 /// Fetch total network byte counts using `getnettotals`.
 ///
@@ -52,12 +52,12 @@ pub async fn fetch_net_totals(config: &RpcConfig) -> Result<NetTotals, MyError>
     });
 
     // Build HTTP client with sane timeouts
-    let client = build_rpc_client()?;
+    let client = build_rpc_client(config)?;
 
     // Execute RPC call
     let response = client
         .post(&config.address)
-        .basic_auth(&config.username, Some(&config.password))
+        .rpc_auth(config)
         .header(CONTENT_TYPE, "application/json")
         .json(&json_rpc_request)
         .send()
@@ -73,11 +73,8 @@ pub async fn fetch_net_totals(config: &RpcConfig) -> Result<NetTotals, MyError>
             }
         })?
         // Parse into wrapper which contains `.result: NetTotals`
-        .json::<NetTotalsJsonWrap>()
-        .await
-        .map_err(|_e| {
-            MyError::CustomError("JSON Parsing error for getnettotals.".to_string())
-        })?;
+        .rpc_json::<NetTotalsJsonWrap>("getnettotals")
+        .await?;
 
     // Return deserialized network totals
     Ok(response.result)
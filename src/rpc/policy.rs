@@ -0,0 +1,55 @@
+//! Read-only RPC policy.
+//!
+//! When `read_only = true` is set in the config, BlockchainInfo refuses to
+//! issue any RPC that changes node state or holds `cs_main` for long
+//! periods. This is a guardrail for operators pointing the dashboard at a
+//! shared or production node.
+//!
+//! The policy is data-driven: the blocked method list lives here, and
+//! `call_log::logged`, which every public wrapper in `rpc.rs` runs through,
+//! calls `ensure_method_allowed` before the request is sent. A refused call
+//! shows up in the RPC log like any other failure.
+
+use crate::config::RpcConfig;
+use crate::models::errors::MyError;
+
+/// RPC methods disabled in read-only mode.
+///
+/// - Mining helpers (regtest) mutate chain state.
+/// - UTXO set scans walk the entire chainstate and can stall a busy node.
+/// - Wallet/mempool mutations are never appropriate for a dashboard.
+pub const READ_ONLY_BLOCKED_METHODS: &[&str] = &[
+    "generatetoaddress",
+    "generatetodescriptor",
+    "generateblock",
+    "scantxoutset",
+    "gettxoutsetinfo",
+    "sendrawtransaction",
+    "submitblock",
+    "prioritisetransaction",
+    "invalidateblock",
+    "reconsiderblock",
+    "preciousblock",
+    "pruneblockchain",
+    "savemempool",
+    "setban",
+    "setnetworkactive",
+];
+
+/// Returns `true` if `method` may be called under the current config.
+pub fn is_method_allowed(config: &RpcConfig, method: &str) -> bool {
+    !(config.read_only && READ_ONLY_BLOCKED_METHODS.contains(&method))
+}
+
+/// Guard for RPC calls: returns `MyError::Config` when `method` is
+/// blocked by read-only mode.
+pub fn ensure_method_allowed(config: &RpcConfig, method: &str) -> Result<(), MyError> {
+    if is_method_allowed(config, method) {
+        Ok(())
+    } else {
+        Err(MyError::Config(format!(
+            "RPC method '{}' is disabled in read-only mode",
+            method
+        )))
+    }
+}
@@ -0,0 +1,68 @@
+//! Retry with exponential backoff for transient RPC failures.
+//!
+//! A node that is briefly busy (long `getblocktemplate`, disk flush, restart)
+//! drops or stalls a request or two. Rather than surfacing that as a stall in
+//! the dashboard, `with_retry` re-issues the call a few times with growing,
+//! jittered delays.
+//!
+//! Only transport-level failures are retried (`MyError::Reqwest`,
+//! `MyError::TimeoutError`). JSON parsing, config and RPC-level errors are
+//! deterministic — retrying them only delays the log entry.
+
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use tokio::time::sleep;
+
+use crate::models::errors::MyError;
+use crate::utils::jittered;
+
+/// Attempts per call for the core polling RPCs (first try included).
+pub const RPC_RETRY_ATTEMPTS: u32 = 3;
+
+/// Delay before the first retry; doubled after each failure.
+pub const RPC_RETRY_BASE_DELAY: Duration = Duration::from_millis(250);
+
+/// Upper bound on time spent retrying one call. No retry is started that
+/// would end past this budget.
+pub const RPC_RETRY_BUDGET: Duration = Duration::from_secs(5);
+
+/// ±% jitter applied to each backoff delay.
+const RETRY_JITTER_PCT: u8 = 25;
+
+/// True for failures worth retrying: network errors and timeouts.
+pub fn is_transient(err: &MyError) -> bool {
+    matches!(err, MyError::Reqwest(_) | MyError::TimeoutError(_))
+}
+
+/// Runs `f` up to `attempts` times, backing off exponentially from
+/// `base_delay` between transient failures.
+///
+/// Returns the first success, the first non-transient error, or the last
+/// transient error once attempts or `RPC_RETRY_BUDGET` run out — so the
+/// caller's logging still sees the real cause.
+pub async fn with_retry<F, Fut, T>(attempts: u32, base_delay: Duration, mut f: F) -> Result<T, MyError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, MyError>>,
+{
+    let started = Instant::now();
+    let mut delay = base_delay;
+    let mut attempt = 1;
+
+    loop {
+        match f().await {
+            Ok(value) => return Ok(value),
+            Err(e) if !is_transient(&e) || attempt >= attempts.max(1) => return Err(e),
+            Err(e) => {
+                let wait = jittered(delay, RETRY_JITTER_PCT);
+                if started.elapsed() + wait > RPC_RETRY_BUDGET {
+                    return Err(e);
+                }
+                sleep(wait).await;
+                delay = delay.saturating_mul(2);
+                attempt += 1;
+            }
+        }
+    }
+}
@@ -11,6 +11,8 @@
 //! - Timestamp  
 //! - Input/output counts  
 //! - Presence and value of OP_RETURN outputs  
+//! - In-mempool parents/children (CPFP package relationships)  
+//! - Per-input prevouts and per-output value/address/type  
 //!
 //! Logic flow:
 //! 1. Try `getrawtransaction` (verbose = true)  
@@ -28,8 +30,8 @@ use crate::models::errors::MyError;
 use chrono::{DateTime, Utc};
 
 use crate::models::transaction_info::GetRawTransactionResponse;
-use crate::models::mempool_info::MempoolEntryJsonWrap;
-use crate::rpc::client::build_rpc_client;
+use crate::models::mempool_info::MempoolEntryLookupJsonWrap;
+use crate::rpc::client::{build_rpc_client, RpcAuth, RpcJson};
 
 /// Fetch transaction details from either:
 /// - The blockchain (confirmed)  
@@ -45,12 +47,16 @@ use crate::rpc::client::build_rpc_client;
 ///     - TXID  
 ///     - Total output value  
 ///     - Confirmation timestamp  
-///     - Count of inputs and outputs  
+///     - Count of inputs and outputs (coinbase → maturity instead of inputs)  
+///     - Size, virtual size and weight  
 ///     - Presence/value of OP_RETURN outputs  
+///     - Input/output breakdown (see `format_io_breakdown`)  
 ///
 /// - If no `blocktime`:
 ///     - Calls `getmempoolentry`  
-///     - Returns fee, timestamp, and OP_RETURN summary  
+///     - Returns fee (and fee rate), size/weight, timestamp, and OP_RETURN summary  
+///     - Lists unconfirmed parents (`depends`) and children (`spentby`)  
+///     - Input/output breakdown  
 ///
 /// ### Error Handling
 /// - Timeout → `MyError::TimeoutError`  
@@ -70,12 +76,12 @@ pub async fn fetch_transaction(config: &RpcConfig, txid: &str) -> Result<String,
     });
 
     // Build HTTP client with tight timeouts for TUI responsiveness
-    let client = build_rpc_client()?;
+    let client = build_rpc_client(config)?;
 
     // Execute getrawtransaction
     let response = client
         .post(&config.address)
-        .basic_auth(&config.username, Some(&config.password))
+        .rpc_auth(config)
         .header(CONTENT_TYPE, "application/json")
         .json(&json_rpc_request)
         .send()
@@ -90,7 +96,9 @@ pub async fn fetch_transaction(config: &RpcConfig, txid: &str) -> Result<String,
                 MyError::Reqwest(e)
             }
         })?
-        .json::<serde_json::Value>()
+        // Unknown txids come back as Core's error (-5 "No such mempool or
+        // blockchain transaction...").
+        .rpc_json::<serde_json::Value>("getrawtransaction")
         .await?;
 
     // Deserialize into typed struct
@@ -105,6 +113,13 @@ pub async fn fetch_transaction(config: &RpcConfig, txid: &str) -> Result<String,
             .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
             .unwrap_or("Invalid timestamp".to_string());
 
+        // Coinbase: no prevouts to look up (and no fee to compute); the
+        // interesting fact is when the new coins become spendable.
+        let inputs = match tx.coinbase_status() {
+            Some(status) => status,
+            None => tx.vin.len().to_string(),
+        };
+
         return Ok(format!(
             "Transaction ID: {}\n\
              Total Amount: {:.8} BTC\n\
@@ -112,14 +127,19 @@ pub async fn fetch_transaction(config: &RpcConfig, txid: &str) -> Result<String,
              Timestamp: {}\n\
              Inputs: {}\n\
              Outputs: {}\n\
-             OP_RETURN Outputs: {} ({:.8} BTC)",
+             {}\n\
+             OP_RETURN Outputs: {} ({:.8} BTC){}\n\
+             {}",
             tx.txid,
             tx.total_output_value(),
             datetime,
-            tx.vin.len(),
+            inputs,
             tx.vout.len(),
+            format_footprint(&tx),
             tx.has_op_return(),
             tx.total_op_return_value().abs(),
+            format_op_return_payloads(&tx),
+            format_io_breakdown(&tx),
         ));
     }
 
@@ -134,17 +154,18 @@ pub async fn fetch_transaction(config: &RpcConfig, txid: &str) -> Result<String,
   
     let wrap = client
         .post(&config.address)
-        .basic_auth(&config.username, Some(&config.password))
+        .rpc_auth(config)
         .header(CONTENT_TYPE, "application/json")
         .json(&mempool_request)
         .send()
         .await
         .map_err(|e| MyError::RpcRequestError(txid.to_string(), e.to_string()))?
-        .json::<MempoolEntryJsonWrap>()
+        .json::<MempoolEntryLookupJsonWrap>()
         .await
         .map_err(|e| MyError::JsonParsingError(txid.to_string(), e.to_string()))?;
 
-    let mempool_entry = wrap.result;
+    let lookup = wrap.result;
+    let mempool_entry = &lookup.entry;
 
     // Convert mempool timestamp (if available)
     let datetime = if mempool_entry.time > 0 {
@@ -159,13 +180,97 @@ pub async fn fetch_transaction(config: &RpcConfig, txid: &str) -> Result<String,
     Ok(format!(
         "Transaction ID: {}\n\
          Status: Unconfirmed (In Mempool)\n\
-         Fee: {:.0} sats\n\
+         Fee: {:.0} sats ({:.2} sat/vB)\n\
+         {}\n\
          Timestamp: {}\n\
-         OP_RETURN Outputs: {} ({:.8} BTC)",
+         OP_RETURN Outputs: {} ({:.8} BTC){}\n\
+         Package: {} ancestors / {} descendants (incl. self)\n\
+         {}\n\
+         {}\n\
+         {}",
         txid,
         mempool_entry.fees.base * 100_000_000.0, // BTC → sats
+        mempool_entry.fees.base * 100_000_000.0 / mempool_entry.vsize.max(1) as f64,
+        format_footprint(&tx),
         datetime,
         tx.has_op_return(),
         tx.total_op_return_value().abs(),
+        format_op_return_payloads(&tx),
+        mempool_entry.ancestorcount,
+        mempool_entry.descendantcount,
+        format_relatives("Parents (depends)", lookup.depends.as_deref()),
+        format_relatives("Children (spentby)", lookup.spentby.as_deref()),
+        format_io_breakdown(&tx),
     ))
 }
+
+/// One indented line per OP_RETURN payload (text or hex), each on its own
+/// line after the `OP_RETURN Outputs` summary. Empty when there are none.
+fn format_op_return_payloads(tx: &GetRawTransactionResponse) -> String {
+    tx.op_return_payloads()
+        .iter()
+        .map(|payload| format!("\n  {}", payload))
+        .collect()
+}
+
+/// Maximum number of inputs / outputs listed in the breakdown.
+const MAX_IO_SHOWN: usize = 100;
+
+/// Formats the per-input and per-output breakdown for the lookup pop-up.
+///
+/// Inputs show the spent outpoint (`txid:vout`) or `coinbase`; outputs
+/// show index, value and address (or script type when there is none).
+/// Each list is capped at `MAX_IO_SHOWN` entries.
+pub fn format_io_breakdown(tx: &GetRawTransactionResponse) -> String {
+    let mut out = format!("\nInputs ({}):", tx.vin.len());
+    push_capped(&mut out, tx.vin.iter().map(|vin| vin.describe()), tx.vin.len());
+
+    out.push_str(&format!("\nOutputs ({}):", tx.vout.len()));
+    push_capped(&mut out, tx.vout.iter().map(|vout| vout.describe()), tx.vout.len());
+
+    out
+}
+
+/// Appends up to `MAX_IO_SHOWN` indented lines, then "… and N more".
+fn push_capped(out: &mut String, lines: impl Iterator<Item = String>, total: usize) {
+    for line in lines.take(MAX_IO_SHOWN) {
+        out.push_str(&format!("\n  {}", line));
+    }
+    if total > MAX_IO_SHOWN {
+        out.push_str(&format!("\n  … and {} more", total - MAX_IO_SHOWN));
+    }
+}
+
+/// Formats a transaction's size, virtual size and weight on one line.
+///
+/// Weight units count non-witness bytes ×4 and witness bytes ×1;
+/// virtual size is weight / 4 rounded up, which is what fee rates use.
+fn format_footprint(tx: &GetRawTransactionResponse) -> String {
+    format!(
+        "Size: {} B | vSize: {} vB | Weight: {} WU (vB = ⌈WU / 4⌉)",
+        tx.size, tx.vsize, tx.weight
+    )
+}
+
+/// Maximum number of parent/child txids listed per direction.
+const MAX_RELATIVES_SHOWN: usize = 5;
+
+/// Formats a list of in-mempool relatives for the lookup pop-up.
+///
+/// Shows a count header followed by up to `MAX_RELATIVES_SHOWN` txids,
+/// one per line, with a trailing "… and N more" when truncated.
+fn format_relatives(label: &str, txids: Option<&[String]>) -> String {
+    let txids = txids.unwrap_or(&[]);
+
+    let mut out = format!("{}: {}", label, txids.len());
+
+    for txid in txids.iter().take(MAX_RELATIVES_SHOWN) {
+        out.push_str(&format!("\n  ↳ {}", txid));
+    }
+
+    if txids.len() > MAX_RELATIVES_SHOWN {
+        out.push_str(&format!("\n  … and {} more", txids.len() - MAX_RELATIVES_SHOWN));
+    }
+
+    out
+}
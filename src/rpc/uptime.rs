@@ -0,0 +1,73 @@
+//! Handles the `uptime` RPC call.
+//!
+//! Provides the node's uptime for the Network section. Operators can
+//! restrict RPC access per user (`-rpcwhitelist`); a node that refuses the
+//! call (HTTP 403) or doesn't know it surfaces as
+//! `MyError::RpcMethodNotFound` so the caller can stop polling and hide the
+//! value instead of logging an error every cycle.
+
+use reqwest::header::CONTENT_TYPE;
+use reqwest::StatusCode;
+use serde_json::json;
+
+use crate::models::uptime::UptimeJsonWrap;
+use crate::models::errors::MyError;
+use crate::config::RpcConfig;
+use crate::rpc::client::{build_rpc_client, RpcAuth, RpcJson};
+
+/// Fetches the node's uptime via `uptime`.
+///
+/// ### Returns
+/// Seconds since the node started.
+///
+/// ### RPC Details
+/// Method: **uptime**
+/// Params: none
+///
+/// ### Error Handling
+/// - Timeout during RPC call
+/// - Reqwest network failure
+/// - `RpcMethodNotFound` when the method is unknown or not whitelisted
+/// - JSON parse failure
+pub async fn fetch_uptime(config: &RpcConfig) -> Result<u64, MyError> {
+
+    let json_rpc_request = json!({
+        "jsonrpc": "1.0",
+        "id": "1",
+        "method": "uptime",
+        "params": []
+    });
+
+    let client = build_rpc_client(config)?;
+
+    let response = client
+        .post(&config.address)
+        .rpc_auth(config)
+        .header(CONTENT_TYPE, "application/json")
+        .json(&json_rpc_request)
+        .send()
+        .await
+        .map_err(|e| {
+            if e.is_timeout() {
+                MyError::TimeoutError(format!(
+                    "Request to {} timed out for method 'uptime'",
+                    config.address
+                ))
+            } else {
+                MyError::Reqwest(e)
+            }
+        })?;
+
+    // A method outside the user's -rpcwhitelist is refused with an empty 403.
+    if response.status() == StatusCode::FORBIDDEN {
+        return Err(MyError::RpcMethodNotFound("uptime".to_string()));
+    }
+
+    // Core answers unknown methods with HTTP 404 and an error object;
+    // `rpc_json` turns that into `RpcMethodNotFound`.
+    let response = response.rpc_json::<UptimeJsonWrap>("uptime").await?;
+
+    response
+        .result
+        .ok_or_else(|| MyError::CustomError("JSON Parsing error for uptime.".to_string()))
+}
@@ -18,7 +18,7 @@
 // This is the heart of BlockChainInfo. The sovereign engine.
 // =================================================================================================
 
-use crate::config::RpcConfig;
+use crate::config::{get_config_path, reload_config, LiveConfig, RpcConfig};
 
 // RPC fetch routines — each returns structured response data or MyError.
 use crate::rpc::{
@@ -31,11 +31,24 @@ use crate::rpc::{
     fetch_peer_info,
     fetch_mempool_distribution,
     fetch_transaction,
+    fetch_address_info,
     fetch_miner,
     getnetworkhashps,
+    fetch_block_stats,
+    fetch_fee_estimates,
+    fetch_mining_info,
+    fetch_chain_tx_stats,
+    fetch_deployment_info,
+    fetch_uptime,
+    fetch_block_header,
+    is_in_mempool,
 };
+use crate::rpc::call_log::recent_calls;
+use crate::rpc::retry::{with_retry, RPC_RETRY_ATTEMPTS, RPC_RETRY_BASE_DELAY};
 
 use crate::models::errors::MyError;
+use crate::geoip::GeoIp;
+use crate::models::reorg::{ReorgTracker, REORG_TRACK_BLOCKS};
 
 // UI render functions for each major dashboard section.
 use crate::display::{
@@ -45,17 +58,31 @@ use crate::display::{
     display_consensus_security_info,
     render_hashrate_distribution_chart,
     draw_last20_miners,
+    display_peer_list,
+    display_peer_detail,
 };
+use crate::display::display_peer_list::peer_at;
+use crate::display::display_network_info::{DistributionView, NetworkView};
 
 // Misc utilities: header/footer, miner loader, block history tracker.
-use crate::utils::{render_header, render_footer, load_miners_data, BLOCK_HISTORY};
+use crate::utils::{render_header, render_footer, render_footer_alert, node_reachable, note_rpc_outcome, poll_sleep, set_refresh_paused, load_miners_data, jittered, block_history_path, BLOCK_HISTORY};
 
 // For peer aggregation functions (versions, clients, etc.)
 use crate::models::peer_info::{PeerInfo, NetworkState};
+use crate::models::watch_list::{WatchList, WatchStatus, short_txid};
+use crate::models::mempool_info::MempoolInfo;
+use crate::models::fee_estimate::FEE_TARGETS;
+use crate::models::block_info::{BlockHistory, BlockInfo, MinersData};
+use crate::models::block_intervals::BlockIntervals;
+use crate::models::blockchain_info::height_24h_ago;
+use crate::models::connection_churn::ConnectionChurn;
+use crate::models::disk_growth::DiskGrowth;
+use crate::models::uptime::format_uptime;
+use crate::export::{export_mempool_distribution_csv, mempool_export_path, snapshot_path, snapshot_state};
 
 // TUI dependencies
 use tui::{
-    backend::{CrosstermBackend, Backend},
+    backend::{CrosstermBackend, Backend, TestBackend},
     layout::{Layout, Constraint, Direction, Margin, Rect, Alignment},
     widgets::{Block, Borders, Paragraph, Clear, Wrap, BorderType},
     style::{Color, Style, Modifier},
@@ -65,30 +92,43 @@ use tui::{
 };
 
 use crossterm::{
-    event::{self, Event, KeyCode},
+    event::{
+        self, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+        Event, KeyCode, MouseButton, MouseEvent, MouseEventKind,
+    },
     execute,
+    style::Print,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 
 use std::io::{self, Stdout};
-use std::collections::VecDeque;
+use std::collections::{HashSet, VecDeque};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::AtomicU8;
 
 use tokio::time::{sleep, Duration, Instant};
 
-use blockchaininfo::utils::log_error;
+use blockchaininfo::utils::{log_error, log_event, LogLevel};
 use crate::ui::colors::*;
+use crate::ui::scroll_view::{ScrollView, SCROLL_PAGE, max_scroll_offset};
+use crate::ui::mouse::{hotspot_at, title_hotspots, Hotspot};
+use crate::ui::layout::{dashboard_areas, too_small, MIN_TERMINAL_HEIGHT, MIN_TERMINAL_WIDTH};
+use crate::ui::color_support::{color_support, PaletteFilter};
 
 use crate::models::chaintips_info::ChainTipsJsonWrap;
 
 // DashSet is used for tracking unique block numbers (propagation-time updates)
 use dashmap::DashSet;
 
+// System clipboard for copying Tx Lookup results
+use arboard::Clipboard;
+
 // OnceCell provides a lazy static container.
 use once_cell::sync::Lazy;
 
-use crate::models::chaintips_info::ChainTip;
+use crate::models::chaintips_info::alerting_forks;
+use crate::models::core_warnings::node_warnings;
 
 // Shared caches used across async tasks for concurrency-safe data access.
 use crate::utils::{
@@ -101,6 +141,16 @@ use crate::utils::{
     NETWORK_INFO_CACHE,
     NET_TOTALS_CACHE,
     MEMPOOL_DISTRIBUTION_CACHE,
+    FEE_RATE_HISTORY_CACHE,
+    FEE_ESTIMATE_CACHE,
+    MINING_INFO_CACHE,
+    LAST_BLOCK_STATS_CACHE,
+    WITNESS_ADOPTION_CACHE,
+    COINBASE_REWARD_CACHE,
+    CHAIN_TX_STATS_CACHE,
+    DEPLOYMENT_INFO_CACHE,
+    UPTIME_CACHE,
+    BLOCK24_PRUNED,
 };
 
 // Atomic flags used for toggles (no locking overhead).
@@ -114,21 +164,32 @@ pub enum PopupType {
     TxLookup,
     Help,
     ConsensusWarning,
+    RpcLog,
+    WatchList,
+    PeerList,
+    PeerDetail,
 }
 
 /// Global application state.
-/// Tracks UI mode, popup state, toggles, paste-detection, etc.
+/// Tracks UI mode, popup state, toggles, input buffers, etc.
 struct App {
     popup: PopupType,            // Which popup is currently open
     tx_input: String,            // TxID text buffer
     tx_result: Option<String>,   // RPC result for Tx lookup
     is_exiting: bool,            // Whether 'q' has been pressed for shutdown
-    is_pasting: bool,            // Detect multi-character paste events
     show_hash_distribution: bool,// Toggle: Hashrate Distribution view
     dust_free: Arc<AtomicBool>,  // Toggle: Dust filtering for mempool distro
     size_lens: Arc<AtomicU8>,     // NEW: 0=All, 1=S, 2=M, 3=L
-    show_client_distribution: bool, // NEW toggle: Version vs Client view
-    last_fork_alert_height: Option<u64>, // For deduping fork warning popups
+    size_by_vsize: bool,          // Toggle: Size Distribution by total vsize instead of tx count
+    distribution_view: DistributionView, // Network chart: Version → Client → Protocol → Network (c cycles)
+    alerted_fork_tips: HashSet<String>, // Fork tip hashes already alerted on (dedup)
+    fork_alert: Option<u64>,    // Branch length of a fork at/over the alert threshold, if any
+    stale_tip: bool,            // No new block for stale_tip_alert_mins; time-since-block flashes
+    stale_tip_alerted: u64,     // Tip height the stale-tip alert last fired for (once per tip)
+    node_warnings: Vec<String>, // Last logged Core warnings (getblockchaininfo + getnetworkinfo)
+    was_reachable: bool,        // Node reachability last frame; changes are logged
+    connection_churn: ConnectionChurn, // Recent in/out connection-count changes
+    disk_growth: DiskGrowth,    // size_on_disk samples for the growth rate
     show_propagation_avg: bool, // NEW toggle: Propagation average over 20 block period
     last_block: Arc<AtomicU64>, // last block to pass to mempool_distro
     show_last20_miners: bool,   // Toggle: Show last 20 blocks / miners.
@@ -137,22 +198,43 @@ struct App {
     last_hashphase: Option<u8>,
     last_percent: f64,
     hashphase_initialized: bool,
+    scroll_mode: bool,          // Toggle: single tall scrollable view instead of fixed panels
+    scroll_offset: u16,         // First visible row of the virtual dashboard in scroll mode
+    rpc_log_scroll: u16,        // First visible line of the RPC call log popup
+    tx_lookup_scroll: u16,      // First visible line of the Tx Lookup result
+    peer_list_scroll: u16,      // First visible row of the peer list popup (the selection)
+    peer_detail_id: Option<u64>, // Peer shown in the peer detail popup
+    watch_list: WatchList,      // Txids tracked from mempool to confirmation
+    watch_input: String,        // TxID text buffer in the watch list popup
+    notice: Option<(String, Instant)>, // Footer notice (watch status change, export result)
+    snapshot_requested: bool,   // 'j' pressed; snapshot taken before the next cache read
+    clipboard: Option<Clipboard>, // Opened on first copy; kept alive so X11 selections persist
+    paused: bool,               // Toggle: auto-refresh paused (space); workers hold off polling
+    miner_fetch_pending: Option<u64>, // New block whose miner lookup waits for refresh to resume
+    hotspots: Vec<Hotspot>,     // Clickable title toggles of the last frame (virtual-terminal cells)
 }
 
 impl App {
     /// Creates fresh runtime state for the TUI.
-    fn new() -> Self {
+    fn new(watch_txids: &[String]) -> Self {
         Self {
             popup: PopupType::None,
             tx_input: String::new(),
             tx_result: None,
             is_exiting: false,
-            is_pasting: false,
             show_hash_distribution: false,
             dust_free: Arc::new(AtomicBool::new(true)), // dust-free enabled by default
             size_lens: Arc::new(AtomicU8::new(0)), // default: All
-            show_client_distribution: false,            // default: show Version view
-            last_fork_alert_height: None,
+            size_by_vsize: false,                   // default: count transactions
+            distribution_view: DistributionView::Version, // default: show Version view
+            alerted_fork_tips: HashSet::new(),
+            fork_alert: None,
+            stale_tip: false,
+            stale_tip_alerted: 0,
+            node_warnings: Vec::new(),
+            was_reachable: true,
+            connection_churn: ConnectionChurn::default(),
+            disk_growth: DiskGrowth::default(),
             show_propagation_avg: false,                //default: show sparkline view
             last_block: Arc::new(AtomicU64::new(0)),
             show_last20_miners: false,
@@ -161,10 +243,30 @@ impl App {
             last_hashphase: None,
             last_percent: 0.0,
             hashphase_initialized: false,
+            scroll_mode: false,                          // default: fixed panels
+            scroll_offset: 0,
+            rpc_log_scroll: 0,
+            tx_lookup_scroll: 0,
+            peer_list_scroll: 0,
+            peer_detail_id: None,
+            watch_list: WatchList::new(watch_txids),
+            watch_input: String::new(),
+            notice: None,
+            snapshot_requested: false,
+            clipboard: None,
+            paused: false,
+            miner_fetch_pending: None,
+            hotspots: Vec::new(),
         }
     }
 }
 
+/// How long a footer notice (watch list change, export result, copy) stays up.
+const NOTICE_DURATION: Duration = Duration::from_secs(15);
+
+/// Rows moved by PgUp/PgDn in the peer list popup.
+const PEER_LIST_PAGE: u16 = 10;
+
 /// Tracks the last block number whose propagation time has been recorded.
 /// DashSet gives us thread-safe "contains" and insert operations.
 static LAST_BLOCK_NUMBER: Lazy<DashSet<u64>> = Lazy::new(|| DashSet::new());
@@ -174,12 +276,13 @@ static LAST_BLOCK_NUMBER: Lazy<DashSet<u64>> = Lazy::new(|| DashSet::new());
 // TERMINAL SETUP / CLEANUP
 // =================================================================================================
 
-/// Enter TUI mode by enabling raw mode and swapping into the alternate screen.
+/// Enter TUI mode by enabling raw mode, swapping into the alternate screen,
+/// capturing the mouse and turning on bracketed paste.
 pub fn setup_terminal() -> Result<Terminal<CrosstermBackend<Stdout>>, io::Error> {
     enable_raw_mode()?;
 
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture, EnableBracketedPaste)?;
 
     let backend = CrosstermBackend::new(stdout);
     Terminal::new(backend)
@@ -188,7 +291,7 @@ pub fn setup_terminal() -> Result<Terminal<CrosstermBackend<Stdout>>, io::Error>
 /// Restore terminal to normal mode when exiting.
 pub fn cleanup_terminal(terminal: &mut Terminal<CrosstermBackend<Stdout>>) -> Result<(), io::Error> {
     disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen, DisableMouseCapture, DisableBracketedPaste)?;
     terminal.show_cursor()
 }
 
@@ -208,15 +311,46 @@ pub async fn run_app<B: Backend>(
     // Rolling 20-sample window of block propagation times.
     let mut propagation_times: VecDeque<i64> = VecDeque::with_capacity(20);
 
+    // Rolling window of seconds between consecutive blocks (header times).
+    let mut block_intervals = BlockIntervals::default();
+
     // Local UI state.
-    let mut app = App::new();
+    let mut app = App::new(&config.watch_txids);
+
+    // Optional offline GeoIP database for the peer list (read once at startup).
+    let geoip = config.geoip_db.as_deref().and_then(|path| match GeoIp::open(Path::new(path)) {
+        Ok(db) => Some(db),
+        Err(e) => {
+            let _ = log_error(&e.to_string());
+            None
+        }
+    });
 
-    // Miner name/address lookup table.
-    let miners_data = load_miners_data()?;
+    // Miner name/address lookup table. Optional: without it, miners are
+    // named from coinbase tags alone.
+    let miners_data = match load_miners_data(config.miners_json.as_deref()) {
+        Ok(data) => data,
+        Err(MyError::FileNotFound(msg)) => {
+            let _ = log_event(LogLevel::Warn, &format!(
+                "{} Miner attribution falls back to coinbase tags.",
+                msg
+            ));
+            MinersData::default()
+        }
+        Err(e) => return Err(e),
+    };
 
     // Shared default miner string for fallback cases.
     let default_miner = "Unknown".to_string();
 
+    // Active config shared with the worker tasks; swapped when the config
+    // file changes (see CONFIG WATCHER below).
+    let live_config = LiveConfig::new(config.clone());
+
+    // Off-screen terminal the scroll-mode dashboard is rendered into; it is
+    // taller than the screen, and only the visible window is copied over.
+    let mut virtual_terminal = Terminal::new(TestBackend::new(1, 1))?;
+
     // Stores block-anchored propagation slots
     let mut network_state = NetworkState {
         last_propagation_index: None,
@@ -225,31 +359,35 @@ pub async fn run_app<B: Backend>(
     };
 
 
-    // Draw initial "Initializing…" screen.
-    terminal.draw(|frame| {
-        let area = frame.size();
-        let block = Block::default().title("Initializing...").borders(Borders::ALL);
-        frame.render_widget(block, area);
-    })?;
+    // Draw initial "Initializing…" screen (updated with readiness below).
+    terminal.draw(|frame| render_init_screen(frame, &[]))?;
 
     // =============================================================================================
     // RPC WORKER TASK: BLOCKCHAIN INFO + BLOCK & 24H BLOCK FETCH
     // =============================================================================================
     //
-    // Runs every ~2 seconds. Updates:
+    // Runs every ~2 seconds by default (`intervals.blockchain`). Updates:
     //  • Latest blockchain height
     //  • Latest block data
     //  • Block data from 24 hours ago
+    //  • Reorg detection (new tip vs. previously seen best blocks)
     //
+    let (reorg_tx, mut reorg_rx) = tokio::sync::mpsc::unbounded_channel::<(u64, u64)>();
+
     tokio::spawn({
-        let config_clone = config.clone();
+        let live = live_config.clone();
 
         async move {
+            let mut reorgs = ReorgTracker::default();
+
             loop {
+                let config_clone = live.current();
                 let start = Instant::now();
 
                 // --- Step 1: Fetch blockchain_info (height, difficulty, chain, etc.) ---
-                match fetch_blockchain_info(&config_clone).await {
+                let result = with_retry(RPC_RETRY_ATTEMPTS, RPC_RETRY_BASE_DELAY, || fetch_blockchain_info(&config_clone)).await;
+                note_rpc_outcome(&result);
+                match result {
                     Ok(new_blockchain_info) => {
                         if *BLOCKCHAIN_INFO_CACHE.read().await != new_blockchain_info
                         // Avoid unnecessary updates to allow the UI to stay calm.
@@ -257,7 +395,7 @@ pub async fn run_app<B: Backend>(
                             *BLOCKCHAIN_INFO_CACHE.write().await = new_blockchain_info;
                         } else {
                             // Data did not change — sleep the remainder of 2 seconds.
-                            sleep(Duration::from_secs(2)).await;
+                            poll_sleep(jittered(Duration::from_secs(config_clone.intervals.blockchain), config_clone.poll_jitter_pct)).await;
                             continue;
                         }
                     }
@@ -267,7 +405,7 @@ pub async fn run_app<B: Backend>(
                         {
                             // eprintln!("Failed to log error: {}", log_err);
                         }
-                        sleep(Duration::from_secs(2)).await;
+                        poll_sleep(jittered(Duration::from_secs(config_clone.intervals.blockchain), config_clone.poll_jitter_pct)).await;
                         continue;
                     }
                 }
@@ -294,14 +432,23 @@ pub async fn run_app<B: Backend>(
                             "Block Data by Height failed at height {}: {}",
                             block_height, e
                         ));
-                        sleep(Duration::from_secs(2)).await;
+                        poll_sleep(jittered(Duration::from_secs(config_clone.intervals.blockchain), config_clone.poll_jitter_pct)).await;
                         continue;
                     }
                 }
 
                 // --- Step 4: Fetch the block from ~24 hours ago ---
-                match fetch_block_data_by_height(&config_clone, block_height, 2).await {
+                // Skipped when pruning has already deleted it; the 24h
+                // projection then shows "N/A (pruned)".
+                let height24 = height_24h_ago(block_height);
+                let block24 = if BLOCKCHAIN_INFO_CACHE.read().await.has_block_data(height24) {
+                    fetch_block_data_by_height(&config_clone, block_height, 2).await
+                } else {
+                    Err(MyError::BlockPruned(format!("height {} is below the prune height", height24)))
+                };
+                match block24 {
                     Ok(block24_data) => {
+                        BLOCK24_PRUNED.store(false, Ordering::Relaxed);
                         let mut cache = BLOCK24_INFO_CACHE.write().await;
 
                         let same24 = cache.first().is_some_and(|prev| prev.hash == block24_data.hash);
@@ -310,21 +457,66 @@ pub async fn run_app<B: Backend>(
                             cache.push(block24_data);
                         }
                     }
+                    Err(MyError::BlockPruned(reason)) => {
+                        // Expected on a pruned node: note it once, carry on.
+                        if !BLOCK24_PRUNED.swap(true, Ordering::Relaxed) {
+                            let _ = log_event(LogLevel::Info, &format!(
+                                "Block Data 24h unavailable at height {}: {}",
+                                height24, reason
+                            ));
+                        }
+                        BLOCK24_INFO_CACHE.write().await.clear();
+                    }
                     Err(e) => {
                         let _ = log_error(&format!(
                             "Block Data 24h failed at height {}: {}",
                             block_height, e
                         ));
-                        sleep(Duration::from_secs(2)).await;
+                        poll_sleep(jittered(Duration::from_secs(config_clone.intervals.blockchain), config_clone.poll_jitter_pct)).await;
                         continue;
                     }
                 }
 
-                // Maintain a strict ~2-second loop duration.
-                let elapsed = start.elapsed();
-                if elapsed < Duration::from_secs(2) {
-                    sleep(Duration::from_secs(2) - elapsed).await;
+                // --- Step 5: Block stats for the new tip (once per height) ---
+                if LAST_BLOCK_STATS_CACHE.read().await.height != block_height {
+                    match fetch_block_stats(&config_clone, block_height).await {
+                        Ok(stats) => {
+                            *LAST_BLOCK_STATS_CACHE.write().await = stats;
+                        }
+                        Err(e) => {
+                            // Non-fatal: retried on the next pass.
+                            let _ = log_event(LogLevel::Warn, &format!(
+                                "Block Stats failed at tip {}: {}",
+                                block_height, e
+                            ));
+                        }
+                    }
+                }
+
+                // --- Step 6: Reorg detection — did the new tip disconnect blocks we saw? ---
+                let tip_hash = BLOCKCHAIN_INFO_CACHE.read().await.bestblockhash.clone();
+                if !tip_hash.is_empty() && reorgs.best().map(|(_, h)| h) != Some(tip_hash.as_str()) {
+                    match walk_new_branch(&config_clone, &reorgs, &tip_hash).await {
+                        Ok(branch) => {
+                            let depth = reorgs.connect(&branch);
+                            if depth > 0 {
+                                let _ = log_event(LogLevel::Warn, &format!(
+                                    "Reorg detected: {} block(s) disconnected, new tip {} at height {}",
+                                    depth, tip_hash, block_height
+                                ));
+                                let _ = reorg_tx.send((depth, block_height));
+                            }
+                        }
+                        Err(e) => {
+                            // Non-fatal: the walk is retried on the next new tip.
+                            let _ = log_error(&format!("Reorg check failed at tip {}: {}", block_height, e));
+                        }
+                    }
                 }
+
+                // Maintain the configured loop duration (~2 seconds by default).
+                let interval = jittered(Duration::from_secs(config_clone.intervals.blockchain), config_clone.poll_jitter_pct);
+                poll_sleep(interval.saturating_sub(start.elapsed())).await;
             }
         }
     });
@@ -334,16 +526,19 @@ pub async fn run_app<B: Backend>(
     // RPC WORKER TASK: MEMPOOL INFO
     // =============================================================================================
     //
-    // Updates general mempool statistics. Runs every 3 seconds.
+    // Updates general mempool statistics. Runs every 3 seconds by default (`intervals.mempool`).
     //
     tokio::spawn({
-        let config_clone = config.clone();
+        let live = live_config.clone();
 
         async move {
             loop {
+                let config_clone = live.current();
                 let start = Instant::now();
 
-                match fetch_mempool_info(&config_clone).await {
+                let result = with_retry(RPC_RETRY_ATTEMPTS, RPC_RETRY_BASE_DELAY, || fetch_mempool_info(&config_clone)).await;
+                note_rpc_outcome(&result);
+                match result {
                     Ok(new_data) => {
                         if *MEMPOOL_INFO_CACHE.read().await != new_data {
                             *MEMPOOL_INFO_CACHE.write().await = new_data;
@@ -355,10 +550,8 @@ pub async fn run_app<B: Backend>(
                 }
 
                 // Maintain ~3-second pacing.
-                let elapsed = start.elapsed();
-                if elapsed < Duration::from_secs(3) {
-                    sleep(Duration::from_secs(3) - elapsed).await;
-                }
+                let interval = jittered(Duration::from_secs(config_clone.intervals.mempool), config_clone.poll_jitter_pct);
+                poll_sleep(interval.saturating_sub(start.elapsed())).await;
             }
         }
     });
@@ -369,16 +562,19 @@ pub async fn run_app<B: Backend>(
     // =============================================================================================
     //
     // Updates peer count, local services, version info, and related fields.
-    // Runs every 7 seconds.
+    // Runs every 7 seconds by default (`intervals.network`).
     //
     tokio::spawn({
-        let config_clone = config.clone();
+        let live = live_config.clone();
 
         async move {
             loop {
+                let config_clone = live.current();
                 let start = Instant::now();
 
-                match fetch_network_info(&config_clone).await {
+                let result = with_retry(RPC_RETRY_ATTEMPTS, RPC_RETRY_BASE_DELAY, || fetch_network_info(&config_clone)).await;
+                note_rpc_outcome(&result);
+                match result {
                     Ok(new_data) => {
                         if *NETWORK_INFO_CACHE.read().await != new_data {
                             *NETWORK_INFO_CACHE.write().await = new_data
@@ -389,10 +585,8 @@ pub async fn run_app<B: Backend>(
                     }
                 }
 
-                let elapsed = start.elapsed();
-                if elapsed < Duration::from_secs(7) {
-                    sleep(Duration::from_secs(7) - elapsed).await;
-                }
+                let interval = jittered(Duration::from_secs(config_clone.intervals.network), config_clone.poll_jitter_pct);
+                poll_sleep(interval.saturating_sub(start.elapsed())).await;
             }
         }
     });
@@ -405,13 +599,14 @@ pub async fn run_app<B: Backend>(
 //   • Client distribution
 //   • Block propagation time estimates
 //
-// Runs every ~7 seconds. Peer sets rarely change faster than this.
+// Runs every ~7 seconds by default (`intervals.peers`). Peer sets rarely change faster than this.
 //
 tokio::spawn({
-    let config_clone = config.clone();
+    let live = live_config.clone();
 
     async move {
         loop {
+            let config_clone = live.current();
             let start = Instant::now();
 
             match fetch_peer_info(&config_clone).await {
@@ -437,11 +632,9 @@ tokio::spawn({
                 }
             }
 
-            // Maintain ~7 second pacing.
-            let elapsed = start.elapsed();
-            if elapsed < Duration::from_secs(7) {
-                sleep(Duration::from_secs(7) - elapsed).await;
-            }
+            // Maintain the configured pacing (~7 seconds by default).
+            let interval = jittered(Duration::from_secs(config_clone.intervals.peers), config_clone.poll_jitter_pct);
+            poll_sleep(interval.saturating_sub(start.elapsed())).await;
         }
     }
 });
@@ -452,13 +645,14 @@ tokio::spawn({
 // =============================================================================================
 // Retrieves alternative chain tips (stale forks, valid forks, headers-only tips).
 // This data drives the Consensus Warning popup.
-// Runs every ~10 seconds.
+// Runs every ~10 seconds by default (`intervals.chain_tips`).
 //
 tokio::spawn({
-    let config_clone = config.clone();
+    let live = live_config.clone();
 
     async move {
         loop {
+            let config_clone = live.current();
             let start = Instant::now();
 
             match fetch_chain_tips(&config_clone).await {
@@ -490,10 +684,8 @@ tokio::spawn({
                 }
             }
 
-            let elapsed = start.elapsed();
-            if elapsed < Duration::from_secs(10) {
-                sleep(Duration::from_secs(10) - elapsed).await;
-            }
+            let interval = jittered(Duration::from_secs(config_clone.intervals.chain_tips), config_clone.poll_jitter_pct);
+            poll_sleep(interval.saturating_sub(start.elapsed())).await;
         }
     }
 });
@@ -506,10 +698,11 @@ tokio::spawn({
 // Useful for diagnosing traffic flow or seeing relay throttling.
 //
 tokio::spawn({
-    let config_clone = config.clone();
+    let live = live_config.clone();
 
     async move {
         loop {
+            let config_clone = live.current();
             let start = Instant::now();
 
             match fetch_net_totals(&config_clone).await {
@@ -525,11 +718,222 @@ tokio::spawn({
                 }
             }
 
-            // Maintain ~7 second pacing.
-            let elapsed = start.elapsed();
-            if elapsed < Duration::from_secs(7) {
-                sleep(Duration::from_secs(7) - elapsed).await;
+            // Maintain the configured pacing (~7 seconds by default).
+            let interval = jittered(Duration::from_secs(config_clone.intervals.net_totals), config_clone.poll_jitter_pct);
+            poll_sleep(interval.saturating_sub(start.elapsed())).await;
+        }
+    }
+});
+
+
+// =============================================================================================
+// RPC WORKER TASK: BLOCK STATS (CONFIRMED FEE-RATE TREND)
+// =============================================================================================
+// Backfills the median confirmed fee rate for the last 20 blocks via `getblockstats`.
+// Only heights missing from the rolling history are requested, so after warm-up this
+// costs a single RPC per new block.
+//
+// Pruned blocks return Core's "pruned data" error (`MyError::BlockPruned`) and are
+// recorded as gaps. Other Core errors, timeouts and network errors are logged and left
+// unrecorded so the next pass retries them.
+//
+tokio::spawn({
+    let live = live_config.clone();
+
+    async move {
+        loop {
+            let config_clone = live.current();
+            let start = Instant::now();
+
+            let tip = BLOCKCHAIN_INFO_CACHE.read().await.blocks;
+
+            if tip > 0 {
+                let missing = FEE_RATE_HISTORY_CACHE.read().await.missing_heights(tip);
+
+                for height in missing {
+                    match fetch_block_stats(&config_clone, height).await {
+                        Ok(stats) => {
+                            FEE_RATE_HISTORY_CACHE
+                                .write()
+                                .await
+                                .record(height, Some(stats.median_feerate()));
+                        }
+                        Err(MyError::BlockPruned(_)) => {
+                            // Stats unavailable for this height (pruned) — leave a gap.
+                            FEE_RATE_HISTORY_CACHE.write().await.record(height, None);
+                        }
+                        Err(e) => {
+                            let _ = log_error(&format!(
+                                "Block Stats failed at height {}: {}",
+                                height, e
+                            ));
+                            break;
+                        }
+                    }
+                }
+            }
+
+            let interval = jittered(Duration::from_secs(config_clone.intervals.block_stats), config_clone.poll_jitter_pct);
+            poll_sleep(interval.saturating_sub(start.elapsed())).await;
+        }
+    }
+});
+
+
+// =============================================================================================
+// RPC WORKER TASK: MINING INFO (NETWORK HASHRATE)
+// =============================================================================================
+// Polls `getmininginfo` every ~15 seconds (`intervals.mining_info`) for Core's network hashrate estimate.
+//
+tokio::spawn({
+    let live = live_config.clone();
+
+    async move {
+        loop {
+            let config_clone = live.current();
+            let start = Instant::now();
+
+            match fetch_mining_info(&config_clone).await {
+                Ok(new_data) => {
+                    if *MINING_INFO_CACHE.read().await != new_data {
+                        *MINING_INFO_CACHE.write().await = new_data;
+                    }
+                }
+                Err(e) => {
+                    let _ = log_error(&format!("Mining Info failed: {}", e));
+                }
+            }
+
+            let interval = jittered(Duration::from_secs(config_clone.intervals.mining_info), config_clone.poll_jitter_pct);
+            poll_sleep(interval.saturating_sub(start.elapsed())).await;
+        }
+    }
+});
+
+
+// =============================================================================================
+// RPC WORKER TASK: CHAIN TX STATS (THROUGHPUT)
+// =============================================================================================
+// Polls `getchaintxstats` every ~30 seconds (`intervals.chain_tx_stats`) over `tx_stats_window_blocks` (default 144).
+//
+tokio::spawn({
+    let live = live_config.clone();
+
+    async move {
+        loop {
+            let config_clone = live.current();
+            let start = Instant::now();
+
+            match fetch_chain_tx_stats(&config_clone, config_clone.tx_stats_window_blocks).await {
+                Ok(new_data) => {
+                    if *CHAIN_TX_STATS_CACHE.read().await != new_data {
+                        *CHAIN_TX_STATS_CACHE.write().await = new_data;
+                    }
+                }
+                Err(e) => {
+                    let _ = log_error(&format!("Chain Tx Stats failed: {}", e));
+                }
+            }
+
+            let interval = jittered(Duration::from_secs(config_clone.intervals.chain_tx_stats), config_clone.poll_jitter_pct);
+            poll_sleep(interval.saturating_sub(start.elapsed())).await;
+        }
+    }
+});
+
+
+// =============================================================================================
+// RPC WORKER TASK: DEPLOYMENT INFO (SOFT FORKS)
+// =============================================================================================
+// Polls `getdeploymentinfo` every ~60 seconds (`intervals.deployments`). Nodes without the RPC (pre-Core 23)
+// end the task on the first "method not found"; the cache stays `None` and the
+// deployment list is hidden.
+//
+tokio::spawn({
+    let live = live_config.clone();
+
+    async move {
+        loop {
+            let config_clone = live.current();
+            let start = Instant::now();
+
+            match fetch_deployment_info(&config_clone).await {
+                Ok(new_data) => {
+                    let mut cache = DEPLOYMENT_INFO_CACHE.write().await;
+                    if cache.as_ref() != Some(&new_data) {
+                        *cache = Some(new_data);
+                    }
+                }
+                Err(MyError::RpcMethodNotFound(_)) => break,
+                Err(e) => {
+                    let _ = log_error(&format!("Deployment Info failed: {}", e));
+                }
+            }
+
+            let interval = jittered(Duration::from_secs(config_clone.intervals.deployments), config_clone.poll_jitter_pct);
+            poll_sleep(interval.saturating_sub(start.elapsed())).await;
+        }
+    }
+});
+
+
+// =============================================================================================
+// RPC WORKER TASK: NODE UPTIME
+// =============================================================================================
+// Polls `uptime` every ~30 seconds (`intervals.uptime`). If the node refuses the
+// method (not in the user's `-rpcwhitelist`), the task ends; the cache stays `None`
+// and the uptime is left out of the Network title.
+//
+tokio::spawn({
+    let live = live_config.clone();
+
+    async move {
+        loop {
+            let config_clone = live.current();
+            let start = Instant::now();
+
+            match fetch_uptime(&config_clone).await {
+                Ok(secs) => {
+                    *UPTIME_CACHE.write().await = Some(secs);
+                }
+                Err(MyError::RpcMethodNotFound(_)) => break,
+                Err(e) => {
+                    let _ = log_error(&format!("Uptime failed: {}", e));
+                }
+            }
+
+            let interval = jittered(Duration::from_secs(config_clone.intervals.uptime), config_clone.poll_jitter_pct);
+            poll_sleep(interval.saturating_sub(start.elapsed())).await;
+        }
+    }
+});
+
+
+// =============================================================================================
+// RPC WORKER TASK: FEE ESTIMATES
+// =============================================================================================
+// Polls `estimatesmartfee` for each target in FEE_TARGETS every ~30 seconds (`intervals.fee_estimates`).
+// Core's estimates only move meaningfully per block, so a slow cadence suffices.
+//
+tokio::spawn({
+    let live = live_config.clone();
+
+    async move {
+        loop {
+            let config_clone = live.current();
+            let start = Instant::now();
+
+            match fetch_fee_estimates(&config_clone, &FEE_TARGETS).await {
+                Ok(estimates) => {
+                    *FEE_ESTIMATE_CACHE.write().await = estimates;
+                }
+                Err(e) => {
+                    let _ = log_error(&format!("Fee Estimates failed: {}", e));
+                }
             }
+
+            let interval = jittered(Duration::from_secs(config_clone.intervals.fee_estimates), config_clone.poll_jitter_pct);
+            poll_sleep(interval.saturating_sub(start.elapsed())).await;
         }
     }
 });
@@ -543,7 +947,7 @@ tokio::spawn({
 //
 // Important:
 //   • Uses the dust_free toggle to filter out tiny transactions.
-//   • Runs every 2 seconds for responsive charts.
+//   • Runs every 2 seconds by default (`intervals.mempool_distribution`) for responsive charts.
 //
 // The previously complex TxID regex dedupe system has been removed —
 // distribution errors no longer require granular logging.
@@ -553,10 +957,11 @@ let size_flag = app.size_lens.clone();          // NEW
 let last_block_clone = app.last_block.clone();
 
 tokio::spawn({
-    let config_clone = config.clone();
+    let live = live_config.clone();
 
     async move {
         loop {
+            let config_clone = live.current();
             let start = Instant::now();
             let dust_free = dust_flag.load(Ordering::Relaxed);
             let size_lens = size_flag.load(Ordering::Relaxed);
@@ -567,9 +972,52 @@ tokio::spawn({
                 let _ = &e; // intentionally unused now
             }
 
-            let elapsed = start.elapsed();
-            if elapsed < Duration::from_secs(2) {
-                sleep(Duration::from_secs(2) - elapsed).await;
+            let interval = jittered(Duration::from_secs(config_clone.intervals.mempool_distribution), config_clone.poll_jitter_pct);
+            poll_sleep(interval.saturating_sub(start.elapsed())).await;
+        }
+    }
+});
+
+
+// =================================================================================================
+// CONFIG WATCHER (HOT RELOAD)
+// =================================================================================================
+// Polls the config file's mtime every ~2 seconds. On change the file is re-read and
+// validated; a good config replaces the active one for all tasks, a bad one is
+// reported in the footer and the last-good config stays active.
+//
+let (reload_tx, mut reload_rx) = tokio::sync::mpsc::unbounded_channel::<String>();
+
+tokio::spawn({
+    let live = live_config.clone();
+    let path = PathBuf::from(get_config_path());
+
+    async move {
+        let modified = |path: &Path| std::fs::metadata(path).and_then(|m| m.modified()).ok();
+        let mut last_modified = modified(&path);
+
+        loop {
+            sleep(Duration::from_secs(2)).await;
+
+            let current = modified(&path);
+            if current.is_none() || current == last_modified {
+                continue;
+            }
+            last_modified = current;
+
+            let notice = match reload_config(&path) {
+                Ok(new_config) => {
+                    live.replace(new_config);
+                    "🔄 Config reloaded".to_string()
+                }
+                Err(e) => {
+                    let _ = log_error(&format!("Config reload failed: {}", e));
+                    format!("⚠️ Config not reloaded: {}", e)
+                }
+            };
+
+            if reload_tx.send(notice).is_err() {
+                break; // UI loop is gone.
             }
         }
     }
@@ -577,10 +1025,51 @@ tokio::spawn({
 
 
 // =================================================================================================
-// SMALL SYNC BEFORE MAIN UI LOOP STARTS
+// STARTUP READINESS — WAIT FOR FIRST DATA BEFORE MAIN UI LOOP STARTS
 // =================================================================================================
+//
+// Shows per-section readiness as the caches fill. Enters the main loop once
+// blockchain, mempool and network data are all in, when `init_timeout_secs`
+// elapses, or on any other key press; 'q' or Esc quits. Slow or remote nodes
+// get visible progress instead of a frozen screen.
+//
+let init_started = Instant::now();
+let init_timeout = Duration::from_secs(config.init_timeout_secs);
+
+loop {
+    let readiness = [
+        ("Blockchain", BLOCKCHAIN_INFO_CACHE.read().await.blocks > 0),
+        ("Mempool", *MEMPOOL_INFO_CACHE.read().await != MempoolInfo::default()),
+        ("Network", NETWORK_INFO_CACHE.read().await.version > 0),
+        ("Peers", !PEER_INFO_CACHE.read().await.is_empty()),
+    ];
+
+    // Peers are optional: a node may briefly have none.
+    let required_ready = readiness.iter().take(3).all(|(_, ready)| *ready);
+    if required_ready || init_started.elapsed() >= init_timeout {
+        break;
+    }
+
+    terminal.draw(|frame| render_init_screen(frame, &readiness))?;
+
+    if event::poll(Duration::from_millis(200))? {
+        if let Event::Key(key) = event::read()? {
+            match key.code {
+                // Quit before the dashboard opens; `main` restores the terminal.
+                // The block history isn't loaded yet, so there is nothing to save.
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                _ => break,
+            }
+        }
+    }
+}
 
-sleep(Duration::from_secs(1)).await;
+// Restore the miner window saved on the last exit, so the Hash Rate
+// Distribution chart is populated right away.
+{
+    let tip = BLOCKCHAIN_INFO_CACHE.read().await.blocks;
+    *BLOCK_HISTORY.write().await = BlockHistory::load(&block_history_path(), tip);
+}
 
 
 // =================================================================================================
@@ -595,6 +1084,47 @@ sleep(Duration::from_secs(1)).await;
 // This loop never blocks on network I/O — all fetches happen inside background tasks.
 //
 loop {
+    // Pick up a hot-reloaded config and its footer notice, if any.
+    let config = live_config.current();
+    while let Ok(notice) = reload_rx.try_recv() {
+        app.notice = Some((notice, Instant::now()));
+    }
+
+    // Reorgs found by the blockchain task: footer notice, plus the bell when
+    // `reorg_alarm` is on and the reorg is deep enough.
+    while let Ok((depth, height)) = reorg_rx.try_recv() {
+        app.notice = Some((
+            format!("⚠️ Reorg: {} block(s) replaced, new tip {}", depth, height),
+            Instant::now(),
+        ));
+        if config.reorg_alarm && depth >= config.reorg_alarm_depth {
+            // Terminal bell, as for watch list confirmations.
+            let _ = execute!(io::stdout(), Print("\x07"));
+        }
+    }
+
+    // ---------------------------------------------------------------------------------------------
+    // Step 0: JSON snapshot, if requested. Runs here, while this loop holds
+    // no cache guards, so its own reads can't wait behind a queued writer.
+    // ---------------------------------------------------------------------------------------------
+    if app.snapshot_requested {
+        app.snapshot_requested = false;
+
+        let path = snapshot_path();
+        let result = match snapshot_state().await {
+            Ok(json) => std::fs::write(&path, json).map_err(MyError::from),
+            Err(e) => Err(e),
+        };
+        let message = match result {
+            Ok(()) => format!("💾 Saved snapshot to {}", path.display()),
+            Err(e) => {
+                let _ = log_error(&format!("Snapshot failed: {}", e));
+                "⚠️ Snapshot failed (see error log)".to_string()
+            }
+        };
+        app.notice = Some((message, Instant::now()));
+    }
+
     // ---------------------------------------------------------------------------------------------
     // Step 1: Retrieve all data from caches simultaneously.
     // ---------------------------------------------------------------------------------------------
@@ -608,6 +1138,15 @@ loop {
         net_totals,
         distribution,
         chaintips_info,
+        fee_rate_history,
+        fee_estimates,
+        mining_info,
+        last_block_stats,
+        witness_adoption,
+        coinbase_reward,
+        chain_tx_stats,
+        deployment_info,
+        uptime,
     ) = tokio::join!(
         BLOCKCHAIN_INFO_CACHE.read(),
         MEMPOOL_INFO_CACHE.read(),
@@ -618,12 +1157,25 @@ loop {
         NET_TOTALS_CACHE.read(),
         MEMPOOL_DISTRIBUTION_CACHE.read(),
         CHAIN_TIP_CACHE.read(),
+        FEE_RATE_HISTORY_CACHE.read(),
+        FEE_ESTIMATE_CACHE.read(),
+        MINING_INFO_CACHE.read(),
+        LAST_BLOCK_STATS_CACHE.read(),
+        WITNESS_ADOPTION_CACHE.read(),
+        COINBASE_REWARD_CACHE.read(),
+        CHAIN_TX_STATS_CACHE.read(),
+        DEPLOYMENT_INFO_CACHE.read(),
+        UPTIME_CACHE.read(),
     );
+    let uptime = *uptime;
     let last_block = app.last_block.load(Ordering::Relaxed);
 
+    // Median confirmed fee rate for the last 20 blocks (0 = gap / pruned).
+    let fee_rate_trend = fee_rate_history.sparkline_data(blockchain_info.blocks);
+
     if last_block != 0 && blockchain_info.blocks > last_block + 1 {
         let missed = blockchain_info.blocks - last_block - 1;
-        let _ = log_error(&format!(
+        let _ = log_event(LogLevel::Warn, &format!(
             "Block gap detected: last seen={}, current={} (missed {} blocks)",
             last_block,
             blockchain_info.blocks,
@@ -632,6 +1184,27 @@ loop {
     }
     app.last_block.store(blockchain_info.blocks, Ordering::Relaxed);
 
+    // ---------------------------------------------------------------------------------------------
+    // Watch list — track watched txids against the mempool TXID cache.
+    // ---------------------------------------------------------------------------------------------
+    if !app.watch_list.is_empty() && blockchain_info.blocks > 0 {
+        let events = app.watch_list.update(is_in_mempool, blockchain_info.blocks, Instant::now().into_std());
+
+        if let Some(event) = events.last() {
+            app.notice = Some((
+                format!("👁 {} {}", short_txid(&event.txid), event.status.label()),
+                Instant::now(),
+            ));
+        }
+
+        if config.watch_bell
+            && events.iter().any(|e| matches!(e.status, WatchStatus::Confirmed { .. }))
+        {
+            // Terminal bell: audible/visual alert depending on terminal settings.
+            let _ = execute!(io::stdout(), Print("\x07"));
+        }
+    }
+
     // ---------------------------------------------------------------------------------------------
     // Epoch progress indicator — drives the animated header ("Flip Dot" logic).
     // ---------------------------------------------------------------------------------------------
@@ -690,10 +1263,11 @@ loop {
         let current_phase = phase_index(into_epoch);
 
         if let Some(phase) = current_phase {
-            if app.last_hashphase != Some(phase) {
+            // Paused: no RPC from the UI loop either; sample once refresh resumes.
+            if app.last_hashphase != Some(phase) && !app.paused {
                 app.last_hashphase = Some(phase);
 
-                if let Ok(rate) = getnetworkhashps(config, 144, blockchain_info.blocks as i64).await {
+                if let Ok(rate) = getnetworkhashps(&config, 144, blockchain_info.blocks as i64).await {
                     app.hashphase_rates.push(rate);
 
                     if app.hashphase_rates.len() > 5 {
@@ -708,37 +1282,122 @@ loop {
 
     // ---------------------------------------------------------------------------------------------
     // Consensus Warning Trigger
-    // If any chaintip is a "valid-fork" of length >= 2, show warning.
-    // Dedup using last_fork_alert_height so the popup appears only once per fork height.
+    // If any displayed chaintip is a "valid-fork" with branchlen >= fork_alert_branchlen,
+    // flash the Consensus border while it lasts. Each new fork tip (by hash) is logged and
+    // raises the warning popup once; hashes are forgotten once Core no longer reports the tip.
     // ---------------------------------------------------------------------------------------------
     let chaintips_result = &chaintips_info.result;
 
-    let mut filtered_tips: Vec<&ChainTip> = chaintips_info
-        .result
-        .iter()
-        .filter(|tip| tip.status == "active" || tip.status == "valid-fork")
-        .collect();
+    // An empty list means nothing fetched yet, not that every fork is gone.
+    if !chaintips_result.is_empty() {
+        app.alerted_fork_tips
+            .retain(|hash| chaintips_result.iter().any(|tip| &tip.hash == hash));
+    }
 
-    // Match display ordering: highest height first
-    filtered_tips.sort_by(|a, b| b.height.cmp(&a.height));
+    let alerting = alerting_forks(chaintips_result, config.fork_alert_branchlen);
+    app.fork_alert = alerting.iter().map(|tip| tip.branchlen).max();
 
-    // Only inspect the same 3 tips BCI shows
-    for tip in filtered_tips.into_iter().take(3) {
-        if tip.status == "valid-fork" && tip.branchlen >= 2 {
-            if app.last_fork_alert_height != Some(tip.height) {
-                app.last_fork_alert_height = Some(tip.height);
-                app.popup = PopupType::ConsensusWarning;
-            }
-            break;
+    for tip in alerting {
+        if app.alerted_fork_tips.insert(tip.hash.clone()) {
+            let _ = log_event(LogLevel::Warn, &format!(
+                "Fork alert: valid-fork tip {} at height {} has branch length {} (threshold {})",
+                tip.hash, tip.height, tip.branchlen, config.fork_alert_branchlen
+            ));
+            app.popup = PopupType::ConsensusWarning;
+        }
+    }
+
+    // ---------------------------------------------------------------------------------------------
+    // Stale Tip Alert
+    // No new block for `stale_tip_alert_mins`: a mining lull or a stalled node. The
+    // time-since-block line flashes while it lasts; the first frame over the threshold for a
+    // given tip logs a warning and, with `stale_tip_bell`, rings the terminal bell.
+    // ---------------------------------------------------------------------------------------------
+    app.stale_tip = blockchain_info.tip_is_stale(
+        chrono::Utc::now().timestamp().max(0) as u64,
+        config.stale_tip_alert_mins.saturating_mul(60),
+    );
+    if app.stale_tip && app.stale_tip_alerted != blockchain_info.blocks {
+        app.stale_tip_alerted = blockchain_info.blocks;
+        let _ = log_event(LogLevel::Warn, &format!(
+            "Stale tip: no block since height {} for over {} minutes",
+            blockchain_info.blocks, config.stale_tip_alert_mins
+        ));
+        if config.stale_tip_bell {
+            let _ = execute!(io::stdout(), Print("\x07"));
+        }
+    }
+
+    // ---------------------------------------------------------------------------------------------
+    // Node Reachability
+    // Losing the node is a warning; getting it back is routine (filtered by LOG_LEVEL=warn).
+    // ---------------------------------------------------------------------------------------------
+    let reachable = node_reachable();
+    if reachable != app.was_reachable {
+        let _ = if reachable {
+            log_event(LogLevel::Info, "Node reachable again (reconnected)")
+        } else {
+            log_event(LogLevel::Warn, "Node unreachable; retrying")
+        };
+        app.was_reachable = reachable;
+    }
+
+    // ---------------------------------------------------------------------------------------------
+    // Core Warnings
+    // "unknown new rules activated", "large valid fork", etc. Shown in the Network header and
+    // logged whenever the set changes.
+    // ---------------------------------------------------------------------------------------------
+    let node_warnings = node_warnings(&blockchain_info, &network_info);
+    if node_warnings != app.node_warnings {
+        if !node_warnings.is_empty() {
+            let _ = log_event(LogLevel::Warn, &format!("Node warnings: {}", node_warnings.join(" | ")));
         }
+        app.node_warnings = node_warnings.clone();
+    }
+
+    // ---------------------------------------------------------------------------------------------
+    // Connection Churn
+    // Connection-count changes over a short window; skipped until getnetworkinfo has loaded so
+    // the jump from zero doesn't count.
+    // ---------------------------------------------------------------------------------------------
+    if network_info.version > 0 {
+        app.connection_churn.observe(
+            chrono::Utc::now().timestamp().max(0) as u64,
+            network_info.connections_in,
+            network_info.connections_out,
+        );
     }
 
+    // ---------------------------------------------------------------------------------------------
+    // Disk Growth
+    // size_on_disk changes over the last hours, for the MB/hour figure next to the size.
+    // ---------------------------------------------------------------------------------------------
+    app.disk_growth.observe(
+        chrono::Utc::now().timestamp().max(0) as u64,
+        blockchain_info.size_on_disk,
+    );
+
     // ---------------------------------------------------------------------------------------------
     // Peer Aggregations: Versions & Clients
     // Used by the Network section based on toggle mode.
     // ---------------------------------------------------------------------------------------------
     let version_counts = PeerInfo::aggregate_and_sort_versions(&peer_info);
     let client_counts = PeerInfo::aggregate_and_sort_clients(&peer_info);
+    let protocol_counts = PeerInfo::aggregate_and_sort_protocol_versions(&peer_info);
+    let network_counts = PeerInfo::aggregate_by_network(&peer_info);
+    let hb_compact_peers = PeerInfo::count_hb_compact_peers(&peer_info);
+    let ping_summary = PeerInfo::ping_summary(&peer_info);
+
+    // ---------------------------------------------------------------------------------------------
+    // Tip Freshness
+    // Flags a node that believes it is synced while its peers report a higher chain.
+    // ---------------------------------------------------------------------------------------------
+    let blocks_behind = if blockchain_info.initialblockdownload {
+        None
+    } else {
+        PeerInfo::blocks_behind_peers(&peer_info, blockchain_info.blocks)
+    };
+    let clock_skew = PeerInfo::clock_skew(&peer_info);
 
     // ---------------------------------------------------------------------------------------------
     // Block Propagation Time Estimation
@@ -748,6 +1407,7 @@ loop {
         &peer_info,
         blockchain_info.time,
         blockchain_info.blocks,
+        config.propagation_unit,
     );
 
     // ---------------------------------------------------------------------------------------------
@@ -755,6 +1415,9 @@ loop {
     // Deduped by remembering the last block number seen.
     // ---------------------------------------------------------------------------------------------
     if !LAST_BLOCK_NUMBER.contains(&blockchain_info.blocks) {
+        // New block — record its interval (the first block seen only sets the baseline).
+        block_intervals.observe(blockchain_info.blocks, blockchain_info.time);
+
         // New block — push a fresh propagation sample.
         if propagation_times.len() == 20 {
             propagation_times.pop_front();
@@ -767,10 +1430,8 @@ loop {
         LAST_BLOCK_NUMBER.clear();
         LAST_BLOCK_NUMBER.insert(network_state.last_block_seen);
 
-        // Also fetch miner attribution for the new block.
-        let block = network_state.last_block_seen;
-
-        let _ = fetch_miner(&config, &miners_data, &block).await;
+        // Also fetch miner attribution for the new block (below, unless paused).
+        app.miner_fetch_pending = Some(network_state.last_block_seen);
 
     } else {
         // Same block — propagation estimate changed.
@@ -795,6 +1456,14 @@ loop {
             }
         }
     }
+
+    // Paused: the miner lookup is an RPC too, so it waits for refresh to resume.
+    if !app.paused {
+        if let Some(block) = app.miner_fetch_pending.take() {
+            let _ = fetch_miner(&config, &miners_data, &block).await;
+        }
+    }
+
     // =============================================================================================
     // MINER DISTRIBUTION + LAST MINER RESOLUTION
     // =============================================================================================
@@ -851,42 +1520,47 @@ loop {
     //   • App shutdown (q)
     //   • Popup opening/closing (t, ?, Esc)
     //   • Hashrate & mempool toggles (h, d)
-    //   • Version <-> Client toggle (c)
+    //   • Version → Client → Protocol → Network cycle (c)
     //   • TxID text input (typing/paste)
     //
     if event::poll(poll_time)? {
-        if let Event::Key(key) = event::read()? {
-            match key.code {
+        let event = event::read()?;
+
+        // Resize: adopt the new size and repaint from scratch on this pass,
+        // rather than diffing against a buffer of the old size.
+        if let Event::Resize(_, _) = event {
+            terminal.autoresize()?;
+            terminal.clear()?;
+        }
+
+        // Mouse: a click on a title toggle acts as its key, the wheel as ↑/↓.
+        let key_code = match event {
+            Event::Key(key) => Some(key.code),
+            Event::Mouse(mouse) => mouse_key(&app, mouse),
+            _ => None,
+        };
+
+        if let Some(key_code) = key_code {
+            match key_code {
                 // Close popup panels
+                // Peer detail returns to the list it was opened from.
+                KeyCode::Esc if app.popup == PopupType::PeerDetail => {
+                    app.popup = PopupType::PeerList;
+                }
+
                 KeyCode::Esc if app.popup != PopupType::None => {
                     app.popup = PopupType::None;
-                    app.is_pasting = false;
                 }
 
-                // Begin Shutdown
-                KeyCode::Char('q') if !app.is_pasting => {
+                // Begin Shutdown ('q' is input in Tx Lookup: bech32 addresses contain it)
+                KeyCode::Char('q') if app.popup != PopupType::TxLookup => {
                     app.is_exiting = true;
 
-                    // Manual layout for one last clean exit frame
-                    let size = terminal.size()?;
-                    let chunks = Layout::default()
-                        .direction(Direction::Vertical)
-                        .margin(1)
-                        .constraints(
-                            [
-                                Constraint::Length(3),
-                                Constraint::Length(14),
-                                Constraint::Length(25),
-                                Constraint::Max(18),
-                                Constraint::Length(7),
-                                Constraint::Length(1),
-                            ]
-                            .as_ref(),
-                        )
-                        .split(size);
+                    // One last clean exit frame
+                    let footer = dashboard_areas(terminal.size()?, &config.layout).footer;
 
                     terminal.draw(|frame| {
-                        render_footer(frame, chunks[5], "Shutting Down Cleanly...");
+                        render_footer(frame, footer, "Shutting Down Cleanly...");
                     })?;
 
                     std::thread::sleep(std::time::Duration::from_millis(500));
@@ -898,7 +1572,7 @@ loop {
                     app.popup = PopupType::TxLookup;
                     app.tx_input.clear();
                     app.tx_result = None;
-                    app.is_pasting = false;
+                    app.tx_lookup_scroll = 0;
                 }
 
                 // Open Help popup
@@ -906,6 +1580,78 @@ loop {
                     app.popup = PopupType::Help;
                 }
 
+                // Open RPC call log popup
+                KeyCode::Char('r') if app.popup == PopupType::None => {
+                    app.popup = PopupType::RpcLog;
+                    app.rpc_log_scroll = 0;
+                }
+
+                // Open watch list popup
+                KeyCode::Char('w') if app.popup == PopupType::None => {
+                    app.popup = PopupType::WatchList;
+                    app.watch_input.clear();
+                }
+
+                // Export the mempool distribution to CSV
+                KeyCode::Char('e') if app.popup == PopupType::None => {
+                    let path = mempool_export_path();
+                    let message = match export_mempool_distribution_csv(&distribution, &path) {
+                        Ok(()) => format!("💾 Exported mempool distribution to {}", path.display()),
+                        Err(e) => {
+                            let _ = log_error(&format!("CSV export failed: {}", e));
+                            "⚠️ CSV export failed (see error log)".to_string()
+                        }
+                    };
+                    app.notice = Some((message, Instant::now()));
+                }
+
+                // JSON snapshot of all cached state (taken at the top of the next loop)
+                KeyCode::Char('j') if app.popup == PopupType::None => {
+                    app.snapshot_requested = true;
+                }
+
+                // Open peer list popup
+                KeyCode::Char('n') if app.popup == PopupType::None => {
+                    app.popup = PopupType::PeerList;
+                    app.peer_list_scroll = 0;
+                }
+
+                // Peer list scrolling (offset is clamped when rendered).
+                KeyCode::Up if app.popup == PopupType::PeerList => {
+                    app.peer_list_scroll = app.peer_list_scroll.saturating_sub(1);
+                }
+                KeyCode::Down if app.popup == PopupType::PeerList => {
+                    app.peer_list_scroll = app.peer_list_scroll.saturating_add(1);
+                }
+                KeyCode::PageUp if app.popup == PopupType::PeerList => {
+                    app.peer_list_scroll = app.peer_list_scroll.saturating_sub(PEER_LIST_PAGE);
+                }
+                KeyCode::PageDown if app.popup == PopupType::PeerList => {
+                    app.peer_list_scroll = app.peer_list_scroll.saturating_add(PEER_LIST_PAGE);
+                }
+                KeyCode::Enter if app.popup == PopupType::PeerList => {
+                    if let Some(peer) = peer_at(&peer_info, app.peer_list_scroll) {
+                        app.peer_detail_id = Some(peer.id);
+                        app.popup = PopupType::PeerDetail;
+                    }
+                }
+
+                // Scroll a Tx Lookup result (vin/vout lists can be long)
+                KeyCode::Up if app.popup == PopupType::TxLookup && app.tx_result.is_some() => {
+                    app.tx_lookup_scroll = app.tx_lookup_scroll.saturating_sub(1);
+                }
+                KeyCode::Down if app.popup == PopupType::TxLookup && app.tx_result.is_some() => {
+                    app.tx_lookup_scroll = app.tx_lookup_scroll.saturating_add(1);
+                }
+
+                // Scroll the RPC call log
+                KeyCode::Up if app.popup == PopupType::RpcLog => {
+                    app.rpc_log_scroll = app.rpc_log_scroll.saturating_sub(1);
+                }
+                KeyCode::Down if app.popup == PopupType::RpcLog => {
+                    app.rpc_log_scroll = app.rpc_log_scroll.saturating_add(1);
+                }
+
                 // Hashrate Distribution toggle
                 KeyCode::Char('h') if app.popup == PopupType::None && !app.show_last20_miners => {
                     app.show_hash_distribution = !app.show_hash_distribution;
@@ -916,27 +1662,45 @@ loop {
                     app.show_last20_miners = !app.show_last20_miners;
                 }
 
+                // Copy the Tx Lookup result to the system clipboard
+                KeyCode::Char('y') if app.popup == PopupType::TxLookup && app.tx_result.is_some() => {
+                    let text = app.tx_result.clone().unwrap_or_default();
+                    let message = match copy_to_clipboard(&mut app.clipboard, text) {
+                        Ok(()) => "📋 Copied lookup result to clipboard".to_string(),
+                        Err(e) => {
+                            let _ = log_event(LogLevel::Warn, &format!("Clipboard copy failed: {}", e));
+                            "⚠️ No clipboard available (headless session?)".to_string()
+                        }
+                    };
+                    app.notice = Some((message, Instant::now()));
+                }
+
                 // CHARACTER INPUT inside Tx Lookup popup
                 KeyCode::Char(c) if app.popup == PopupType::TxLookup => {
-                    if app.is_pasting {
-                        // Ignore weird control characters during paste
-                        if c != 'q' && c != '\n' {
-                            app.tx_input.push(c);
-                        }
-                    } else {
-                        app.tx_input.push(c);
-                    }
+                    app.tx_input.push(c);
+                }
 
-                    // Heuristic: if input suddenly becomes long, treat it as a paste event
-                    if !app.is_pasting && app.tx_input.len() > 10 {
-                        app.is_pasting = true;
-                    }
+                // CHARACTER INPUT inside watch list popup (txids are hex)
+                KeyCode::Char(c) if app.popup == PopupType::WatchList => {
+                    push_watch_input(&mut app.watch_input, [c]);
+                }
+
+                KeyCode::Backspace if app.popup == PopupType::WatchList => {
+                    app.watch_input.pop();
+                }
+
+                // Press Enter inside watch list popup → add txid
+                KeyCode::Enter if app.popup == PopupType::WatchList
+                    && is_valid_txid(app.watch_input.trim()) => {
+                    app.watch_list.add(&app.watch_input);
+                    app.watch_input.clear();
                 }
 
                 // Backspace logic inside Tx Lookup popup
                 KeyCode::Backspace if app.popup == PopupType::TxLookup => {
                     app.tx_input.pop();
-                    app.is_pasting = false;
+                    // Editing starts a new query, so `y` types again.
+                    app.tx_result = None;
                 }
 
                 // Press Enter inside Tx Lookup popup → run validation + RPC
@@ -951,13 +1715,21 @@ loop {
                                 |e| Some(format!("{}", e)),
                                 Some,
                             );
+                            app.tx_lookup_scroll = 0;
+                        } else if is_valid_address(trimmed) {
+                            let addr_result = fetch_address_info(&config, trimmed).await;
+
+                            app.tx_result = addr_result.map_or_else(
+                                |e| Some(format!("{}", e)),
+                                Some,
+                            );
+                            app.tx_lookup_scroll = 0;
                         } else {
                             app.tx_result = Some(
-                                "Invalid TxID. Please enter a 64-character hex string."
+                                "Invalid input. Enter a 64-character hex TxID or a Bitcoin address."
                                     .to_string()
                             );
                         }
-                        app.is_pasting = false;
                     }
                 }
 
@@ -967,6 +1739,11 @@ loop {
                     app.dust_free.store(!old, Ordering::Relaxed);
                 }
 
+                // Size Distribution: tx count vs. total vsize
+                KeyCode::Char('v') => {
+                    app.size_by_vsize = !app.size_by_vsize;
+                }
+
                 // Size toggles for mempool distribution
                 KeyCode::Char('1') => {
                     let cur = app.size_lens.load(Ordering::Relaxed);
@@ -995,287 +1772,463 @@ loop {
 
                 // Version <-> Client distribution toggle
                 KeyCode::Char('c') => {
-                    app.show_client_distribution = !app.show_client_distribution;
+                    app.distribution_view = app.distribution_view.next();
                 }
 
                  // Propagation sparkline <-> average toggle
                 KeyCode::Char('p') => {
                     app.show_propagation_avg = !app.show_propagation_avg;
                 }
-                // If a non-character key is pressed during paste, end paste mode.
-                _ => {
-                    if app.is_pasting {
-                        app.is_pasting = false;
-                    }
+
+                // Pause/resume auto-refresh; resuming polls right away
+                KeyCode::Char(' ') if app.popup == PopupType::None => {
+                    app.paused = !app.paused;
+                    set_refresh_paused(app.paused);
+                }
+
+                // Scroll mode toggle (fixed panels <-> single scrollable view)
+                KeyCode::Char('s') if app.popup == PopupType::None => {
+                    app.scroll_mode = !app.scroll_mode;
+                    app.scroll_offset = 0;
                 }
+
+                // Scroll mode navigation (offset is clamped at render time)
+                KeyCode::Up if app.scroll_mode && app.popup == PopupType::None => {
+                    app.scroll_offset = app.scroll_offset.saturating_sub(1);
+                }
+                KeyCode::Down if app.scroll_mode && app.popup == PopupType::None => {
+                    app.scroll_offset = app.scroll_offset.saturating_add(1);
+                }
+                KeyCode::PageUp if app.scroll_mode && app.popup == PopupType::None => {
+                    app.scroll_offset = app.scroll_offset.saturating_sub(SCROLL_PAGE);
+                }
+                KeyCode::PageDown if app.scroll_mode && app.popup == PopupType::None => {
+                    app.scroll_offset = app.scroll_offset.saturating_add(SCROLL_PAGE);
+                }
+                KeyCode::Home if app.scroll_mode && app.popup == PopupType::None => {
+                    app.scroll_offset = 0;
+                }
+                KeyCode::End if app.scroll_mode && app.popup == PopupType::None => {
+                    app.scroll_offset = u16::MAX;
+                }
+                _ => {}
+            }
+        }
+
+        // Bracketed paste: the whole clipboard arrives as one event, so a
+        // pasted txid or address lands in the input in one piece.
+        if let Event::Paste(text) = event {
+            match app.popup {
+                PopupType::TxLookup => {
+                    app.tx_input.push_str(&pasted_input(&text));
+                    // A new query, as with Backspace.
+                    app.tx_result = None;
+                }
+                PopupType::WatchList => {
+                    push_watch_input(&mut app.watch_input, pasted_input(&text).chars());
+                }
+                _ => {}
             }
         }
     }
 
 
+    // =============================================================================================
+    // MINIMUM SIZE GUARD
+    // =============================================================================================
+    //
+    // Below the minimum size no layout is readable; show a notice instead of a broken UI.
+    //
+    let screen = terminal.size()?;
+    if too_small(screen.width, screen.height) {
+        terminal.draw(|frame| render_too_small(frame))?;
+        continue;
+    }
+
     // =============================================================================================
     // MAIN RENDERING PASS — Draw All Dashboard Sections
     // =============================================================================================
+    //
+    //   • Fixed mode:  sections are drawn straight onto the real terminal.
+    //   • Scroll mode: sections are drawn into `virtual_terminal`, whose height is the
+    //                  `[layout]` total (or the screen, if taller), and only the window
+    //                  starting at `scroll_offset` is copied onto the real terminal.
+    //
+    if app.scroll_mode {
+        let view_height = config.layout.total_height().max(screen.height);
+        if virtual_terminal.size()? != Rect::new(0, 0, screen.width, view_height) {
+            virtual_terminal.backend_mut().resize(screen.width, view_height);
+        }
+        app.scroll_offset = app.scroll_offset.min(max_scroll_offset(view_height, screen.height));
+    }
 
-    terminal.draw(|frame| {
-        // Layout of the entire dashboard (vertical stacking)
-        let chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .margin(1)
-            .constraints(
-                [
-                    Constraint::Length(3),   // Header
-                    Constraint::Length(14),  // Blockchain
-                    Constraint::Length(24),  // Mempool
-                    Constraint::Max(16),     // Network
-                    Constraint::Length(7),   // Consensus Security
-                    Constraint::Length(1),   // Footer
-                ]
-                .as_ref(),
-            )
-            .split(frame.size());
+    // Fixed mode clips sections when the screen can't fit the configured layout.
+    let too_short = !app.scroll_mode && screen.height < config.layout.total_height();
+
+    // The dashboard sections, drawn onto whichever frame is current: the
+    // real terminal in fixed mode, the off-screen one in scroll mode. A
+    // macro rather than a closure, since the two frames differ in backend
+    // type and closures can't be generic.
+    macro_rules! draw_sections {
+        ($frame:ident) => {{
+        let frame = &mut *$frame;
+        // Layout of the entire dashboard (vertical stacking, `[layout]` heights)
+        let areas = dashboard_areas(frame.size(), &config.layout);
+        app.hotspots.clear();
 
         // -----------------------------------------------------------------------------------------
         // HEADER SECTION
         // -----------------------------------------------------------------------------------------
         {
             let header_block = Block::default().borders(Borders::NONE);
-            frame.render_widget(header_block, chunks[0]);
+            frame.render_widget(header_block, areas.header);
 
             let header_widget = render_header(percent, &app.hashphase_rates);
-            frame.render_widget(header_widget, chunks[0]);
+            frame.render_widget(header_widget, areas.header);
         }
 
         // -----------------------------------------------------------------------------------------
         // BLOCKCHAIN SECTION
         // -----------------------------------------------------------------------------------------
+        if let Some(area) = areas.blockchain {
+            // Build HRD toggle label
+            let hrd_label = if app.show_hash_distribution {
+                Span::styled(
+                    "[H] HRD",
+                    Style::default().fg(C_KEYTOGGLE_HIGHLIGHT).add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Span::styled("[H] HRD", Style::default().fg(*C_KEYTOGGLE_DIM))
+            };
 
-        // Build HRD toggle label
-        let hrd_label = if app.show_hash_distribution {
-            Span::styled(
-                "[H] HRD",
-                Style::default().fg(C_KEYTOGGLE_HIGHLIGHT).add_modifier(Modifier::BOLD),
-            )
-        } else {
-            Span::styled("[H] HRD", Style::default().fg(C_KEYTOGGLE_DIM))
-        };
+            // Build Last20 toggle label
+            let last20_label = if app.show_last20_miners {
+                Span::styled(
+                    "[L] 20",
+                    Style::default().fg(C_KEYTOGGLE_HIGHLIGHT).add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Span::styled("[L] 20", Style::default().fg(*C_KEYTOGGLE_DIM))
+            };
 
-        // Build Last20 toggle label
-        let last20_label = if app.show_last20_miners {
-            Span::styled(
-                "[L] 20",
-                Style::default().fg(C_KEYTOGGLE_HIGHLIGHT).add_modifier(Modifier::BOLD),
-            )
-        } else {
-            Span::styled("[L] 20", Style::default().fg(C_KEYTOGGLE_DIM))
-        };
+            // Full title for Blockchain block
+            let blockchain_title = Spans::from(vec![
+                Span::styled(
+                    "[Blockchain] ",
+                    Style::default()
+                        .fg(*C_SECTION_LABELS)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                hrd_label,
+                Span::raw(" "), // spacing
+                last20_label,
+            ]);
 
-        // Full title for Blockchain block
-        let blockchain_title = Spans::from(vec![
-            Span::styled(
-                "[Blockchain] ",
-                Style::default()
-                    .fg(C_SECTION_LABELS)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            hrd_label,
-            Span::raw(" "), // spacing
-            last20_label,
-        ]);
-
-        let block_blockchain = Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(C_BLOCKCHAIN_BORDER))
-            .border_type(BorderType::Rounded)
-            .title(blockchain_title);
-
-        frame.render_widget(block_blockchain, chunks[1]);
-
-        // Choose between HRD chart OR normal blockchain info
-        if app.show_hash_distribution {
-            render_hashrate_distribution_chart(&hash_distribution, frame, chunks[1]);
-        
-        } else if app.show_last20_miners {
-            // assuming you already computed rows in runapp and have them available here
-            // e.g., `last20_rows: &[(u64, Option<Arc<str>>)]`
-            draw_last20_miners(frame, chunks[1], &app.last20_miners);
-        
-        } else {
-            if !block_info.is_empty() && !block24_info.is_empty() {
-                let latest_block = &block_info[block_info.len() - 1];
-                let block24 = &block24_info[block24_info.len() - 1];
-        
-                display_blockchain_info(
-                    &blockchain_info,
-                    latest_block,
-                    block24,
-                    last_miner_ref,
+            let block_blockchain = Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(*C_BLOCKCHAIN_BORDER))
+                .border_type(BorderType::Rounded)
+                .title(blockchain_title.clone());
+
+            app.hotspots.extend(title_hotspots(area, blockchain_title.0.iter().map(|s| s.content.as_ref())));
+
+            frame.render_widget(block_blockchain, area);
+
+            // Choose between HRD chart OR normal blockchain info
+            if app.show_hash_distribution {
+                render_hashrate_distribution_chart(
+                    &hash_distribution,
+                    blockchain_info.initialblockdownload,
                     frame,
-                    chunks[1],
+                    area,
                 );
+
+            } else if app.show_last20_miners {
+                // assuming you already computed rows in runapp and have them available here
+                // e.g., `last20_rows: &[(u64, Option<Arc<str>>)]`
+                draw_last20_miners(frame, area, &app.last20_miners);
+
+            } else {
+                // On a pruned node the 24h-ago block may never arrive; the
+                // 24h projection then shows "N/A (pruned)" instead.
+                let block24_pruned = BLOCK24_PRUNED.load(Ordering::Relaxed);
+                if !block_info.is_empty() && (!block24_info.is_empty() || block24_pruned) {
+                    let latest_block = &block_info[block_info.len() - 1];
+                    let no_block24 = BlockInfo::default();
+                    let block24 = block24_info.last().unwrap_or(&no_block24);
+
+                    display_blockchain_info(
+                        &blockchain_info,
+                        latest_block,
+                        block24,
+                        last_miner_ref,
+                        &mining_info,
+                        &last_block_stats,
+                        &witness_adoption,
+                        &coinbase_reward,
+                        &chain_tx_stats,
+                        &block_intervals,
+                        &app.disk_growth,
+                        config.size_units,
+                        app.stale_tip,
+                        frame,
+                        area,
+                    );
+                }
             }
         }
-        
 
         // -----------------------------------------------------------------------------------------
         // MEMPOOL SECTION
         // -----------------------------------------------------------------------------------------
+        if let Some(area) = areas.mempool {
+            // Dust-free toggle label
+            let dust_label = if app.dust_free.load(Ordering::Relaxed) {
+                Span::styled(
+                    " [D] DUST-FREE",
+                    Style::default()
+                        .fg(C_KEYTOGGLE_HIGHLIGHT)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Span::styled(" [D] ALL TX", Style::default().fg(*C_KEYTOGGLE_DIM))
+            };
 
-        // Dust-free toggle label
-        let dust_label = if app.dust_free.load(Ordering::Relaxed) {
-            Span::styled(
-                " [D] DUST-FREE",
-                Style::default()
-                    .fg(C_KEYTOGGLE_HIGHLIGHT)
-                    .add_modifier(Modifier::BOLD),
-            )
-        } else {
-            Span::styled(" [D] ALL TX", Style::default().fg(C_KEYTOGGLE_DIM))
-        };
+            let size = app.size_lens.load(Ordering::Relaxed);
 
-        let size = app.size_lens.load(Ordering::Relaxed);
+            let s1 = if size == 1 {
+                Span::styled(" [1] S", Style::default().fg(C_KEYTOGGLE_HIGHLIGHT).add_modifier(Modifier::BOLD))
+            } else {
+                Span::styled(" [1] S", Style::default().fg(*C_KEYTOGGLE_DIM))
+            };
 
-        let s1 = if size == 1 {
-            Span::styled(" [1] S", Style::default().fg(C_KEYTOGGLE_HIGHLIGHT).add_modifier(Modifier::BOLD))
-        } else {
-            Span::styled(" [1] S", Style::default().fg(C_KEYTOGGLE_DIM))
-        };
+            let s2 = if size == 2 {
+                Span::styled(" [2] M", Style::default().fg(C_KEYTOGGLE_HIGHLIGHT).add_modifier(Modifier::BOLD))
+            } else {
+                Span::styled(" [2] M", Style::default().fg(*C_KEYTOGGLE_DIM))
+            };
 
-        let s2 = if size == 2 {
-            Span::styled(" [2] M", Style::default().fg(C_KEYTOGGLE_HIGHLIGHT).add_modifier(Modifier::BOLD))
-        } else {
-            Span::styled(" [2] M", Style::default().fg(C_KEYTOGGLE_DIM))
-        };
+            let s3 = if size == 3 {
+                Span::styled(" [3] L", Style::default().fg(C_KEYTOGGLE_HIGHLIGHT).add_modifier(Modifier::BOLD))
+            } else {
+                Span::styled(" [3] L", Style::default().fg(*C_KEYTOGGLE_DIM))
+            };
 
-        let s3 = if size == 3 {
-            Span::styled(" [3] L", Style::default().fg(C_KEYTOGGLE_HIGHLIGHT).add_modifier(Modifier::BOLD))
-        } else {
-            Span::styled(" [3] L", Style::default().fg(C_KEYTOGGLE_DIM))
-        };
+            let vsize_label = if app.size_by_vsize {
+                Span::styled(" [V] VSIZE", Style::default().fg(C_KEYTOGGLE_HIGHLIGHT).add_modifier(Modifier::BOLD))
+            } else {
+                Span::styled(" [V] COUNT", Style::default().fg(*C_KEYTOGGLE_DIM))
+            };
 
-        let mempool_title = Spans(vec![
-            Span::styled(
-                "[Mempool]",
-                Style::default()
-                    .fg(C_SECTION_LABELS)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            dust_label,
-            s1, s2, s3
-        ]);
-
-        let block_mempool = Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(C_MEMPOOL_BORDER))
-            .border_type(BorderType::Rounded)
-            .title(mempool_title);
-
-        frame.render_widget(block_mempool, chunks[2]);
-
-        display_mempool_info(
-            &mempool_info,
-            &distribution,
-            app.dust_free.load(Ordering::Relaxed),
-            frame,
-            chunks[2],
-        );
+            let mempool_title = Spans(vec![
+                Span::styled(
+                    "[Mempool]",
+                    Style::default()
+                        .fg(*C_SECTION_LABELS)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                dust_label,
+                s1, s2, s3,
+                vsize_label,
+            ]);
+
+            let block_mempool = Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(*C_MEMPOOL_BORDER))
+                .border_type(BorderType::Rounded)
+                .title(mempool_title.clone());
+
+            app.hotspots.extend(title_hotspots(area, mempool_title.0.iter().map(|s| s.content.as_ref())));
+
+            frame.render_widget(block_mempool, area);
+
+            display_mempool_info(
+                &mempool_info,
+                &distribution,
+                app.dust_free.load(Ordering::Relaxed),
+                app.size_by_vsize,
+                &fee_rate_trend,
+                &fee_estimates,
+                config.size_units,
+                frame,
+                area,
+            );
+        }
 
         // -----------------------------------------------------------------------------------------
         // NETWORK SECTION
         // -----------------------------------------------------------------------------------------
+        if let Some(area) = areas.network {
+            // Label describing what pressing 'c' will switch TO
+            let cv_label = format!("(c→{})", app.distribution_view.next().label());
 
-        // Label describing what pressing 'c' will toggle TO
-        let cv_label = if app.show_client_distribution {
-            "(c→Version)"
-        } else {
-            "(c→Client)"
-        };
-        
-        // Label describing what pressing 'p' will toggle TO
-        let prop_label = if app.show_propagation_avg {
-            "(p→Spark)"
-        } else {
-            "(p→Avg)"
-        };
+            // Label describing what pressing 'p' will toggle TO
+            let prop_label = if app.show_propagation_avg {
+                "(p→Spark)"
+            } else {
+                "(p→Avg)"
+            };
 
-        // If node is absent populate with micro-glyph for Network title header.
-        let network_absence = if network_info.connections_out == 0 &&
-            network_info.connections_in == 0 {
-                Some("∅")
-        } else {
-                None
-        };
+            // If node is absent populate with micro-glyph for Network title header.
+            let network_absence = if network_info.connections_out == 0 &&
+                network_info.connections_in == 0 {
+                    Some("∅")
+            } else {
+                    None
+            };
 
-        let title = match network_absence {
-            Some(glyph) => format!("[Network] {} {}  {}", cv_label, prop_label, glyph),
-            None => format!("[Network] {} {}", cv_label, prop_label),
-        };
+            // Node uptime, when the `uptime` RPC is available.
+            let uptime_label = uptime
+                .map(|secs| format!("  ⏱ up {}", format_uptime(secs)))
+                .unwrap_or_default();
 
-        let block_network = Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(C_NETWORK_BORDER))
-            .border_type(BorderType::Rounded)
-            .title(
-                Span::styled(
-                    title,
-                    Style::default()
-                        .fg(C_SECTION_LABELS)
-                        .add_modifier(Modifier::BOLD),
-                )
+            let title = match network_absence {
+                Some(glyph) => format!("[Network] {} {}{}  {}", cv_label, prop_label, uptime_label, glyph),
+                None => format!("[Network] {} {}{}", cv_label, prop_label, uptime_label),
+            };
+
+            let block_network = Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(*C_NETWORK_BORDER))
+                .border_type(BorderType::Rounded)
+                .title(
+                    Span::styled(
+                        title,
+                        Style::default()
+                            .fg(*C_SECTION_LABELS)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                );
+
+            frame.render_widget(block_network, area);
+
+            app.hotspots.extend(title_hotspots(area, ["[Network] ", cv_label.as_str(), " ", prop_label]));
+
+            // Pass both version and client arrays. UI chooses based on toggle.
+            display_network_info(
+                &network_info,
+                &net_totals,
+                frame,
+                NetworkView {
+                    version_counts: &version_counts,
+                    client_counts: &client_counts,
+                    protocol_counts: &protocol_counts,
+                    network_counts: &network_counts,
+                    hb_compact_peers,
+                    ping_summary,
+                    connection_churn: &app.connection_churn,
+                },
+                &avg_block_propagate_time,
+                &propagation_times,
+                config.propagation_unit,
+                config.size_units,
+                &node_warnings,
+                blocks_behind,
+                clock_skew,
+                app.distribution_view,
+                app.show_propagation_avg,
+                area,
             );
+        }
 
-        frame.render_widget(block_network, chunks[3]);
-
-        // Pass both version and client arrays. UI chooses based on toggle.
-        display_network_info(
-            &network_info,
-            &net_totals,
-            frame,
-            &version_counts,
-            &client_counts,
-            &avg_block_propagate_time,
-            &propagation_times,
-            app.show_client_distribution,
-            app.show_propagation_avg,
-            chunks[3],
-        );
         // -----------------------------------------------------------------------------------------
         // CONSENSUS SECURITY SECTION
         // -----------------------------------------------------------------------------------------
-        {
+        if let Some(area) = areas.consensus {
+            // Flash red (1s on, 1s off) while a fork is over the alert threshold.
+            let consensus_border = if app.fork_alert.is_some() && chrono::Utc::now().timestamp() % 2 == 0 {
+                *C_STATUS_HIGH
+            } else {
+                *C_CONSENSUS_BORDER
+            };
+
             let consensus_block = Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(C_CONSENSUS_BORDER))
+                .border_style(Style::default().fg(consensus_border))
                 .border_type(BorderType::Rounded)
                 .title(
                     Span::styled(
                         "[Consensus Security]",
                         Style::default()
-                            .fg(C_SECTION_LABELS)
+                            .fg(*C_SECTION_LABELS)
                             .add_modifier(Modifier::BOLD),
                     )
                 );
 
-            frame.render_widget(consensus_block, chunks[4]);
+            frame.render_widget(consensus_block, area);
 
             // Displays fork info, stale tips, etc.
-            display_consensus_security_info(&chaintips_result, frame, chunks[4]);
+            display_consensus_security_info(
+                &chaintips_result,
+                deployment_info.as_ref(),
+                frame,
+                area,
+            );
         }
 
         // -----------------------------------------------------------------------------------------
         // FOOTER SECTION
         // -----------------------------------------------------------------------------------------
         {
+            // Stale numbers must not look live: the unreachable banner
+            // outranks notices and the key hints.
+            let unreachable = !app.is_exiting && !node_reachable();
             let footer_msg = if app.is_exiting {
-                "Shutting Down Cleanly..."
+                "Shutting Down Cleanly...".to_string()
+            } else if unreachable {
+                "⚠ Node unreachable — reconnecting…".to_string()
+            } else if let Some((notice, _)) = app
+                .notice
+                .as_ref()
+                .filter(|(_, at)| at.elapsed() < NOTICE_DURATION)
+            {
+                notice.clone()
             } else {
-                "Press 'q' to quit | 't' for Tx Lookup | '?' for Help"
+                let hints = if too_short {
+                    // Sections are clipped; say why and how to get them back.
+                    format!(
+                        "⚠ Terminal too short ({} of {} rows) | 's' to scroll, or hide sections in [layout]",
+                        screen.height,
+                        config.layout.total_height()
+                    )
+                } else if config.read_only {
+                    // Safe mode: make it obvious that state-changing RPCs are disabled.
+                    "🔒 READ-ONLY | Press 'q' to quit | 't' for Tx Lookup | '?' for Help".to_string()
+                } else {
+                    "Press 'q' to quit | 't' for Tx Lookup | '?' for Help".to_string()
+                };
+                // Frozen numbers must not pass for live ones either.
+                if app.paused {
+                    format!("⏸ PAUSED (space to resume) | {}", hints)
+                } else {
+                    hints
+                }
             };
 
             let footer_block = Block::default().borders(Borders::NONE);
-            frame.render_widget(footer_block, chunks[5]);
+            frame.render_widget(footer_block, areas.footer);
 
-            render_footer(frame, chunks[5], footer_msg);
+            if unreachable {
+                render_footer_alert(frame, areas.footer, &footer_msg);
+            } else {
+                render_footer(frame, areas.footer, &footer_msg);
+            }
+        }
+        }};
+    }
+
+    if app.scroll_mode {
+        virtual_terminal.draw(|frame| draw_sections!(frame))?;
+    }
+
+    terminal.draw(|frame| {
+        if app.scroll_mode {
+            frame.render_widget(
+                ScrollView::new(virtual_terminal.backend().buffer(), app.scroll_offset),
+                frame.size(),
+            );
+        } else {
+            draw_sections!(frame);
         }
 
         // =========================================================================================
@@ -1296,13 +2249,39 @@ loop {
             PopupType::ConsensusWarning => {
                 render_consensus_warning_popup(frame, &app);
             }
+
+            PopupType::RpcLog => {
+                render_rpc_log_popup(frame, &mut app);
+            }
+
+            PopupType::WatchList => {
+                render_watch_list_popup(frame, &app);
+            }
+
+            PopupType::PeerList => {
+                let area = centered_rect(90, 70, frame.size());
+                display_peer_list(&peer_info, geoip.as_ref(), &mut app.peer_list_scroll, frame, area);
+            }
+
+            PopupType::PeerDetail => {
+                let area = centered_rect(70, 70, frame.size());
+                let peer = peer_info.iter().find(|p| Some(p.id) == app.peer_detail_id);
+                display_peer_detail(peer, config.size_units, frame, area);
+            }
         }
 
+        // Map the finished frame down to what the terminal can display.
+        frame.render_widget(PaletteFilter::new(color_support()), frame.size());
+
     })?; // END terminal.draw()
 
 } // END main loop
 
-// Exit gracefully
+// Exit gracefully, keeping the miner window for the next launch.
+if let Err(e) = BLOCK_HISTORY.read().await.save(&block_history_path()) {
+    let _ = log_error(&format!("Saving block history failed: {}", e));
+}
+
 Ok(())
 } // END run_app
 
@@ -1338,29 +2317,123 @@ fn centered_rect(percent_x: u16, percent_y: u16, size: Rect) -> Rect {
 
 
 // =================================================================================================
-// HELPER: TXID VALIDATION
+// STARTUP: INITIALIZING SCREEN
+// =================================================================================================
+/// Draws the startup screen with a ✓ / … marker per data section.
+fn render_init_screen<B: Backend>(frame: &mut Frame<B>, readiness: &[(&str, bool)]) {
+    let mut lines = vec![Spans::from("")];
+
+    for (section, ready) in readiness {
+        let (mark, color) = if *ready { ("✓", C_STATUS_LOW) } else { ("…", *C_STATUS_MED) };
+        lines.push(Spans::from(vec![
+            Span::styled(format!("  {:<12}", section), Style::default().fg(*C_MAIN_LABELS)),
+            Span::styled(mark, Style::default().fg(color)),
+        ]));
+    }
+
+    if !readiness.is_empty() {
+        lines.push(Spans::from(""));
+        lines.push(Spans::from(Span::styled(
+            "  Waiting for node data… press any key to continue, q to quit",
+            Style::default().fg(*C_SEPARATORS),
+        )));
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default().title("Initializing...").borders(Borders::ALL));
+    frame.render_widget(paragraph, frame.size());
+}
+
+
+
+// =================================================================================================
+// HELPER: TXID / ADDRESS VALIDATION
 // =================================================================================================
 /// Verifies a string is a valid 64-character hex TxID.
 fn is_valid_txid(tx_id: &str) -> bool {
     tx_id.len() == 64 && tx_id.chars().all(|c| c.is_ascii_hexdigit())
 }
 
+/// Syntactic check for a Bitcoin address (bech32/bech32m or base58).
+///
+/// Checks prefix, alphabet and length only; checksums and the network are
+/// left to the node, which rejects anything this lets through.
+fn is_valid_address(addr: &str) -> bool {
+    const BECH32_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+    const BASE58_CHARSET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+    // Bech32 is case-insensitive but must not mix cases.
+    let lower = addr.to_ascii_lowercase();
+    for hrp in ["bc1", "tb1", "bcrt1"] {
+        if let Some(data) = lower.strip_prefix(hrp) {
+            return (addr == lower || addr == addr.to_ascii_uppercase())
+                && (14..=90).contains(&lower.len())
+                && data.len() >= 6
+                && data.chars().all(|c| BECH32_CHARSET.contains(c));
+        }
+    }
+
+    (26..=35).contains(&addr.len())
+        && addr.starts_with(['1', '3', 'm', 'n', '2'])
+        && addr.chars().all(|c| BASE58_CHARSET.contains(c))
+}
+
+
+
+// =================================================================================================
+// HELPER: CLIPBOARD
+// =================================================================================================
+/// Copies text to the system clipboard, opening it on first use.
+///
+/// Fails (instead of panicking) when there is no clipboard, e.g. on a
+/// headless Linux box without X11/Wayland. The handle is dropped on
+/// failure so the next attempt retries from scratch.
+fn copy_to_clipboard(clipboard: &mut Option<Clipboard>, text: String) -> Result<(), arboard::Error> {
+    if clipboard.is_none() {
+        *clipboard = Some(Clipboard::new()?);
+    }
+    let result = clipboard
+        .as_mut()
+        .map_or(Err(arboard::Error::ClipboardNotSupported), |cb| cb.set_text(text));
+    if result.is_err() {
+        *clipboard = None;
+    }
+    result
+}
+
 
 
 // =================================================================================================
 // POPUP: TX LOOKUP
 // =================================================================================================
 /// Renders the Transaction Lookup popup overlay.
-/// Allows typed or pasted TxID, validates it, and displays RPC result.
+/// Allows typed or pasted TxID or address, validates it, and displays RPC result.
 fn render_tx_lookup_popup<B: Backend>(frame: &mut Frame<B>, app: &mut App) {
-    let popup_area = centered_rect(80, 28, frame.size());
+    let popup_area = centered_rect(80, 60, frame.size());
+    let result_area = popup_area.inner(&Margin { vertical: 5, horizontal: 2 });
 
     // Clear under-popup area so text doesn't bleed through
     frame.render_widget(Clear, popup_area);
 
+    // Keep the scroll offset within the (wrapped) result text.
+    if let Some(tx) = &app.tx_result {
+        let width = result_area.width.max(1) as usize;
+        let rendered: usize = tx
+            .lines()
+            .map(|line| line.chars().count().max(1).div_ceil(width))
+            .sum();
+        let max_scroll = (rendered as u16).saturating_sub(result_area.height);
+        app.tx_lookup_scroll = app.tx_lookup_scroll.min(max_scroll);
+    }
+
     // Outer popup block
+    let title = if app.tx_result.is_some() {
+        "Transaction Lookup (↑/↓ scroll, y copy, Esc to go back)"
+    } else {
+        "Transaction Lookup (Press Esc to go back)"
+    };
     let popup = Block::default()
-        .title("Transaction Lookup (Press Esc to go back)")
+        .title(title)
         .borders(Borders::ALL)
         .style(Style::default().fg(Color::Yellow));
 
@@ -1373,13 +2446,15 @@ fn render_tx_lookup_popup<B: Backend>(frame: &mut Frame<B>, app: &mut App) {
     let result = match &app.tx_result {
         Some(tx) => Paragraph::new(tx.clone())
             .style(Style::default().fg(C_TX_LOOKUP_TXT))
-            .wrap(Wrap { trim: true }),
+            // Keep the indentation of the vin/vout and relatives lists.
+            .wrap(Wrap { trim: false })
+            .scroll((app.tx_lookup_scroll, 0)),
 
         None => {
             if app.tx_input.trim().is_empty() {
-                Paragraph::new("Enter a TxID and press Enter")
+                Paragraph::new("Enter a TxID or address and press Enter")
             } else {
-                Paragraph::new("Press Enter to validate TxID")
+                Paragraph::new("Press Enter to look it up")
                     .style(Style::default().fg(Color::Yellow))
             }
         }
@@ -1391,10 +2466,73 @@ fn render_tx_lookup_popup<B: Backend>(frame: &mut Frame<B>, app: &mut App) {
         input,
         popup_area.inner(&Margin { vertical: 2, horizontal: 2 }),
     );
-    frame.render_widget(
-        result,
-        popup_area.inner(&Margin { vertical: 5, horizontal: 2 }),
+    frame.render_widget(result, result_area);
+}
+
+
+
+// =================================================================================================
+// PASTE
+// =================================================================================================
+/// Pasted text as input: surrounding whitespace (a copied trailing newline)
+/// and control characters dropped.
+fn pasted_input(text: &str) -> String {
+    text.trim().chars().filter(|c| !c.is_control()).collect()
+}
+
+/// Appends the hex digits of `chars` to the watch list input, up to a
+/// txid's 64 characters.
+fn push_watch_input(input: &mut String, chars: impl IntoIterator<Item = char>) {
+    let room = 64usize.saturating_sub(input.len());
+    input.extend(chars.into_iter().filter(|c| c.is_ascii_hexdigit()).take(room));
+}
+
+
+
+// =================================================================================================
+// MOUSE
+// =================================================================================================
+/// Key a mouse event stands for, if any.
+///
+/// The wheel scrolls like ↑/↓ (popups, scroll mode). A left click on a
+/// section-title toggle presses its key; only on the dashboard, so clicks
+/// never type into a popup's input.
+fn mouse_key(app: &App, mouse: MouseEvent) -> Option<KeyCode> {
+    match mouse.kind {
+        MouseEventKind::ScrollUp => Some(KeyCode::Up),
+        MouseEventKind::ScrollDown => Some(KeyCode::Down),
+        MouseEventKind::Down(MouseButton::Left) if app.popup == PopupType::None => {
+            // Hotspots are in dashboard rows; the screen shows them from `scroll_offset` on.
+            let row = mouse.row.saturating_add(app.scroll_offset);
+            hotspot_at(&app.hotspots, mouse.column, row).map(KeyCode::Char)
+        }
+        _ => None,
+    }
+}
+
+
+
+// =================================================================================================
+// TERMINAL TOO SMALL
+// =================================================================================================
+/// Replaces the dashboard while the terminal is below the minimum size.
+fn render_too_small<B: Backend>(frame: &mut Frame<B>) {
+    let size = frame.size();
+    let text = format!(
+        "Terminal too small ({}x{})\nNeed at least {}x{}\n\nPress 'q' to quit",
+        size.width, size.height, MIN_TERMINAL_WIDTH, MIN_TERMINAL_HEIGHT
     );
+
+    // Vertically centered over the 4 text rows.
+    let top = size.height.saturating_sub(4) / 2;
+    let area = Rect::new(size.x, size.y + top, size.width, size.height - top);
+
+    let paragraph = Paragraph::new(text)
+        .alignment(Alignment::Center)
+        .style(Style::default().fg(*C_STATUS_MED))
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(paragraph, area);
 }
 
 
@@ -1413,7 +2551,14 @@ fn render_help_popup<B: Backend>(frame: &mut Frame<B>, _app: &App) {
         " GLOBAL CONTROLS",
         " ─────────────────────────",
         "  Q     Quit application",
-        "  T     Transaction lookup",
+        "  T     Transaction / address lookup (↑/↓ scroll, Y copy the result)",
+        "  S     Scroll mode (↑/↓, PgUp/PgDn)",
+        "  SPACE Pause / resume auto-refresh",
+        "  R     RPC call log",
+        "  W     Watch list (track txids)",
+        "  E     Export mempool distribution (CSV)",
+        "  J     Snapshot dashboard state (JSON)",
+        "  N     Peer list (↑/↓, PgUp/PgDn, Enter for details)",
         "  ESC   Close panels",
         "",
         " DASHBOARD SECTIONS",
@@ -1448,19 +2593,138 @@ fn render_help_popup<B: Backend>(frame: &mut Frame<B>, _app: &App) {
 
 
 
+// =================================================================================================
+// POPUP: RPC CALL LOG
+// =================================================================================================
+/// Draws the most recent RPC calls (newest first): time, method, duration, outcome.
+///
+/// The list comes from the bounded ring buffer in `rpc::call_log`; ↑/↓ scroll it.
+fn render_rpc_log_popup<B: Backend>(frame: &mut Frame<B>, app: &mut App) {
+    let popup_area = centered_rect(80, 50, frame.size());
+    frame.render_widget(Clear, popup_area);
+
+    let calls = recent_calls();
+
+    let lines: Vec<Spans> = if calls.is_empty() {
+        vec![Spans::from("No RPC calls recorded yet.")]
+    } else {
+        calls
+            .iter()
+            .map(|call| {
+                let (status, color) = match &call.error {
+                    None => ("OK".to_string(), C_STATUS_LOW),
+                    Some(e) => (format!("ERR {}", e), *C_STATUS_HIGH),
+                };
+
+                Spans::from(vec![
+                    Span::styled(
+                        format!("{} ", call.at.format("%H:%M:%S")),
+                        Style::default().fg(*C_SEPARATORS),
+                    ),
+                    Span::styled(
+                        format!("{:<24}", call.method),
+                        Style::default().fg(*C_MAIN_LABELS),
+                    ),
+                    Span::styled(
+                        format!("{:>7} ms  ", call.duration.as_millis()),
+                        Style::default().fg(*C_MAIN_LABELS),
+                    ),
+                    Span::styled(status, Style::default().fg(color)),
+                ])
+            })
+            .collect()
+    };
+
+    // Keep the scroll offset within the list.
+    let visible = popup_area.height.saturating_sub(2);
+    let max_scroll = (lines.len() as u16).saturating_sub(visible);
+    app.rpc_log_scroll = app.rpc_log_scroll.min(max_scroll);
+
+    let block = Block::default()
+        .title(format!(
+            "RPC Call Log — last {} calls (↑/↓ scroll, Esc to go back)",
+            calls.len()
+        ))
+        .borders(Borders::ALL)
+        .style(Style::default().fg(Color::Yellow));
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((app.rpc_log_scroll, 0));
+
+    frame.render_widget(paragraph, popup_area);
+}
+
+// =================================================================================================
+// POPUP: WATCH LIST
+// =================================================================================================
+/// Lists watched txids with their status, plus an input line for adding more.
+///
+/// Txids added here last for the session; put permanent ones in `watch_txids`.
+fn render_watch_list_popup<B: Backend>(frame: &mut Frame<B>, app: &App) {
+    let popup_area = centered_rect(80, 40, frame.size());
+    frame.render_widget(Clear, popup_area);
+
+    let mut lines = vec![
+        Spans::from(vec![
+            Span::styled("Add TxID: ", Style::default().fg(*C_MAIN_LABELS)),
+            Span::styled(app.watch_input.clone(), Style::default().fg(Color::Cyan)),
+        ]),
+        Spans::from(""),
+    ];
+
+    if app.watch_list.is_empty() {
+        lines.push(Spans::from("No transactions watched. Type a TxID and press Enter."));
+    } else {
+        for entry in app.watch_list.entries() {
+            let color = match entry.status {
+                WatchStatus::Waiting => *C_SEPARATORS,
+                WatchStatus::InMempool => *C_STATUS_MED,
+                WatchStatus::LeftMempool => *C_STATUS_HIGH,
+                WatchStatus::Confirmed { .. } => C_STATUS_LOW,
+            };
+
+            lines.push(Spans::from(vec![
+                Span::styled(format!("{}  ", short_txid(&entry.txid)), Style::default().fg(*C_MAIN_LABELS)),
+                Span::styled(entry.status.label(), Style::default().fg(color)),
+            ]));
+        }
+    }
+
+    let block = Block::default()
+        .title(format!(
+            "Watch List — {} tx (Enter to add, Esc to go back)",
+            app.watch_list.entries().len()
+        ))
+        .borders(Borders::ALL)
+        .style(Style::default().fg(Color::Yellow));
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(paragraph, popup_area);
+}
+
 // =================================================================================================
 // POPUP: CONSENSUS WARNING
 // =================================================================================================
-/// Alerts the user when a stale fork of length ≥ 2 is detected.
-fn render_consensus_warning_popup<B: Backend>(frame: &mut Frame<B>, _app: &App) {
+/// Alerts the user when a valid fork of at least `fork_alert_branchlen`
+/// blocks is detected.
+fn render_consensus_warning_popup<B: Backend>(frame: &mut Frame<B>, app: &App) {
     let popup_area = centered_rect(70, 20, frame.size());
     frame.render_widget(Clear, popup_area);
 
+    let reached = format!(
+        " A valid fork has reached length {}.",
+        app.fork_alert.unwrap_or_default()
+    );
+
     let warning_text = vec![
         "",
         " CONSENSUS WARNING",
         " ─────────────────────────",
-        " A stale fork has reached length 2.",
+        reached.as_str(),
         "",
         " This is unusual and may indicate:",
         "  • network propagation delay",
@@ -1488,6 +2752,36 @@ fn render_consensus_warning_popup<B: Backend>(frame: &mut Frame<B>, _app: &App)
 }
 
 
+/// Headers from the new tip back to where it joins `tracker`'s chain, as
+/// `(height, hash)` pairs, lowest first.
+///
+/// Stops after `REORG_TRACK_BLOCKS` headers, so a tip far ahead (e.g. after
+/// a long sleep or during IBD) costs a bounded number of RPCs.
+async fn walk_new_branch(
+    config: &RpcConfig,
+    tracker: &ReorgTracker,
+    tip_hash: &str,
+) -> Result<Vec<(u64, String)>, MyError> {
+    let mut header = fetch_block_header(config, tip_hash).await?;
+    let mut branch = Vec::new();
+
+    loop {
+        let prev = header.previousblockhash.take();
+        let needs_parent = tracker.needs_parent(header.height, prev.as_deref());
+        branch.push((header.height, header.hash));
+
+        match prev {
+            Some(prev) if needs_parent && branch.len() < REORG_TRACK_BLOCKS => {
+                header = fetch_block_header(config, &prev).await?;
+            }
+            _ => break,
+        }
+    }
+
+    branch.reverse();
+    Ok(branch)
+}
+
 fn phase_index(blocks_into_epoch: u64) -> Option<u8> {
     if blocks_into_epoch >= 2015 {
         Some(4) // final block before reset
@@ -1 +1,13 @@
-pub mod colors;
\ No newline at end of file
+pub mod colors;
+
+/// Terminal color capability detection and palette fallback.
+pub mod color_support;
+
+/// Off-screen rendering window used by scroll mode.
+pub mod scroll_view;
+
+/// Dashboard section areas from the `[layout]` config.
+pub mod layout;
+
+/// Clickable section-title toggles.
+pub mod mouse;
@@ -0,0 +1,239 @@
+// src/ui/color_support.rs
+//
+// Terminal color capability detection.
+//
+// The palette in `colors.rs` assumes a modern terminal. Some colors are
+// 24-bit (`Color::Rgb`), which a 16- or 256-color terminal renders as
+// garbage or not at all. At startup we detect what the terminal supports
+// and, when it is limited, every rendered cell is mapped down to the
+// closest color the terminal can actually show.
+//
+// Mono mode strips every color but keeps modifiers (bold, italic,
+// reversed), so emphasis survives in logged sessions and for users who
+// can't rely on color. Flashing values switch to a modifier instead of a
+// color change (see `flash_style`).
+//
+// Detection order:
+// 0. `--mono` flag or a non-empty `NO_COLOR` (https://no-color.org) → mono
+// 1. `BCI_COLOR` override: `truecolor`, `256`, `16` or `mono`
+// 2. `COLORTERM=truecolor|24bit`
+// 3. `TERM` (`dumb` → mono, `*-256color` → 256, `*-direct` → truecolor)
+
+use std::env;
+use std::sync::OnceLock;
+
+use tui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::Widget,
+};
+
+/// Color depth supported by the terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSupport {
+    /// 24-bit RGB — the palette is used as-is.
+    TrueColor,
+    /// xterm 256-color palette — RGB colors map to the 6×6×6 cube / gray ramp.
+    Ansi256,
+    /// The 16 standard ANSI colors — RGB colors map to the nearest one.
+    Basic,
+    /// No color — everything renders in the terminal's default colors.
+    Mono,
+}
+
+static COLOR_SUPPORT: OnceLock<ColorSupport> = OnceLock::new();
+
+/// Detects the terminal's color support once and stores it.
+///
+/// Call before the terminal enters TUI mode. Later calls are no-ops and
+/// return the stored value.
+pub fn init_color_support() -> ColorSupport {
+    *COLOR_SUPPORT.get_or_init(|| {
+        if mono_requested(
+            env::var("NO_COLOR").ok().as_deref(),
+            env::args().any(|arg| arg == "--mono"),
+        ) {
+            return ColorSupport::Mono;
+        }
+        detect_color_support(
+            env::var("BCI_COLOR").ok().as_deref(),
+            env::var("COLORTERM").ok().as_deref(),
+            env::var("TERM").ok().as_deref(),
+        )
+    })
+}
+
+/// The detected color support (detecting on first use if needed).
+pub fn color_support() -> ColorSupport {
+    init_color_support()
+}
+
+/// True if color output is switched off by `--mono` or `NO_COLOR`.
+///
+/// Per the `NO_COLOR` convention, an empty value does not count.
+pub fn mono_requested(no_color: Option<&str>, mono_flag: bool) -> bool {
+    mono_flag || no_color.is_some_and(|v| !v.is_empty())
+}
+
+/// Style for a value that briefly flashes on change.
+///
+/// Uses `flash` / `idle` colors normally. In mono mode, where colors are
+/// stripped, an active flash is shown reversed instead.
+pub fn flash_style(flashing: bool, flash: Color, idle: Color) -> Style {
+    match (color_support(), flashing) {
+        (ColorSupport::Mono, true) => Style::default().add_modifier(Modifier::REVERSED),
+        (ColorSupport::Mono, false) => Style::default(),
+        (_, true) => Style::default().fg(flash),
+        (_, false) => Style::default().fg(idle),
+    }
+}
+
+/// Pure detection logic from the relevant environment values.
+pub fn detect_color_support(
+    override_mode: Option<&str>,
+    colorterm: Option<&str>,
+    term: Option<&str>,
+) -> ColorSupport {
+    if let Some(mode) = override_mode {
+        match mode.trim().to_ascii_lowercase().as_str() {
+            "truecolor" | "24bit" => return ColorSupport::TrueColor,
+            "256" => return ColorSupport::Ansi256,
+            "16" | "basic" => return ColorSupport::Basic,
+            "mono" | "none" => return ColorSupport::Mono,
+            _ => {} // Unknown value: fall through to auto-detection.
+        }
+    }
+
+    if let Some(ct) = colorterm {
+        let ct = ct.to_ascii_lowercase();
+        if ct == "truecolor" || ct == "24bit" {
+            return ColorSupport::TrueColor;
+        }
+    }
+
+    match term.map(str::to_ascii_lowercase) {
+        Some(t) if t == "dumb" => ColorSupport::Mono,
+        Some(t) if t.ends_with("-direct") => ColorSupport::TrueColor,
+        Some(t) if t.contains("256color") => ColorSupport::Ansi256,
+        Some(t) if !t.is_empty() => ColorSupport::Basic,
+        // No TERM: the Windows console understands 24-bit color; elsewhere
+        // stay conservative.
+        _ if cfg!(windows) => ColorSupport::TrueColor,
+        _ => ColorSupport::Basic,
+    }
+}
+
+/// Maps a palette color to one the terminal can display.
+pub fn adapt_color(color: Color, support: ColorSupport) -> Color {
+    match (support, color) {
+        (ColorSupport::TrueColor, c) => c,
+        (ColorSupport::Mono, _) => Color::Reset,
+        (ColorSupport::Ansi256, Color::Rgb(r, g, b)) => Color::Indexed(rgb_to_ansi256(r, g, b)),
+        (ColorSupport::Basic, Color::Rgb(r, g, b)) => nearest_basic(r, g, b),
+        (ColorSupport::Basic, Color::Indexed(i)) if i >= 16 => {
+            let (r, g, b) = ansi256_to_rgb(i);
+            nearest_basic(r, g, b)
+        }
+        (_, c) => c,
+    }
+}
+
+/// Index into the xterm 256-color palette closest to an RGB value.
+fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    // Grays use the 24-step ramp (232–255) for finer resolution.
+    if r == g && g == b {
+        return match r {
+            0..=7 => 16,
+            248..=255 => 231,
+            v => 232 + ((v as u16 - 8) / 10).min(23) as u8,
+        };
+    }
+
+    let level = |v: u8| -> u8 {
+        if v < 48 {
+            0
+        } else if v < 115 {
+            1
+        } else {
+            ((v as u16 - 35) / 40) as u8
+        }
+    };
+    16 + 36 * level(r) + 6 * level(g) + level(b)
+}
+
+/// Approximate RGB value of an xterm 256-color index ≥ 16.
+fn ansi256_to_rgb(i: u8) -> (u8, u8, u8) {
+    if i >= 232 {
+        let v = 8 + (i - 232) * 10;
+        return (v, v, v);
+    }
+    let i = i - 16;
+    let step = |n: u8| if n == 0 { 0 } else { 55 + n * 40 };
+    (step(i / 36), step((i / 6) % 6), step(i % 6))
+}
+
+/// Nearest of the 16 standard ANSI colors (xterm default RGB values).
+fn nearest_basic(r: u8, g: u8, b: u8) -> Color {
+    const BASIC: [(Color, (u8, u8, u8)); 16] = [
+        (Color::Black, (0, 0, 0)),
+        (Color::Red, (205, 0, 0)),
+        (Color::Green, (0, 205, 0)),
+        (Color::Yellow, (205, 205, 0)),
+        (Color::Blue, (0, 0, 238)),
+        (Color::Magenta, (205, 0, 205)),
+        (Color::Cyan, (0, 205, 205)),
+        (Color::Gray, (229, 229, 229)),
+        (Color::DarkGray, (127, 127, 127)),
+        (Color::LightRed, (255, 0, 0)),
+        (Color::LightGreen, (0, 255, 0)),
+        (Color::LightYellow, (255, 255, 0)),
+        (Color::LightBlue, (92, 92, 255)),
+        (Color::LightMagenta, (255, 0, 255)),
+        (Color::LightCyan, (0, 255, 255)),
+        (Color::White, (255, 255, 255)),
+    ];
+
+    let dist = |(pr, pg, pb): (u8, u8, u8)| -> u32 {
+        let dr = r as i32 - pr as i32;
+        let dg = g as i32 - pg as i32;
+        let db = b as i32 - pb as i32;
+        (dr * dr + dg * dg + db * db) as u32
+    };
+
+    BASIC
+        .iter()
+        .min_by_key(|(_, rgb)| dist(*rgb))
+        .map(|(c, _)| *c)
+        .unwrap_or(Color::Reset)
+}
+
+/// Rewrites every cell of the frame to colors the terminal supports.
+///
+/// Rendered last, over the dashboard and any popup. A no-op on
+/// truecolor terminals.
+pub struct PaletteFilter {
+    support: ColorSupport,
+}
+
+impl PaletteFilter {
+    pub fn new(support: ColorSupport) -> Self {
+        Self { support }
+    }
+}
+
+impl Widget for PaletteFilter {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if self.support == ColorSupport::TrueColor {
+            return;
+        }
+
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let cell = buf.get_mut(x, y);
+                cell.fg = adapt_color(cell.fg, self.support);
+                cell.bg = adapt_color(cell.bg, self.support);
+            }
+        }
+    }
+}
@@ -2,34 +2,117 @@
 //
 // Theme customization:
 // You can freely change these colors to suit your terminal theme.
-// BCI does not load themes dynamically by design — colors are compile-time
-// constants to keep the UI predictable and lightweight.
+// Most colors are compile-time constants. The semantic groups below
+// (labels, values, separators, bars, warning, critical) can also be
+// overridden from the `[theme]` table in `config.toml`; those are
+// `Lazy` statics resolved once, on first use after `init_theme`.
+// Anything not set in `[theme]` keeps its default from this file.
+//
+// On terminals without 24-bit color these are mapped down at render time
+// (see `color_support.rs`); set `BCI_COLOR` to override detection.
 //
 // Suggested alternatives:
 // - Monochrome: White / DarkGray
 // - Solarized: Blue / Cyan / Yellow
 // - High-contrast: White / Red / Green
 
+use std::sync::OnceLock;
+
+use once_cell::sync::Lazy;
 use tui::style::Color;
 
-/// Borders
-pub const C_BLOCKCHAIN_BORDER: Color = Color::DarkGray;
-pub const C_MEMPOOL_BORDER: Color = Color::DarkGray;
-pub const C_NETWORK_BORDER: Color = Color::DarkGray;
-pub const C_CONSENSUS_BORDER: Color = Color::DarkGray;
+/// Color overrides from the `[theme]` config table, one per semantic group.
+///
+/// `None` keeps each color's built-in default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Theme {
+    pub labels: Option<Color>,
+    pub values: Option<Color>,
+    pub separators: Option<Color>,
+    pub bars: Option<Color>,
+    pub warning: Option<Color>,
+    pub critical: Option<Color>,
+}
+
+static THEME: OnceLock<Theme> = OnceLock::new();
+
+/// Installs the configured theme.
+///
+/// Call at startup, before anything is drawn: themed colors are resolved
+/// on first use. Later calls are ignored.
+pub fn init_theme(theme: Theme) {
+    let _ = THEME.set(theme);
+}
+
+fn theme() -> Theme {
+    THEME.get().copied().unwrap_or_default()
+}
+
+/// Parses a theme color: a tui color name (`"gray"`, `"dark_gray"`,
+/// `"lightred"`, …) or `#rrggbb` hex. Case-insensitive.
+pub fn parse_color(s: &str) -> Option<Color> {
+    let s = s.trim();
+    if let Some(hex) = s.strip_prefix('#') {
+        if hex.len() != 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        return Some(Color::Rgb(channel(0)?, channel(2)?, channel(4)?));
+    }
+
+    let name: String = s
+        .chars()
+        .filter(|c| !matches!(c, '_' | '-' | ' '))
+        .collect::<String>()
+        .to_ascii_lowercase();
+    Some(match name.as_str() {
+        "reset" | "default" => Color::Reset,
+        "black" => Color::Black,
+        "red" => Color::Red,
+        "green" => Color::Green,
+        "yellow" => Color::Yellow,
+        "blue" => Color::Blue,
+        "magenta" => Color::Magenta,
+        "cyan" => Color::Cyan,
+        "gray" | "grey" => Color::Gray,
+        "darkgray" | "darkgrey" => Color::DarkGray,
+        "lightred" => Color::LightRed,
+        "lightgreen" => Color::LightGreen,
+        "lightyellow" => Color::LightYellow,
+        "lightblue" => Color::LightBlue,
+        "lightmagenta" => Color::LightMagenta,
+        "lightcyan" => Color::LightCyan,
+        "white" => Color::White,
+        _ => return None,
+    })
+}
+
+/// Declares a color that the `[theme]` group `$group` can override.
+macro_rules! themed {
+    ($(#[$meta:meta])* $name:ident, $group:ident, $default:expr) => {
+        $(#[$meta])*
+        pub static $name: Lazy<Color> = Lazy::new(|| theme().$group.unwrap_or($default));
+    };
+}
+
+// Borders
+themed!(C_BLOCKCHAIN_BORDER, separators, Color::DarkGray);
+themed!(C_MEMPOOL_BORDER, separators, Color::DarkGray);
+themed!(C_NETWORK_BORDER, separators, Color::DarkGray);
+themed!(C_CONSENSUS_BORDER, separators, Color::DarkGray);
 
 /// Core identity colors
 pub const C_APP_TITLE: Color = Color::Cyan;
-pub const C_APP_VERSION: Color = Color::DarkGray;
-pub const C_MAIN_LABELS: Color = Color::Gray;
-pub const C_SECTION_LABELS: Color = Color::DarkGray;
-pub const C_HORIZONTAL_ASCII_BAR: Color = Color::Gray;
-pub const C_SEPARATORS: Color = Color::DarkGray;
+themed!(C_APP_VERSION, separators, Color::DarkGray);
+themed!(C_MAIN_LABELS, labels, Color::Gray);
+themed!(C_SECTION_LABELS, labels, Color::DarkGray);
+themed!(C_HORIZONTAL_ASCII_BAR, bars, Color::Gray);
+themed!(C_SEPARATORS, separators, Color::DarkGray);
 pub const C_KEYTOGGLE_HIGHLIGHT: Color = Color::Yellow;
-pub const C_KEYTOGGLE_DIM: Color = Color::DarkGray;
+themed!(C_KEYTOGGLE_DIM, separators, Color::DarkGray);
 pub const C_HASH_PHASE: Color = Color::Yellow;
-pub const C_HASH_PHASE_NEW: Color = Color::DarkGray;
-pub const C_FOOTER_DISPLAY: Color = Color::Gray;
+themed!(C_HASH_PHASE_NEW, separators, Color::DarkGray);
+themed!(C_FOOTER_DISPLAY, values, Color::Gray);
 
 // Used for Best Block, Transactions, Connections In
 // *_FLASH colors are used for brief visual emphasis on state change.
@@ -49,37 +132,40 @@ pub const C_ESTIMATE_POS: Color = Color::Green;
 pub const C_ESTIMATE_NEG: Color = Color::Red;
 pub const C_CHAINWORK: Color = Color::LightYellow;
 pub const C_VERIFICATION: Color = Color::Yellow;
-pub const C_HASHRATE_CHART_BARS: Color = Color::DarkGray;
+themed!(C_HASHRATE_CHART_BARS, bars, Color::DarkGray);
 pub const C_HASHRATE_CHART_VALUES: Color = Color::White;
 
 pub const C_LAST20_HEIGHT_LABEL: Color = Color::Cyan;
-pub const C_LAST20_MINER_LABEL: Color = Color::Gray;
+themed!(C_LAST20_MINER_LABEL, labels, Color::Gray);
 
 /// Mempool
 pub const C_MEMPOOL_DIST_LABELS: Color = Color::Yellow;
-pub const C_MEMPOOL_USAGE_GAUGE_FG: Color = Color::DarkGray;
+themed!(C_MEMPOOL_USAGE_GAUGE_FG, bars, Color::DarkGray);
 pub const C_MEMPOOL_USAGE_GAUGE_BG: Color = Color::Black;
-pub const C_MEMPOOL_VALUES: Color = Color::Gray;
-pub const C_DUST_FREE_PCT: Color = Color::Gray;
-pub const C_DUST_FREE_LABEL: Color = Color::DarkGray;
+themed!(C_MEMPOOL_VALUES, values, Color::Gray);
+themed!(C_DUST_FREE_PCT, values, Color::Gray);
+themed!(C_DUST_FREE_LABEL, labels, Color::DarkGray);
+themed!(C_FEE_TREND_SPARKLINE, bars, Color::DarkGray);
+pub const C_NEXT_BLOCK_FLOOR: Color = Color::Yellow;
 
 
 /// Network
 pub const C_CONNECTIONS_OUT: Color = Color::Yellow;
-pub const C_VERSION_CHART_BARS: Color = Color::DarkGray;
+pub const C_CONNECTIONS_IN: Color = Color::Cyan;
+themed!(C_VERSION_CHART_BARS, bars, Color::DarkGray);
 pub const C_VERSION_CHART_VALUES: Color = Color::White;
 pub const C_CLIENT_DIST_MINER_LABEL: Color = Color::Cyan;
-pub const C_CLIENT_DIST_MINER_COUNT:Color = Color::Gray;
-pub const C_CLIENT_DIST_MINER_PCT:Color = Color::Gray;
-pub const C_SPARKLINE: Color = Color::DarkGray;
+themed!(C_CLIENT_DIST_MINER_COUNT, values, Color::Gray);
+themed!(C_CLIENT_DIST_MINER_PCT, values, Color::Gray);
+themed!(C_SPARKLINE, bars, Color::DarkGray);
 
 /// Consensus
 pub const C_CONSENSUS_STATUS_SECTION: Color = Color::Yellow;
 
 /// Status colors
 pub const C_STATUS_LOW: Color = Color::Green;
-pub const C_STATUS_MED: Color = Color::Yellow;
-pub const C_STATUS_HIGH: Color = Color::Red;
+themed!(C_STATUS_MED, warning, Color::Yellow);
+themed!(C_STATUS_HIGH, critical, Color::Red);
 
 pub const C_TX_LOOKUP_TXT: Color = Color::Rgb(255, 165, 0);
 pub const C_HELP_TXT: Color = Color::Rgb(255, 180, 80);
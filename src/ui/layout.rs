@@ -0,0 +1,128 @@
+// src/ui/layout.rs
+//
+// Dashboard layout.
+//
+// Splits the screen into the header, the configured sections and the
+// footer. Heights and visibility come from the `[layout]` config table;
+// hidden sections get no area and are skipped when drawing.
+//
+// On a screen shorter than the layout, the less critical sections give way
+// first: Consensus is collapsed, then Network is shrunk and collapsed, and
+// whatever remains shares the rows in proportion to its configured height.
+// Below `MIN_TERMINAL_WIDTH` x `MIN_TERMINAL_HEIGHT` the dashboard is not
+// drawn at all.
+
+use tui::layout::{Constraint, Direction, Layout, Rect};
+
+use crate::config::{DashboardSection, LayoutConfig};
+
+/// Header height (hashphase line and borders).
+const HEADER_HEIGHT: u16 = 3;
+
+/// Footer height (key hints and notices).
+const FOOTER_HEIGHT: u16 = 1;
+
+/// Narrowest terminal the dashboard is drawn in.
+pub const MIN_TERMINAL_WIDTH: u16 = 60;
+
+/// Shortest terminal the dashboard is drawn in.
+pub const MIN_TERMINAL_HEIGHT: u16 = 16;
+
+/// Rows the Network section keeps before it is collapsed.
+const NETWORK_MIN_HEIGHT: u16 = 8;
+
+/// Whether a `width` x `height` terminal is below the minimum size.
+pub fn too_small(width: u16, height: u16) -> bool {
+    width < MIN_TERMINAL_WIDTH || height < MIN_TERMINAL_HEIGHT
+}
+
+/// Screen areas of the dashboard; `None` for hidden sections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DashboardAreas {
+    pub header: Rect,
+    pub blockchain: Option<Rect>,
+    pub mempool: Option<Rect>,
+    pub network: Option<Rect>,
+    pub consensus: Option<Rect>,
+    pub footer: Rect,
+}
+
+/// Lays out the dashboard inside `area` (the whole frame, with a 1-cell margin).
+///
+/// Sections get their configured height when it fits; otherwise see
+/// `fit_sections`.
+pub fn dashboard_areas(area: Rect, layout: &LayoutConfig) -> DashboardAreas {
+    let visible: Vec<(DashboardSection, u16)> = layout
+        .sections()
+        .into_iter()
+        .filter(|(section, _)| layout.is_visible(*section))
+        .collect();
+    let available = area.height.saturating_sub(LayoutConfig::FIXED_ROWS);
+    let visible = fit_sections(visible, available);
+
+    let mut constraints = vec![Constraint::Length(HEADER_HEIGHT)];
+    constraints.extend(visible.iter().map(|(_, height)| Constraint::Length(*height)));
+    constraints.push(Constraint::Length(FOOTER_HEIGHT));
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints(constraints)
+        .split(area);
+
+    let area_of = |wanted: DashboardSection| {
+        visible
+            .iter()
+            .position(|(section, _)| *section == wanted)
+            .map(|i| chunks[i + 1])
+    };
+
+    DashboardAreas {
+        header: chunks[0],
+        blockchain: area_of(DashboardSection::Blockchain),
+        mempool: area_of(DashboardSection::Mempool),
+        network: area_of(DashboardSection::Network),
+        consensus: area_of(DashboardSection::Consensus),
+        footer: chunks[chunks.len() - 1],
+    }
+}
+
+/// Fits section heights into `available` rows.
+///
+/// In order, until the sections fit: Consensus is dropped; Network is
+/// shrunk down to `NETWORK_MIN_HEIGHT`, then dropped; the rest are scaled
+/// in proportion to their heights, keeping at least
+/// `LayoutConfig::MIN_SECTION_HEIGHT` rows each.
+fn fit_sections(
+    mut sections: Vec<(DashboardSection, u16)>,
+    available: u16,
+) -> Vec<(DashboardSection, u16)> {
+    let total = |sections: &[(DashboardSection, u16)]| -> u16 {
+        sections.iter().map(|(_, height)| height).sum()
+    };
+
+    if total(&sections) > available {
+        sections.retain(|(section, _)| *section != DashboardSection::Consensus);
+    }
+
+    if total(&sections) > available {
+        if let Some(i) = sections.iter().position(|(s, _)| *s == DashboardSection::Network) {
+            let room = available.saturating_sub(total(&sections) - sections[i].1);
+            if room >= NETWORK_MIN_HEIGHT.min(sections[i].1) {
+                sections[i].1 = room.min(sections[i].1);
+            } else {
+                sections.remove(i);
+            }
+        }
+    }
+
+    let sum = total(&sections);
+    if sum > available {
+        for (_, height) in sections.iter_mut() {
+            let share = (*height as u32 * available as u32 / sum as u32) as u16;
+            *height = share.max(LayoutConfig::MIN_SECTION_HEIGHT);
+        }
+    }
+
+    sections
+}
@@ -0,0 +1,63 @@
+// src/ui/mouse.rs
+//
+// Mouse support.
+//
+// Section titles carry their toggles as `[K] LABEL` (e.g. `[H] HRD`) or
+// `(k→NEXT)` (e.g. `(c→Client)`) segments. Each frame records where those
+// segments were drawn; a left click on one acts like pressing its key.
+
+use tui::layout::Rect;
+use tui::text::Span;
+
+/// Clickable title segment: the cells it covers and the key it stands for.
+pub type Hotspot = (Rect, char);
+
+/// Key named by a title segment, if it is a toggle.
+///
+/// `"[H] HRD"` and `" [D] ALL TX"` name `h` and `d`; `"(c→Client)"` names
+/// `c`. Section names such as `"[Mempool]"` are not toggles.
+pub fn toggle_key(segment: &str) -> Option<char> {
+    let mut chars = segment.trim_start().chars();
+    let key = match (chars.next(), chars.next(), chars.next()) {
+        (Some('['), Some(key), Some(']')) => key,
+        (Some('('), Some(key), Some('→')) => key,
+        _ => return None,
+    };
+    key.is_ascii_alphanumeric().then(|| key.to_ascii_lowercase())
+}
+
+/// Hotspots of a block title drawn on the top border of `area`.
+///
+/// `segments` are the title's pieces in order, as rendered; the title starts
+/// one cell in, after the left border.
+pub fn title_hotspots<'a>(area: Rect, segments: impl IntoIterator<Item = &'a str>) -> Vec<Hotspot> {
+    let mut hotspots = Vec::new();
+    let mut x = area.x.saturating_add(1);
+    let right = area.right().saturating_sub(1);
+
+    for segment in segments {
+        let width = Span::raw(segment).width() as u16;
+        if let Some(key) = toggle_key(segment) {
+            // Leading spaces separate toggles; they aren't part of the target.
+            let pad = (segment.len() - segment.trim_start().len()) as u16;
+            let start = x.saturating_add(pad).min(right);
+            let end = x.saturating_add(width).min(right);
+            if end > start {
+                hotspots.push((Rect::new(start, area.y, end - start, 1), key));
+            }
+        }
+        x = x.saturating_add(width);
+    }
+
+    hotspots
+}
+
+/// Key of the hotspot under the cell (`column`, `row`).
+pub fn hotspot_at(hotspots: &[Hotspot], column: u16, row: u16) -> Option<char> {
+    hotspots
+        .iter()
+        .find(|(rect, _)| {
+            column >= rect.x && column < rect.right() && row >= rect.y && row < rect.bottom()
+        })
+        .map(|(_, key)| *key)
+}
@@ -0,0 +1,52 @@
+// src/ui/scroll_view.rs
+//
+// Scroll mode support.
+//
+// In scroll mode the dashboard is rendered into an off-screen buffer tall
+// enough for every section at its configured height (see
+// `LayoutConfig::total_height`), and `ScrollView` copies the visible window
+// of that buffer onto the real terminal. Sections are never squeezed or
+// clipped; the user moves the window with the arrow keys.
+
+use tui::{buffer::Buffer, layout::Rect, widgets::Widget};
+
+/// Rows moved per PageUp / PageDown.
+pub const SCROLL_PAGE: u16 = 10;
+
+/// Copies a window of an off-screen buffer onto the frame.
+///
+/// Row `offset` of `source` lands on the first row of the target area.
+/// Cells outside `source` are left untouched.
+pub struct ScrollView<'a> {
+    source: &'a Buffer,
+    offset: u16,
+}
+
+impl<'a> ScrollView<'a> {
+    pub fn new(source: &'a Buffer, offset: u16) -> Self {
+        Self { source, offset }
+    }
+}
+
+impl Widget for ScrollView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let src = self.source.area;
+
+        for row in 0..area.height {
+            let src_y = src.y + self.offset + row;
+            if src_y >= src.bottom() {
+                break;
+            }
+
+            for col in 0..area.width.min(src.width) {
+                let cell = self.source.get(src.x + col, src_y).clone();
+                *buf.get_mut(area.x + col, area.y + row) = cell;
+            }
+        }
+    }
+}
+
+/// Largest valid scroll offset for a viewport of `viewport_height` rows.
+pub fn max_scroll_offset(view_height: u16, viewport_height: u16) -> u16 {
+    view_height.saturating_sub(viewport_height)
+}
@@ -2,17 +2,19 @@
 //!
 //! This module provides:
 //! - Global caches (Arc<RwLock<...>>) for cross-module data sharing
-//! - Formatting helpers (sizes, percentages)
+//! - Formatting helpers (sizes, percentages, inline sparklines)
 //! - Difficulty adjustment estimators
 //! - Dashboard header/footer render utilities
-//! - Error logging with automatic rotation
-//! - Keychain/RPC password retrieval (macOS only)
+//! - Event logging (info/warn/error) with automatic rotation
+//! - Keychain/RPC password retrieval (macOS Keychain, Linux `pass`, Windows Credential Manager)
+//! - Data directory resolution (XDG on Linux) for logs and data files
 //! - File loading helpers (e.g., miners.json)
 //!
 //! All logic is intentionally lightweight—optimized for clarity,
 //! thread-safety, and serving the TUI layer cleanly.
 
 use crate::models::errors::MyError;
+use crate::rpc::retry::is_transient;
 use tui::widgets::{Block, Borders, Paragraph};
 use tui::text::{Span, Spans};
 use tui::style::{Style, Modifier};
@@ -24,14 +26,19 @@ use std::fs::{OpenOptions, metadata, rename};
 use std::fs;
 use std::io::{self, Write};
 use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::io::Read;
 use std::collections::{HashSet, VecDeque};
 use std::sync::Arc;
+use std::time::Duration;
+use std::env;
+use std::path::{Path, PathBuf};
 
 use lazy_static::lazy_static;
 use chrono::Local;
-use tokio::sync::RwLock;
+use tokio::sync::{Notify, RwLock};
 use once_cell::sync::Lazy;
+use rand::Rng;
 
 use crate::models::blockchain_info::BlockchainInfo;
 use crate::models::block_info::BlockInfo;
@@ -41,6 +48,13 @@ use crate::models::peer_info::PeerInfo;
 use crate::models::network_info::NetworkInfo;
 use crate::models::network_totals::NetTotals;
 use crate::models::block_info::{BlockHistory, MinersData};
+use crate::models::block_stats::{BlockStats, FeeRateHistory};
+use crate::models::fee_estimate::FeeEstimates;
+use crate::models::mining_info::MiningInfo;
+use crate::models::chain_tx_stats::ChainTxStats;
+use crate::models::deployment_info::DeploymentInfo;
+use crate::models::witness_adoption::WitnessAdoption;
+use crate::models::coinbase_reward::CoinbaseReward;
 use crate::consensus::satoshi_math::*;
 use crate::ui::colors::*;
 
@@ -50,30 +64,95 @@ use crate::ui::colors::*;
 // ────────────────────────────────────────────────────────────────────────────────
 //
 
-const KB: u64 = 1024;
-const MB: u64 = KB * 1024;
-const GB: u64 = MB * 1024;
-const TB: u64 = GB * 1024;
+/// Unit system used by [`format_size`].
+///
+/// Set via `size_units` in `config.toml`:
+/// - `"binary"` (default) — 1024 steps, labeled KiB / MiB / GiB / TiB
+/// - `"decimal"` — 1000 steps, labeled kB / MB / GB / TB
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SizeUnits {
+    #[default]
+    Binary,
+    Decimal,
+}
+
+impl SizeUnits {
+    /// Step between units and the labels from kilo to tera.
+    fn scale(self) -> (u64, [&'static str; 4]) {
+        match self {
+            SizeUnits::Binary => (1024, ["KiB", "MiB", "GiB", "TiB"]),
+            SizeUnits::Decimal => (1000, ["kB", "MB", "GB", "TB"]),
+        }
+    }
+}
+
+/// Eighth-block glyphs used by [`text_sparkline`], lowest first.
+const SPARK_GLYPHS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// One-line sparkline for inline use, one glyph per value, scaled to the
+/// largest value (all zeros render as the lowest glyph).
+///
+/// Example: `[1, 5, 10] → "▁▄█"`
+pub fn text_sparkline<'a, I: IntoIterator<Item = &'a u64>>(values: I) -> String {
+    let values: Vec<u64> = values.into_iter().copied().collect();
+    let max = values.iter().copied().max().unwrap_or(0).max(1);
+    values
+        .iter()
+        .map(|&v| {
+            let idx = (v as f64 / max as f64 * (SPARK_GLYPHS.len() - 1) as f64).round() as usize;
+            SPARK_GLYPHS[idx.min(SPARK_GLYPHS.len() - 1)]
+        })
+        .collect()
+}
 
 /// Convert raw bytes into human-readable units.
 ///
 /// Examples:
-/// - `1536 → "1.50 KB"`
-/// - `1048576 → "1.00 MB"`
-pub fn format_size(bytes: u64) -> String {
-    if bytes >= TB {
-        format!("{:.2} TB", bytes as f64 / TB as f64)
-    } else if bytes >= GB {
-        format!("{:.2} GB", bytes as f64 / GB as f64)
-    } else if bytes >= MB {
-        format!("{:.2} MB", bytes as f64 / MB as f64)
-    } else if bytes >= KB {
-        format!("{:.2} KB", bytes as f64 / KB as f64)
-    } else {
-        format!("{} bytes", bytes)
+/// - `1536, Binary → "1.50 KiB"`
+/// - `1048576, Binary → "1.00 MiB"`
+/// - `1000000, Decimal → "1.00 MB"`
+pub fn format_size(bytes: u64, units: SizeUnits) -> String {
+    let (step, labels) = units.scale();
+
+    // Largest unit the value reaches; below one kilo-unit, plain bytes.
+    let mut unit = step;
+    let mut label = None;
+    for l in labels {
+        if bytes < unit {
+            break;
+        }
+        label = Some((l, unit));
+        unit = unit.saturating_mul(step);
+    }
+
+    match label {
+        Some((l, size)) => format!("{:.2} {}", bytes as f64 / size as f64, l),
+        None => format!("{} bytes", bytes),
     }
 }
 
+/// Format a hashrate (hashes/second) with SI units.
+///
+/// `format_size` is byte-oriented (binary or decimal per `size_units`);
+/// hashrates always use decimal steps of 1000.
+///
+/// Examples:
+/// - `812_340_000_000_000_000_000.0 → "812.34 EH/s"`
+/// - `1_500_000_000_000_000.0 → "1.50 PH/s"`
+pub fn format_hashrate(hashes_per_sec: f64) -> String {
+    const UNITS: [&str; 8] = ["H/s", "kH/s", "MH/s", "GH/s", "TH/s", "PH/s", "EH/s", "ZH/s"];
+
+    let mut value = hashes_per_sec.max(0.0);
+    let mut unit = 0;
+    while value >= 1000.0 && unit < UNITS.len() - 1 {
+        value /= 1000.0;
+        unit += 1;
+    }
+
+    format!("{:.2} {}", value, UNITS[unit])
+}
+
 //
 // ────────────────────────────────────────────────────────────────────────────────
 //   APP VERSION (from Cargo.toml)
@@ -111,12 +190,120 @@ pub static BLOCK_INFO_CACHE: Lazy<Arc<RwLock<Vec<BlockInfo>>>> =
 pub static BLOCK24_INFO_CACHE: Lazy<Arc<RwLock<Vec<BlockInfo>>>> =
     Lazy::new(|| Arc::new(RwLock::new(Vec::new())));
 
+// Set while the ~24h-ago block is pruned on the node; the 24h difficulty
+// projection then reads "N/A (pruned)".
+pub static BLOCK24_PRUNED: AtomicBool = AtomicBool::new(false);
+
 pub static CHAIN_TIP_CACHE: Lazy<Arc<RwLock<ChainTipsJsonWrap>>> =
     Lazy::new(|| Arc::new(RwLock::new(ChainTipsJsonWrap::default())));
 
 pub static MEMPOOL_DISTRIBUTION_CACHE: Lazy<Arc<RwLock<MempoolDistribution>>> =
     Lazy::new(|| Arc::new(RwLock::new(MempoolDistribution::default())));
 
+pub static FEE_RATE_HISTORY_CACHE: Lazy<Arc<RwLock<FeeRateHistory>>> =
+    Lazy::new(|| Arc::new(RwLock::new(FeeRateHistory::new())));
+
+/// `getblockstats` for the current tip (fetched once per new height).
+pub static LAST_BLOCK_STATS_CACHE: Lazy<Arc<RwLock<BlockStats>>> =
+    Lazy::new(|| Arc::new(RwLock::new(BlockStats::default())));
+
+/// SegWit/Taproot shares of the tip, counted from the miner-lookup block.
+pub static WITNESS_ADOPTION_CACHE: Lazy<Arc<RwLock<WitnessAdoption>>> =
+    Lazy::new(|| Arc::new(RwLock::new(WitnessAdoption::default())));
+
+/// Subsidy/fees split of the tip's coinbase, from the miner-lookup block.
+pub static COINBASE_REWARD_CACHE: Lazy<Arc<RwLock<CoinbaseReward>>> =
+    Lazy::new(|| Arc::new(RwLock::new(CoinbaseReward::default())));
+
+pub static CHAIN_TX_STATS_CACHE: Lazy<Arc<RwLock<ChainTxStats>>> =
+    Lazy::new(|| Arc::new(RwLock::new(ChainTxStats::default())));
+
+/// Soft-fork deployment state; `None` until fetched, and for nodes
+/// without `getdeploymentinfo`.
+pub static DEPLOYMENT_INFO_CACHE: Lazy<Arc<RwLock<Option<DeploymentInfo>>>> =
+    Lazy::new(|| Arc::new(RwLock::new(None)));
+
+/// Node uptime in seconds; `None` until fetched, and for nodes that
+/// refuse the `uptime` RPC.
+pub static UPTIME_CACHE: Lazy<Arc<RwLock<Option<u64>>>> =
+    Lazy::new(|| Arc::new(RwLock::new(None)));
+
+pub static MINING_INFO_CACHE: Lazy<Arc<RwLock<MiningInfo>>> =
+    Lazy::new(|| Arc::new(RwLock::new(MiningInfo::default())));
+
+/// Core's fee estimates as `(confirmation target, sat/vB)`.
+pub static FEE_ESTIMATE_CACHE: Lazy<Arc<RwLock<FeeEstimates>>> =
+    Lazy::new(|| Arc::new(RwLock::new(Vec::new())));
+
+// Whether the node answered the last core polling RPC. While false, the
+// footer shows a "Node unreachable" banner so stale numbers aren't trusted.
+pub static NODE_REACHABLE: AtomicBool = AtomicBool::new(true);
+
+/// Records the outcome of a core polling RPC for the connectivity banner.
+///
+/// Success marks the node reachable; network errors and timeouts mark it
+/// unreachable. Other errors (bad JSON, RPC-level failures) mean the node
+/// did answer, so the state is left as is.
+pub fn note_rpc_outcome<T>(result: &Result<T, MyError>) {
+    match result {
+        Ok(_) => NODE_REACHABLE.store(true, Ordering::Relaxed),
+        Err(e) if is_transient(e) => NODE_REACHABLE.store(false, Ordering::Relaxed),
+        Err(_) => {}
+    }
+}
+
+/// True unless the last core polling RPC failed to reach the node.
+pub fn node_reachable() -> bool {
+    NODE_REACHABLE.load(Ordering::Relaxed)
+}
+
+// Set while auto-refresh is paused (space bar). Worker tasks hold off between
+// polls, so the caches, and with them the dashboard, stay frozen.
+pub static REFRESH_PAUSED: AtomicBool = AtomicBool::new(false);
+
+// Wakes the worker tasks waiting in `poll_sleep` when refresh resumes.
+static REFRESH_RESUMED: Lazy<Notify> = Lazy::new(Notify::new);
+
+/// Pauses or resumes the polling worker tasks.
+///
+/// Resuming wakes every waiting task, so the next poll runs right away
+/// instead of after a full interval.
+pub fn set_refresh_paused(paused: bool) {
+    REFRESH_PAUSED.store(paused, Ordering::Relaxed);
+    if !paused {
+        REFRESH_RESUMED.notify_waiters();
+    }
+}
+
+/// True while auto-refresh is paused.
+pub fn refresh_paused() -> bool {
+    REFRESH_PAUSED.load(Ordering::Relaxed)
+}
+
+/// Waits between two polls of a worker task.
+///
+/// Sleeps for `duration`, then for as long as refresh is paused. A resume
+/// ends the wait early. A poll already in flight when the pause starts
+/// still completes.
+///
+/// Call it after every poll, even with nothing left of the interval: this
+/// is where a pause holds the task.
+pub async fn poll_sleep(duration: Duration) {
+    tokio::select! {
+        _ = tokio::time::sleep(duration) => {}
+        _ = REFRESH_RESUMED.notified() => return,
+    }
+
+    loop {
+        // Registered before the check, so a resume in between isn't missed.
+        let resumed = REFRESH_RESUMED.notified();
+        if !refresh_paused() {
+            return;
+        }
+        resumed.await;
+    }
+}
+
 // Tracks logged TXIDs to avoid duplication in logs.
 // (500 item rolling window)
 lazy_static! {
@@ -199,9 +386,60 @@ pub fn get_rpc_password_from_keychain() -> Result<String, MyError> {
     }
 }
 
+/// Reads the RPC password from Windows Credential Manager.
+///
+/// Looks up a *generic* credential, by default `blockchaininfo/rpc-password`
+/// (override with `BCI_PASS_ENTRY`). Create it with:
+///
+/// ```text
+/// cmdkey /generic:blockchaininfo/rpc-password /user:bitcoin /pass:<password>
+/// ```
+///
+/// `cmdkey` and the Credential Manager UI store the secret as UTF-16;
+/// credentials written by other tools as UTF-8 bytes are accepted too.
 #[cfg(target_os = "windows")]
 pub fn get_rpc_password_from_keychain() -> Result<String, MyError> {
-    Err(MyError::Keychain("Windows keychain access not supported".into()))
+    let entry = std::env::var("BCI_PASS_ENTRY").unwrap_or_else(|_| "blockchaininfo/rpc-password".into());
+
+    // An explicit target makes the lookup hit the generic credential by
+    // its TargetName, exactly as `cmdkey /generic:` stored it.
+    let credential = keyring::Entry::new_with_target(&entry, "blockchaininfo", "bitcoin")
+        .map_err(|e| MyError::Keychain(format!("Credential Manager lookup failed ({}): {}", entry, e)))?;
+
+    let blob = credential.get_secret().map_err(|e| {
+        MyError::Keychain(match e {
+            keyring::Error::NoEntry => format!("Credential '{}' not found in Credential Manager", entry),
+            keyring::Error::NoStorageAccess(_) => format!("Access denied reading credential '{}'", entry),
+            other => format!("Credential Manager lookup failed ({}): {}", entry, other),
+        })
+    })?;
+
+    let password = decode_credential_blob(&blob)
+        .ok_or_else(|| MyError::Keychain(format!("Credential '{}' is not valid text", entry)))?;
+
+    let password = password.trim().to_string();
+    if password.is_empty() {
+        Err(MyError::Keychain("Password retrieved but empty".into()))
+    } else {
+        Ok(password)
+    }
+}
+
+/// Credential blobs are UTF-16LE when written by Windows tools, but may be
+/// raw UTF-8 from other writers. A NUL byte means UTF-16.
+#[allow(dead_code)]
+pub fn decode_credential_blob(blob: &[u8]) -> Option<String> {
+    if !blob.contains(&0) {
+        return String::from_utf8(blob.to_vec()).ok();
+    }
+    if !blob.len().is_multiple_of(2) {
+        return None;
+    }
+    let wide: Vec<u16> = blob
+        .chunks_exact(2)
+        .map(|b| u16::from_le_bytes([b[0], b[1]]))
+        .collect();
+    String::from_utf16(&wide).ok().map(|s| s.trim_end_matches('\0').to_string())
 }
 
 #[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
@@ -215,30 +453,51 @@ pub fn get_rpc_password_from_keychain() -> Result<String, MyError> {
 // ────────────────────────────────────────────────────────────────────────────────
 //
 
+/// Blocks into an epoch before the epoch estimate is shown; earlier
+/// projections swing wildly on one or two block times.
+pub const MIN_EPOCH_BLOCKS_FOR_ESTIMATE: u64 = 5;
+
 /// Estimate % difficulty change for the *current epoch*.
+///
+/// `None` until `MIN_EPOCH_BLOCKS_FOR_ESTIMATE` blocks into the epoch
+/// (including exactly on a 2016 boundary), or when no time has elapsed
+/// since the epoch start block.
 pub fn estimate_difficulty_change(
     current_block_height: u64,
     current_block_time: u64,
     epoch_start_block_time: u64,
-) -> f64 {
-    let blocks_in_epoch = (current_block_height % DIFFICULTY_ADJUSTMENT_INTERVAL) - 1;
-    let expected = blocks_in_epoch * BLOCK_TIME_SECONDS;
+) -> Option<f64> {
+    let blocks_into_epoch = current_block_height % DIFFICULTY_ADJUSTMENT_INTERVAL;
+    if blocks_into_epoch < MIN_EPOCH_BLOCKS_FOR_ESTIMATE {
+        return None;
+    }
+    let expected = (blocks_into_epoch - 1) * BLOCK_TIME_SECONDS;
     let actual = current_block_time.saturating_sub(epoch_start_block_time);
 
-    let factor = expected as f64 / actual as f64;
-    (factor - 1.0) * 100.0
+    change_pct(expected, actual)
 }
 
 /// Estimate % difficulty change over the past 24 hours (144 blocks).
+///
+/// `None` if the two block times are equal or out of order.
 pub fn estimate_24h_difficulty_change(
     current_block_time: u64,
     block24_time: u64,
-) -> f64 {
+) -> Option<f64> {
     let expected = 144 * BLOCK_TIME_SECONDS;
     let actual = current_block_time.saturating_sub(block24_time);
 
+    change_pct(expected, actual)
+}
+
+/// Percent change implied by `expected` vs `actual` seconds, guarding the
+/// division so inf/NaN never reaches the UI.
+fn change_pct(expected: u64, actual: u64) -> Option<f64> {
+    if actual == 0 {
+        return None;
+    }
     let factor = expected as f64 / actual as f64;
-    (factor - 1.0) * 100.0
+    Some((factor - 1.0) * 100.0)
 }
 
 //
@@ -247,23 +506,26 @@ pub fn estimate_24h_difficulty_change(
 // ────────────────────────────────────────────────────────────────────────────────
 //
 
-/// Render the header block, including the epoch-cycle dot and version.
+/// Segments in the header's epoch progress meter.
+pub const EPOCH_METER_SEGMENTS: usize = 8;
+
+/// Epoch progress meter, e.g. `[●●●○○○○○]` at 40%.
+///
+/// Each of the `EPOCH_METER_SEGMENTS` segments stands for an equal share
+/// of the epoch and fills once that share is complete, so the meter is
+/// only full at 100%.
+pub fn epoch_meter(percent: f64) -> String {
+    let share = percent.clamp(0.0, 100.0) / 100.0;
+    let filled = ((share * EPOCH_METER_SEGMENTS as f64).floor() as usize).min(EPOCH_METER_SEGMENTS);
+    format!("[{}{}]", "●".repeat(filled), "○".repeat(EPOCH_METER_SEGMENTS - filled))
+}
+
+/// Render the header block, including the epoch progress meter and version.
 pub fn render_header(percent: f64, rates: &[f64]) -> Paragraph<'static> {
-    // Phase glyph (visual epoch indicator)
-    let dot = if percent == 0.0 {
-        "●" // New epoch (solid circle)
-    } else if percent < 25.0 {
-        "○"
-    } else if percent < 50.0 {
-        "◔"
-    } else if percent < 75.0 {
-        "◑"
-    } else {
-        "◕"
-    };
+    let meter = epoch_meter(percent);
 
     // We want the first phase change to be at 10%, and the percent is passed already converted.
-    let color = if percent < 10.0 { C_HASH_PHASE_NEW } else { C_HASH_PHASE };
+    let color = if percent < 10.0 { *C_HASH_PHASE_NEW } else { C_HASH_PHASE };
     let rate_display = if rates.is_empty() {
         "[---, ---, ---, ---, ---] EH/s".to_string()
     } else {
@@ -281,15 +543,15 @@ pub fn render_header(percent: f64, rates: &[f64]) -> Paragraph<'static> {
     Paragraph::new(vec![
         Spans::from(vec![
             Span::styled("₿lockChainInfo ", Style::default().fg(C_APP_TITLE)),
-            Span::styled(dot, Style::default().fg(color)),
+            Span::styled(meter, Style::default().fg(color)),
         ]),
         Spans::from(Span::styled(
             format!("v{}", APP_VERSION),
-            Style::default().fg(C_APP_VERSION).add_modifier(Modifier::ITALIC),
+            Style::default().fg(*C_APP_VERSION).add_modifier(Modifier::ITALIC),
         )),
         Spans::from(Span::styled(
             rate_display,
-            Style::default().fg(C_APP_VERSION),
+            Style::default().fg(*C_APP_VERSION),
         )),
     ])
     .alignment(Alignment::Center)
@@ -298,12 +560,23 @@ pub fn render_header(percent: f64, rates: &[f64]) -> Paragraph<'static> {
 
 /// Render footer message centered across the dashboard.
 pub fn render_footer<B: Backend>(f: &mut Frame<B>, area: Rect, message: &str) {
-    let footer = Paragraph::new(vec![Spans::from(Span::styled(
+    render_footer_styled(f, area, message, Style::default().fg(*C_FOOTER_DISPLAY));
+}
+
+/// Render a footer alert (e.g. node unreachable) in bold critical color.
+pub fn render_footer_alert<B: Backend>(f: &mut Frame<B>, area: Rect, message: &str) {
+    render_footer_styled(
+        f,
+        area,
         message,
-        Style::default().fg(C_FOOTER_DISPLAY),
-    ))])
-    .alignment(Alignment::Center)
-    .block(Block::default().borders(Borders::NONE));
+        Style::default().fg(*C_STATUS_HIGH).add_modifier(Modifier::BOLD),
+    );
+}
+
+fn render_footer_styled<B: Backend>(f: &mut Frame<B>, area: Rect, message: &str, style: Style) {
+    let footer = Paragraph::new(vec![Spans::from(Span::styled(message, style))])
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::NONE));
 
     f.render_widget(footer, area);
 }
@@ -319,11 +592,123 @@ lazy_static! {
     static ref LOG_FILE: Mutex<()> = Mutex::new(());
 }
 
-/// Append an error message to `error_log.txt`, with auto-rotation:
+/// Severity of a logged event. Ordered, so `LOG_LEVEL` works as a floor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    /// Routine events worth a trace (e.g. node reconnected).
+    Info,
+    /// Something noteworthy that didn't fail (reorg, block gap, fork alert).
+    Warn,
+    /// A failed RPC call or operation.
+    Error,
+}
+
+impl LogLevel {
+    /// Lowercase name, as written to the log.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LogLevel::Info => "info",
+            LogLevel::Warn => "warn",
+            LogLevel::Error => "error",
+        }
+    }
+
+    /// Parses a `LOG_LEVEL` value; unset or unknown values log everything.
+    pub fn from_env_value(value: Option<&str>) -> Self {
+        match value.map(|v| v.trim().to_ascii_lowercase()).as_deref() {
+            Some("warn") | Some("warning") => LogLevel::Warn,
+            Some("error") => LogLevel::Error,
+            _ => LogLevel::Info,
+        }
+    }
+}
+
+/// Read once from `LOG_LEVEL`; events below it are dropped.
+static LOG_LEVEL: Lazy<LogLevel> =
+    Lazy::new(|| LogLevel::from_env_value(env::var("LOG_LEVEL").ok().as_deref()));
+
+/// Line format of `error_log.txt`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    /// `[ts] [LEVEL] message` (default).
+    Text,
+    /// One JSON object per line: `{"ts":...,"level":"error","msg":...}`.
+    Json,
+}
+
+impl LogFormat {
+    /// Parses a `LOG_FORMAT` value; anything but `json` means text.
+    pub fn from_env_value(value: Option<&str>) -> Self {
+        match value.map(str::trim) {
+            Some(v) if v.eq_ignore_ascii_case("json") => LogFormat::Json,
+            _ => LogFormat::Text,
+        }
+    }
+}
+
+/// Read once from `LOG_FORMAT`; the format does not change while the app runs.
+static LOG_FORMAT: Lazy<LogFormat> =
+    Lazy::new(|| LogFormat::from_env_value(env::var("LOG_FORMAT").ok().as_deref()));
+
+/// One JSON log line; field order is kept for readability.
+#[derive(serde::Serialize)]
+struct JsonLogEntry<'a> {
+    ts: String,
+    level: &'a str,
+    msg: &'a str,
+}
+
+/// Formats one `error_log.txt` line (newline included).
+pub fn format_log_entry(format: LogFormat, level: LogLevel, message: &str) -> String {
+    match format {
+        LogFormat::Text => {
+            let ts = Local::now().format("%Y-%m-%d %H:%M:%S %z");
+            format!("[{}] [{}] {}\n", ts, level.as_str().to_ascii_uppercase(), message)
+        }
+        LogFormat::Json => {
+            let entry = JsonLogEntry {
+                ts: Local::now().to_rfc3339(),
+                level: level.as_str(),
+                msg: message,
+            };
+            // Serializing a struct of strings cannot fail.
+            format!("{}\n", serde_json::to_string(&entry).unwrap_or_default())
+        }
+    }
+}
+
+/// True for logs written by old versions, which logged errors with `{:?}`
+/// right after the timestamp (`[ts] JsonParsingError(...)`). Only text lines
+/// are checked, and only the text after the timestamp, so neither JSON lines
+/// nor messages that merely mention the variant trigger a rotation.
+fn is_legacy_log(contents: &str) -> bool {
+    contents.lines().any(|line| {
+        line.starts_with('[')
+            && line
+                .split_once("] ")
+                .is_some_and(|(_, rest)| rest.starts_with("JsonParsingError("))
+    })
+}
+
+/// Append an error-level message to `error_log.txt`; see [`log_event`].
+pub fn log_error(message: &str) -> io::Result<()> {
+    log_event(LogLevel::Error, message)
+}
+
+/// Append an event to `error_log.txt` in [`data_dir`], with auto-rotation:
+/// - Skipped if below the `LOG_LEVEL` floor
 /// - Rotates if legacy format detected
 /// - Rotates if file exceeds 500 KB
-pub fn log_error(message: &str) -> io::Result<()> {
-    let log_path = "error_log.txt";
+///
+/// Lines are plain text, or JSON when `LOG_FORMAT=json`.
+pub fn log_event(level: LogLevel, message: &str) -> io::Result<()> {
+    if level < *LOG_LEVEL {
+        return Ok(());
+    }
+
+    let dir = data_dir();
+    let log_path = dir.join("error_log.txt");
+    let log_path = log_path.as_path();
 
     // Rotate if old-format log detected
     if let Ok(meta) = metadata(log_path) {
@@ -331,9 +716,9 @@ pub fn log_error(message: &str) -> io::Result<()> {
             let mut contents = String::new();
             OpenOptions::new().read(true).open(log_path)?.read_to_string(&mut contents)?;
 
-            if contents.contains("JsonParsingError(") {
+            if is_legacy_log(&contents) {
                 let ts = Local::now().format("%Y%m%d_%H%M%S");
-                rename(log_path, format!("error_log_{}.txt", ts))?;
+                rename(log_path, dir.join(format!("error_log_{}.txt", ts)))?;
             }
         }
     }
@@ -342,7 +727,7 @@ pub fn log_error(message: &str) -> io::Result<()> {
     if let Ok(meta) = metadata(log_path) {
         if meta.len() > 500_000 {
             let ts = Local::now().format("%Y%m%d_%H%M%S");
-            rename(log_path, format!("error_log_{}.txt", ts))?;
+            rename(log_path, dir.join(format!("error_log_{}.txt", ts)))?;
         }
     }
 
@@ -350,22 +735,140 @@ pub fn log_error(message: &str) -> io::Result<()> {
     let _lock = LOG_FILE.lock().unwrap();
 
     let mut file = OpenOptions::new().create(true).append(true).open(log_path)?;
-    let ts = Local::now().format("%Y-%m-%d %H:%M:%S %z");
-    let entry = format!("[{}] {}\n", ts, message);
+    let entry = format_log_entry(*LOG_FORMAT, level, message);
 
     file.write_all(entry.as_bytes())
 }
 
+//
+// ────────────────────────────────────────────────────────────────────────────────
+//   POLLING JITTER
+// ────────────────────────────────────────────────────────────────────────────────
+
+/// Randomize a polling interval by up to ±`pct` percent.
+///
+/// The worker loops start within milliseconds of each other; with fixed
+/// sleeps they drift into lockstep and periodically hit the node together.
+/// A uniform ±jitter spreads calls out while keeping the *average*
+/// interval equal to `base`. `pct = 0` returns `base` unchanged.
+pub fn jittered(base: Duration, pct: u8) -> Duration {
+    if pct == 0 {
+        return base;
+    }
+
+    let spread = pct.min(100) as f64 / 100.0;
+    let factor = rand::rng().random_range((1.0 - spread)..=(1.0 + spread));
+    base.mul_f64(factor)
+}
+
+//
+// ────────────────────────────────────────────────────────────────────────────────
+//   DATA DIRECTORY
+// ────────────────────────────────────────────────────────────────────────────────
+//
+
+/// Application directory name under the platform data root.
+const APP_DIR_NAME: &str = "blockchaininfo";
+
+/// Resolved once; the directory does not move while the app runs.
+static DATA_DIR: Lazy<PathBuf> = Lazy::new(resolve_data_dir);
+
+/// Directory for every file the app writes (logs, caches, exports).
+///
+/// Resolution order:
+/// 1. `BCI_DATA_DIR` environment variable
+/// 2. Platform default:
+///    - Linux/BSD: `$XDG_DATA_HOME/blockchaininfo` or `~/.local/share/blockchaininfo`
+///    - macOS: `~/Library/Application Support/blockchaininfo`
+///    - Windows: `%APPDATA%\blockchaininfo`
+/// 3. The current directory, if no home directory can be found
+///
+/// The directory is created on first use. If that fails, the current
+/// directory is used so logging never stops working.
+pub fn data_dir() -> PathBuf {
+    DATA_DIR.clone()
+}
+
+/// Where `BLOCK_HISTORY` is saved on exit and restored at startup.
+pub fn block_history_path() -> PathBuf {
+    data_dir().join("block_history.json")
+}
+
+fn resolve_data_dir() -> PathBuf {
+    let dir = env::var_os("BCI_DATA_DIR")
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+        .or_else(platform_data_dir)
+        .unwrap_or_else(|| PathBuf::from("."));
+
+    match fs::create_dir_all(&dir) {
+        Ok(()) => dir,
+        Err(_) => PathBuf::from("."),
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn platform_data_dir() -> Option<PathBuf> {
+    let home = env::var_os("HOME").filter(|v| !v.is_empty())?;
+    Some(PathBuf::from(home).join("Library/Application Support").join(APP_DIR_NAME))
+}
+
+#[cfg(windows)]
+fn platform_data_dir() -> Option<PathBuf> {
+    let appdata = env::var_os("APPDATA").filter(|v| !v.is_empty())?;
+    Some(PathBuf::from(appdata).join(APP_DIR_NAME))
+}
+
+#[cfg(not(any(target_os = "macos", windows)))]
+fn platform_data_dir() -> Option<PathBuf> {
+    if let Some(xdg) = env::var_os("XDG_DATA_HOME").filter(|v| !v.is_empty()) {
+        let xdg = PathBuf::from(xdg);
+        // The XDG spec requires an absolute path; ignore anything else.
+        if xdg.is_absolute() {
+            return Some(xdg.join(APP_DIR_NAME));
+        }
+    }
+    let home = env::var_os("HOME").filter(|v| !v.is_empty())?;
+    Some(PathBuf::from(home).join(".local/share").join(APP_DIR_NAME))
+}
+
 /// Load miners.json into a parsed MinersData struct.
-pub fn load_miners_data() -> Result<MinersData, MyError> {
-    let path = "miners.json";
-    let data = fs::read_to_string(path).map_err(|e| {
-        if e.kind() == io::ErrorKind::NotFound {
-            MyError::FileNotFound(format!("'{}' not found.", path))
-        } else {
-            MyError::Io(e)
+///
+/// `path` is the configured `miners_json`, used as given. Without one,
+/// looks in [`data_dir`] first, then falls back to the current directory
+/// (where the repository ships its copy).
+///
+/// ### Errors
+/// - `FileNotFound` if no file exists at the path(s) tried
+/// - I/O or JSON errors reading the file
+pub fn load_miners_data(path: Option<&str>) -> Result<MinersData, MyError> {
+    let path = match path {
+        Some(path) => {
+            let path = PathBuf::from(path);
+            if !path.exists() {
+                return Err(MyError::FileNotFound(format!(
+                    "'{}' (miners_json) not found.",
+                    path.display()
+                )));
+            }
+            path
         }
-    })?;
+        None => {
+            let candidates = [data_dir().join("miners.json"), PathBuf::from("miners.json")];
+            candidates
+                .iter()
+                .find(|p| p.exists())
+                .cloned()
+                .ok_or_else(|| {
+                    MyError::FileNotFound(format!(
+                        "'miners.json' not found in '{}' or the current directory.",
+                        candidates[0].parent().unwrap_or(Path::new(".")).display()
+                    ))
+                })?
+        }
+    };
+
+    let data = fs::read_to_string(path)?;
 
     Ok(serde_json::from_str(&data)?)
 }
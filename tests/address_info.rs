@@ -0,0 +1,71 @@
+//! Wallet view of an address in the transaction lookup's address mode.
+
+use blockchaininfo::models::address_info::{AddressInfo, AddressInfoJsonWrap};
+use serde_json::json;
+
+fn info(value: serde_json::Value) -> AddressInfo {
+    serde_json::from_value(value).expect("address info deserializes")
+}
+
+#[test]
+fn owned_taproot_address_lists_labels_and_descriptor() {
+    let a = info(json!({
+        "address": "bc1pxyz",
+        "scriptPubKey": "5120abcd",
+        "ismine": true,
+        "solvable": true,
+        "desc": "tr([d34db33f/86h/0h/0h/0/3]abcd)#checksum",
+        "iswatchonly": false,
+        "isscript": false,
+        "iswitness": true,
+        "witness_version": 1,
+        "ischange": false,
+        "hdkeypath": "m/86h/0h/0h/0/3",
+        "labels": ["savings", ""]
+    }));
+
+    assert_eq!(a.ownership(), "mine");
+    assert_eq!(a.kind(), "taproot (witness v1)");
+    let s = a.summary();
+    assert!(s.contains("Labels:     savings\n"));
+    assert!(s.contains("Chain:      receive"));
+    assert!(s.contains("HD path:    m/86h/0h/0h/0/3"));
+    assert!(s.contains("Descriptor: tr("));
+}
+
+#[test]
+fn foreign_address_reports_not_in_wallet() {
+    let a = info(json!({
+        "address": "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy",
+        "scriptPubKey": "a914b472a266d0bd89c13706a4132ccfb16f7c3b9fcb87",
+        "isscript": true,
+        "iswitness": false,
+        "labels": []
+    }));
+
+    assert_eq!(a.ownership(), "not in wallet");
+    assert_eq!(a.kind(), "P2SH");
+    let s = a.summary();
+    assert!(s.contains("Labels:     none"));
+    assert!(s.contains("Solvable:   no"));
+    assert!(!s.contains("Chain:"));
+}
+
+#[test]
+fn watch_only_wins_over_not_in_wallet() {
+    let a = info(json!({ "address": "tb1qabc", "iswatchonly": true, "iswitness": true }));
+    assert_eq!(a.ownership(), "watch-only");
+    assert_eq!(a.kind(), "P2WPKH");
+}
+
+#[test]
+fn no_wallet_error_leaves_result_empty() {
+    let wrap: AddressInfoJsonWrap = serde_json::from_value(json!({
+        "result": null,
+        "error": { "code": -18, "message": "No wallet is loaded." },
+        "id": "1"
+    }))
+    .unwrap();
+    assert!(wrap.result.is_none());
+    assert_eq!(wrap.error.unwrap().code, -18);
+}
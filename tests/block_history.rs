@@ -0,0 +1,51 @@
+//! Saving and restoring the 144-block miner window.
+
+use blockchaininfo::models::block_info::BlockHistory;
+use std::path::PathBuf;
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("bci-{}-{}.json", name, std::process::id()))
+}
+
+#[test]
+fn round_trip_drops_blocks_outside_window() {
+    let path = temp_path("history-roundtrip");
+
+    let history = BlockHistory::new();
+    for height in 1_000..1_144 {
+        let miner = if height % 2 == 0 { "Foundry USA" } else { "AntPool" };
+        history.add_block(height, Some(miner.to_string()));
+    }
+    history.save(&path).unwrap();
+
+    // Ten new blocks since the save: the ten oldest entries fall out.
+    let restored = BlockHistory::load(&path, 1_153);
+    let kept = restored.last_n_with_heights(200);
+    assert_eq!(kept.len(), 134);
+    assert_eq!(kept.last().unwrap().0, 1_010);
+    assert_eq!(kept.first().unwrap().0, 1_143);
+    assert_eq!(restored.last_miner().as_deref(), Some("AntPool"));
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn corrupt_or_missing_file_gives_empty_history() {
+    let path = temp_path("history-corrupt");
+    std::fs::write(&path, "{not json").unwrap();
+    assert!(BlockHistory::load(&path, 900_000).last_n_with_heights(144).is_empty());
+    let _ = std::fs::remove_file(&path);
+
+    assert!(BlockHistory::load(&temp_path("history-missing"), 900_000)
+        .last_n_with_heights(144)
+        .is_empty());
+}
+
+#[test]
+fn same_height_replaces_last_entry() {
+    let history = BlockHistory::new();
+    history.add_block(5, Some("Unknown".to_string()));
+    history.add_block(5, Some("F2Pool".to_string()));
+    assert_eq!(history.last_n_with_heights(10).len(), 1);
+    assert_eq!(history.last_miner().as_deref(), Some("F2Pool"));
+}
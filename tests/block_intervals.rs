@@ -0,0 +1,53 @@
+//! Rolling block-interval history and its inline sparkline.
+
+use blockchaininfo::models::block_intervals::{BlockIntervals, BLOCK_INTERVAL_WINDOW};
+use blockchaininfo::utils::text_sparkline;
+
+#[test]
+fn first_block_only_sets_the_baseline() {
+    let mut h = BlockIntervals::default();
+    assert_eq!(h.observe(100, 1_000), None);
+    assert_eq!(h.mean(), None);
+
+    assert_eq!(h.observe(101, 1_600), Some(600));
+    assert_eq!(h.observe(102, 1_660), Some(60));
+    assert_eq!(h.intervals().iter().copied().collect::<Vec<_>>(), vec![600, 60]);
+    assert_eq!(h.mean(), Some(330));
+}
+
+#[test]
+fn skipped_heights_and_reorgs_rebase_without_a_sample() {
+    let mut h = BlockIntervals::default();
+    h.observe(100, 1_000);
+    // Two blocks arrived between polls: per-block interval unknown.
+    assert_eq!(h.observe(102, 2_000), None);
+    // Reorg back to the same height.
+    assert_eq!(h.observe(102, 2_100), None);
+    assert_eq!(h.observe(103, 2_700), Some(600));
+    assert_eq!(h.intervals().len(), 1);
+}
+
+#[test]
+fn earlier_timestamp_counts_as_zero() {
+    let mut h = BlockIntervals::default();
+    h.observe(100, 5_000);
+    assert_eq!(h.observe(101, 4_900), Some(0));
+}
+
+#[test]
+fn window_is_bounded() {
+    let mut h = BlockIntervals::default();
+    for i in 0..=(BLOCK_INTERVAL_WINDOW as u64 + 5) {
+        h.observe(i, i * 600 + i);
+    }
+    assert_eq!(h.intervals().len(), BLOCK_INTERVAL_WINDOW);
+    // Oldest samples were dropped: every remaining interval is 601 s.
+    assert!(h.intervals().iter().all(|&s| s == 601));
+}
+
+#[test]
+fn sparkline_scales_to_the_largest_value() {
+    assert_eq!(text_sparkline(&[0, 5, 10]), "▁▅█");
+    assert_eq!(text_sparkline(&[0, 0]), "▁▁");
+    assert_eq!(text_sparkline(&[]), "");
+}
@@ -0,0 +1,38 @@
+//! Throughput from `getchaintxstats`, including the empty-window edge case.
+
+use blockchaininfo::models::chain_tx_stats::ChainTxStats;
+
+#[test]
+fn rate_over_window() {
+    let stats: ChainTxStats = serde_json::from_str(
+        r#"{"time":1700000000,"txcount":900000000,"window_final_block_hash":"00",
+            "window_final_block_height":820000,"window_block_count":144,
+            "window_tx_count":432000,"window_interval":86400,"txrate":5.0}"#,
+    )
+    .unwrap();
+
+    assert_eq!(stats.tx_per_sec(), Some(5.0));
+}
+
+#[test]
+fn genesis_window_has_no_rate() {
+    // At genesis Core omits window_tx_count / window_interval / txrate.
+    let stats: ChainTxStats = serde_json::from_str(
+        r#"{"time":1231006505,"txcount":1,"window_final_block_hash":"00",
+            "window_final_block_height":0,"window_block_count":0}"#,
+    )
+    .unwrap();
+
+    assert_eq!(stats.tx_per_sec(), None);
+}
+
+#[test]
+fn zero_interval_does_not_divide() {
+    let stats = ChainTxStats {
+        window_tx_count: Some(10),
+        window_interval: Some(0),
+        ..Default::default()
+    };
+
+    assert_eq!(stats.tx_per_sec(), None);
+}
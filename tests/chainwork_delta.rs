@@ -0,0 +1,42 @@
+//! Chainwork parsing and the work added since the epoch-start block.
+
+use blockchaininfo::models::blockchain_info::{format_work_bits, parse_chainwork, BlockchainInfo};
+use blockchaininfo::models::errors::MyError;
+
+fn tip(chainwork: &str) -> BlockchainInfo {
+    BlockchainInfo {
+        chainwork: chainwork.to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn parses_zero_padded_hex() {
+    let hex = "0000000000000000000000000000000000000000000000000000000000000400";
+    assert_eq!(parse_chainwork(hex).unwrap(), 1024);
+    assert!(matches!(parse_chainwork("xyz"), Err(MyError::InvalidChainworkHexString(_))));
+}
+
+#[test]
+fn delta_since_epoch_start() {
+    let info = tip("0000000000000000000000000000000000000000000000000000000000000c00");
+    let work = info.chainwork_since("400").unwrap();
+    assert_eq!(work, 2048);
+    assert_eq!(format_work_bits(work), "11.00 bits");
+    assert_eq!(info.formatted_chainwork_bits().unwrap(), "11.58 bits");
+}
+
+#[test]
+fn tip_at_epoch_start_added_nothing() {
+    let info = tip("c00");
+    assert_eq!(info.chainwork_since("c00").unwrap(), 0);
+    assert_eq!(format_work_bits(0), "0 bits");
+}
+
+#[test]
+fn bad_or_ahead_values_fail() {
+    // Epoch-start block not cached yet (empty chainwork).
+    assert!(tip("c00").chainwork_since("").is_err());
+    assert!(tip("not hex").chainwork_since("400").is_err());
+    assert!(tip("400").chainwork_since("c00").is_err());
+}
@@ -0,0 +1,43 @@
+//! Peer client classification from user agents.
+
+use blockchaininfo::models::client_tags::match_client;
+use blockchaininfo::models::peer_info::PeerInfo;
+
+fn client(subver: &str) -> Option<String> {
+    PeerInfo::extract_client(subver, 70016)
+}
+
+#[test]
+fn core_and_derivatives() {
+    assert_eq!(client("/Satoshi:27.0.0/").as_deref(), Some("Core"));
+    assert_eq!(client("/Satoshi:27.1.0/Knots:20240801/").as_deref(), Some("Knots"));
+    assert_eq!(client("/Ronin:23.0.1/Satoshi:23.0.0/").as_deref(), Some("Ronin"));
+    // Feature flags don't override the client.
+    assert_eq!(client("/Satoshi:29.2.0/Knots:20251110/UASF-BIP110:0.1/").as_deref(), Some("Knots"));
+}
+
+#[test]
+fn alternative_clients() {
+    assert_eq!(client("/bcoin:2.2.0/").as_deref(), Some("bcoin"));
+    assert_eq!(client("/btcwire:0.5.0/btcd:0.24.2/").as_deref(), Some("btcd"));
+    assert_eq!(client("/libbitcoin:3.8.0/").as_deref(), Some("libbitcoin"));
+    assert_eq!(client("/bread:2.1/").as_deref(), Some("bread"));
+    assert_eq!(client("/breadwallet:1.6.5/").as_deref(), Some("bread"));
+    assert_eq!(client("/Gocoin:1.10.3/").as_deref(), Some("Gocoin"));
+    assert_eq!(client("/Bitcoin ABC:0.30.8(EB32.0)/").as_deref(), Some("Bitcoin ABC"));
+}
+
+#[test]
+fn alternative_clients_ignore_protocol_floor() {
+    assert_eq!(PeerInfo::extract_client("/libbitcoin:3.8.0/", 70013).as_deref(), Some("libbitcoin"));
+    // Satoshi-based agents below 70016 stay excluded.
+    assert_eq!(PeerInfo::extract_client("/Satoshi:0.20.1/", 70015), None);
+}
+
+#[test]
+fn unknown_agents() {
+    assert_eq!(client("/MySatoshiFork:1.0/"), Some("Other".to_string()));
+    assert_eq!(client("/crawler:0.1/"), None);
+    assert_eq!(client(""), None);
+    assert_eq!(match_client("/btcwire:0.5.0/"), None);
+}
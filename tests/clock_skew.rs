@@ -0,0 +1,41 @@
+//! Median peer time offset and clock-skew warning.
+
+use blockchaininfo::models::peer_info::{PeerInfo, CLOCK_SKEW_WARN_SECS};
+
+fn peers(offsets: &[i64]) -> Vec<PeerInfo> {
+    offsets
+        .iter()
+        .map(|&timeoffset| PeerInfo {
+            timeoffset,
+            ..Default::default()
+        })
+        .collect()
+}
+
+#[test]
+fn median_ignores_outliers() {
+    let p = peers(&[40, -3600, 42, 45, 7200, 41, 43]);
+    assert_eq!(PeerInfo::median_time_offset(&p), Some(42));
+    assert_eq!(PeerInfo::clock_skew(&p), Some(42));
+}
+
+#[test]
+fn small_offsets_do_not_warn() {
+    let p = peers(&[-2, 0, 1, 3, -1]);
+    assert_eq!(PeerInfo::median_time_offset(&p), Some(0));
+    assert_eq!(PeerInfo::clock_skew(&p), None);
+}
+
+#[test]
+fn negative_skew_warns_at_threshold() {
+    let t = CLOCK_SKEW_WARN_SECS;
+    let p = peers(&[-t, -t, -t, 0, -t - 5]);
+    assert_eq!(PeerInfo::clock_skew(&p), Some(-t));
+}
+
+#[test]
+fn too_few_peers_yield_nothing() {
+    let p = peers(&[600, 600, 600]);
+    assert_eq!(PeerInfo::median_time_offset(&p), None);
+    assert_eq!(PeerInfo::clock_skew(&p), None);
+}
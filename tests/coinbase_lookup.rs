@@ -0,0 +1,50 @@
+//! Coinbase detection for the transaction lookup.
+
+use blockchaininfo::models::transaction_info::GetRawTransactionResponse;
+
+fn coinbase_fixture(confirmations: u32) -> GetRawTransactionResponse {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/tx/coinbase.json");
+    let mut value: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+    value["confirmations"] = confirmations.into();
+    serde_json::from_value(value).expect("coinbase tx deserializes")
+}
+
+#[test]
+fn coinbase_input_without_prevout_parses() {
+    let tx = coinbase_fixture(58);
+    assert!(tx.is_coinbase());
+    assert!(tx.vin[0].txid.is_empty());
+}
+
+#[test]
+fn maturity_counts_down_to_100_confirmations() {
+    assert_eq!(coinbase_fixture(58).coinbase_blocks_to_maturity(), Some(42));
+    assert_eq!(
+        coinbase_fixture(58).coinbase_status().as_deref(),
+        Some("Coinbase (matures in 42 blocks)")
+    );
+    assert_eq!(
+        coinbase_fixture(99).coinbase_status().as_deref(),
+        Some("Coinbase (matures in 1 block)")
+    );
+    assert_eq!(
+        coinbase_fixture(100).coinbase_status().as_deref(),
+        Some("Coinbase (mature)")
+    );
+}
+
+#[test]
+fn regular_tx_is_not_coinbase() {
+    let json = r#"{
+        "txid": "a1075db55d416d3ca199f55b6084e2115b9345e16c5cf302fc80e9d5fbf5d48d",
+        "size": 225, "vsize": 225, "weight": 900, "version": 1,
+        "vin": [{"txid": "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b", "vout": 0, "sequence": 4294967295}],
+        "vout": [{"value": 0.5, "n": 0}],
+        "confirmations": 3, "blocktime": 1700000000
+    }"#;
+    let tx: GetRawTransactionResponse = serde_json::from_str(json).unwrap();
+
+    assert!(!tx.is_coinbase());
+    assert_eq!(tx.coinbase_status(), None);
+}
@@ -0,0 +1,57 @@
+//! Coinbase payout split into subsidy (from height) and fees.
+
+use blockchaininfo::models::block_info::BlockInfoFull;
+use blockchaininfo::models::coinbase_reward::CoinbaseReward;
+use serde_json::{json, Value};
+
+fn output(btc: f64) -> Value {
+    json!({"value": btc, "n": 0, "scriptPubKey": {"asm": "", "desc": "", "hex": "", "type": "witness_v0_keyhash"}})
+}
+
+fn block(height: u64, coinbase_outputs: Vec<Value>) -> BlockInfoFull {
+    let coinbase = json!({
+        "txid": "00", "hash": "00", "version": 2, "size": 200, "vsize": 150,
+        "weight": 600, "locktime": 0,
+        "vin": [{"coinbase": "03a0830c", "sequence": 0xffff_ffffu32}],
+        "vout": coinbase_outputs
+    });
+    serde_json::from_value(json!({
+        "hash": "00", "confirmations": 1, "height": height, "version": 0x2000_0000u32,
+        "versionHex": "20000000", "merkleroot": "00", "time": 1_700_000_000u64,
+        "mediantime": 1_700_000_000u64, "nonce": 0, "bits": "17034219", "difficulty": 1.0,
+        "chainwork": "00", "nTx": 1, "strippedsize": 0, "size": 0, "weight": 0,
+        "tx": [coinbase]
+    }))
+    .unwrap()
+}
+
+#[test]
+fn splits_outputs_into_subsidy_and_fees() {
+    // Payout plus a zero-value witness commitment output.
+    let reward = CoinbaseReward::from_block(&block(850_000, vec![output(3.20912345), output(0.0)])).unwrap();
+
+    assert_eq!(reward.height, 850_000);
+    assert_eq!(reward.total, 320_912_345);
+    assert_eq!(reward.subsidy, 312_500_000);
+    assert_eq!(reward.fees(), 8_412_345);
+    assert_eq!(reward.subsidy_btc(), 3.125);
+    assert!((reward.fees_btc() - 0.08412345).abs() < 1e-12);
+}
+
+#[test]
+fn underclaimed_coinbase_has_no_fees() {
+    let reward = CoinbaseReward::from_block(&block(700_000, vec![output(6.0)])).unwrap();
+    assert_eq!(reward.subsidy, 625_000_000);
+    assert_eq!(reward.fees(), 0);
+}
+
+#[test]
+fn block_without_coinbase_is_none() {
+    let empty: BlockInfoFull = serde_json::from_value(json!({
+        "hash": "00", "confirmations": 1, "height": 1, "version": 1, "versionHex": "00000001",
+        "merkleroot": "00", "time": 0, "mediantime": 0, "nonce": 0, "bits": "1d00ffff",
+        "difficulty": 1.0, "chainwork": "00", "nTx": 0, "strippedsize": 0, "size": 0, "weight": 0
+    }))
+    .unwrap();
+    assert_eq!(CoinbaseReward::from_block(&empty), None);
+}
@@ -0,0 +1,164 @@
+//! Minimal mock Bitcoin Core JSON-RPC server for integration tests.
+//!
+//! Listens on an ephemeral localhost port and answers each POST with a
+//! canned response chosen by the JSON-RPC `method` field. One request is
+//! served per connection (`Connection: close`), which is all reqwest needs.
+//!
+//! Built on `std::net` so the test suite needs no extra dependencies.
+//!
+//! Also holds `mempool_entry`, the shared `MempoolEntry` builder.
+
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use blockchaininfo::config::RpcConfig;
+use blockchaininfo::models::mempool_info::{Fees, MempoolEntry};
+
+/// How the mock answers a given RPC method.
+#[derive(Clone)]
+pub enum Reply {
+    /// HTTP status and raw body.
+    Body(u16, String),
+    /// Accept the request but never answer (drives client timeouts).
+    Hang,
+}
+
+impl Reply {
+    /// 200 OK with `body`.
+    pub fn ok(body: impl Into<String>) -> Self {
+        Reply::Body(200, body.into())
+    }
+}
+
+pub struct MockRpc {
+    pub address: String,
+    /// Methods received, in arrival order.
+    pub calls: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockRpc {
+    /// Starts a server answering from `routes`; unknown methods get Core's
+    /// "Method not found" error object with HTTP 404.
+    pub fn start(routes: HashMap<String, Reply>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind mock rpc");
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let routes = Arc::new(routes);
+
+        let calls_bg = calls.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let routes = routes.clone();
+                let calls = calls_bg.clone();
+                thread::spawn(move || handle(stream, &routes, &calls));
+            }
+        });
+
+        MockRpc { address, calls }
+    }
+
+    /// An `RpcConfig` pointing at this server.
+    pub fn config(&self) -> RpcConfig {
+        config_for(&self.address)
+    }
+}
+
+pub fn config_for(address: &str) -> RpcConfig {
+    toml::from_str(&format!(
+        "username = \"user\"\npassword = \"pass\"\naddress = \"{}\"\n",
+        address
+    ))
+    .expect("valid test config")
+}
+
+/// Reads `tests/fixtures/<version>/<name>.json`.
+pub fn fixture(version: &str, name: &str) -> String {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "tests", "fixtures", version, name]
+        .iter()
+        .collect::<PathBuf>()
+        .with_extension("json");
+    std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("missing fixture {}: {}", path.display(), e))
+}
+
+fn handle(stream: TcpStream, routes: &HashMap<String, Reply>, calls: &Mutex<Vec<String>>) {
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+    // Headers
+    let mut content_length = 0usize;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).unwrap_or(0) == 0 {
+            return;
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or(0);
+            }
+        }
+    }
+
+    // Body → method name
+    let mut body = vec![0u8; content_length];
+    if reader.read_exact(&mut body).is_err() {
+        return;
+    }
+    let method = serde_json::from_slice::<serde_json::Value>(&body)
+        .ok()
+        .and_then(|v| v["method"].as_str().map(String::from))
+        .unwrap_or_default();
+    calls.lock().unwrap().push(method.clone());
+
+    let reply = routes.get(&method).cloned().unwrap_or_else(|| {
+        Reply::Body(
+            404,
+            r#"{"result":null,"error":{"code":-32601,"message":"Method not found"},"id":"1"}"#
+                .to_string(),
+        )
+    });
+
+    match reply {
+        Reply::Hang => thread::sleep(Duration::from_secs(60)),
+        Reply::Body(status, body) => {
+            let mut stream = stream;
+            let response = format!(
+                "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            let _ = stream.write_all(response.as_bytes());
+        }
+    }
+}
+
+/// A standalone (no ancestors/descendants) mempool entry paying
+/// `fee_sats` for `vsize` vbytes.
+pub fn mempool_entry(fee_sats: u64, vsize: u64) -> MempoolEntry {
+    let btc = fee_sats as f64 / 100_000_000.0;
+    MempoolEntry {
+        vsize,
+        weight: vsize * 4,
+        time: 0,
+        height: 0,
+        descendantcount: 1,
+        descendantsize: vsize,
+        ancestorcount: 1,
+        ancestorsize: vsize,
+        wtxid: [0u8; 32],
+        fees: Fees { base: btc, modified: btc, ancestor: btc, descendant: btc },
+        bip125_replaceable: false,
+        unbroadcast: None,
+    }
+}
@@ -0,0 +1,29 @@
+//! BIP152 high-bandwidth peer aggregation.
+
+use blockchaininfo::models::peer_info::PeerInfo;
+
+fn peer(hb_to: bool, hb_from: bool) -> PeerInfo {
+    PeerInfo {
+        bip152_hb_to: hb_to,
+        bip152_hb_from: hb_from,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn counts_hb_peers_in_and_out() {
+    let peers = vec![
+        peer(true, false),
+        peer(true, true),
+        peer(true, false),
+        peer(false, true),
+        peer(false, false),
+    ];
+
+    assert_eq!(PeerInfo::count_hb_compact_peers(&peers), (3, 2));
+}
+
+#[test]
+fn no_peers_is_zero() {
+    assert_eq!(PeerInfo::count_hb_compact_peers(&[]), (0, 0));
+}
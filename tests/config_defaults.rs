@@ -0,0 +1,11 @@
+//! `RpcConfig::default()` matches what serde fills in for a minimal file.
+
+use blockchaininfo::config::{parse_config_str, RpcConfig};
+
+#[test]
+fn default_matches_serde_defaults() {
+    let parsed = parse_config_str("[bitcoin_rpc]\naddress = \"http://127.0.0.1:8332\"\n").unwrap();
+    let built = RpcConfig { address: "http://127.0.0.1:8332".to_string(), ..RpcConfig::default() };
+
+    assert_eq!(toml::to_string(&built).unwrap(), toml::to_string(&parsed).unwrap());
+}
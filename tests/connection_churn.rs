@@ -0,0 +1,45 @@
+//! Connection ratio and churn tracking.
+
+use blockchaininfo::models::connection_churn::{
+    inbound_outbound_ratio, ChurnLevel, ConnectionChurn, CHURN_WINDOW_SECS,
+};
+
+#[test]
+fn first_observation_is_baseline() {
+    let mut churn = ConnectionChurn::default();
+    churn.observe(1_000, 40, 10);
+
+    assert_eq!(churn.churn(), 0);
+    assert_eq!(churn.level(), ChurnLevel::Normal);
+}
+
+#[test]
+fn sums_changes_in_both_directions() {
+    let mut churn = ConnectionChurn::default();
+    churn.observe(1_000, 40, 10);
+    churn.observe(1_007, 42, 10); // +2 in
+    churn.observe(1_014, 39, 9); // -3 in, -1 out
+    churn.observe(1_021, 39, 9); // unchanged
+
+    assert_eq!(churn.churn(), 6);
+    assert_eq!(churn.level(), ChurnLevel::Elevated);
+}
+
+#[test]
+fn old_changes_leave_the_window() {
+    let mut churn = ConnectionChurn::default();
+    churn.observe(1_000, 40, 10);
+    churn.observe(1_010, 30, 10); // -10 in
+    assert_eq!(churn.level(), ChurnLevel::High);
+
+    churn.observe(1_010 + CHURN_WINDOW_SECS, 31, 10);
+    assert_eq!(churn.churn(), 1);
+    assert_eq!(churn.level(), ChurnLevel::Normal);
+}
+
+#[test]
+fn ratio_needs_outbound_peers() {
+    assert_eq!(inbound_outbound_ratio(30, 10), Some(3.0));
+    assert_eq!(inbound_outbound_ratio(0, 8), Some(0.0));
+    assert_eq!(inbound_outbound_ratio(12, 0), None);
+}
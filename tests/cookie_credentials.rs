@@ -0,0 +1,33 @@
+//! Cookie-file credentials are cached between requests.
+
+use std::fs::{self, File};
+use std::time::{Duration, SystemTime};
+
+use blockchaininfo::config::RpcConfig;
+
+#[test]
+fn cookie_is_not_rechecked_within_the_interval() {
+    let path = std::env::temp_dir().join(format!("bci-cookie-{}", std::process::id()));
+    fs::write(&path, "__cookie__:first").unwrap();
+
+    let config = RpcConfig {
+        address: "http://127.0.0.1:8332".to_string(),
+        cookie_path: Some(path.to_string_lossy().into_owned()),
+        ..RpcConfig::default()
+    };
+    assert_eq!(config.credentials(), ("__cookie__".to_string(), "first".to_string()));
+
+    // A rotated cookie with a clearly newer mtime, as after a node restart.
+    fs::write(&path, "__cookie__:second").unwrap();
+    File::options()
+        .write(true)
+        .open(&path)
+        .unwrap()
+        .set_modified(SystemTime::now() + Duration::from_secs(60))
+        .unwrap();
+
+    // Still inside the recheck interval: served from the cache, no stat.
+    assert_eq!(config.credentials(), ("__cookie__".to_string(), "first".to_string()));
+
+    let _ = fs::remove_file(&path);
+}
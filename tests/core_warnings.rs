@@ -0,0 +1,55 @@
+//! Core `warnings`: string (≤27) and array (28+) shapes.
+
+use blockchaininfo::models::blockchain_info::BlockchainInfo;
+use blockchaininfo::models::core_warnings::node_warnings;
+use blockchaininfo::models::network_info::NetworkInfo;
+use serde_json::{json, Value};
+
+fn blockchain_info(warnings: Value) -> BlockchainInfo {
+    serde_json::from_value(json!({
+        "bestblockhash": "00",
+        "blocks": 1,
+        "chain": "main",
+        "chainwork": "00",
+        "difficulty": 1.0,
+        "initialblockdownload": false,
+        "mediantime": 0,
+        "pruned": false,
+        "size_on_disk": 0,
+        "time": 0,
+        "verificationprogress": 1.0,
+        "warnings": warnings,
+    }))
+    .unwrap()
+}
+
+#[test]
+fn string_and_array_shapes() {
+    assert!(blockchain_info(json!("")).warnings.is_empty());
+    assert!(blockchain_info(json!([])).warnings.is_empty());
+    assert!(blockchain_info(Value::Null).warnings.is_empty());
+
+    assert_eq!(
+        blockchain_info(json!("Warning: unknown new rules activated (versionbit 28)")).warnings,
+        vec!["Warning: unknown new rules activated (versionbit 28)"]
+    );
+    assert_eq!(
+        blockchain_info(json!(["This is a pre-release test build", " "])).warnings,
+        vec!["This is a pre-release test build"]
+    );
+}
+
+#[test]
+fn combined_warnings_are_deduplicated() {
+    let chain = BlockchainInfo {
+        warnings: vec!["large valid fork".to_string()],
+        ..Default::default()
+    };
+    let net = NetworkInfo {
+        warnings: vec!["large valid fork".to_string(), "clock skew".to_string()],
+        ..Default::default()
+    };
+
+    assert_eq!(node_warnings(&chain, &net), vec!["large valid fork", "clock skew"]);
+    assert!(node_warnings(&BlockchainInfo::default(), &NetworkInfo::default()).is_empty());
+}
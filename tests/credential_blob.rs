@@ -0,0 +1,48 @@
+//! `decode_credential_blob` for Windows Credential Manager secrets.
+
+use blockchaininfo::utils::decode_credential_blob;
+
+fn utf16le(s: &str) -> Vec<u8> {
+    s.encode_utf16().flat_map(|u| u.to_le_bytes()).collect()
+}
+
+#[test]
+fn utf16le_blob_from_cmdkey_is_decoded() {
+    assert_eq!(decode_credential_blob(&utf16le("s3cret")).as_deref(), Some("s3cret"));
+}
+
+#[test]
+fn trailing_nul_terminator_is_dropped() {
+    assert_eq!(decode_credential_blob(&utf16le("s3cret\0")).as_deref(), Some("s3cret"));
+}
+
+#[test]
+fn non_ascii_utf16_survives() {
+    assert_eq!(decode_credential_blob(&utf16le("pässwörd₿")).as_deref(), Some("pässwörd₿"));
+}
+
+#[test]
+fn raw_utf8_blob_is_accepted() {
+    assert_eq!(decode_credential_blob(b"s3cret").as_deref(), Some("s3cret"));
+    assert_eq!(decode_credential_blob("₿itcoin".as_bytes()).as_deref(), Some("₿itcoin"));
+}
+
+#[test]
+fn odd_length_wide_blob_is_rejected() {
+    assert_eq!(decode_credential_blob(&[b'a', 0, b'b']), None);
+}
+
+#[test]
+fn unpaired_surrogate_is_rejected() {
+    assert_eq!(decode_credential_blob(&[0x00, 0xD8, b'a', 0x00]), None);
+}
+
+#[test]
+fn invalid_utf8_is_rejected() {
+    assert_eq!(decode_credential_blob(&[0xFF, 0xFE, 0xFD]), None);
+}
+
+#[test]
+fn empty_blob_decodes_to_empty_string() {
+    assert_eq!(decode_credential_blob(&[]).as_deref(), Some(""));
+}
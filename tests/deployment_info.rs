@@ -0,0 +1,45 @@
+//! Pending soft-fork deployments from `getdeploymentinfo`.
+
+use blockchaininfo::models::deployment_info::DeploymentInfoJsonWrap;
+use blockchaininfo::models::rpc_envelope::RPC_METHOD_NOT_FOUND;
+
+#[test]
+fn lists_only_pending_deployments() {
+    let wrap: DeploymentInfoJsonWrap = serde_json::from_str(
+        r#"{"id":"1","error":null,"result":{"hash":"00","height":850000,"deployments":{
+            "segwit":{"type":"buried","active":true,"height":481824},
+            "taproot":{"type":"bip9","active":true,"height":709632,
+                "bip9":{"start_time":0,"timeout":0,"min_activation_height":709632,
+                        "status":"active","since":709632,"status_next":"active"}},
+            "testdummy":{"type":"bip9","active":false,
+                "bip9":{"bit":28,"start_time":0,"timeout":0,"min_activation_height":0,
+                        "status":"started","since":840000,"status_next":"started",
+                        "statistics":{"period":2016,"threshold":1815,"elapsed":200,
+                                      "count":90,"possible":true}}},
+            "neverdummy":{"type":"bip9","active":false,
+                "bip9":{"status":"failed","since":0}}
+        }}}"#,
+    )
+    .unwrap();
+
+    let info = wrap.result.unwrap();
+    let pending = info.pending();
+    assert_eq!(pending.len(), 1);
+
+    let (name, dep) = pending[0];
+    assert_eq!(name, "testdummy");
+    assert_eq!(dep.status(), "started");
+    assert_eq!(dep.signaling_pct(), Some(45.0));
+    assert_eq!(dep.threshold_pct().map(|p| p.round()), Some(90.0));
+}
+
+#[test]
+fn method_not_found_parses_as_error_object() {
+    let wrap: DeploymentInfoJsonWrap = serde_json::from_str(
+        r#"{"result":null,"error":{"code":-32601,"message":"Method not found"},"id":"1"}"#,
+    )
+    .unwrap();
+
+    assert!(wrap.result.is_none());
+    assert_eq!(wrap.error.unwrap().code, RPC_METHOD_NOT_FOUND);
+}
@@ -0,0 +1,45 @@
+//! Guards in the difficulty-change estimators.
+
+use blockchaininfo::utils::{
+    estimate_24h_difficulty_change, estimate_difficulty_change, MIN_EPOCH_BLOCKS_FOR_ESTIMATE,
+};
+
+const EPOCH_START_TIME: u64 = 1_700_000_000;
+
+#[test]
+fn exactly_on_epoch_boundary_is_none() {
+    // Height % 2016 == 0 used to underflow `blocks_in_epoch`.
+    assert_eq!(estimate_difficulty_change(2016 * 400, EPOCH_START_TIME + 600, EPOCH_START_TIME), None);
+    assert_eq!(estimate_difficulty_change(0, EPOCH_START_TIME, EPOCH_START_TIME), None);
+}
+
+#[test]
+fn too_early_in_epoch_is_none() {
+    let height = 2016 * 400 + MIN_EPOCH_BLOCKS_FOR_ESTIMATE - 1;
+    assert_eq!(estimate_difficulty_change(height, EPOCH_START_TIME + 3000, EPOCH_START_TIME), None);
+}
+
+#[test]
+fn zero_elapsed_time_is_none() {
+    let height = 2016 * 400 + 100;
+    assert_eq!(estimate_difficulty_change(height, EPOCH_START_TIME, EPOCH_START_TIME), None);
+    // Out-of-order timestamps saturate to zero elapsed.
+    assert_eq!(estimate_difficulty_change(height, EPOCH_START_TIME - 1, EPOCH_START_TIME), None);
+    assert_eq!(estimate_24h_difficulty_change(EPOCH_START_TIME, EPOCH_START_TIME), None);
+}
+
+#[test]
+fn on_schedule_epoch_is_flat() {
+    // 101 blocks in: 100 intervals of exactly 10 minutes.
+    let height = 2016 * 400 + 101;
+    let change = estimate_difficulty_change(height, EPOCH_START_TIME + 100 * 600, EPOCH_START_TIME)
+        .expect("estimate past the minimum");
+    assert!(change.abs() < 1e-9, "{change}");
+}
+
+#[test]
+fn fast_blocks_raise_difficulty() {
+    let change = estimate_24h_difficulty_change(EPOCH_START_TIME + 144 * 300, EPOCH_START_TIME)
+        .expect("elapsed time");
+    assert!((change - 100.0).abs() < 1e-9, "{change}");
+}
@@ -0,0 +1,56 @@
+//! `size_on_disk` growth rate, including pruning and the window.
+
+use blockchaininfo::models::disk_growth::{
+    DiskGrowth, DISK_GROWTH_MIN_SPAN_SECS, DISK_GROWTH_WINDOW_SECS,
+};
+
+const T0: u64 = 1_700_000_000;
+const MB: u64 = 1_000_000;
+
+#[test]
+fn no_rate_until_min_span() {
+    let mut growth = DiskGrowth::default();
+    assert_eq!(growth.bytes_per_hour(), None);
+
+    growth.observe(T0, 600_000 * MB);
+    growth.observe(T0 + DISK_GROWTH_MIN_SPAN_SECS - 1, 600_001 * MB);
+    assert_eq!(growth.bytes_per_hour(), None);
+}
+
+#[test]
+fn growth_per_hour() {
+    let mut growth = DiskGrowth::default();
+    growth.observe(T0, 600_000 * MB);
+    growth.observe(T0 + 1800, 600_004 * MB);
+    growth.observe(T0 + 3600, 600_008 * MB);
+    assert_eq!(growth.bytes_per_hour(), Some(8 * MB));
+
+    // An unchanged size over another hour halves the rate.
+    growth.observe(T0 + 7200, 600_008 * MB);
+    assert_eq!(growth.bytes_per_hour(), Some(4 * MB));
+}
+
+#[test]
+fn pruning_clamps_to_zero() {
+    let mut growth = DiskGrowth::default();
+    growth.observe(T0, 550 * 1000 * MB);
+    growth.observe(T0 + 3600, 548 * 1000 * MB);
+    assert_eq!(growth.bytes_per_hour(), Some(0));
+}
+
+#[test]
+fn old_samples_leave_the_window() {
+    let mut growth = DiskGrowth::default();
+    // A burst long ago (e.g. catching up), then steady 2 MB/h.
+    growth.observe(T0, 500_000 * MB);
+    growth.observe(T0 + 60, 500_900 * MB);
+    let start = T0 + 60 + DISK_GROWTH_WINDOW_SECS;
+    for hour in 0..=6 {
+        growth.observe(start + hour * 3600, 501_000 * MB + hour * 2 * MB);
+    }
+    assert_eq!(growth.bytes_per_hour(), Some(2 * MB));
+
+    // Zero (nothing fetched yet) is ignored.
+    growth.observe(start + 7 * 3600, 0);
+    assert_eq!(growth.bytes_per_hour(), Some(2 * MB));
+}
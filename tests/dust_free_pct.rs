@@ -0,0 +1,41 @@
+//! Dust-free percentage: kept entries over examined entries, not over the
+//! full mempool count.
+
+use blockchaininfo::models::mempool_info::MempoolDistribution;
+
+fn dist(small: usize, medium: usize, large: usize, dust: usize) -> MempoolDistribution {
+    MempoolDistribution { small, medium, large, dust_count: dust, ..Default::default() }
+}
+
+#[test]
+fn empty_cache_has_no_percentage() {
+    assert_eq!(MempoolDistribution::default().dust_free_pct(), None);
+}
+
+#[test]
+fn share_of_examined_entries() {
+    assert_eq!(dist(2, 1, 1, 1).dust_free_pct(), Some(80.0));
+    assert_eq!(dist(0, 0, 0, 5).dust_free_pct(), Some(0.0));
+    assert_eq!(dist(3, 0, 0, 0).dust_free_pct(), Some(100.0));
+}
+
+#[test]
+fn warming_cache_reads_the_same_as_a_full_one() {
+    // A few cycles in: 40 of 50k mempool txs examined, 10 of them dust.
+    let warming = dist(20, 8, 2, 10).dust_free_pct().unwrap();
+    // Fully warmed, same mix.
+    let warm = dist(20_000, 8_000, 2_000, 10_000).dust_free_pct().unwrap();
+
+    assert_eq!(warming, 75.0);
+    assert_eq!(warming, warm);
+}
+
+#[test]
+fn never_exceeds_100_percent() {
+    // The mempool shrank after a block while the cache still holds more
+    // entries than `size`; the percentage must not depend on it.
+    for d in [dist(10_000, 5_000, 100, 0), dist(1, 0, 0, 0), dist(7, 3, 0, 1)] {
+        let pct = d.dust_free_pct().unwrap();
+        assert!((0.0..=100.0).contains(&pct), "{}", pct);
+    }
+}
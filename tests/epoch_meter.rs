@@ -0,0 +1,34 @@
+//! Header epoch progress meter.
+
+use blockchaininfo::utils::{epoch_meter, EPOCH_METER_SEGMENTS};
+
+fn filled(meter: &str) -> usize {
+    meter.chars().filter(|&c| c == '●').count()
+}
+
+#[test]
+fn segment_count_is_fixed() {
+    for percent in [0.0, 12.5, 40.0, 99.9, 100.0] {
+        let meter = epoch_meter(percent);
+        assert_eq!(meter.chars().count(), EPOCH_METER_SEGMENTS + 2, "{}", meter);
+        assert!(meter.starts_with('[') && meter.ends_with(']'));
+    }
+}
+
+#[test]
+fn filled_segments_follow_percent() {
+    assert_eq!(epoch_meter(0.0), "[○○○○○○○○]");
+    assert_eq!(filled(&epoch_meter(12.4)), 0);
+    assert_eq!(filled(&epoch_meter(12.5)), 1);
+    assert_eq!(epoch_meter(40.0), "[●●●○○○○○]");
+    assert_eq!(filled(&epoch_meter(50.0)), 4);
+    assert_eq!(filled(&epoch_meter(99.9)), 7);
+    assert_eq!(epoch_meter(100.0), "[●●●●●●●●]");
+}
+
+#[test]
+fn out_of_range_is_clamped() {
+    assert_eq!(epoch_meter(-5.0), epoch_meter(0.0));
+    assert_eq!(epoch_meter(250.0), epoch_meter(100.0));
+    assert_eq!(epoch_meter(f64::NAN).chars().count(), EPOCH_METER_SEGMENTS + 2);
+}
@@ -0,0 +1,38 @@
+//! `MyError` is a `std::error::Error` and exposes wrapped errors as its source.
+
+use std::error::Error;
+use std::io;
+
+use blockchaininfo::models::errors::MyError;
+
+#[test]
+fn wrapped_errors_are_the_source() {
+    let err = MyError::from(io::Error::new(io::ErrorKind::NotFound, "miners.json"));
+    let source = err.source().expect("io error source");
+    assert_eq!(source.to_string(), "miners.json");
+    assert!(source.downcast_ref::<io::Error>().is_some());
+
+    let err = MyError::from(serde_json::from_str::<u64>("nope").unwrap_err());
+    assert!(err.source().unwrap().downcast_ref::<serde_json::Error>().is_some());
+
+    let err = MyError::from(toml::from_str::<toml::Table>("= 1").unwrap_err());
+    assert!(err.source().unwrap().downcast_ref::<toml::de::Error>().is_some());
+}
+
+#[test]
+fn message_only_errors_have_no_source() {
+    assert!(MyError::CustomError("boom".into()).source().is_none());
+    assert!(MyError::RpcMethodNotFound("uptime".into()).source().is_none());
+    assert!(MyError::TimeoutError("Request timed out".into()).source().is_none());
+}
+
+#[test]
+fn boxes_into_dyn_error() {
+    fn load() -> Result<(), Box<dyn Error + Send + Sync>> {
+        Err(MyError::Config("missing address".into()))?
+    }
+
+    let err = load().unwrap_err();
+    assert_eq!(err.to_string(), "Configuration error: missing address");
+    assert!(err.downcast_ref::<MyError>().is_some());
+}
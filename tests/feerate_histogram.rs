@@ -0,0 +1,36 @@
+//! Fee-rate histogram bands in the mempool distribution.
+
+mod common;
+
+use blockchaininfo::models::mempool_info::{feerate_bucket, MempoolDistribution, FEERATE_BUCKET_LABELS};
+use common::mempool_entry;
+use dashmap::DashMap;
+
+#[test]
+fn band_edges_are_inclusive_lower_bounds() {
+    assert_eq!(feerate_bucket(50, 200), 0); // 0.25 sat/vB
+    assert_eq!(feerate_bucket(399, 200), 0); // just under 2
+    assert_eq!(feerate_bucket(400, 200), 1); // exactly 2
+    assert_eq!(feerate_bucket(1_000, 200), 2); // exactly 5
+    assert_eq!(feerate_bucket(2_000, 200), 3); // exactly 10
+    assert_eq!(feerate_bucket(4_000, 200), 4); // exactly 20
+    assert_eq!(feerate_bucket(9_999, 200), 4); // just under 50
+    assert_eq!(feerate_bucket(10_000, 200), 5); // exactly 50
+    assert_eq!(feerate_bucket(1_000_000, 200), 5);
+    assert_eq!(FEERATE_BUCKET_LABELS.len(), 6);
+}
+
+#[test]
+fn update_metrics_counts_each_band() {
+    let cache = DashMap::new();
+    let rates = [1, 3, 3, 7, 15, 15, 15, 30, 100];
+    for (i, rate) in rates.iter().enumerate() {
+        cache.insert([i as u8; 32], mempool_entry(rate * 250, 250));
+    }
+
+    let mut dist = MempoolDistribution::default();
+    dist.update_metrics(&cache);
+
+    assert_eq!(dist.feerate_buckets, [1, 2, 1, 3, 1, 1]);
+    assert_eq!(dist.feerate_buckets.iter().sum::<usize>(), rates.len());
+}
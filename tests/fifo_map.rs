@@ -0,0 +1,95 @@
+//! Bounded FIFO map backing the mempool entry cache.
+
+use blockchaininfo::models::fifo_map::FifoMap;
+
+fn key(n: u32) -> [u8; 32] {
+    let mut k = [0u8; 32];
+    k[..4].copy_from_slice(&n.to_be_bytes());
+    k
+}
+
+#[test]
+fn never_exceeds_capacity() {
+    let cache = FifoMap::new(100);
+    for n in 0..1_000 {
+        cache.insert(key(n), n);
+        assert!(cache.as_map().len() <= 100);
+    }
+    assert_eq!(cache.as_map().len(), 100);
+}
+
+#[test]
+fn recently_inserted_entries_survive() {
+    let cache = FifoMap::new(100);
+    for n in 0..250 {
+        cache.insert(key(n), n);
+    }
+
+    // The last 100 inserts are kept; everything older was evicted.
+    assert!((150..250).all(|n| cache.contains_key(&key(n))));
+    assert!((0..150).all(|n| !cache.contains_key(&key(n))));
+}
+
+#[test]
+fn replacing_a_key_does_not_evict_or_requeue() {
+    let cache = FifoMap::new(2);
+    cache.insert(key(1), 1);
+    cache.insert(key(2), 2);
+    cache.insert(key(1), 10);
+
+    assert_eq!(cache.as_map().len(), 2);
+    assert_eq!(*cache.as_map().get(&key(1)).unwrap(), 10);
+
+    // Key 1 was inserted first, so it is still the first to go.
+    cache.insert(key(3), 3);
+    assert!(!cache.contains_key(&key(1)));
+    assert!(cache.contains_key(&key(2)));
+}
+
+#[test]
+fn retained_out_entries_free_their_slots() {
+    let cache = FifoMap::new(4);
+    for n in 0..4 {
+        cache.insert(key(n), n);
+    }
+    cache.retain(|_, v| *v % 2 == 0);
+    assert_eq!(cache.as_map().len(), 2);
+
+    cache.insert(key(10), 10);
+    cache.insert(key(11), 11);
+    assert_eq!(cache.as_map().len(), 4);
+    assert!(cache.contains_key(&key(0)) && cache.contains_key(&key(2)));
+
+    // Full again: the oldest live entry goes next.
+    cache.insert(key(12), 12);
+    assert!(!cache.contains_key(&key(0)));
+}
+
+#[test]
+fn reinserted_key_is_queued_at_the_back() {
+    let cache = FifoMap::new(3);
+    cache.insert(key(1), 1);
+    cache.insert(key(2), 2);
+    cache.retain(|_, v| *v != 1);
+    cache.insert(key(3), 3);
+    cache.insert(key(1), 1);
+
+    // Key 1 left and came back last; key 2 is now the oldest.
+    cache.insert(key(4), 4);
+    assert!(!cache.contains_key(&key(2)));
+    assert!(cache.contains_key(&key(1)));
+    assert_eq!(cache.as_map().len(), 3);
+}
+
+#[test]
+fn clear_empties_the_queue_too() {
+    let cache = FifoMap::new(2);
+    cache.insert(key(1), 1);
+    cache.insert(key(2), 2);
+    cache.clear();
+    assert!(cache.as_map().is_empty());
+
+    cache.insert(key(3), 3);
+    cache.insert(key(4), 4);
+    assert!(cache.contains_key(&key(3)) && cache.contains_key(&key(4)));
+}
@@ -0,0 +1,42 @@
+//! Fork alert selection from `getchaintips`.
+
+use blockchaininfo::models::chaintips_info::{alerting_forks, ChainTip};
+
+fn tip(height: u64, hash: &str, branchlen: u64, status: &str) -> ChainTip {
+    ChainTip {
+        height,
+        hash: hash.to_string(),
+        branchlen,
+        status: status.to_string(),
+    }
+}
+
+#[test]
+fn alerts_on_valid_fork_at_threshold() {
+    let tips = vec![
+        tip(900_000, "a", 0, "active"),
+        tip(899_999, "f2", 2, "valid-fork"),
+        tip(899_990, "f1", 1, "valid-fork"),
+    ];
+
+    let alerting: Vec<&str> = alerting_forks(&tips, 2).iter().map(|t| t.hash.as_str()).collect();
+    assert_eq!(alerting, vec!["f2"]);
+
+    let alerting: Vec<&str> = alerting_forks(&tips, 1).iter().map(|t| t.hash.as_str()).collect();
+    assert_eq!(alerting, vec!["f2", "f1"]);
+}
+
+#[test]
+fn ignores_other_statuses_and_hidden_tips() {
+    let tips = vec![
+        tip(900_000, "a", 0, "active"),
+        tip(899_999, "h", 5, "valid-headers"),
+        tip(899_998, "i", 5, "invalid"),
+        tip(899_997, "f1", 1, "valid-fork"),
+        tip(899_996, "f2", 1, "valid-fork"),
+        // Not among the three tips the panel shows.
+        tip(800_000, "old", 9, "valid-fork"),
+    ];
+
+    assert!(alerting_forks(&tips, 2).is_empty());
+}
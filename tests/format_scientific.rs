@@ -0,0 +1,43 @@
+//! `BlockchainInfo::format_scientific` across mainnet, testnet and regtest
+//! difficulty ranges.
+
+use blockchaininfo::models::blockchain_info::BlockchainInfo;
+
+fn fmt(value: f64) -> String {
+    BlockchainInfo::format_scientific(value).unwrap()
+}
+
+#[test]
+fn mainnet_difficulty() {
+    assert_eq!(fmt(1.2e14), "120.0×10¹²");
+    assert_eq!(fmt(1.2e13), "12000.0×10⁹");
+}
+
+#[test]
+fn regtest_difficulty_has_negative_exponent() {
+    // Regtest's minimum difficulty.
+    assert_eq!(fmt(4.656542373906925e-10), "465.7×10⁻¹²");
+    assert_eq!(fmt(-4.656542373906925e-10), "-465.7×10⁻¹²");
+}
+
+#[test]
+fn tiny_values_group_exponents_downward() {
+    // -10 rounds down to -12 rather than up to a fractional mantissa.
+    assert_eq!(fmt(4.656e-9), "4.7×10⁻⁹");
+    assert_eq!(fmt(0.5), "500.0×10⁻³");
+    assert_eq!(fmt(2.0e-7), "200.0×10⁻⁹");
+    assert_eq!(fmt(f64::MIN_POSITIVE), "22.3×10⁻³⁰⁹");
+}
+
+#[test]
+fn small_testnet_difficulty() {
+    assert_eq!(fmt(1.0), "1.0×10⁰");
+    assert_eq!(fmt(250.0), "250.0×10⁰");
+}
+
+#[test]
+fn zero_and_non_finite() {
+    assert_eq!(fmt(0.0), "0.0");
+    assert!(BlockchainInfo::format_scientific(f64::INFINITY).is_err());
+    assert!(BlockchainInfo::format_scientific(f64::NAN).is_err());
+}
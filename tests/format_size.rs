@@ -0,0 +1,48 @@
+//! `format_size` in binary (KiB/MiB) and decimal (kB/MB) modes.
+
+use blockchaininfo::config::parse_config_str;
+use blockchaininfo::utils::{format_size, SizeUnits};
+
+#[test]
+fn binary_mode_uses_1024_steps_and_iec_labels() {
+    assert_eq!(format_size(1023, SizeUnits::Binary), "1023 bytes");
+    assert_eq!(format_size(1024, SizeUnits::Binary), "1.00 KiB");
+    assert_eq!(format_size(1_000_000, SizeUnits::Binary), "976.56 KiB");
+    assert_eq!(format_size(1_048_576, SizeUnits::Binary), "1.00 MiB");
+    assert_eq!(format_size(1 << 30, SizeUnits::Binary), "1.00 GiB");
+    assert_eq!(format_size(1 << 40, SizeUnits::Binary), "1.00 TiB");
+    assert_eq!(format_size(1 << 50, SizeUnits::Binary), "1024.00 TiB");
+}
+
+#[test]
+fn decimal_mode_uses_1000_steps_and_si_labels() {
+    assert_eq!(format_size(999, SizeUnits::Decimal), "999 bytes");
+    assert_eq!(format_size(1000, SizeUnits::Decimal), "1.00 kB");
+    assert_eq!(format_size(1024, SizeUnits::Decimal), "1.02 kB");
+    assert_eq!(format_size(999_999, SizeUnits::Decimal), "1000.00 kB");
+    assert_eq!(format_size(1_000_000, SizeUnits::Decimal), "1.00 MB");
+    assert_eq!(format_size(1_000_000_000, SizeUnits::Decimal), "1.00 GB");
+    assert_eq!(format_size(600_000_000_000, SizeUnits::Decimal), "600.00 GB");
+    assert_eq!(format_size(1_000_000_000_000, SizeUnits::Decimal), "1.00 TB");
+}
+
+#[test]
+fn zero_and_max_do_not_panic() {
+    assert_eq!(format_size(0, SizeUnits::Binary), "0 bytes");
+    assert!(format_size(u64::MAX, SizeUnits::Binary).ends_with(" TiB"));
+    assert!(format_size(u64::MAX, SizeUnits::Decimal).ends_with(" TB"));
+}
+
+#[test]
+fn config_selects_units_and_defaults_to_binary() {
+    let base = r#"
+        [bitcoin_rpc]
+        username = "u"
+        password = "p"
+        address = "http://127.0.0.1:8332"
+        "#;
+    assert_eq!(parse_config_str(base).unwrap().size_units, SizeUnits::Binary);
+
+    let decimal = format!("{}size_units = \"decimal\"\n", base);
+    assert_eq!(parse_config_str(&decimal).unwrap().size_units, SizeUnits::Decimal);
+}
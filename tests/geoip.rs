@@ -0,0 +1,23 @@
+//! GeoIP helpers: peer address parsing and graceful failure.
+
+use blockchaininfo::geoip::{peer_ip, GeoIp};
+use std::net::IpAddr;
+use std::path::Path;
+
+#[test]
+fn parses_ipv4_and_ipv6_peer_addresses() {
+    assert_eq!(peer_ip("203.0.113.7:8333"), Some("203.0.113.7".parse::<IpAddr>().unwrap()));
+    assert_eq!(peer_ip("[2001:db8::1]:8333"), Some("2001:db8::1".parse::<IpAddr>().unwrap()));
+    assert_eq!(peer_ip("198.51.100.1"), Some("198.51.100.1".parse::<IpAddr>().unwrap()));
+}
+
+#[test]
+fn overlay_addresses_have_no_ip() {
+    assert_eq!(peer_ip("vww6ybal4bd7szmgncyruucpgfkqahzddi37ktceo3ah7ngmcopnpyyd.onion:8333"), None);
+    assert_eq!(peer_ip("ukeu3k5oycgaauneqgtnvselmt4yemvoilkln7jpvamvfx7dnkdq.b32.i2p:0"), None);
+}
+
+#[test]
+fn missing_database_is_an_error_not_a_panic() {
+    assert!(GeoIp::open(Path::new("/nonexistent/GeoLite2-City.mmdb")).is_err());
+}
@@ -0,0 +1,44 @@
+//! Block subsidy schedule and the halving countdown.
+
+use blockchaininfo::consensus::satoshi_math::{
+    block_subsidy, blocks_until_halving, BLOCK_TIME_SECONDS, HALVING_INTERVAL,
+};
+use blockchaininfo::models::blockchain_info::BlockchainInfo;
+
+#[test]
+fn subsidy_at_known_halving_heights() {
+    assert_eq!(block_subsidy(0), 5_000_000_000);
+    assert_eq!(block_subsidy(209_999), 5_000_000_000);
+    assert_eq!(block_subsidy(210_000), 2_500_000_000);
+    assert_eq!(block_subsidy(420_000), 1_250_000_000);
+    assert_eq!(block_subsidy(630_000), 625_000_000);
+    assert_eq!(block_subsidy(840_000), 312_500_000);
+    assert_eq!(block_subsidy(839_999), 625_000_000);
+}
+
+#[test]
+fn subsidy_runs_out() {
+    // 50 BTC in satoshis fits in 33 bits; the 33rd halving pays nothing.
+    assert_eq!(block_subsidy(32 * HALVING_INTERVAL), 1);
+    assert_eq!(block_subsidy(33 * HALVING_INTERVAL), 0);
+    // Past 64 halvings the shift itself would overflow.
+    assert_eq!(block_subsidy(64 * HALVING_INTERVAL), 0);
+    assert_eq!(block_subsidy(u64::MAX), 0);
+}
+
+#[test]
+fn countdown_to_next_halving() {
+    assert_eq!(blocks_until_halving(839_999), 1);
+    assert_eq!(blocks_until_halving(840_000), HALVING_INTERVAL);
+    assert_eq!(blocks_until_halving(850_000), 200_000);
+}
+
+#[test]
+fn halving_estimate_projects_from_tip_time() {
+    let tip = BlockchainInfo { blocks: 1_049_990, time: 1_700_000_000, ..Default::default() };
+    assert_eq!(
+        tip.halving_estimate(BLOCK_TIME_SECONDS),
+        Some((10, 1_700_000_000 + 10 * BLOCK_TIME_SECONDS))
+    );
+    assert_eq!(BlockchainInfo::default().halving_estimate(BLOCK_TIME_SECONDS), None);
+}
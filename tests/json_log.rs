@@ -0,0 +1,66 @@
+//! `LOG_FORMAT=json`: one valid JSON object per `error_log.txt` line;
+//! `LOG_LEVEL` drops events below the floor.
+
+use blockchaininfo::utils::{data_dir, format_log_entry, log_error, log_event, LogFormat, LogLevel};
+
+#[test]
+fn format_is_read_from_env_value() {
+    assert_eq!(LogFormat::from_env_value(Some("json")), LogFormat::Json);
+    assert_eq!(LogFormat::from_env_value(Some(" JSON ")), LogFormat::Json);
+    assert_eq!(LogFormat::from_env_value(Some("text")), LogFormat::Text);
+    assert_eq!(LogFormat::from_env_value(None), LogFormat::Text);
+}
+
+#[test]
+fn level_is_read_from_env_value() {
+    assert_eq!(LogLevel::from_env_value(Some("warn")), LogLevel::Warn);
+    assert_eq!(LogLevel::from_env_value(Some("ERROR")), LogLevel::Error);
+    assert_eq!(LogLevel::from_env_value(Some("info")), LogLevel::Info);
+    assert_eq!(LogLevel::from_env_value(None), LogLevel::Info);
+    assert!(LogLevel::Info < LogLevel::Warn && LogLevel::Warn < LogLevel::Error);
+}
+
+#[test]
+fn text_entries_keep_the_bracketed_timestamp_and_level() {
+    let line = format_log_entry(LogFormat::Text, LogLevel::Warn, "boom");
+    assert!(line.starts_with('['));
+    assert!(line.ends_with("] [WARN] boom\n"));
+}
+
+#[test]
+fn json_log_file_has_one_valid_object_per_line() {
+    // All three are read once per process; this is the only test that logs.
+    let dir = std::env::temp_dir().join(format!("bci-jsonlog-{}", std::process::id()));
+    std::env::set_var("BCI_DATA_DIR", &dir);
+    std::env::set_var("LOG_FORMAT", "json");
+    std::env::set_var("LOG_LEVEL", "warn");
+    assert_eq!(data_dir(), dir);
+
+    let messages = [
+        "plain message",
+        "quotes \" and backslash \\ survive",
+        "multi\nline",
+        // Must not be mistaken for a legacy text log and rotated away.
+        "JsonParsingError(\"abc\", \"eof\")",
+    ];
+    for msg in messages {
+        log_error(msg).unwrap();
+    }
+    // Below the LOG_LEVEL floor: dropped.
+    log_event(LogLevel::Info, "reconnected").unwrap();
+    log_event(LogLevel::Warn, "reorg").unwrap();
+
+    let contents = std::fs::read_to_string(dir.join("error_log.txt")).unwrap();
+    let lines: Vec<&str> = contents.lines().collect();
+    assert_eq!(lines.len(), messages.len() + 1);
+
+    let expected = messages.iter().map(|m| ("error", *m)).chain([("warn", "reorg")]);
+    for (line, (level, msg)) in lines.iter().zip(expected) {
+        let v: serde_json::Value = serde_json::from_str(line).expect("line is valid JSON");
+        assert_eq!(v["level"], level);
+        assert_eq!(v["msg"], msg);
+        assert!(v["ts"].as_str().is_some_and(|ts| !ts.is_empty()));
+    }
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,93 @@
+//! `[layout]` config: section heights, hidden sections and the screen split.
+
+use blockchaininfo::config::{parse_config_str, DashboardSection, LayoutConfig};
+use blockchaininfo::ui::layout::{
+    dashboard_areas, too_small, MIN_TERMINAL_HEIGHT, MIN_TERMINAL_WIDTH,
+};
+use tui::layout::Rect;
+
+const BASE: &str = "[bitcoin_rpc]\naddress = \"http://127.0.0.1:8332\"\n";
+
+#[test]
+fn defaults_match_the_classic_dashboard() {
+    let layout = parse_config_str(BASE).unwrap().layout;
+    assert_eq!(layout, LayoutConfig::default());
+    assert_eq!(layout.total_height(), 76);
+
+    let areas = dashboard_areas(Rect::new(0, 0, 120, 76), &layout);
+    assert_eq!(areas.header, Rect::new(1, 1, 118, 3));
+    assert_eq!(areas.blockchain, Some(Rect::new(1, 4, 118, 17)));
+    assert_eq!(areas.mempool, Some(Rect::new(1, 21, 118, 30)));
+    assert_eq!(areas.network, Some(Rect::new(1, 51, 118, 16)));
+    assert_eq!(areas.consensus, Some(Rect::new(1, 67, 118, 7)));
+    assert_eq!(areas.footer, Rect::new(1, 74, 118, 1));
+}
+
+#[test]
+fn hidden_sections_get_no_area() {
+    let config = parse_config_str(&format!(
+        "{}[layout]\nmempool = 20\nhidden = [\"consensus\", \"blockchain\"]\n",
+        BASE
+    ))
+    .unwrap();
+    let layout = config.layout;
+    assert!(!layout.is_visible(DashboardSection::Consensus));
+    assert_eq!(layout.total_height(), 6 + 20 + 16);
+
+    let areas = dashboard_areas(Rect::new(0, 0, 80, 42), &layout);
+    assert_eq!(areas.blockchain, None);
+    assert_eq!(areas.consensus, None);
+    assert_eq!(areas.mempool, Some(Rect::new(1, 4, 78, 20)));
+    assert_eq!(areas.network, Some(Rect::new(1, 24, 78, 16)));
+    assert_eq!(areas.footer, Rect::new(1, 40, 78, 1));
+}
+
+/// Heights of (blockchain, mempool, network, consensus) on a screen `rows` tall.
+fn heights(rows: u16) -> [Option<u16>; 4] {
+    let areas = dashboard_areas(Rect::new(0, 0, 120, rows), &LayoutConfig::default());
+    [areas.blockchain, areas.mempool, areas.network, areas.consensus].map(|a| a.map(|a| a.height))
+}
+
+#[test]
+fn short_screens_collapse_consensus_then_network() {
+    assert_eq!(heights(76), [Some(17), Some(30), Some(16), Some(7)]);
+    // Consensus goes first...
+    assert_eq!(heights(70), [Some(17), Some(30), Some(16), None]);
+    // ...then Network shrinks...
+    assert_eq!(heights(66), [Some(17), Some(30), Some(13), None]);
+    // ...and is dropped once it would fall below its minimum.
+    assert_eq!(heights(60), [Some(17), Some(30), None, None]);
+}
+
+#[test]
+fn remaining_sections_share_rows_proportionally() {
+    // 24 rows for 17 + 30.
+    assert_eq!(heights(30), [Some(8), Some(15), None, None]);
+    // Scroll mode lays out the full height: nothing collapses.
+    assert_eq!(heights(LayoutConfig::default().total_height() + 10)[3], Some(7));
+}
+
+#[test]
+fn minimum_terminal_size() {
+    assert!(!too_small(MIN_TERMINAL_WIDTH, MIN_TERMINAL_HEIGHT));
+    assert!(too_small(MIN_TERMINAL_WIDTH - 1, 50));
+    assert!(too_small(200, MIN_TERMINAL_HEIGHT - 1));
+}
+
+#[test]
+fn bad_layouts_are_rejected() {
+    for table in [
+        "[layout]\nnetwork = 2\n",
+        "[layout]\nmempool = 101\n",
+        "[layout]\nhidden = [\"header\"]\n",
+        "[layout]\nsidebar = 10\n",
+    ] {
+        assert!(
+            parse_config_str(&format!("{}{}", BASE, table))
+                .and_then(|c| c.layout.validate())
+                .is_err(),
+            "{}",
+            table
+        );
+    }
+}
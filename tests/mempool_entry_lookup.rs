@@ -0,0 +1,49 @@
+//! `getmempoolentry` parsing: cached entries vs. the Tx Lookup view.
+
+use blockchaininfo::models::mempool_info::{MempoolEntry, MempoolEntryLookup};
+use serde_json::json;
+
+fn getmempoolentry() -> serde_json::Value {
+    json!({
+        "vsize": 141, "weight": 561, "time": 1_700_000_000, "height": 820_000,
+        "descendantcount": 2, "descendantsize": 282,
+        "ancestorcount": 2, "ancestorsize": 250,
+        "wtxid": "11".repeat(32),
+        "fees": { "base": 0.00002, "modified": 0.00002, "ancestor": 0.00003, "descendant": 0.00004 },
+        "depends": ["aa".repeat(32)],
+        "spentby": ["bb".repeat(32)],
+        "bip125-replaceable": true,
+        "unbroadcast": false,
+    })
+}
+
+#[test]
+fn lookup_keeps_relatives_alongside_entry_fields() {
+    let lookup: MempoolEntryLookup = serde_json::from_value(getmempoolentry()).unwrap();
+
+    assert_eq!(lookup.entry.vsize, 141);
+    assert_eq!(lookup.entry.ancestorcount, 2);
+    assert_eq!(lookup.entry.fees.base, 0.00002);
+    assert!(lookup.entry.bip125_replaceable);
+    assert_eq!(lookup.depends, Some(vec!["aa".repeat(32)]));
+    assert_eq!(lookup.spentby, Some(vec!["bb".repeat(32)]));
+}
+
+#[test]
+fn lookup_tolerates_missing_relatives() {
+    let mut value = getmempoolentry();
+    let obj = value.as_object_mut().unwrap();
+    obj.remove("depends");
+    obj.remove("spentby");
+
+    let lookup: MempoolEntryLookup = serde_json::from_value(value).unwrap();
+    assert_eq!(lookup.depends, None);
+    assert_eq!(lookup.spentby, None);
+}
+
+#[test]
+fn cached_entry_parses_the_same_response() {
+    let entry: MempoolEntry = serde_json::from_value(getmempoolentry()).unwrap();
+    assert_eq!(entry.vsize, 141);
+    assert_eq!(entry.descendantcount, 2);
+}
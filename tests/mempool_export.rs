@@ -0,0 +1,44 @@
+//! CSV export of the mempool distribution.
+
+use blockchaininfo::export::{
+    export_mempool_distribution_csv, mempool_distribution_csv_row, MEMPOOL_CSV_HEADER,
+};
+use blockchaininfo::models::mempool_info::MempoolDistribution;
+
+fn sample() -> MempoolDistribution {
+    MempoolDistribution {
+        small: 10,
+        medium: 20,
+        large: 3,
+        young: 25,
+        moderate: 6,
+        old: 2,
+        rbf_count: 30,
+        non_rbf_count: 3,
+        average_fee: 1_500,
+        median_fee: 900,
+        average_fee_rate: 12,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn row_matches_header_columns() {
+    let row = mempool_distribution_csv_row(&sample(), "2025-01-01T00:00:00+00:00");
+    assert_eq!(row, "2025-01-01T00:00:00+00:00,10,20,3,25,6,2,30,3,1500,900,12");
+    assert_eq!(row.split(',').count(), MEMPOOL_CSV_HEADER.split(',').count());
+}
+
+#[test]
+fn writes_header_and_row() {
+    let path = std::env::temp_dir().join(format!("bci-export-{}.csv", std::process::id()));
+    export_mempool_distribution_csv(&sample(), &path).unwrap();
+
+    let written = std::fs::read_to_string(&path).unwrap();
+    let lines: Vec<&str> = written.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0], MEMPOOL_CSV_HEADER);
+    assert!(lines[1].ends_with(",10,20,3,25,6,2,30,3,1500,900,12"));
+
+    let _ = std::fs::remove_file(&path);
+}
@@ -0,0 +1,37 @@
+//! Mempool fee floors in sat/vB and the mempool-pressure signal.
+
+use blockchaininfo::models::mempool_info::{btc_per_kvb_to_sat_vb, MempoolInfo};
+
+fn info(mempoolminfee: f64, minrelaytxfee: f64, incrementalrelayfee: f64) -> MempoolInfo {
+    MempoolInfo {
+        mempoolminfee,
+        minrelaytxfee,
+        incrementalrelayfee,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn converts_btc_per_kvb() {
+    assert_eq!(btc_per_kvb_to_sat_vb(0.00001), 1.0);
+    assert_eq!(btc_per_kvb_to_sat_vb(0.000001), 0.1);
+    assert_eq!(btc_per_kvb_to_sat_vb(0.00012345), 12.35);
+    assert_eq!(btc_per_kvb_to_sat_vb(0.0), 0.0);
+}
+
+#[test]
+fn all_three_floors() {
+    let info = info(0.00002, 0.00001, 0.000001);
+    assert_eq!(info.mempool_min_fee_rate(), 2.0);
+    assert_eq!(info.min_relay_fee_rate(), 1.0);
+    assert_eq!(info.incremental_relay_fee_rate(), 0.1);
+    assert_eq!(info.min_relay_tx_fee_vsats(), 1);
+}
+
+#[test]
+fn pressure_when_mempool_min_exceeds_relay_min() {
+    assert!(!info(0.00001, 0.00001, 0.00001).under_fee_pressure());
+    assert!(info(0.0000101, 0.00001, 0.00001).under_fee_pressure());
+    // Float noise in Core's BTC/kvB values alone is not pressure.
+    assert!(!info(0.00001 + 1e-15, 0.00001, 0.00001).under_fee_pressure());
+}
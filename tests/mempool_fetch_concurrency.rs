@@ -0,0 +1,28 @@
+//! `mempool_fetch_concurrency`: default, override and clamping.
+
+use blockchaininfo::config::{parse_config_str, MAX_MEMPOOL_FETCH_CONCURRENCY};
+
+const BASE: &str = "[bitcoin_rpc]\naddress = \"http://127.0.0.1:8332\"\n";
+
+fn permits(extra: &str) -> usize {
+    parse_config_str(&format!("{}{}", BASE, extra))
+        .unwrap()
+        .mempool_fetch_permits()
+}
+
+#[test]
+fn defaults_to_ten() {
+    assert_eq!(permits(""), 10);
+}
+
+#[test]
+fn configured_value_is_used() {
+    assert_eq!(permits("mempool_fetch_concurrency = 50\n"), 50);
+    assert_eq!(permits("mempool_fetch_concurrency = 3\n"), 3);
+}
+
+#[test]
+fn out_of_range_is_clamped() {
+    assert_eq!(permits("mempool_fetch_concurrency = 0\n"), 1);
+    assert_eq!(permits("mempool_fetch_concurrency = 10000\n"), MAX_MEMPOOL_FETCH_CONCURRENCY);
+}
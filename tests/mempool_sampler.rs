@@ -0,0 +1,111 @@
+//! Mempool sampling strategies and their effect on distribution bucketing.
+
+mod common;
+
+use blockchaininfo::config::parse_config_str;
+use blockchaininfo::models::mempool_info::{MempoolDistribution, MempoolEntry};
+use blockchaininfo::models::mempool_sampler::{
+    Full, MempoolSampler, MempoolSampling, Sampled5Percent,
+};
+use common::mempool_entry;
+use dashmap::DashMap;
+
+fn txid(n: u32) -> [u8; 32] {
+    let mut id = [0u8; 32];
+    id[..4].copy_from_slice(&n.to_le_bytes());
+    id[31] = 0xab;
+    id
+}
+
+/// Caches the entries `sampler` selects out of `mempool`.
+fn sample(sampler: &dyn MempoolSampler, mempool: &[([u8; 32], MempoolEntry)]) -> DashMap<[u8; 32], MempoolEntry> {
+    let cache = DashMap::new();
+    for (id, e) in mempool {
+        if sampler.includes(id) {
+            cache.insert(*id, e.clone());
+        }
+    }
+    cache
+}
+
+#[test]
+fn full_includes_every_txid() {
+    assert!((0..1_000).all(|n| Full.includes(&txid(n))));
+    assert_eq!(Full.fraction(), 1.0);
+}
+
+#[test]
+fn sampled_selects_one_in_twenty_stably() {
+    let picked: Vec<u32> = (0..2_000).filter(|&n| Sampled5Percent.includes(&txid(n))).collect();
+
+    assert_eq!(picked.len(), 100);
+    assert!(picked.iter().all(|n| n % 20 == 0));
+    // Same txids on every call, so the cache keeps its sample across cycles.
+    assert!(picked.iter().all(|&n| Sampled5Percent.includes(&txid(n))));
+    assert_eq!(Sampled5Percent.fraction(), 0.05);
+}
+
+#[test]
+fn full_buckets_every_entry() {
+    // Sizes cycle small (200 vB), medium (500 vB), large (2000 vB).
+    let mempool: Vec<_> = (0..60u32)
+        .map(|n| (txid(n), mempool_entry(2_000, [200, 500, 2_000][n as usize % 3])))
+        .collect();
+
+    let mut dist = MempoolDistribution::default();
+    dist.update_metrics_sampled(&sample(&Full, &mempool), Full.fraction());
+
+    assert_eq!((dist.small, dist.medium, dist.large), (20, 20, 20));
+}
+
+#[test]
+fn sampled_buckets_only_the_sample() {
+    // Txids 0, 20, 40, ... are sampled; give them distinct sizes.
+    let mempool: Vec<_> = (0..100u32)
+        .map(|n| {
+            let vsize = match n {
+                0 | 20 => 200,
+                40 => 500,
+                60 | 80 => 2_000,
+                _ => 300,
+            };
+            (txid(n), mempool_entry(2_000, vsize))
+        })
+        .collect();
+
+    let mut dist = MempoolDistribution::default();
+    dist.update_metrics_sampled(&sample(&Sampled5Percent, &mempool), Sampled5Percent.fraction());
+
+    assert_eq!((dist.small, dist.medium, dist.large), (2, 1, 2));
+}
+
+#[test]
+fn sampled_floor_uses_a_scaled_block() {
+    // 20 × 10k vB sampled at 20, 19, ..., 1 sat/vB. A 5% block is 50k vB,
+    // so the top five fit and the floor is 16 sat/vB.
+    let mempool: Vec<_> = (0..20u32)
+        .map(|i| (txid(i * 20), mempool_entry((20 - i as u64) * 10_000, 10_000)))
+        .collect();
+
+    let mut dist = MempoolDistribution::default();
+    dist.update_metrics_sampled(&sample(&Sampled5Percent, &mempool), Sampled5Percent.fraction());
+    assert_eq!(dist.next_block_fee_rate_floor, Some(16));
+
+    // The same cache read as a full mempool fits in one block.
+    dist.update_metrics_sampled(&sample(&Sampled5Percent, &mempool), Full.fraction());
+    assert_eq!(dist.next_block_fee_rate_floor, None);
+}
+
+#[test]
+fn config_selects_sampling_strategy() {
+    let base = "[bitcoin_rpc]\naddress = \"http://127.0.0.1:8332\"\n";
+    assert_eq!(parse_config_str(base).unwrap().mempool_sampling, MempoolSampling::Full);
+
+    let sampled = format!("{}mempool_sampling = \"sampled\"\n", base);
+    let config = parse_config_str(&sampled).unwrap();
+    assert_eq!(config.mempool_sampling, MempoolSampling::Sampled);
+    assert_eq!(config.mempool_sampling.sampler().fraction(), 0.05);
+
+    let bad = format!("{}mempool_sampling = \"half\"\n", base);
+    assert!(parse_config_str(&bad).is_err());
+}
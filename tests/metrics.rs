@@ -0,0 +1,75 @@
+//! `--metrics`: flag parsing and Prometheus text rendering.
+
+use blockchaininfo::metrics::{metrics_addr, MetricsSnapshot};
+use blockchaininfo::models::errors::MyError;
+
+fn args(list: &[&str]) -> Vec<String> {
+    list.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn address_follows_the_flag() {
+    assert_eq!(
+        metrics_addr(args(&["bci", "--metrics", "0.0.0.0:9332"])).unwrap().as_deref(),
+        Some("0.0.0.0:9332")
+    );
+    assert_eq!(
+        metrics_addr(args(&["bci", "--metrics=127.0.0.1:9332"])).unwrap().as_deref(),
+        Some("127.0.0.1:9332")
+    );
+    assert_eq!(metrics_addr(args(&["bci", "--config", "c.toml"])).unwrap(), None);
+}
+
+#[test]
+fn flag_without_address_is_a_usage_error() {
+    // Missing value: not mistaken for the next flag, and no silent TUI start.
+    for list in [
+        &["bci", "--metrics"][..],
+        &["bci", "--metrics", "--mono"],
+        &["bci", "--metrics", "--config", "c.toml"],
+        &["bci", "--metrics="],
+    ] {
+        assert!(
+            matches!(metrics_addr(args(list)), Err(MyError::Config(_))),
+            "{:?} should be rejected",
+            list
+        );
+    }
+}
+
+#[test]
+fn every_sample_has_help_and_type() {
+    let snapshot = MetricsSnapshot {
+        node_reachable: true,
+        block_height: 840_000,
+        mempool_txs: 12_345,
+        mempool_bytes: 6_789_000,
+        connections_in: 8,
+        connections_out: 10,
+        bytes_sent: 1_000,
+        bytes_recv: 2_000,
+        block_propagation_ms: 1_250,
+    };
+    let text = snapshot.to_prometheus();
+
+    for line in [
+        "blockchaininfo_node_reachable 1",
+        "blockchaininfo_block_height 840000",
+        "blockchaininfo_mempool_transactions 12345",
+        "blockchaininfo_mempool_bytes 6789000",
+        "blockchaininfo_connections{direction=\"in\"} 8",
+        "blockchaininfo_connections{direction=\"out\"} 10",
+        "blockchaininfo_network_bytes_total{direction=\"sent\"} 1000",
+        "blockchaininfo_network_bytes_total{direction=\"recv\"} 2000",
+        "blockchaininfo_block_propagation_milliseconds 1250",
+    ] {
+        assert!(text.lines().any(|l| l == line), "missing: {}", line);
+    }
+
+    // Each sample's metric family is declared before it.
+    for sample in text.lines().filter(|l| !l.starts_with('#')) {
+        let name = sample.split(['{', ' ']).next().unwrap();
+        assert!(text.contains(&format!("# TYPE {} ", name)), "no TYPE for {}", name);
+        assert!(text.contains(&format!("# HELP {} ", name)), "no HELP for {}", name);
+    }
+}
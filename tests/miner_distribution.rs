@@ -0,0 +1,49 @@
+//! Ordering of `BlockHistory::get_miner_distribution`.
+
+use blockchaininfo::models::block_info::BlockHistory;
+
+#[test]
+fn distribution_sorted_by_count_then_name() {
+    let history = BlockHistory::new();
+    let sequence = [
+        Some("ViaBTC"),
+        Some("Foundry USA"),
+        Some("AntPool"),
+        None,
+        Some("Foundry USA"),
+        Some("ViaBTC"),
+        Some("F2Pool"),
+        Some("Foundry USA"),
+        Some("AntPool"),
+    ];
+
+    for (i, miner) in sequence.iter().enumerate() {
+        history.add_block(800_000 + i as u64, miner.map(String::from));
+    }
+
+    let dist: Vec<(String, u64)> = history
+        .get_miner_distribution()
+        .into_iter()
+        .map(|(m, c)| (m.to_string(), c))
+        .collect();
+
+    assert_eq!(
+        dist,
+        vec![
+            ("Foundry USA".to_string(), 3),
+            ("AntPool".to_string(), 2),
+            ("ViaBTC".to_string(), 2),
+            ("F2Pool".to_string(), 1),
+        ]
+    );
+
+    // Repeated calls return the same order.
+    for _ in 0..10 {
+        let again: Vec<String> = history
+            .get_miner_distribution()
+            .into_iter()
+            .map(|(m, _)| m.to_string())
+            .collect();
+        assert_eq!(again, ["Foundry USA", "AntPool", "ViaBTC", "F2Pool"]);
+    }
+}
@@ -0,0 +1,95 @@
+//! Coinbase-tag miner classification (fallback when `miners.json` has no match).
+
+use blockchaininfo::models::block_info::Transaction;
+use blockchaininfo::models::miner_tags::{classify_miner_from_coinbase, match_primary_tag};
+
+/// Coinbase tx whose scriptSig is `script` (BIP34 height push + pool data).
+fn coinbase_tx(script: &[u8]) -> Transaction {
+    serde_json::from_value(serde_json::json!({
+        "txid": "00", "hash": "00", "version": 2, "size": 0, "vsize": 0,
+        "weight": 0, "locktime": 0,
+        "vin": [{ "coinbase": hex::encode(script), "sequence": 4294967295u32 }],
+        "vout": []
+    }))
+    .expect("coinbase tx deserializes")
+}
+
+fn script(parts: &[&[u8]]) -> Vec<u8> {
+    parts.concat()
+}
+
+#[test]
+fn foundry_tag() {
+    let tx = coinbase_tx(&script(&[
+        &[0x03, 0x40, 0xd0, 0x0c, 0x1c],
+        b"/Foundry USA Pool #dropgold/",
+        &[0x00, 0x8f, 0x4b, 0x01, 0x00, 0x00, 0x00, 0x00, 0xd2],
+    ]));
+
+    assert_eq!(
+        classify_miner_from_coinbase(&tx),
+        Some(("Foundry USA".to_string(), None))
+    );
+}
+
+#[test]
+fn antpool_tag() {
+    let tx = coinbase_tx(&script(&[
+        &[0x03, 0x41, 0xd0, 0x0c, 0x04, 0xa8, 0x9c, 0x1f, 0x66],
+        b"/Mined by AntPool 919/",
+        &[0x00, 0x83, 0x00, 0x0f, 0x7a, 0xc1, 0x0e, 0x00],
+    ]));
+
+    assert_eq!(
+        classify_miner_from_coinbase(&tx),
+        Some(("AntPool".to_string(), None))
+    );
+}
+
+#[test]
+fn ocean_with_known_upstream() {
+    let tx = coinbase_tx(&script(&[
+        &[0x03, 0x42, 0xd0, 0x0c, 0x0d],
+        b"< OCEAN.XYZ >",
+        &[0x00, 0x08],
+        b"NiceHash",
+        &[0x00, 0x12, 0x00, 0x00],
+    ]));
+
+    assert_eq!(
+        classify_miner_from_coinbase(&tx),
+        Some(("NiceHash".to_string(), Some("OCEAN".to_string())))
+    );
+}
+
+#[test]
+fn ocean_with_unlisted_template_tag() {
+    // Sub-miner tag split by a control byte; no NUL in the gap, so it merges.
+    let tx = coinbase_tx(&script(&[
+        &[0x03, 0x43, 0xd0, 0x0c, 0x0d],
+        b"< OCEAN.XYZ >",
+        &[0x00, 0x06],
+        b"BDE",
+        &[0x01],
+        b"HX",
+        &[0x00, 0x00],
+    ]));
+
+    assert_eq!(
+        classify_miner_from_coinbase(&tx),
+        Some(("BDEHX".to_string(), Some("OCEAN".to_string())))
+    );
+}
+
+#[test]
+fn no_tag_is_none() {
+    let tx = coinbase_tx(&[0x03, 0x44, 0xd0, 0x0c, 0x01, 0x00]);
+    assert_eq!(classify_miner_from_coinbase(&tx), None);
+}
+
+#[test]
+fn table_matches_normalized_signatures() {
+    assert_eq!(match_primary_tag("foundryusapooldropgold"), Some("Foundry USA"));
+    assert_eq!(match_primary_tag("minedbyantpool919"), Some("AntPool"));
+    assert_eq!(match_primary_tag("somethingelse"), None);
+}
@@ -0,0 +1,41 @@
+//! Loading miners.json from a configured path, and the missing-file case.
+
+use blockchaininfo::config::parse_config_str;
+use blockchaininfo::models::errors::MyError;
+use blockchaininfo::utils::load_miners_data;
+use std::path::PathBuf;
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("bci-{}-{}.json", name, std::process::id()))
+}
+
+#[test]
+fn configured_path_is_loaded() {
+    let path = temp_path("miners");
+    std::fs::write(&path, r#"{"miners":[{"name":"Foundry USA","wallet":"bc1qexample"}]}"#).unwrap();
+
+    let data = load_miners_data(path.to_str()).unwrap();
+    assert_eq!(data.miners.len(), 1);
+    assert_eq!(data.miners[0].name, "Foundry USA");
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn missing_configured_file_is_file_not_found() {
+    let path = temp_path("miners-missing");
+    match load_miners_data(path.to_str()) {
+        Err(MyError::FileNotFound(msg)) => assert!(msg.contains("miners_json")),
+        Err(other) => panic!("unexpected {:?}", other),
+        Ok(_) => panic!("loaded a file that does not exist"),
+    }
+}
+
+#[test]
+fn miners_json_is_optional_in_config() {
+    let base = "[bitcoin_rpc]\naddress = \"http://127.0.0.1:8332\"\n";
+    assert_eq!(parse_config_str(base).unwrap().miners_json, None);
+
+    let config = parse_config_str(&format!("{}miners_json = \"/etc/bci/miners.json\"\n", base)).unwrap();
+    assert_eq!(config.miners_json.as_deref(), Some("/etc/bci/miners.json"));
+}
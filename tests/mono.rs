@@ -0,0 +1,40 @@
+//! Monochrome mode: `--mono` / `NO_COLOR` and the palette filter.
+
+use blockchaininfo::ui::color_support::{mono_requested, ColorSupport, PaletteFilter};
+use tui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::Widget,
+};
+
+#[test]
+fn no_color_and_flag() {
+    assert!(mono_requested(None, true));
+    assert!(mono_requested(Some("1"), false));
+    // An empty NO_COLOR is treated as unset.
+    assert!(!mono_requested(Some(""), false));
+    assert!(!mono_requested(None, false));
+}
+
+#[test]
+fn mono_filter_keeps_modifiers() {
+    let area = Rect::new(0, 0, 2, 1);
+    let mut buf = Buffer::empty(area);
+    buf.set_string(
+        0,
+        0,
+        "ab",
+        Style::default()
+            .fg(Color::Rgb(255, 165, 0))
+            .bg(Color::Blue)
+            .add_modifier(Modifier::BOLD | Modifier::ITALIC),
+    );
+
+    PaletteFilter::new(ColorSupport::Mono).render(area, &mut buf);
+
+    let cell = buf.get(0, 0);
+    assert_eq!(cell.fg, Color::Reset);
+    assert_eq!(cell.bg, Color::Reset);
+    assert!(cell.modifier.contains(Modifier::BOLD | Modifier::ITALIC));
+}
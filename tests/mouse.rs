@@ -0,0 +1,50 @@
+//! Clickable section-title toggles.
+
+use blockchaininfo::ui::mouse::{hotspot_at, title_hotspots, toggle_key};
+use tui::layout::Rect;
+
+#[test]
+fn recognizes_toggle_segments() {
+    assert_eq!(toggle_key("[H] HRD"), Some('h'));
+    assert_eq!(toggle_key(" [D] DUST-FREE"), Some('d'));
+    assert_eq!(toggle_key(" [2] M"), Some('2'));
+    assert_eq!(toggle_key("(c→Client)"), Some('c'));
+    assert_eq!(toggle_key("[Mempool]"), None);
+    assert_eq!(toggle_key("[Blockchain] "), None);
+    assert_eq!(toggle_key(" "), None);
+}
+
+#[test]
+fn hotspots_follow_the_rendered_title() {
+    // Title drawn on the top border of a section at (10, 5).
+    let area = Rect::new(10, 5, 60, 17);
+    let hotspots = title_hotspots(area, ["[Blockchain] ", "[H] HRD", " ", "[L] 20"]);
+
+    // "[Blockchain] " covers x 11..24, "[H] HRD" 24..31, "[L] 20" 32..38.
+    assert_eq!(hotspots, vec![(Rect::new(24, 5, 7, 1), 'h'), (Rect::new(32, 5, 6, 1), 'l')]);
+    assert_eq!(hotspot_at(&hotspots, 24, 5), Some('h'));
+    assert_eq!(hotspot_at(&hotspots, 30, 5), Some('h'));
+    assert_eq!(hotspot_at(&hotspots, 31, 5), None);
+    assert_eq!(hotspot_at(&hotspots, 33, 5), Some('l'));
+    // Only the border row is clickable.
+    assert_eq!(hotspot_at(&hotspots, 24, 6), None);
+}
+
+#[test]
+fn leading_spaces_and_arrows_are_measured_by_display_width() {
+    let area = Rect::new(0, 0, 80, 10);
+    let hotspots = title_hotspots(area, ["[Network] ", "(c→Client)", " ", "(p→Avg)"]);
+
+    // '→' is one cell wide.
+    assert_eq!(hotspots, vec![(Rect::new(11, 0, 10, 1), 'c'), (Rect::new(22, 0, 7, 1), 'p')]);
+
+    let mempool = title_hotspots(area, ["[Mempool]", " [D] ALL TX"]);
+    assert_eq!(mempool, vec![(Rect::new(11, 0, 10, 1), 'd')]);
+}
+
+#[test]
+fn hotspots_are_clipped_to_the_border() {
+    let narrow = Rect::new(0, 0, 16, 5);
+    let hotspots = title_hotspots(narrow, ["[Blockchain] ", "[H] HRD"]);
+    assert_eq!(hotspots, vec![(Rect::new(14, 0, 1, 1), 'h')]);
+}
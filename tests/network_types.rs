@@ -0,0 +1,46 @@
+//! Peer breakdown by network type.
+
+use blockchaininfo::models::peer_info::PeerInfo;
+
+fn peer(network: Option<&str>) -> PeerInfo {
+    PeerInfo { network: network.map(str::to_string), ..Default::default() }
+}
+
+#[test]
+fn counts_each_network_type() {
+    let peers: Vec<PeerInfo> = [
+        Some("ipv4"),
+        Some("onion"),
+        Some("ipv4"),
+        Some("i2p"),
+        Some("onion"),
+        Some("ipv4"),
+        Some("ipv6"),
+        Some("cjdns"),
+    ]
+    .into_iter()
+    .map(peer)
+    .collect();
+
+    assert_eq!(
+        PeerInfo::aggregate_by_network(&peers),
+        vec![
+            ("ipv4".to_string(), 3),
+            ("onion".to_string(), 2),
+            ("cjdns".to_string(), 1),
+            ("i2p".to_string(), 1),
+            ("ipv6".to_string(), 1),
+        ]
+    );
+}
+
+#[test]
+fn missing_network_is_unknown() {
+    let peers = vec![peer(None), peer(Some("")), peer(Some("onion"))];
+
+    assert_eq!(
+        PeerInfo::aggregate_by_network(&peers),
+        vec![("unknown".to_string(), 2), ("onion".to_string(), 1)]
+    );
+    assert!(PeerInfo::aggregate_by_network(&[]).is_empty());
+}
@@ -0,0 +1,75 @@
+//! Next-block fee-rate floor estimator.
+
+mod common;
+
+use blockchaininfo::models::mempool_info::{
+    next_block_fee_rate_floor, MempoolDistribution, MempoolEntry, MempoolInfo, NEXT_BLOCK_VSIZE,
+};
+use common::mempool_entry;
+use dashmap::DashMap;
+
+#[test]
+fn floor_is_lowest_rate_inside_block() {
+    // 100k vB at each of 10, 20, ..., 150 sat/vB; the block fits the top
+    // ten (150 down to 60 sat/vB).
+    let mut entries: Vec<(u64, u64)> = (1..=15)
+        .map(|i| (i * 10 * 100_000, 100_000))
+        .collect();
+
+    assert_eq!(next_block_fee_rate_floor(&mut entries, NEXT_BLOCK_VSIZE), Some(60));
+}
+
+#[test]
+fn floor_is_none_when_mempool_fits_in_block() {
+    let mut entries = vec![(5_000, 250), (1_000, 500)];
+    assert_eq!(next_block_fee_rate_floor(&mut entries, NEXT_BLOCK_VSIZE), None);
+    assert_eq!(next_block_fee_rate_floor(&mut [], NEXT_BLOCK_VSIZE), None);
+}
+
+#[test]
+fn update_metrics_populates_floor_from_cache() {
+    let cache: DashMap<[u8; 32], MempoolEntry> = DashMap::new();
+
+    // 8 × 200k vB at descending fee rates: 80, 70, ..., 10 sat/vB.
+    // Only the top five (1M vB) fit, so the floor is 40 sat/vB.
+    for i in 0..8u8 {
+        let rate = 80 - i as u64 * 10;
+        let mut key = [0u8; 32];
+        key[0] = i;
+        cache.insert(key, mempool_entry(rate * 200_000, 200_000));
+    }
+
+    let mut dist = MempoolDistribution::default();
+    dist.update_metrics(&cache);
+
+    assert_eq!(dist.next_block_fee_rate_floor, Some(40));
+}
+
+#[test]
+fn min_feerate_falls_back_to_min_relay() {
+    let dist = MempoolDistribution::default();
+    assert_eq!(dist.next_block_fee_rate_floor, None);
+    assert_eq!(dist.next_block_min_feerate(0.1), 0.1);
+
+    let dist = MempoolDistribution {
+        next_block_fee_rate_floor: Some(12),
+        ..Default::default()
+    };
+    assert_eq!(dist.next_block_min_feerate(1.0), 12.0);
+
+    // A floor below the relay minimum can't be paid; the minimum wins.
+    let dist = MempoolDistribution {
+        next_block_fee_rate_floor: Some(0),
+        ..Default::default()
+    };
+    assert_eq!(dist.next_block_min_feerate(1.0), 1.0);
+}
+
+#[test]
+fn min_relay_fee_rate_keeps_sub_sat_values() {
+    let info = MempoolInfo { minrelaytxfee: 0.000001, ..Default::default() };
+    assert_eq!(info.min_relay_fee_rate(), 0.1);
+
+    let info = MempoolInfo { minrelaytxfee: 0.00001, ..Default::default() };
+    assert_eq!(info.min_relay_fee_rate(), 1.0);
+}
@@ -0,0 +1,22 @@
+//! Connectivity state behind the "Node unreachable" footer banner.
+
+use blockchaininfo::models::errors::MyError;
+use blockchaininfo::utils::{node_reachable, note_rpc_outcome};
+
+#[test]
+fn transport_errors_flip_state_until_next_success() {
+    assert!(node_reachable());
+
+    note_rpc_outcome::<()>(&Err(MyError::TimeoutError("getblockchaininfo".into())));
+    assert!(!node_reachable());
+
+    // The node answered, even if with something unusable: no change.
+    note_rpc_outcome::<()>(&Err(MyError::CustomError("bad response".into())));
+    assert!(!node_reachable());
+
+    note_rpc_outcome(&Ok(()));
+    assert!(node_reachable());
+
+    note_rpc_outcome::<()>(&Err(MyError::RpcMethodNotFound("getdeploymentinfo".into())));
+    assert!(node_reachable());
+}
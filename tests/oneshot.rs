@@ -0,0 +1,37 @@
+//! `--oneshot` / `--json`: flag detection and the JSON report shape.
+
+use blockchaininfo::export::{oneshot_report, oneshot_requested};
+use blockchaininfo::models::blockchain_info::BlockchainInfo;
+use blockchaininfo::models::mempool_info::MempoolInfo;
+use blockchaininfo::models::network_info::NetworkInfo;
+use blockchaininfo::models::network_totals::NetTotals;
+
+fn args(list: &[&str]) -> Vec<String> {
+    list.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn either_flag_requests_oneshot() {
+    assert!(oneshot_requested(args(&["bci", "--oneshot"])));
+    assert!(oneshot_requested(args(&["bci", "--config", "c.toml", "--json"])));
+    assert!(!oneshot_requested(args(&["bci", "--config", "c.toml"])));
+    assert!(!oneshot_requested(args(&["bci", "--mono"])));
+}
+
+#[test]
+fn report_holds_the_four_core_sections() {
+    let blockchain = BlockchainInfo { blocks: 840_000, ..Default::default() };
+    let report = oneshot_report(
+        &blockchain,
+        &MempoolInfo::default(),
+        &NetworkInfo::default(),
+        &NetTotals::default(),
+    )
+    .unwrap();
+
+    let v: serde_json::Value = serde_json::from_str(&report).expect("report is valid JSON");
+    assert_eq!(v["blockchain"]["blocks"], 840_000);
+    for key in ["timestamp", "mempool", "network", "net_totals"] {
+        assert!(!v[key].is_null(), "missing {}", key);
+    }
+}
@@ -0,0 +1,64 @@
+//! OP_RETURN payload decoding: UTF-8 text vs. hex fallback.
+
+use blockchaininfo::models::transaction_info::{GetRawTransactionResponse, OpReturnPayload};
+use serde_json::json;
+
+fn tx_with_asm(asms: &[&str]) -> GetRawTransactionResponse {
+    let vout: Vec<_> = asms
+        .iter()
+        .enumerate()
+        .map(|(n, asm)| json!({ "value": 0.0, "n": n, "scriptPubKey": { "asm": asm, "type": "nulldata" } }))
+        .collect();
+    serde_json::from_value(json!({
+        "txid": "aa", "size": 1, "vsize": 1, "weight": 4, "version": 2,
+        "vin": [], "vout": vout,
+    }))
+    .expect("tx deserializes")
+}
+
+#[test]
+fn text_memo_decodes_as_utf8() {
+    // "hello world"
+    let tx = tx_with_asm(&["OP_RETURN 68656c6c6f20776f726c64"]);
+    let payload = tx.vout[0].decipher_op_return().unwrap();
+
+    assert_eq!(payload, OpReturnPayload::Text("hello world".to_string()));
+    assert_eq!(payload.as_text(), Some("hello world"));
+    assert_eq!(payload.to_string(), "OP_RETURN: hello world");
+    assert_eq!(tx.get_op_return_msg(), vec!["hello world".to_string()]);
+}
+
+#[test]
+fn binary_payload_falls_back_to_hex() {
+    let tx = tx_with_asm(&["OP_RETURN deadbeef00ff"]);
+    let payload = tx.vout[0].decipher_op_return().unwrap();
+
+    assert_eq!(payload, OpReturnPayload::Hex("deadbeef00ff".to_string()));
+    assert_eq!(payload.as_text(), None);
+    assert_eq!(payload.to_string(), "OP_RETURN (hex): deadbeef00ff");
+    // Text-only callers see nothing, as before.
+    assert!(tx.get_op_return_msg().is_empty());
+    assert_eq!(tx.op_return_payloads().len(), 1);
+}
+
+#[test]
+fn long_hex_is_cut_off() {
+    let long = "ff".repeat(60);
+    let tx = tx_with_asm(&[&format!("OP_RETURN {}", long)]);
+    let shown = tx.vout[0].decipher_op_return().unwrap().to_string();
+
+    assert_eq!(shown, format!("OP_RETURN (hex): {}...", &long[..80]));
+}
+
+#[test]
+fn protocol_opcodes_are_skipped_and_pushes_joined() {
+    // Runes-style: marker opcode followed by a binary push.
+    let tx = tx_with_asm(&["OP_RETURN OP_13 14c0a233", "OP_RETURN", "OP_DUP 6869"]);
+
+    assert_eq!(
+        tx.vout[0].decipher_op_return(),
+        Some(OpReturnPayload::Hex("14c0a233".to_string()))
+    );
+    assert_eq!(tx.vout[1].decipher_op_return(), None);
+    assert_eq!(tx.vout[2].decipher_op_return(), None);
+}
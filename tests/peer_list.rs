@@ -0,0 +1,76 @@
+//! Peer list popup helpers: address shortening and per-message breakdown.
+
+use blockchaininfo::display::display_peer_list::{message_breakdown, shorten_addr};
+use blockchaininfo::models::peer_info::PeerInfo;
+use std::collections::HashMap;
+
+#[test]
+fn short_addresses_are_untouched() {
+    assert_eq!(shorten_addr("203.0.113.7:8333", 28), "203.0.113.7:8333");
+    assert_eq!(shorten_addr("[2001:db8::1]:8333", 28), "[2001:db8::1]:8333");
+}
+
+#[test]
+fn onion_keeps_suffix_and_port() {
+    let addr = "vww6ybal4bd7szmgncyruucpgfkqahzddi37ktceo3ah7ngmcopnpyyd.onion:8333";
+    let short = shorten_addr(addr, 28);
+
+    assert_eq!(short.chars().count(), 28);
+    assert!(short.starts_with("vww6yba"));
+    assert!(short.ends_with(".onion:8333"));
+    assert!(short.contains('…'));
+}
+
+#[test]
+fn i2p_keeps_suffix_without_port() {
+    let addr = "ukeu3k5oycgaauneqgtnvselmt4yemvoilkln7jpvamvfx7dnkdq.b32.i2p:0";
+    let short = shorten_addr(addr, 28);
+
+    assert_eq!(short.chars().count(), 28);
+    assert!(short.ends_with(".b32.i2p:0"));
+}
+
+#[test]
+fn tiny_width_falls_back_to_plain_cut() {
+    let short = shorten_addr("vww6ybal4bd7szmgncyruucpgfkqahzddi37.onion:8333", 10);
+    assert_eq!(short.chars().count(), 10);
+    assert!(short.ends_with('…'));
+}
+
+fn bytes(pairs: &[(&str, u64)]) -> Option<HashMap<String, u64>> {
+    Some(pairs.iter().map(|(k, v)| (k.to_string(), *v)).collect())
+}
+
+#[test]
+fn breakdown_merges_and_sorts_by_total() {
+    let peer = PeerInfo {
+        bytessent_per_msg: bytes(&[("inv", 500), ("tx", 100), ("ping", 32)]),
+        bytesrecv_per_msg: bytes(&[("tx", 900), ("headers", 200), ("ping", 32)]),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        message_breakdown(&peer),
+        vec![
+            ("tx".to_string(), 100, 900),
+            ("inv".to_string(), 500, 0),
+            ("headers".to_string(), 0, 200),
+            ("ping".to_string(), 32, 32),
+        ]
+    );
+}
+
+#[test]
+fn breakdown_is_empty_when_node_omits_maps() {
+    assert!(message_breakdown(&PeerInfo::default()).is_empty());
+}
+
+#[test]
+fn per_message_maps_deserialize_when_present() {
+    let mut json = serde_json::to_value(PeerInfo::default()).unwrap();
+    json["bytessent_per_msg"] = serde_json::json!({ "cmpctblock": 12000 });
+    json["bytesrecv_per_msg"] = serde_json::json!({ "cmpctblock": 34000 });
+
+    let peer: PeerInfo = serde_json::from_value(json).unwrap();
+    assert_eq!(message_breakdown(&peer), vec![("cmpctblock".to_string(), 12000, 34000)]);
+}
@@ -0,0 +1,55 @@
+//! Peer latency summary from `getpeerinfo` ping fields.
+
+use blockchaininfo::models::peer_info::{PeerInfo, PeerInfoJsonWrap, SLOW_PING_SECS};
+
+fn peer(pingtime: Option<f64>, minping: Option<f64>) -> PeerInfo {
+    PeerInfo { pingtime, minping, ..Default::default() }
+}
+
+#[test]
+fn summarizes_min_median_max() {
+    let peers = vec![
+        peer(Some(0.040), Some(0.030)),
+        peer(Some(0.120), None),
+        peer(Some(0.015), Some(0.012)),
+        peer(Some(0.300), Some(0.250)),
+        // No pong yet: skipped.
+        peer(None, None),
+    ];
+
+    let ping = PeerInfo::ping_summary(&peers).unwrap();
+    assert!((ping.min_ms - 12.0).abs() < 1e-9, "min uses minping");
+    assert!((ping.median_ms - 80.0).abs() < 1e-9);
+    assert!((ping.max_ms - 300.0).abs() < 1e-9);
+    assert_eq!(ping.slow, 0);
+}
+
+#[test]
+fn counts_slow_peers() {
+    let peers = vec![
+        peer(Some(0.050), None),
+        peer(Some(SLOW_PING_SECS + 0.5), None),
+        peer(Some(SLOW_PING_SECS), None), // at the threshold: not slow
+    ];
+
+    assert_eq!(PeerInfo::ping_summary(&peers).unwrap().slow, 1);
+}
+
+#[test]
+fn none_without_pings() {
+    assert_eq!(PeerInfo::ping_summary(&[]), None);
+    assert_eq!(PeerInfo::ping_summary(&[peer(None, None)]), None);
+}
+
+#[test]
+fn parses_ping_fields() {
+    // A full getpeerinfo entry, with the ping fields as Core reports them.
+    let mut entry = serde_json::to_value(PeerInfo::default()).unwrap();
+    entry["pingtime"] = serde_json::json!(0.0421);
+    entry["minping"] = serde_json::json!(0.0315);
+    let json = serde_json::json!({ "result": [entry], "error": null, "id": "1" });
+
+    let peers = serde_json::from_value::<PeerInfoJsonWrap>(json).unwrap().result;
+    assert_eq!(peers[0].pingtime, Some(0.0421));
+    assert_eq!(peers[0].minping, Some(0.0315));
+}
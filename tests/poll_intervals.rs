@@ -0,0 +1,65 @@
+//! `[intervals]` config table.
+
+use blockchaininfo::config::{parse_config_str, PollIntervals};
+
+#[test]
+fn intervals_table_next_to_bitcoin_rpc() {
+    let config = parse_config_str(
+        r#"
+        [bitcoin_rpc]
+        username = "u"
+        password = "p"
+        address = "http://127.0.0.1:8332"
+
+        [intervals]
+        blockchain = 10
+        mempool = 20
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(config.address, "http://127.0.0.1:8332");
+    assert_eq!(config.intervals.blockchain, 10);
+    assert_eq!(config.intervals.mempool, 20);
+    // Unlisted sections keep their defaults.
+    assert_eq!(config.intervals.chain_tips, PollIntervals::default().chain_tips);
+}
+
+#[test]
+fn defaults_without_table() {
+    let config = parse_config_str(
+        r#"
+        username = "u"
+        password = "p"
+        address = "http://127.0.0.1:8332"
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(config.intervals, PollIntervals::default());
+    assert!(config.intervals.validate().is_ok());
+}
+
+#[test]
+fn zero_interval_is_rejected() {
+    let intervals = PollIntervals {
+        peers: 0,
+        ..Default::default()
+    };
+    let err = intervals.validate().unwrap_err().to_string();
+    assert!(err.contains("intervals.peers"), "{err}");
+}
+
+#[test]
+fn unknown_section_is_an_error() {
+    let result = parse_config_str(
+        r#"
+        [bitcoin_rpc]
+        address = "http://127.0.0.1:8332"
+
+        [intervals]
+        mempol = 5
+        "#,
+    );
+    assert!(result.is_err());
+}
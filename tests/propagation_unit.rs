@@ -0,0 +1,118 @@
+//! Block propagation estimate: MAD-filtered median, in seconds or ms.
+
+use blockchaininfo::config::parse_config_str;
+use blockchaininfo::models::peer_info::{
+    median_without_outliers, PeerInfo, PropagationUnit, PROPAGATION_MAX_DELTA_SECS,
+};
+
+const BEST_TIME: u64 = 1_700_000_000;
+const HEIGHT: i64 = 820_000;
+
+fn satoshi_peer(last_block: u64, height: i64) -> PeerInfo {
+    PeerInfo {
+        subver: "/Satoshi:27.0.0/".to_string(),
+        last_block,
+        synced_blocks: height,
+        ..Default::default()
+    }
+}
+
+/// Up-to-date peers that received the tip `deltas` seconds after its header time.
+fn peers_at(deltas: &[i64]) -> Vec<PeerInfo> {
+    deltas
+        .iter()
+        .map(|d| satoshi_peer((BEST_TIME as i64 + d) as u64, HEIGHT))
+        .collect()
+}
+
+fn propagation(peers: &[PeerInfo], unit: PropagationUnit) -> i64 {
+    PeerInfo::calculate_block_propagation_time(peers, BEST_TIME, HEIGHT as u64, unit)
+}
+
+#[test]
+fn seconds_and_ms_convert() {
+    assert_eq!(PropagationUnit::Seconds.scale_ms(0), 0);
+    assert_eq!(PropagationUnit::Seconds.scale_ms(12_400), 12);
+    assert_eq!(PropagationUnit::Seconds.scale_ms(-12_500), -13);
+    assert_eq!(PropagationUnit::RawMs.scale_ms(-12_500), -12_500);
+    assert_eq!(PropagationUnit::RawMs.to_seconds(18_000), 18);
+    assert_eq!(PropagationUnit::Seconds.to_seconds(18), 18);
+}
+
+#[test]
+fn default_is_seconds_and_old_name_still_parses() {
+    assert_eq!(PropagationUnit::default(), PropagationUnit::Seconds);
+
+    let base = "[bitcoin_rpc]\naddress = \"http://127.0.0.1:8332\"\n";
+    for (value, unit) in [
+        ("seconds", PropagationUnit::Seconds),
+        ("normalized", PropagationUnit::Seconds),
+        ("raw_ms", PropagationUnit::RawMs),
+    ] {
+        let config = parse_config_str(&format!("{}propagation_unit = \"{}\"\n", base, value)).unwrap();
+        assert_eq!(config.propagation_unit, unit);
+    }
+}
+
+#[test]
+fn calculation_takes_median_of_eligible_peers() {
+    let mut peers = peers_at(&[12, 24, 30]);
+    // Ignored: not at tip, and a non-Satoshi agent.
+    peers.push(satoshi_peer(BEST_TIME + 300, HEIGHT - 1));
+    peers.push(PeerInfo {
+        subver: "/btcd:0.24.0/".to_string(),
+        last_block: BEST_TIME + 300,
+        synced_blocks: HEIGHT,
+        ..Default::default()
+    });
+
+    assert_eq!(propagation(&peers, PropagationUnit::Seconds), 24);
+    assert_eq!(propagation(&peers, PropagationUnit::RawMs), 24_000);
+}
+
+#[test]
+fn no_samples_is_zero() {
+    assert_eq!(propagation(&[], PropagationUnit::Seconds), 0);
+    // Every delta is beyond the sane range.
+    let skewed = peers_at(&[PROPAGATION_MAX_DELTA_SECS + 1, -PROPAGATION_MAX_DELTA_SECS - 60]);
+    assert_eq!(propagation(&skewed, PropagationUnit::Seconds), 0);
+}
+
+#[test]
+fn negative_direction_is_kept() {
+    // The miner's clock ran ~20s ahead of ours.
+    let peers = peers_at(&[-22, -20, -19, -21]);
+    assert_eq!(propagation(&peers, PropagationUnit::Seconds), -21);
+}
+
+#[test]
+fn clock_skewed_outliers_are_rejected() {
+    // A tight cluster around 8s plus peers with badly skewed clocks that are
+    // still inside the ±600s sanity window.
+    let deltas = [6, 7, 8, 8, 9, 10, 550, 580, -400];
+    assert_eq!(median_without_outliers(&deltas.map(|d| d * 1000)), Some(8_000));
+    assert_eq!(propagation(&peers_at(&deltas), PropagationUnit::Seconds), 8);
+}
+
+#[test]
+fn mad_filter_keeps_genuine_spread() {
+    // Widely spread but consistent samples: nothing is an outlier.
+    let samples = [2_000, 10_000, 20_000, 35_000, 50_000];
+    assert_eq!(median_without_outliers(&samples), Some(20_000));
+    // Identical samples have zero MAD and survive the filter.
+    assert_eq!(median_without_outliers(&[5_000; 4]), Some(5_000));
+    assert_eq!(median_without_outliers(&[]), None);
+}
+
+#[test]
+fn one_extreme_sample_does_not_swing_the_result() {
+    let typical = [4, 5, 6, 7, 9];
+    let baseline = propagation(&peers_at(&typical), PropagationUnit::Seconds);
+
+    // One peer just inside the ±600s window; a mean would jump by ~100s.
+    let mut with_extreme = typical.to_vec();
+    with_extreme.push(PROPAGATION_MAX_DELTA_SECS - 1);
+
+    assert_eq!(baseline, 6);
+    assert_eq!(propagation(&peers_at(&with_extreme), PropagationUnit::Seconds), baseline);
+}
@@ -0,0 +1,50 @@
+//! Peer distribution by P2P protocol version, and the `c` view cycle.
+
+use blockchaininfo::display::display_network_info::DistributionView;
+use blockchaininfo::models::peer_info::PeerInfo;
+
+fn peer(version: i32) -> PeerInfo {
+    PeerInfo { version, ..Default::default() }
+}
+
+#[test]
+fn aggregates_by_protocol_version() {
+    let peers: Vec<PeerInfo> = [70016, 70016, 70015, 70016, 70015, 70014, 0]
+        .into_iter()
+        .map(peer)
+        .collect();
+
+    assert_eq!(
+        PeerInfo::aggregate_and_sort_protocol_versions(&peers),
+        vec![
+            ("70016".to_string(), 3),
+            ("70015".to_string(), 2),
+            ("70014".to_string(), 1),
+        ]
+    );
+}
+
+#[test]
+fn ties_sort_newest_first() {
+    let peers: Vec<PeerInfo> = [70015, 70016].into_iter().map(peer).collect();
+    let counts = PeerInfo::aggregate_and_sort_protocol_versions(&peers);
+
+    assert_eq!(counts[0].0, "70016");
+    assert_eq!(counts[1].0, "70015");
+}
+
+#[test]
+fn c_cycles_version_client_protocol() {
+    let start = DistributionView::default();
+    assert_eq!(start, DistributionView::Version);
+    assert_eq!(start.next(), DistributionView::Client);
+    assert_eq!(start.next().next(), DistributionView::Protocol);
+    assert_eq!(start.next().next().next(), DistributionView::Network);
+    assert_eq!(DistributionView::Client.next().label(), "Protocol");
+}
+
+#[test]
+fn c_cycle_ends_with_network_view() {
+    assert_eq!(DistributionView::Protocol.next(), DistributionView::Network);
+    assert_eq!(DistributionView::Network.next(), DistributionView::Version);
+}
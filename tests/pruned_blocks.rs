@@ -0,0 +1,59 @@
+//! Pruned nodes: which heights still have block data, and Core's
+//! pruned-data error.
+
+use blockchaininfo::models::blockchain_info::{height_24h_ago, BlockchainInfo};
+use blockchaininfo::models::errors::MyError;
+use blockchaininfo::models::rpc_envelope::RpcErrorObject;
+
+#[test]
+fn archival_node_has_every_block() {
+    let node = BlockchainInfo { blocks: 820_000, ..Default::default() };
+    assert!(node.has_block_data(0));
+    assert!(node.has_block_data(height_24h_ago(820_000)));
+}
+
+#[test]
+fn pruned_node_has_blocks_from_prune_height() {
+    let node = BlockchainInfo {
+        blocks: 820_000,
+        pruned: true,
+        pruneheight: Some(819_900),
+        ..Default::default()
+    };
+    assert!(node.has_block_data(819_900));
+    assert!(node.has_block_data(820_000));
+    assert!(!node.has_block_data(819_899));
+    assert!(!node.has_block_data(height_24h_ago(820_000)));
+
+    // Older nodes may not report a prune height; assume the block is there.
+    let unknown = BlockchainInfo { pruneheight: None, ..node };
+    assert!(unknown.has_block_data(height_24h_ago(820_000)));
+}
+
+#[test]
+fn height_24h_ago_is_143_blocks_back() {
+    assert_eq!(height_24h_ago(820_000), 819_857);
+    assert_eq!(height_24h_ago(100), 0);
+}
+
+#[test]
+fn pruned_data_error_is_block_pruned() {
+    let error = RpcErrorObject { code: -1, message: "Block not available (pruned data)".to_string() };
+    match error.into_error("getblock") {
+        MyError::BlockPruned(msg) => {
+            assert_eq!(msg, "getblock failed: Block not available (pruned data)")
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn prune_height_is_read_from_getblockchaininfo() {
+    let info: BlockchainInfo = serde_json::from_str(
+        r#"{"chain":"main","blocks":820000,"headers":820000,"bestblockhash":"00","difficulty":1.0,
+            "time":0,"mediantime":0,"verificationprogress":1.0,"initialblockdownload":false,
+            "chainwork":"00","size_on_disk":0,"pruned":true,"pruneheight":819000,"warnings":""}"#,
+    )
+    .unwrap();
+    assert_eq!(info.pruneheight, Some(819_000));
+}
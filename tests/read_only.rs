@@ -0,0 +1,51 @@
+//! Read-only mode: blocked methods are refused before any request is sent.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use blockchaininfo::config::parse_config_str;
+use blockchaininfo::models::errors::MyError;
+use blockchaininfo::rpc::call_log::{logged, recent_calls};
+use blockchaininfo::rpc::policy::is_method_allowed;
+
+const BASE: &str = "[bitcoin_rpc]\naddress = \"http://127.0.0.1:8332\"\n";
+
+#[tokio::test]
+async fn blocked_method_is_refused_in_read_only_mode() {
+    let config = parse_config_str(&format!("{}read_only = true\n", BASE)).unwrap();
+    let sent = AtomicBool::new(false);
+
+    let result = logged(&config, "scantxoutset", async {
+        sent.store(true, Ordering::Relaxed);
+        Ok::<_, MyError>(())
+    })
+    .await;
+
+    match result {
+        Err(MyError::Config(msg)) => assert!(msg.contains("scantxoutset")),
+        other => panic!("expected a read-only refusal, got {:?}", other),
+    }
+    assert!(!sent.load(Ordering::Relaxed), "blocked call must not run");
+
+    let record = recent_calls().into_iter().find(|r| r.method == "scantxoutset").unwrap();
+    assert!(record.error.unwrap().contains("read-only"));
+}
+
+#[tokio::test]
+async fn labelled_calls_are_checked_by_method_name() {
+    let config = parse_config_str(&format!("{}read_only = true\n", BASE)).unwrap();
+    let result = logged(&config, "gettxoutsetinfo (stats)", async { Ok::<_, MyError>(()) }).await;
+    assert!(matches!(result, Err(MyError::Config(_))));
+}
+
+#[tokio::test]
+async fn polled_methods_pass_in_read_only_mode() {
+    let config = parse_config_str(&format!("{}read_only = true\n", BASE)).unwrap();
+    let result = logged(&config, "getblockchaininfo", async { Ok::<_, MyError>(1) }).await;
+    assert_eq!(result.unwrap(), 1);
+}
+
+#[test]
+fn nothing_is_blocked_without_read_only() {
+    let config = parse_config_str(BASE).unwrap();
+    assert!(is_method_allowed(&config, "scantxoutset"));
+}
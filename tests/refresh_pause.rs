@@ -0,0 +1,29 @@
+//! Pausing auto-refresh holds the worker tasks; resuming releases them at once.
+
+use std::time::Duration;
+
+use blockchaininfo::utils::{poll_sleep, refresh_paused, set_refresh_paused};
+use tokio::time::{timeout, Instant};
+
+// One test: the pause flag is process-wide.
+#[tokio::test]
+async fn pause_holds_polls_and_resume_releases_them() {
+    // Not paused: a plain sleep.
+    let start = Instant::now();
+    poll_sleep(Duration::from_millis(20)).await;
+    assert!(start.elapsed() >= Duration::from_millis(20));
+
+    // Paused: the wait outlasts the interval.
+    set_refresh_paused(true);
+    assert!(refresh_paused());
+    assert!(timeout(Duration::from_millis(200), poll_sleep(Duration::ZERO)).await.is_err());
+
+    // Resuming wakes a task mid-interval instead of letting it sleep it out.
+    let waiter = tokio::spawn(poll_sleep(Duration::from_secs(60)));
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let resumed_at = Instant::now();
+    set_refresh_paused(false);
+    timeout(Duration::from_secs(5), waiter).await.unwrap().unwrap();
+    assert!(resumed_at.elapsed() < Duration::from_secs(5));
+    assert!(!refresh_paused());
+}
@@ -0,0 +1,79 @@
+//! Reorg detection from successive best blocks.
+
+use blockchaininfo::models::reorg::{ReorgTracker, REORG_TRACK_BLOCKS};
+
+fn b(height: u64, hash: &str) -> (u64, String) {
+    (height, hash.to_string())
+}
+
+/// Tracker that has seen `hashes` as consecutive tips starting at `start`.
+fn tracker(start: u64, hashes: &[&str]) -> ReorgTracker {
+    let mut t = ReorgTracker::default();
+    for (i, hash) in hashes.iter().enumerate() {
+        assert_eq!(t.connect(&[b(start + i as u64, hash)]), 0);
+    }
+    t
+}
+
+#[test]
+fn first_tip_and_extensions_are_not_reorgs() {
+    let mut t = ReorgTracker::default();
+    assert!(!t.needs_parent(100, Some("genesis-ish")));
+    assert_eq!(t.connect(&[b(100, "a")]), 0);
+
+    assert!(!t.needs_parent(101, Some("a")));
+    assert_eq!(t.connect(&[b(101, "b")]), 0);
+    assert_eq!(t.best(), Some((101, "b")));
+}
+
+#[test]
+fn competing_block_at_same_height_is_depth_one() {
+    let mut t = tracker(100, &["a", "b"]);
+
+    assert!(!t.needs_parent(101, Some("a")));
+    assert_eq!(t.connect(&[b(101, "b2")]), 1);
+    assert_eq!(t.best(), Some((101, "b2")));
+}
+
+#[test]
+fn longer_branch_walks_back_to_fork_point() {
+    let mut t = tracker(100, &["a", "b", "c"]);
+
+    // New tip 103 "x" builds on an unseen 102 "w", which builds on 101 "v".
+    assert!(t.needs_parent(103, Some("w")));
+    assert!(t.needs_parent(102, Some("v")));
+    assert!(!t.needs_parent(101, Some("a")));
+
+    assert_eq!(t.connect(&[b(101, "v"), b(102, "w"), b(103, "x")]), 2);
+    assert_eq!(t.best(), Some((103, "x")));
+}
+
+#[test]
+fn missed_blocks_on_the_same_chain_are_not_reorgs() {
+    let mut t = tracker(100, &["a"]);
+
+    // Tip jumped two blocks between polls.
+    assert!(t.needs_parent(102, Some("b")));
+    assert!(!t.needs_parent(101, Some("a")));
+    assert_eq!(t.connect(&[b(101, "b"), b(102, "c")]), 0);
+}
+
+#[test]
+fn shorter_replacement_counts_blocks_above_new_tip() {
+    let mut t = tracker(100, &["a", "b", "c"]);
+
+    // Node switched to a branch whose tip is lower (e.g. invalidateblock).
+    assert_eq!(t.connect(&[b(101, "b2")]), 2);
+    assert_eq!(t.best(), Some((101, "b2")));
+}
+
+#[test]
+fn walk_stops_below_remembered_window() {
+    let hashes: Vec<String> = (0..REORG_TRACK_BLOCKS + 10).map(|i| format!("h{}", i)).collect();
+    let refs: Vec<&str> = hashes.iter().map(String::as_str).collect();
+    let t = tracker(1_000, &refs);
+
+    // Oldest heights were dropped, so a mismatch there can't be resolved.
+    assert!(!t.needs_parent(1_001, Some("unknown")));
+    assert!(t.needs_parent(1_000 + REORG_TRACK_BLOCKS as u64, Some("unknown")));
+}
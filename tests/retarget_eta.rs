@@ -0,0 +1,46 @@
+//! Time-to-retarget estimate from observed block intervals.
+
+use blockchaininfo::models::block_intervals::{BlockIntervals, MIN_INTERVALS_FOR_ESTIMATE};
+use blockchaininfo::models::blockchain_info::{format_eta, BlockchainInfo};
+
+fn intervals(secs: &[u64]) -> BlockIntervals {
+    let mut history = BlockIntervals::default();
+    let (mut height, mut time) = (800_000, 1_700_000_000);
+    history.observe(height, time);
+    for s in secs {
+        height += 1;
+        time += s;
+        history.observe(height, time);
+    }
+    history
+}
+
+#[test]
+fn falls_back_to_target_spacing() {
+    assert_eq!(BlockIntervals::default().expected_interval(), 600);
+    let few = intervals(&[300; MIN_INTERVALS_FOR_ESTIMATE - 1]);
+    assert_eq!(few.expected_interval(), 600);
+}
+
+#[test]
+fn uses_observed_mean_with_enough_history() {
+    let history = intervals(&[400, 500, 600, 700, 300, 500]);
+    assert_eq!(history.expected_interval(), 500);
+}
+
+#[test]
+fn eta_is_blocks_left_times_interval() {
+    let info = BlockchainInfo { blocks: 2016 * 400 + 1000, ..Default::default() };
+    // 1016 blocks left.
+    assert_eq!(info.seconds_until_adjustment(600).unwrap(), 609_600);
+    assert_eq!(format_eta(609_600), "7d 1h");
+    assert!(BlockchainInfo::default().seconds_until_adjustment(600).is_err());
+}
+
+#[test]
+fn eta_formatting() {
+    assert_eq!(format_eta(3 * 86_400 + 4 * 3_600 + 59 * 60), "3d 4h");
+    assert_eq!(format_eta(4 * 3_600 + 12 * 60), "4h 12m");
+    assert_eq!(format_eta(12 * 60 + 30), "12m");
+    assert_eq!(format_eta(0), "0m");
+}
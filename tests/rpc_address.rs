@@ -0,0 +1,46 @@
+//! RPC address validation and normalization.
+
+use blockchaininfo::config::normalize_rpc_address;
+use blockchaininfo::models::errors::MyError;
+
+#[test]
+fn well_formed_addresses_are_kept() {
+    for address in [
+        "http://127.0.0.1:8332",
+        "https://node.example.com:8332",
+        "http://[::1]:8332",
+        "http://127.0.0.1:8332/wallet/cold",
+        "http://umbrel.local:8332",
+    ] {
+        assert_eq!(normalize_rpc_address(address).unwrap(), address);
+    }
+    assert_eq!(normalize_rpc_address("  http://127.0.0.1:8332\n").unwrap(), "http://127.0.0.1:8332");
+}
+
+#[test]
+fn bare_host_and_port_gets_a_scheme() {
+    assert_eq!(normalize_rpc_address("127.0.0.1:8332").unwrap(), "http://127.0.0.1:8332");
+    assert_eq!(normalize_rpc_address("localhost:18443").unwrap(), "http://localhost:18443");
+    assert_eq!(normalize_rpc_address("mynode").unwrap(), "http://mynode");
+}
+
+#[test]
+fn malformed_addresses_are_rejected() {
+    for address in [
+        "",
+        "   ",
+        "ftp://127.0.0.1:8332",
+        "ws://127.0.0.1:8332",
+        "http://",
+        "http://:8332",
+        "http://127.0.0.1:99999",
+        "http://127.0.0.1:port",
+        "127.0.0.1:0",
+        "http://bad host:8332",
+    ] {
+        match normalize_rpc_address(address) {
+            Err(MyError::Config(msg)) => assert!(msg.contains("not a valid RPC URL"), "{}", msg),
+            other => panic!("{:?} accepted: {:?}", address, other),
+        }
+    }
+}
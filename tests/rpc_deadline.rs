@@ -0,0 +1,36 @@
+//! Overall RPC deadline: hung calls become `MyError::TimeoutError`.
+
+use std::time::Duration;
+
+use blockchaininfo::config::{parse_config_str, RpcConfig};
+use blockchaininfo::models::errors::MyError;
+use blockchaininfo::rpc::call_log::{logged_within, recent_calls, RPC_CALL_DEADLINE};
+
+fn config() -> RpcConfig {
+    parse_config_str("[bitcoin_rpc]\naddress = \"http://127.0.0.1:8332\"\n").unwrap()
+}
+
+#[tokio::test]
+async fn elapsed_converts_to_timeout_error() {
+    let elapsed = tokio::time::timeout(Duration::from_millis(1), std::future::pending::<()>())
+        .await
+        .unwrap_err();
+    assert!(matches!(MyError::from(elapsed), MyError::TimeoutError(_)));
+}
+
+#[tokio::test]
+async fn hung_call_hits_the_deadline_and_is_logged() {
+    let result: Result<(), MyError> =
+        logged_within(&config(), "hungcall", Duration::from_millis(20), std::future::pending()).await;
+    assert!(matches!(result, Err(MyError::TimeoutError(_))));
+
+    let record = recent_calls().into_iter().find(|r| r.method == "hungcall").unwrap();
+    assert!(record.error.unwrap().contains("timed out"));
+}
+
+#[tokio::test]
+async fn fast_call_passes_through() {
+    let result = logged_within(&config(), "fastcall", Duration::from_secs(5), async { Ok::<_, MyError>(7) }).await;
+    assert_eq!(result.unwrap(), 7);
+    assert!(RPC_CALL_DEADLINE > Duration::from_secs(60));
+}
@@ -0,0 +1,46 @@
+//! Core's JSON-RPC error objects surface as errors instead of parse failures.
+
+use blockchaininfo::models::errors::MyError;
+use blockchaininfo::models::rpc_envelope::{RpcEnvelope, RpcErrorObject, RPC_METHOD_NOT_FOUND};
+use serde::de::IgnoredAny;
+
+#[test]
+fn error_object_keeps_message_and_code() {
+    let envelope: RpcEnvelope<IgnoredAny> = serde_json::from_str(
+        r#"{"result":null,"error":{"code":-8,"message":"Block height out of range"},"id":"1"}"#,
+    )
+    .unwrap();
+
+    match envelope.error.unwrap().into_error("getblockhash") {
+        MyError::CustomError(msg) => {
+            assert_eq!(msg, "getblockhash failed: Block height out of range (-8)")
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn unknown_method_is_method_not_found() {
+    let error = RpcErrorObject { code: RPC_METHOD_NOT_FOUND, message: "Method not found".to_string() };
+    assert!(matches!(error.into_error("uptime"), MyError::RpcMethodNotFound(m) if m == "uptime"));
+}
+
+#[test]
+fn success_has_no_error() {
+    // Any result shape; only the error matters to the envelope check.
+    for body in [
+        r#"{"result":{"blocks":840000},"error":null,"id":"1"}"#,
+        r#"{"result":"00000000000000000002a7c4","error":null,"id":"1"}"#,
+        r#"{"result":12.5,"id":1}"#,
+    ] {
+        let envelope: RpcEnvelope<IgnoredAny> = serde_json::from_str(body).unwrap();
+        assert!(envelope.error.is_none(), "{}", body);
+    }
+}
+
+#[test]
+fn typed_result_parses() {
+    let envelope: RpcEnvelope<u64> =
+        serde_json::from_str(r#"{"result":840000,"error":null,"id":"1"}"#).unwrap();
+    assert_eq!(envelope.result, Some(840_000));
+}
@@ -0,0 +1,178 @@
+//! RPC layer tests against a mock Bitcoin Core server.
+//!
+//! Fixtures live in `tests/fixtures/<core version>/<method>.json` and are
+//! trimmed copies of real responses.
+
+mod common;
+
+use std::collections::HashMap;
+use std::net::TcpListener;
+
+use blockchaininfo::models::errors::MyError;
+use blockchaininfo::rpc::{
+    fetch_block_stats, fetch_blockchain_info, fetch_mempool_info, fetch_network_info,
+};
+use common::{config_for, fixture, MockRpc, Reply};
+
+const CORE_VERSIONS: &[&str] = &["core_v25", "core_v28"];
+
+fn routes_for(version: &str, methods: &[&str]) -> HashMap<String, Reply> {
+    methods
+        .iter()
+        .map(|m| (m.to_string(), Reply::ok(fixture(version, m))))
+        .collect()
+}
+
+fn single(method: &str, reply: Reply) -> MockRpc {
+    MockRpc::start(HashMap::from([(method.to_string(), reply)]))
+}
+
+#[tokio::test]
+async fn blockchain_info_across_core_versions() {
+    for version in CORE_VERSIONS {
+        let mock = MockRpc::start(routes_for(version, &["getblockchaininfo"]));
+        let info = fetch_blockchain_info(&mock.config())
+            .await
+            .unwrap_or_else(|e| panic!("{}: {}", version, e));
+
+        assert_eq!(info.chain, "main", "{}", version);
+        assert!(info.blocks > 0, "{}", version);
+        assert!(!info.initialblockdownload, "{}", version);
+        assert_eq!(mock.calls.lock().unwrap().as_slice(), ["getblockchaininfo"]);
+    }
+}
+
+#[tokio::test]
+async fn mempool_info_across_core_versions() {
+    for version in CORE_VERSIONS {
+        let mock = MockRpc::start(routes_for(version, &["getmempoolinfo", "getrawmempool"]));
+        let info = fetch_mempool_info(&mock.config())
+            .await
+            .unwrap_or_else(|e| panic!("{}: {}", version, e));
+
+        assert!(info.loaded, "{}", version);
+        assert_eq!(info.size, 2, "{}", version);
+        assert_eq!(info.min_relay_tx_fee_vsats(), 1, "{}", version);
+        assert_eq!(info.fullrbf, *version == "core_v28", "{}", version);
+        assert_eq!(info.unbroadcastcount, u64::from(*version == "core_v28"), "{}", version);
+        assert_eq!(
+            mock.calls.lock().unwrap().as_slice(),
+            ["getmempoolinfo", "getrawmempool"]
+        );
+    }
+}
+
+#[tokio::test]
+async fn network_info_across_core_versions() {
+    let expected = [("core_v25", 250000, 10), ("core_v28", 280000, 42)];
+
+    for (version, node_version, connections) in expected {
+        let mock = MockRpc::start(routes_for(version, &["getnetworkinfo"]));
+        let info = fetch_network_info(&mock.config())
+            .await
+            .unwrap_or_else(|e| panic!("{}: {}", version, e));
+
+        assert_eq!(info.version, node_version);
+        assert_eq!(info.connections, connections);
+        assert_eq!(info.connections, info.connections_in + info.connections_out);
+    }
+}
+
+#[tokio::test]
+async fn malformed_json_is_a_parse_error() {
+    let mock = single("getblockchaininfo", Reply::ok("{\"result\": {\"chain\": "));
+
+    match fetch_blockchain_info(&mock.config()).await {
+        Err(MyError::CustomError(msg)) => assert!(msg.contains("getblockchaininfo")),
+        other => panic!("expected parse error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn core_error_object_is_surfaced_as_error() {
+    // Core during startup: HTTP 500 with an error object and null result.
+    let mock = single(
+        "getnetworkinfo",
+        Reply::Body(
+            500,
+            r#"{"result":null,"error":{"code":-28,"message":"Loading block index…"},"id":"1"}"#
+                .to_string(),
+        ),
+    );
+
+    assert!(matches!(
+        fetch_network_info(&mock.config()).await,
+        Err(MyError::CustomError(_))
+    ));
+}
+
+#[tokio::test]
+async fn pruned_block_stats_are_block_pruned() {
+    // Core on a pruned node: HTTP 500 with a generic -1 code.
+    let mock = single(
+        "getblockstats",
+        Reply::Body(
+            500,
+            r#"{"result":null,"error":{"code":-1,"message":"Block not available (pruned data)"},"id":"1"}"#
+                .to_string(),
+        ),
+    );
+
+    assert!(matches!(
+        fetch_block_stats(&mock.config(), 100).await,
+        Err(MyError::BlockPruned(_))
+    ));
+}
+
+#[tokio::test]
+async fn unknown_method_is_an_error() {
+    // No routes at all → mock answers 404 "Method not found".
+    let mock = MockRpc::start(HashMap::new());
+    assert!(fetch_mempool_info(&mock.config()).await.is_err());
+}
+
+#[tokio::test]
+async fn connection_refused_is_a_reqwest_error() {
+    let address = {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        format!("http://{}", listener.local_addr().unwrap())
+    }; // listener dropped → port closed
+
+    assert!(matches!(
+        fetch_blockchain_info(&config_for(&address)).await,
+        Err(MyError::Reqwest(_))
+    ));
+}
+
+#[tokio::test]
+async fn unresponsive_node_times_out() {
+    // Waits for the client's request timeout (10s on a direct connection).
+    let mock = single("getblockchaininfo", Reply::Hang);
+
+    match fetch_blockchain_info(&mock.config()).await {
+        Err(MyError::TimeoutError(msg)) => {
+            assert!(msg.contains("getblockchaininfo"));
+            assert!(msg.contains(&mock.address));
+        }
+        other => panic!("expected timeout, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn shared_client_follows_configured_timeout() {
+    // A call with the default settings builds the shared client first...
+    let mock = MockRpc::start(routes_for("core_v28", &["getblockchaininfo"]));
+    fetch_blockchain_info(&mock.config()).await.unwrap();
+
+    // ...a shorter configured timeout must still apply afterwards.
+    let hung = single("getblockchaininfo", Reply::Hang);
+    let mut config = hung.config();
+    config.request_timeout_secs = Some(1);
+
+    let started = std::time::Instant::now();
+    assert!(matches!(
+        fetch_blockchain_info(&config).await,
+        Err(MyError::TimeoutError(_))
+    ));
+    assert!(started.elapsed() < std::time::Duration::from_secs(5));
+}
@@ -0,0 +1,51 @@
+//! Decoding of `getnetworkinfo.localservices`.
+
+use blockchaininfo::models::network_info::{decode_service_flags, NetworkInfo};
+
+fn flags(hex: &str) -> Vec<String> {
+    decode_service_flags(hex).expect("valid hex")
+}
+
+#[test]
+fn decodes_typical_pruned_v2_node() {
+    assert_eq!(
+        flags("0000000000000c09"),
+        ["NETWORK", "WITNESS", "NETWORK_LIMITED", "P2P_V2"]
+    );
+}
+
+#[test]
+fn decodes_full_node_with_bloom_and_filters() {
+    assert_eq!(
+        flags("000000000000044d"),
+        ["NETWORK", "BLOOM", "WITNESS", "COMPACT_FILTERS", "NETWORK_LIMITED"]
+    );
+}
+
+#[test]
+fn reports_unknown_bits_like_core() {
+    assert_eq!(flags("0000000001000001"), ["NETWORK", "UNKNOWN[2^24]"]);
+}
+
+#[test]
+fn zero_and_invalid_input() {
+    assert!(flags("0000000000000000").is_empty());
+    assert_eq!(decode_service_flags(""), None);
+    assert_eq!(decode_service_flags("not-hex"), None);
+}
+
+#[test]
+fn falls_back_to_decoding_when_names_missing() {
+    let info = NetworkInfo {
+        localservices: "0000000000000409".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(info.local_service_names(), ["NETWORK", "WITNESS", "NETWORK_LIMITED"]);
+
+    let info = NetworkInfo {
+        localservices: "0000000000000409".to_string(),
+        localservicesnames: vec!["NETWORK".to_string()],
+        ..Default::default()
+    };
+    assert_eq!(info.local_service_names(), ["NETWORK"]);
+}
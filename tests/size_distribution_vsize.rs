@@ -0,0 +1,34 @@
+//! Size distribution by transaction count and by total vsize.
+
+mod common;
+
+use blockchaininfo::models::mempool_info::MempoolDistribution;
+use common::mempool_entry;
+use dashmap::DashMap;
+
+#[test]
+fn buckets_sum_vsize_alongside_counts() {
+    let cache = DashMap::new();
+    // Many small txs, one large one that outweighs them all in block space.
+    let sizes = [150, 200, 249, 150, 250, 1_000, 60_000];
+    for (i, vsize) in sizes.iter().enumerate() {
+        cache.insert([i as u8; 32], mempool_entry(0, *vsize));
+    }
+
+    let mut dist = MempoolDistribution::default();
+    dist.update_metrics(&cache);
+
+    assert_eq!((dist.small, dist.medium, dist.large), (4, 2, 1));
+    assert_eq!(
+        (dist.small_vsize, dist.medium_vsize, dist.large_vsize),
+        (749, 1_250, 60_000)
+    );
+}
+
+#[test]
+fn empty_cache_has_no_vsize() {
+    let mut dist = MempoolDistribution::default();
+    dist.update_metrics(&DashMap::new());
+
+    assert_eq!(dist.small_vsize + dist.medium_vsize + dist.large_vsize, 0);
+}
@@ -0,0 +1,23 @@
+//! JSON snapshot of the cached dashboard state.
+
+use blockchaininfo::export::snapshot_state;
+
+#[tokio::test]
+async fn snapshot_contains_every_section() {
+    let json = snapshot_state().await.unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    for key in [
+        "timestamp",
+        "blockchain",
+        "mempool",
+        "mempool_distribution",
+        "network",
+        "net_totals",
+        "peers",
+        "chain_tips",
+    ] {
+        assert!(value.get(key).is_some(), "missing {key}");
+    }
+    assert!(value["peers"].is_array());
+}
@@ -0,0 +1,46 @@
+//! Stale-tip alert: threshold, IBD and config defaults.
+
+use blockchaininfo::config::parse_config_str;
+use blockchaininfo::models::blockchain_info::BlockchainInfo;
+
+const TIP_TIME: u64 = 1_700_000_000;
+const THIRTY_MINS: u64 = 30 * 60;
+
+fn tip() -> BlockchainInfo {
+    BlockchainInfo { blocks: 820_000, time: TIP_TIME, ..Default::default() }
+}
+
+#[test]
+fn stale_at_and_after_threshold() {
+    assert!(!tip().tip_is_stale(TIP_TIME + THIRTY_MINS - 1, THIRTY_MINS));
+    assert!(tip().tip_is_stale(TIP_TIME + THIRTY_MINS, THIRTY_MINS));
+    assert!(tip().tip_is_stale(TIP_TIME + 3 * THIRTY_MINS, THIRTY_MINS));
+}
+
+#[test]
+fn never_stale_when_disabled_syncing_or_unknown() {
+    let far_future = TIP_TIME + 100 * THIRTY_MINS;
+    assert!(!tip().tip_is_stale(far_future, 0));
+
+    let syncing = BlockchainInfo { initialblockdownload: true, ..tip() };
+    assert!(!syncing.tip_is_stale(far_future, THIRTY_MINS));
+
+    // Nothing fetched yet.
+    assert!(!BlockchainInfo::default().tip_is_stale(far_future, THIRTY_MINS));
+}
+
+#[test]
+fn config_defaults_and_overrides() {
+    let base = "[bitcoin_rpc]\naddress = \"http://127.0.0.1:8332\"\n";
+    let config = parse_config_str(base).unwrap();
+    assert_eq!(config.stale_tip_alert_mins, 30);
+    assert!(!config.stale_tip_bell);
+
+    let config = parse_config_str(&format!(
+        "{}stale_tip_alert_mins = 45\nstale_tip_bell = true\n",
+        base
+    ))
+    .unwrap();
+    assert_eq!(config.stale_tip_alert_mins, 45);
+    assert!(config.stale_tip_bell);
+}
@@ -0,0 +1,23 @@
+//! IBD verification-progress gauge.
+
+use blockchaininfo::display::display_blockchain_info::sync_gauge;
+
+#[test]
+fn fills_proportionally() {
+    assert_eq!(sync_gauge(0.0, 10), "░░░░░░░░░░");
+    assert_eq!(sync_gauge(0.5, 10), "█████░░░░░");
+    assert_eq!(sync_gauge(1.0, 10), "██████████");
+}
+
+#[test]
+fn cells_fill_only_once_reached() {
+    // 0.999 of 10 cells is not yet the full bar.
+    assert_eq!(sync_gauge(0.999, 10), "█████████░");
+    assert_eq!(sync_gauge(0.09, 10), "░░░░░░░░░░");
+}
+
+#[test]
+fn out_of_range_is_clamped() {
+    assert_eq!(sync_gauge(-0.2, 4), "░░░░");
+    assert_eq!(sync_gauge(1.7, 4), "████");
+}
@@ -0,0 +1,81 @@
+//! `[theme]` config table and color parsing.
+
+use blockchaininfo::config::{parse_config_str, ThemeConfig};
+use blockchaininfo::ui::colors::{parse_color, Theme};
+use tui::style::Color;
+
+#[test]
+fn parses_names_and_hex() {
+    assert_eq!(parse_color("gray"), Some(Color::Gray));
+    assert_eq!(parse_color("DarkGray"), Some(Color::DarkGray));
+    assert_eq!(parse_color("dark_gray"), Some(Color::DarkGray));
+    assert_eq!(parse_color("light-blue"), Some(Color::LightBlue));
+    assert_eq!(parse_color("#1a2B3c"), Some(Color::Rgb(0x1a, 0x2b, 0x3c)));
+
+    assert_eq!(parse_color("#12345"), None);
+    assert_eq!(parse_color("#gggggg"), None);
+    assert_eq!(parse_color("teal"), None);
+}
+
+#[test]
+fn theme_table_next_to_bitcoin_rpc() {
+    let config = parse_config_str(
+        r##"
+        [bitcoin_rpc]
+        username = "u"
+        password = "p"
+        address = "http://127.0.0.1:8332"
+
+        [theme]
+        labels = "black"
+        separators = "#444444"
+        "##,
+    )
+    .unwrap();
+
+    let theme = config.theme.to_theme().unwrap();
+    assert_eq!(theme.labels, Some(Color::Black));
+    assert_eq!(theme.separators, Some(Color::Rgb(0x44, 0x44, 0x44)));
+    // Unlisted groups keep the built-in palette.
+    assert_eq!(theme.values, None);
+    assert_eq!(theme.critical, None);
+}
+
+#[test]
+fn defaults_without_table() {
+    let config = parse_config_str(
+        r#"
+        username = "u"
+        password = "p"
+        address = "http://127.0.0.1:8332"
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(config.theme, ThemeConfig::default());
+    assert_eq!(config.theme.to_theme().unwrap(), Theme::default());
+}
+
+#[test]
+fn bad_color_is_rejected() {
+    let theme = ThemeConfig {
+        warning: Some("orange".to_string()),
+        ..Default::default()
+    };
+    let err = theme.to_theme().unwrap_err().to_string();
+    assert!(err.contains("theme.warning"), "{err}");
+}
+
+#[test]
+fn unknown_group_is_an_error() {
+    let result = parse_config_str(
+        r#"
+        [bitcoin_rpc]
+        address = "http://127.0.0.1:8332"
+
+        [theme]
+        background = "white"
+        "#,
+    );
+    assert!(result.is_err());
+}
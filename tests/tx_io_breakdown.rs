@@ -0,0 +1,48 @@
+//! Per-input / per-output lines in the transaction lookup.
+
+use blockchaininfo::models::transaction_info::GetRawTransactionResponse;
+use serde_json::json;
+
+fn tx(value: serde_json::Value) -> GetRawTransactionResponse {
+    serde_json::from_value(value).expect("tx deserializes")
+}
+
+#[test]
+fn inputs_show_outpoint_or_coinbase() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/tx/coinbase.json");
+    let coinbase: GetRawTransactionResponse =
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+    assert_eq!(coinbase.vin[0].describe(), "coinbase");
+    // Genesis output is a bare pubkey: no address, type only.
+    assert_eq!(coinbase.vout[0].describe(), "#0  50.00000000 BTC  pubkey");
+
+    let spend = tx(json!({
+        "txid": "aa", "size": 1, "vsize": 1, "weight": 4, "version": 2,
+        "vin": [{ "txid": "bb".repeat(32), "vout": 3, "sequence": 0 }],
+        "vout": [],
+    }));
+    assert_eq!(spend.vin[0].describe(), format!("{}:3", "bb".repeat(32)));
+}
+
+#[test]
+fn outputs_show_address_from_either_field() {
+    let t = tx(json!({
+        "txid": "aa", "size": 1, "vsize": 1, "weight": 4, "version": 2,
+        "vin": [],
+        "vout": [
+            // Core 22+: single `address`.
+            { "value": 0.0015, "n": 0, "scriptPubKey": {
+                "type": "witness_v0_keyhash", "address": "bc1qexample" } },
+            // Older Core: `addresses` list.
+            { "value": 1.0, "n": 1, "scriptPubKey": {
+                "type": "pubkeyhash", "addresses": ["1Example"] } },
+            { "value": 0.0, "n": 2, "scriptPubKey": {
+                "asm": "OP_RETURN 6869", "type": "nulldata" } },
+        ],
+    }));
+
+    assert_eq!(t.vout[0].describe(), "#0  0.00150000 BTC  bc1qexample (witness_v0_keyhash)");
+    assert_eq!(t.vout[1].describe(), "#1  1.00000000 BTC  1Example (pubkeyhash)");
+    assert_eq!(t.vout[2].describe(), "#2  0.00000000 BTC  nulldata");
+    assert!(t.vout[0].is_spendable_by("bc1qexample"));
+}
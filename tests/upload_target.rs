@@ -0,0 +1,57 @@
+//! `getnettotals` upload-target parsing.
+
+use blockchaininfo::models::network_totals::NetTotalsJsonWrap;
+
+#[test]
+fn parses_upload_target() {
+    let json = r#"{
+        "result": {
+            "totalbytesrecv": 1000,
+            "totalbytessent": 2000,
+            "timemillis": 1700000000000,
+            "uploadtarget": {
+                "timeframe": 86400,
+                "target": 5000000000,
+                "target_reached": false,
+                "serve_historical_blocks": true,
+                "bytes_left_in_cycle": 1200000000,
+                "time_left_in_cycle": 3600
+            }
+        },
+        "error": null,
+        "id": "1"
+    }"#;
+
+    let totals = serde_json::from_str::<NetTotalsJsonWrap>(json).unwrap().result;
+    let upload = &totals.uploadtarget;
+
+    assert!(upload.is_set());
+    assert!(!upload.target_reached);
+    assert_eq!(upload.timeframe, 86_400);
+    assert_eq!(upload.bytes_left_in_cycle, 1_200_000_000);
+    assert_eq!(upload.time_left_in_cycle, 3_600);
+}
+
+#[test]
+fn unset_target_reads_as_not_set() {
+    let json = r#"{
+        "result": {
+            "totalbytesrecv": 1000,
+            "totalbytessent": 2000,
+            "timemillis": 1700000000000,
+            "uploadtarget": {
+                "timeframe": 86400,
+                "target": 0,
+                "target_reached": false,
+                "serve_historical_blocks": true,
+                "bytes_left_in_cycle": 0,
+                "time_left_in_cycle": 0
+            }
+        },
+        "error": null,
+        "id": "1"
+    }"#;
+
+    let totals = serde_json::from_str::<NetTotalsJsonWrap>(json).unwrap().result;
+    assert!(!totals.uploadtarget.is_set());
+}
@@ -0,0 +1,31 @@
+//! Node uptime: response parsing and the days/hours/minutes format.
+
+use blockchaininfo::models::uptime::{format_uptime, UptimeJsonWrap};
+
+#[test]
+fn formats_days_hours_minutes() {
+    assert_eq!(format_uptime(0), "0m");
+    assert_eq!(format_uptime(59), "0m");
+    assert_eq!(format_uptime(12 * 60 + 30), "12m");
+    assert_eq!(format_uptime(4 * 3_600), "4h 0m");
+    assert_eq!(format_uptime(3 * 86_400 + 4 * 3_600 + 12 * 60), "3d 4h 12m");
+    assert_eq!(format_uptime(86_400), "1d 0h 0m");
+}
+
+#[test]
+fn parses_result() {
+    let wrap: UptimeJsonWrap =
+        serde_json::from_str(r#"{"result":273120,"error":null,"id":"1"}"#).unwrap();
+    assert_eq!(wrap.result, Some(273_120));
+    assert!(wrap.error.is_none());
+}
+
+#[test]
+fn parses_method_not_found() {
+    let wrap: UptimeJsonWrap = serde_json::from_str(
+        r#"{"result":null,"error":{"code":-32601,"message":"Method not found"},"id":"1"}"#,
+    )
+    .unwrap();
+    assert_eq!(wrap.result, None);
+    assert_eq!(wrap.error.unwrap().code, -32601);
+}
@@ -0,0 +1,97 @@
+//! Watch list state machine: mempool presence, grace period, confirmation.
+
+use std::time::{Duration, Instant};
+
+use blockchaininfo::models::watch_list::{WatchEvent, WatchList, WatchStatus, WATCH_ABSENCE_GRACE};
+
+const TXID: &str = "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b";
+
+fn present(_: &[u8; 32]) -> bool {
+    true
+}
+
+fn absent(_: &[u8; 32]) -> bool {
+    false
+}
+
+fn event(status: WatchStatus) -> Vec<WatchEvent> {
+    vec![WatchEvent { txid: TXID.to_string(), status }]
+}
+
+/// A watch list whose txid entered the mempool at height 100, at `t0`.
+fn watching_in_mempool(t0: Instant) -> WatchList {
+    let mut list = WatchList::new(&[TXID.to_string()]);
+    assert_eq!(list.update(present, 100, t0), event(WatchStatus::InMempool));
+    list
+}
+
+fn status(list: &WatchList) -> WatchStatus {
+    list.entries()[0].status
+}
+
+#[test]
+fn stays_in_mempool_without_further_events() {
+    let t0 = Instant::now();
+    let mut list = watching_in_mempool(t0);
+
+    for secs in 1..=30 {
+        let tip = 100 + secs / 10;
+        assert!(list.update(present, tip, t0 + Duration::from_secs(secs)).is_empty());
+    }
+    assert_eq!(status(&list), WatchStatus::InMempool);
+}
+
+#[test]
+fn confirmation_waits_out_the_grace_period() {
+    let t0 = Instant::now();
+    let mut list = watching_in_mempool(t0);
+
+    // Mined in block 101: gone from the mempool, but not yet for long enough.
+    assert!(list.update(absent, 101, t0 + Duration::from_secs(1)).is_empty());
+    let almost = t0 + Duration::from_secs(1) + WATCH_ABSENCE_GRACE - Duration::from_millis(1);
+    assert!(list.update(absent, 101, almost).is_empty());
+    assert_eq!(status(&list), WatchStatus::InMempool);
+
+    let expired = t0 + Duration::from_secs(1) + WATCH_ABSENCE_GRACE;
+    assert_eq!(
+        list.update(absent, 101, expired),
+        event(WatchStatus::Confirmed { height: 101 })
+    );
+
+    // Confirmed is final, even if a stale cache read shows it again.
+    assert!(list.update(present, 101, expired + Duration::from_secs(1)).is_empty());
+}
+
+#[test]
+fn drops_out_after_grace_period_without_a_block() {
+    let t0 = Instant::now();
+    let mut list = watching_in_mempool(t0);
+
+    assert!(list.update(absent, 100, t0 + Duration::from_secs(1)).is_empty());
+    assert_eq!(
+        list.update(absent, 100, t0 + Duration::from_secs(1) + WATCH_ABSENCE_GRACE),
+        event(WatchStatus::LeftMempool)
+    );
+
+    // A block mined while it is still gone is taken as confirming it.
+    assert_eq!(
+        list.update(absent, 101, t0 + Duration::from_secs(20)),
+        event(WatchStatus::Confirmed { height: 101 })
+    );
+}
+
+#[test]
+fn reappearing_inside_grace_period_resets_the_clock() {
+    let t0 = Instant::now();
+    let mut list = watching_in_mempool(t0);
+
+    // A miss during a cache rebuild, then back on the next read.
+    assert!(list.update(absent, 100, t0 + Duration::from_secs(1)).is_empty());
+    assert!(list.update(present, 100, t0 + Duration::from_secs(2)).is_empty());
+    assert_eq!(status(&list), WatchStatus::InMempool);
+
+    // The next miss starts a fresh grace period rather than resuming the old one.
+    let missed_again = t0 + Duration::from_secs(1) + WATCH_ABSENCE_GRACE;
+    assert!(list.update(absent, 100, missed_again).is_empty());
+    assert_eq!(status(&list), WatchStatus::InMempool);
+}
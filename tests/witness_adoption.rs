@@ -0,0 +1,68 @@
+//! SegWit/Taproot adoption counted from a verbose=2 block.
+
+use blockchaininfo::models::block_info::BlockInfoFull;
+use blockchaininfo::models::witness_adoption::WitnessAdoption;
+use serde_json::{json, Value};
+
+fn output(script_type: &str) -> Value {
+    json!({"value": 0.001, "n": 0, "scriptPubKey": {"asm": "", "desc": "", "hex": "", "type": script_type}})
+}
+
+fn tx(vin: Value, vout: Vec<Value>) -> Value {
+    json!({
+        "txid": "00", "hash": "00", "version": 2, "size": 200, "vsize": 150,
+        "weight": 600, "locktime": 0, "vin": [vin], "vout": vout
+    })
+}
+
+fn block(txs: Vec<Value>) -> BlockInfoFull {
+    serde_json::from_value(json!({
+        "hash": "00", "confirmations": 1, "height": 820_000, "version": 0x2000_0000u32,
+        "versionHex": "20000000", "merkleroot": "00", "time": 1_700_000_000u64,
+        "mediantime": 1_700_000_000u64, "nonce": 0, "bits": "17034219", "difficulty": 1.0,
+        "chainwork": "00", "nTx": txs.len(), "strippedsize": 0, "size": 0, "weight": 0,
+        "tx": txs
+    }))
+    .unwrap()
+}
+
+fn coinbase() -> Value {
+    // Carries the witness reserved value, like every SegWit-era coinbase.
+    tx(
+        json!({"coinbase": "03a0830c", "txinwitness": ["00".repeat(32)], "sequence": 0xffff_ffffu32}),
+        vec![output("witness_v1_taproot"), output("nulldata")],
+    )
+}
+
+fn spend(witness: bool) -> Value {
+    let mut vin = json!({"txid": "11", "vout": 0, "scriptSig": {"asm": "", "hex": ""}, "sequence": 0});
+    if witness {
+        vin["txinwitness"] = json!(["3044", "02ab"]);
+    }
+    vin
+}
+
+#[test]
+fn counts_witness_spends_and_taproot_outputs() {
+    let adoption = WitnessAdoption::from_block(&block(vec![
+        coinbase(),
+        tx(spend(true), vec![output("witness_v1_taproot"), output("witness_v0_keyhash")]),
+        tx(spend(true), vec![output("witness_v0_keyhash")]),
+        tx(spend(true), vec![output("scripthash")]),
+        tx(spend(false), vec![output("pubkeyhash"), output("witness_v1_taproot")]),
+    ]));
+
+    assert_eq!(adoption.height, 820_000);
+    // The coinbase counts toward neither ratio.
+    assert_eq!((adoption.txs, adoption.segwit_txs), (4, 3));
+    assert_eq!((adoption.outputs, adoption.taproot_outputs), (6, 2));
+    assert_eq!(adoption.segwit_pct(), Some(75.0));
+    assert!((adoption.taproot_outputs_pct().unwrap() - 100.0 / 3.0).abs() < 1e-9);
+}
+
+#[test]
+fn coinbase_only_block_has_no_ratios() {
+    let adoption = WitnessAdoption::from_block(&block(vec![coinbase()]));
+    assert_eq!(adoption.segwit_pct(), None);
+    assert_eq!(adoption.taproot_outputs_pct(), None);
+}